@@ -0,0 +1,153 @@
+use crate::{model_spec, parse_model_name, ModelConfig};
+use clap::Args;
+use core::models::{price_batch, Greeks, OptionType, PricedOption, PricingRequest};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Prices many options from one file and writes their prices and Greeks to another.
+#[derive(Args)]
+pub struct BatchOpts {
+    /// The requests: CSV with the columns `option_type,s,k,r,sigma,t` and an optional
+    /// `id`, or a JSON array of objects with those fields.
+    input: PathBuf,
+    /// Where to write the results, as CSV or JSON by extension.
+    output: PathBuf,
+    /// The model to price with.
+    #[arg(long, value_parser = parse_model_name, default_value = "black_scholes")]
+    model: String,
+    /// Pricing threads; one per core by default.
+    #[arg(long)]
+    threads: Option<usize>,
+}
+
+/// A result as written to JSON: the request's fields, then the price and Greeks.
+#[derive(Serialize)]
+struct Record<'a> {
+    #[serde(flatten)]
+    request: &'a PricingRequest,
+    price: f64,
+    #[serde(flatten)]
+    greeks: &'a Greeks,
+}
+
+const COLUMNS: [&str; 13] = [
+    "id",
+    "option_type",
+    "s",
+    "k",
+    "r",
+    "sigma",
+    "t",
+    "price",
+    "delta",
+    "gamma",
+    "vega",
+    "theta",
+    "rho",
+];
+
+fn is_csv(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"))
+}
+
+impl BatchOpts {
+    /// The files the requests are read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        vec![self.input.clone()]
+    }
+}
+
+pub fn run(opts: &BatchOpts, config: &ModelConfig) -> Result<String, String> {
+    let requests = read_requests(&opts.input)?;
+    let model = model_spec(&opts.model, config)
+        .expect("model names are validated")
+        .shared();
+    let threads = opts
+        .threads
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+
+    let bar = ProgressBar::new(requests.len() as u64).with_style(
+        ProgressStyle::with_template("{bar:40} {pos}/{len} options, {per_sec}, eta {eta}")
+            .expect("progress template is valid"),
+    );
+    let priced = price_batch(model.as_ref(), &requests, threads, |done| {
+        bar.set_position(done as u64)
+    });
+    bar.finish_and_clear();
+
+    write_results(&opts.output, &priced)?;
+    Ok(format!(
+        "priced {} options with {} into {}\n",
+        priced.len(),
+        opts.model,
+        opts.output.display()
+    ))
+}
+
+fn read_requests(path: &Path) -> Result<Vec<PricingRequest>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    if is_csv(path) {
+        csv::Reader::from_reader(text.as_bytes())
+            .deserialize()
+            .collect::<Result<_, _>>()
+            .map_err(|err| format!("invalid CSV in {}: {}", path.display(), err))
+    } else {
+        serde_json::from_str(&text)
+            .map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))
+    }
+}
+
+fn write_results(path: &Path, priced: &[PricedOption]) -> Result<(), String> {
+    let write_error =
+        |err: &dyn std::fmt::Display| format!("cannot write {}: {}", path.display(), err);
+    if !is_csv(path) {
+        let records: Vec<Record> = priced
+            .iter()
+            .map(|priced| Record {
+                request: &priced.request,
+                price: priced.price,
+                greeks: &priced.greeks,
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&records).expect("results serialize as JSON");
+        return std::fs::write(path, json + "\n").map_err(|err| write_error(&err));
+    }
+    let mut writer = csv::Writer::from_path(path).map_err(|err| write_error(&err))?;
+    writer
+        .write_record(COLUMNS)
+        .map_err(|err| write_error(&err))?;
+    for priced in priced {
+        let request = &priced.request;
+        let g = &priced.greeks;
+        let numbers = [
+            request.s,
+            request.k,
+            request.r,
+            request.sigma,
+            request.t,
+            priced.price,
+            g.delta,
+            g.gamma,
+            g.vega,
+            g.theta,
+            g.rho,
+        ];
+        let option_type = match request.option_type {
+            OptionType::Call => "call",
+            OptionType::Put => "put",
+        };
+        let record = [
+            request.id.clone().unwrap_or_default(),
+            option_type.to_string(),
+        ]
+        .into_iter()
+        .chain(numbers.iter().map(f64::to_string));
+        writer
+            .write_record(record)
+            .map_err(|err| write_error(&err))?;
+    }
+    writer.flush().map_err(|err| write_error(&err))
+}
@@ -0,0 +1,337 @@
+use crate::output::{self, OutputFormat};
+use crate::theme::Theme;
+use crate::{model_spec, parse_model_name, ModelConfig};
+use clap::Args;
+use core::chain::{OptionChain, OptionQuote};
+use core::models::{Greeks, OptionType};
+use core::strategies::ModelSpec;
+use crossterm::event::{self, Event, KeyCode};
+use ratatui::{
+    backend::Backend,
+    layout::{Constraint, Rect},
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame, Terminal,
+};
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Loads an option chain and shows its implied volatilities and Greeks as a strike
+/// ladder, calls on the left and puts on the right.
+#[derive(Args)]
+pub struct ChainOpts {
+    /// The chain as JSON (`spot`, `rate` and `quotes`), or as CSV with the columns
+    /// `option_type,strike,expiry,bid,ask` and optionally `volume,open_interest`.
+    file: PathBuf,
+    /// The underlying price; required for CSV, overrides the JSON's.
+    #[arg(short, long)]
+    spot: Option<f64>,
+    /// The risk-free rate; required for CSV, overrides the JSON's.
+    #[arg(short, long)]
+    rate: Option<f64>,
+    /// The model to imply volatilities with.
+    #[arg(long, value_parser = parse_model_name, default_value = "black_scholes")]
+    model: String,
+    /// Print every quote in this format and exit instead of browsing the ladder.
+    #[arg(short, long, value_enum)]
+    output: Option<OutputFormat>,
+}
+
+/// A quote with the volatility implied by its mid price and the Greeks at that
+/// volatility; both are `None` when the mid price has no implied volatility.
+#[derive(Clone, Debug, Serialize)]
+pub struct ChainRow {
+    #[serde(flatten)]
+    quote: OptionQuote,
+    implied_vol: Option<f64>,
+    greeks: Option<Greeks>,
+}
+
+impl ChainOpts {
+    /// Whether to browse the ladder rather than print the quotes.
+    pub fn is_interactive(&self) -> bool {
+        self.output.is_none()
+    }
+
+    /// The files the chain is read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        vec![self.file.clone()]
+    }
+}
+
+/// Prints every quote in the `--output` format.
+pub fn run(opts: &ChainOpts, config: &ModelConfig) -> Result<String, String> {
+    let format = opts.output.expect("interactive runs browse the ladder");
+    Ok(render(format, &load(opts, config)?))
+}
+
+/// Reads the chain and values every quote with the chosen model.
+pub fn load(opts: &ChainOpts, config: &ModelConfig) -> Result<Vec<ChainRow>, String> {
+    let model = model_spec(&opts.model, config).expect("model names are validated");
+    load_file(&opts.file, opts.spot, opts.rate, &model)
+}
+
+/// Reads the chain at `path`, overriding its spot and rate when given, and values every
+/// quote with `model`.
+pub fn load_file(
+    path: &Path,
+    spot: Option<f64>,
+    rate: Option<f64>,
+    model: &ModelSpec,
+) -> Result<Vec<ChainRow>, String> {
+    let chain = read_chain(path, spot, rate)?;
+    let model = model.build();
+    Ok(chain
+        .implied_vols(model.as_ref())
+        .into_iter()
+        .map(|implied| {
+            let greeks = implied.implied_vol.map(|sigma| {
+                let params = chain.params(&implied.quote, sigma);
+                model.option_greeks(&params, implied.quote.option_type)
+            });
+            ChainRow {
+                quote: implied.quote,
+                implied_vol: implied.implied_vol,
+                greeks,
+            }
+        })
+        .collect())
+}
+
+fn read_chain(path: &Path, spot: Option<f64>, rate: Option<f64>) -> Result<OptionChain, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let is_csv = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    let mut chain = if is_csv {
+        let (Some(spot), Some(rate)) = (spot, rate) else {
+            return Err("a CSV chain needs --spot and --rate".to_string());
+        };
+        let quotes: Result<Vec<OptionQuote>, csv::Error> =
+            csv::Reader::from_reader(text.as_bytes())
+                .deserialize()
+                .collect();
+        OptionChain {
+            spot,
+            rate,
+            quotes: quotes.map_err(|err| format!("invalid CSV in {}: {}", path.display(), err))?,
+        }
+    } else {
+        serde_json::from_str(&text)
+            .map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))?
+    };
+    if let Some(spot) = spot {
+        chain.spot = spot;
+    }
+    if let Some(rate) = rate {
+        chain.rate = rate;
+    }
+    Ok(chain)
+}
+
+const COLUMNS: [&str; 12] = [
+    "type",
+    "strike",
+    "expiry",
+    "bid",
+    "ask",
+    "implied_vol",
+    "delta",
+    "gamma",
+    "vega",
+    "theta",
+    "rho",
+    "open_interest",
+];
+
+/// Formats one line per quote, in chain order.
+pub fn render(format: OutputFormat, rows: &[ChainRow]) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(rows).expect("chain serializes as JSON") + "\n"
+        }
+        OutputFormat::Csv => output::csv(&COLUMNS, &cells(rows, |x| x.to_string())),
+        OutputFormat::Table => output::table(&COLUMNS, &cells(rows, |x| format!("{:.4}", x))),
+    }
+}
+
+fn cells(rows: &[ChainRow], number: impl Fn(f64) -> String) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|row| {
+            let q = &row.quote;
+            let optional = |value: Option<f64>| value.map_or_else(String::new, &number);
+            let greek = |pick: fn(&Greeks) -> f64| optional(row.greeks.as_ref().map(pick));
+            vec![
+                type_name(q.option_type).to_string(),
+                number(q.strike),
+                number(q.expiry),
+                number(q.bid),
+                number(q.ask),
+                optional(row.implied_vol),
+                greek(|g| g.delta),
+                greek(|g| g.gamma),
+                greek(|g| g.vega),
+                greek(|g| g.theta),
+                greek(|g| g.rho),
+                number(q.open_interest),
+            ]
+        })
+        .collect()
+}
+
+fn type_name(option_type: OptionType) -> &'static str {
+    match option_type {
+        OptionType::Call => "call",
+        OptionType::Put => "put",
+    }
+}
+
+/// The browsable strike ladder: one expiry at a time, one row per strike.
+pub struct Ladder {
+    rows: Vec<ChainRow>,
+    expiries: Vec<f64>,
+    expiry: usize,
+    table_state: TableState,
+}
+
+impl Ladder {
+    pub fn new(rows: Vec<ChainRow>) -> Self {
+        let mut expiries: Vec<f64> = rows.iter().map(|row| row.quote.expiry).collect();
+        expiries.sort_by(|a, b| a.total_cmp(b));
+        expiries.dedup();
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+        Self {
+            rows,
+            expiries,
+            expiry: 0,
+            table_state,
+        }
+    }
+
+    /// The strikes of the current expiry with their call and put, lowest first.
+    fn strikes(&self) -> Vec<(f64, Option<&ChainRow>, Option<&ChainRow>)> {
+        let Some(&expiry) = self.expiries.get(self.expiry) else {
+            return Vec::new();
+        };
+        let mut strikes: Vec<(f64, Option<&ChainRow>, Option<&ChainRow>)> = Vec::new();
+        for row in self.rows.iter().filter(|row| row.quote.expiry == expiry) {
+            let strike = row.quote.strike;
+            let i = match strikes.iter().position(|(k, _, _)| *k == strike) {
+                Some(i) => i,
+                None => {
+                    strikes.push((strike, None, None));
+                    strikes.len() - 1
+                }
+            };
+            match row.quote.option_type {
+                OptionType::Call => strikes[i].1 = Some(row),
+                OptionType::Put => strikes[i].2 = Some(row),
+            }
+        }
+        strikes.sort_by(|a, b| a.0.total_cmp(&b.0));
+        strikes
+    }
+
+    pub fn move_row(&mut self, step: isize) {
+        let len = self.strikes().len();
+        if len == 0 {
+            return;
+        }
+        let i = self.table_state.selected().unwrap_or(0) as isize;
+        self.table_state
+            .select(Some((i + step).rem_euclid(len as isize) as usize));
+    }
+
+    pub fn move_expiry(&mut self, step: isize) {
+        if self.expiries.is_empty() {
+            return;
+        }
+        let len = self.expiries.len() as isize;
+        self.expiry = (self.expiry as isize + step).rem_euclid(len) as usize;
+        let strikes = self.strikes().len();
+        if self.table_state.selected().unwrap_or(0) >= strikes {
+            self.table_state.select(Some(strikes.saturating_sub(1)));
+        }
+    }
+}
+
+pub fn run_ladder<B: Backend>(terminal: &mut Terminal<B>, ladder: &mut Ladder) -> io::Result<()> {
+    loop {
+        terminal.draw(|f| render_ladder(f, f.area(), ladder, &Theme::default()))?;
+        if let Event::Key(key) = event::read()? {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down => ladder.move_row(1),
+                KeyCode::Up => ladder.move_row(-1),
+                KeyCode::Right => ladder.move_expiry(1),
+                KeyCode::Left => ladder.move_expiry(-1),
+                _ => {}
+            }
+        }
+    }
+}
+
+const CALL_COLUMNS: [&str; 7] = ["Bid", "Ask", "IV", "Delta", "Gamma", "Vega", "Theta"];
+
+/// A side's cells, ordered outwards from the strike column when `mirrored`.
+fn side_cells(row: Option<&ChainRow>, mirrored: bool) -> Vec<Cell<'static>> {
+    let mut cells: Vec<String> = match row {
+        None => vec![String::new(); CALL_COLUMNS.len()],
+        Some(row) => {
+            let optional =
+                |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{:.4}", v));
+            let g = row.greeks.as_ref();
+            vec![
+                format!("{:.2}", row.quote.bid),
+                format!("{:.2}", row.quote.ask),
+                row.implied_vol
+                    .map_or_else(|| "-".to_string(), |v| format!("{:.2}%", v * 100.0)),
+                optional(g.map(|g| g.delta)),
+                optional(g.map(|g| g.gamma)),
+                optional(g.map(|g| g.vega)),
+                optional(g.map(|g| g.theta)),
+            ]
+        }
+    };
+    if mirrored {
+        cells.reverse();
+    }
+    cells.into_iter().map(Cell::from).collect()
+}
+
+/// Draws the current expiry of `ladder` into `area`.
+pub fn render_ladder(f: &mut Frame, area: Rect, ladder: &Ladder, theme: &Theme) {
+    let bold = theme.heading();
+    let header = Row::new(
+        CALL_COLUMNS
+            .iter()
+            .copied()
+            .chain(["Strike"])
+            .chain(CALL_COLUMNS.iter().rev().copied())
+            .map(|title| Cell::from(title).style(bold)),
+    )
+    .style(theme.header_row());
+    let rows = ladder.strikes().into_iter().map(|(strike, call, put)| {
+        let mut cells = side_cells(call, false);
+        cells.push(Cell::from(format!("{:.2}", strike)).style(bold));
+        cells.extend(side_cells(put, true));
+        Row::new(cells)
+    });
+    let widths = [Constraint::Ratio(1, 15); 15];
+    let title = match ladder.expiries.get(ladder.expiry) {
+        Some(expiry) => format!(
+            "Calls | expiry {:.4}y ({}/{}, ←/→ to change) | Puts",
+            expiry,
+            ladder.expiry + 1,
+            ladder.expiries.len()
+        ),
+        None => "empty chain".to_string(),
+    };
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::TOP).title(title))
+        .highlight_style(theme.selected());
+    f.render_stateful_widget(table, area, &mut ladder.table_state.clone());
+}
@@ -0,0 +1,231 @@
+use crate::theme::Theme;
+use core::models::{OptionParameters, OptionPricingModel, OptionType};
+use core::strategies::StrategyDefinition;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    symbols::Marker,
+    text::Line,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType},
+    Frame,
+};
+
+/// Number of underlying prices plotted across the chart.
+const POINTS: usize = 121;
+/// The plotted underlying prices run from `SPAN` to `2 - SPAN` times spot.
+const SPAN: f64 = 0.5;
+
+/// What the payoff pane plots: a single option of the selected model, or a strategy
+/// valued with it.
+pub enum Subject<'a> {
+    Option(OptionType),
+    Strategy(&'a StrategyDefinition),
+}
+
+/// The payoff at expiry and the value today of a position across underlying prices.
+pub struct Curves {
+    pub title: String,
+    pub expiry: Vec<(f64, f64)>,
+    pub today: Vec<(f64, f64)>,
+}
+
+impl Curves {
+    pub fn new(
+        subject: &Subject,
+        model_name: &str,
+        model: &dyn OptionPricingModel,
+        params: &OptionParameters,
+    ) -> Self {
+        match subject {
+            Subject::Option(option_type) => {
+                let grid = grid(params.s);
+                let price = |spot: f64| {
+                    let params = OptionParameters {
+                        s: spot,
+                        ..params.clone()
+                    };
+                    model.option_price(&params, *option_type)
+                };
+                let intrinsic = |spot: f64| match option_type {
+                    OptionType::Call => (spot - params.k).max(0.0),
+                    OptionType::Put => (params.k - spot).max(0.0),
+                };
+                Curves {
+                    title: format!(
+                        "{} {} (K={})",
+                        model_name,
+                        option_name(*option_type),
+                        params.k
+                    ),
+                    expiry: grid.iter().map(|&s| (s, intrinsic(s))).collect(),
+                    today: grid.iter().map(|&s| (s, price(s))).collect(),
+                }
+            }
+            Subject::Strategy(definition) => {
+                let spot = definition
+                    .legs
+                    .first()
+                    .map(|leg| leg.params.s)
+                    .or_else(|| definition.stock.first().map(|stock| stock.spot))
+                    .unwrap_or(params.s);
+                let grid = grid(spot);
+                let strategy = definition.strategy(model);
+                Curves {
+                    title: format!("{} strategy", model_name),
+                    expiry: strategy.payoff_curve(&grid),
+                    today: grid
+                        .iter()
+                        .map(|&s| (s, strategy.value_at(s, 0.0)))
+                        .collect(),
+                }
+            }
+        }
+    }
+}
+
+/// Evenly spaced underlying prices around `spot`.
+fn grid(spot: f64) -> Vec<f64> {
+    let (low, high) = (spot * SPAN, spot * (2.0 - SPAN));
+    let step = (high - low) / (POINTS - 1) as f64;
+    (0..POINTS).map(|i| low + step * i as f64).collect()
+}
+
+/// One plotted line: its legend name, color and points.
+pub type Series<'a> = (&'a str, Color, &'a [(f64, f64)]);
+
+/// Greeks the Greeks pane can plot, in toggle order.
+pub const GREEKS: [&str; 4] = ["delta", "gamma", "vega", "theta"];
+
+/// The Greeks of an option across underlying prices around today's spot.
+pub struct GreekCurves {
+    pub title: String,
+    /// One curve per entry of `GREEKS`, or `None` when that Greek is hidden.
+    pub curves: Vec<Option<Vec<(f64, f64)>>>,
+}
+
+impl GreekCurves {
+    pub fn new(
+        model_name: &str,
+        model: &dyn OptionPricingModel,
+        params: &OptionParameters,
+        option_type: OptionType,
+        shown: &[bool; 4],
+    ) -> Self {
+        let greeks: Vec<(f64, _)> = grid(params.s)
+            .into_iter()
+            .map(|spot| {
+                let params = OptionParameters {
+                    s: spot,
+                    ..params.clone()
+                };
+                (spot, model.option_greeks(&params, option_type))
+            })
+            .collect();
+        let curves = shown
+            .iter()
+            .enumerate()
+            .map(|(i, &shown)| {
+                shown.then(|| {
+                    greeks
+                        .iter()
+                        .map(|(spot, g)| {
+                            let value = [g.delta, g.gamma, g.vega, g.theta][i];
+                            (*spot, value)
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        GreekCurves {
+            title: format!(
+                "{} {} Greeks (K={})",
+                model_name,
+                option_name(option_type),
+                params.k
+            ),
+            curves,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let series: Vec<Series> = self
+            .curves
+            .iter()
+            .enumerate()
+            .filter_map(|(i, curve)| {
+                curve
+                    .as_deref()
+                    .map(|points| (GREEKS[i], theme.series[i], points))
+            })
+            .collect();
+        render(f, area, &self.title, &series);
+    }
+}
+
+fn option_name(option_type: OptionType) -> &'static str {
+    match option_type {
+        OptionType::Call => "call",
+        OptionType::Put => "put",
+    }
+}
+
+impl Curves {
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &Theme) {
+        let series = [
+            ("expiry", theme.series[0], self.expiry.as_slice()),
+            ("T+0", theme.series[1], self.today.as_slice()),
+        ];
+        render(f, area, &self.title, &series);
+    }
+}
+
+/// Plots `series` as lines on shared axes fitted to all of them.
+fn render(f: &mut Frame, area: Rect, title: &str, series: &[Series]) {
+    let points = series.iter().flat_map(|(_, _, points)| points.iter());
+    let (x_min, x_max) = bounds(points.clone().map(|&(x, _)| x));
+    let (y_min, y_max) = bounds(points.map(|&(_, y)| y));
+    let labels = |min: f64, max: f64| {
+        [min, (min + max) / 2.0, max].map(|value| Line::from(format!("{:.2}", value)))
+    };
+    let datasets = series
+        .iter()
+        .map(|&(name, color, points)| {
+            Dataset::default()
+                .name(name)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(points)
+        })
+        .collect();
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::TOP).title(title))
+        .x_axis(
+            Axis::default()
+                .title("underlying")
+                .bounds([x_min, x_max])
+                .labels(labels(x_min, x_max)),
+        )
+        .y_axis(
+            Axis::default()
+                .bounds([y_min, y_max])
+                .labels(labels(y_min, y_max)),
+        );
+    f.render_widget(chart, area);
+}
+
+/// The range of `values`, widened when flat so the curve stays visible.
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if !min.is_finite() {
+        (0.0, 1.0)
+    } else if max - min < 1e-9 {
+        (min - 1.0, max + 1.0)
+    } else {
+        (min, max)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::stress::Shock;
+use crate::theme::ThemeName;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Settings read from the `--config` file, a JSON object such as
+/// `{"theme": "high-contrast", "stress": {"crash": "-10% spot +5 vol"}}`; every field is
+/// optional.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CliConfig {
+    pub theme: Option<ThemeName>,
+    /// The named shocks `stress` runs.
+    pub stress: BTreeMap<String, Shock>,
+}
+
+/// Reads the config file at `path`.
+pub fn read(path: &Path) -> Result<CliConfig, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    serde_json::from_str(&text)
+        .map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn write(text: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_reads_theme() {
+        let file = write(r#"{"theme": "high-contrast"}"#);
+        assert_eq!(
+            read(file.path()).unwrap().theme,
+            Some(ThemeName::HighContrast)
+        );
+        assert_eq!(read(write("{}").path()).unwrap().theme, None);
+    }
+
+    #[test]
+    fn test_reads_named_shocks() {
+        let file = write(r#"{"stress": {"crash": "-10% spot +5 vol", "calm": "-2 vol"}}"#);
+        let config = read(file.path()).unwrap();
+        let names: Vec<&str> = config.stress.keys().map(String::as_str).collect();
+        assert_eq!(names, ["calm", "crash"]);
+        assert_eq!(config.stress["crash"], "-10% spot +5 vol".parse().unwrap());
+        assert!(read(write(r#"{"stress": {"crash": "-10 spot"}}"#).path()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_unknown_fields_and_unreadable_files() {
+        assert!(read(write(r#"{"colour": "dark"}"#).path()).is_err());
+        assert!(read(write(r#"{"theme": "sepia"}"#).path()).is_err());
+        assert!(read(Path::new("/nonexistent/cli.json")).is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use clap::Args;
+use flow::graph::DecisionGraphBuilder;
+use flow::rule::DecisionReader;
+use serde_json::Value;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Evaluates a decision flow against an input document.
+#[derive(Args)]
+pub struct FlowOpts {
+    /// The flow definition, as JSON or YAML.
+    file: PathBuf,
+    /// The JSON input to evaluate, or `-` for stdin.
+    #[arg(long, default_value = "-")]
+    input: PathBuf,
+}
+
+impl FlowOpts {
+    /// The flow, the files its decisions refer to and the input, if not stdin.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        let mut inputs = vec![self.file.clone()];
+        if let Ok(decisions) = DecisionReader::read_flow_refs(&self.file) {
+            inputs.extend(
+                decisions
+                    .into_iter()
+                    .filter(|decision| {
+                        matches!(
+                            decision.kind.as_str(),
+                            "table" | "function" | "flow" | "loop"
+                        )
+                    })
+                    .map(|decision| PathBuf::from(decision.rules)),
+            );
+        }
+        if self.input.as_os_str() != "-" {
+            inputs.push(self.input.clone());
+        }
+        inputs
+    }
+}
+
+/// Builds the flow, evaluates it and returns its result as pretty JSON.
+pub async fn run(opts: &FlowOpts) -> Result<String, String> {
+    let decisions = DecisionReader::read_flow(&opts.file)
+        .await
+        .map_err(|err| format!("cannot read {}: {}", opts.file.display(), err))?;
+    let content = DecisionGraphBuilder::new()
+        .try_build(decisions)
+        .map_err(|errors| {
+            let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+            format!(
+                "invalid flow {}: {}",
+                opts.file.display(),
+                errors.join("; ")
+            )
+        })?;
+    let input = read_input(&opts.input)?;
+    let response = flow::iteration::engine()
+        .create_decision(Arc::new(content))
+        .evaluate(&input)
+        .await
+        .map_err(|err| format!("evaluating {} failed: {}", opts.file.display(), err))?;
+    Ok(serde_json::to_string_pretty(&response.result).expect("results serialize as JSON") + "\n")
+}
+
+fn read_input(path: &PathBuf) -> Result<Value, String> {
+    let text = if path.as_os_str() == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|err| format!("cannot read stdin: {}", err))?;
+        text
+    } else {
+        std::fs::read_to_string(path)
+            .map_err(|err| format!("cannot read {}: {}", path.display(), err))?
+    };
+    serde_json::from_str(&text).map_err(|err| format!("invalid JSON input: {}", err))
+}
@@ -0,0 +1,74 @@
+use chrono::{Local, NaiveDate};
+use clap::Args;
+use core::time::{time_to_expiry, DayCount};
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// An expiry date to derive `t` from, in place of a year fraction.
+#[derive(Args, Clone, Debug)]
+pub struct ExpiryOpts {
+    /// The expiry date, e.g. `2025-12-19`, instead of `-t`.
+    #[arg(long, conflicts_with = "t")]
+    expiry: Option<NaiveDate>,
+    /// The valuation date `--expiry` is counted from; today by default.
+    #[arg(long)]
+    valuation_date: Option<NaiveDate>,
+    /// How `--expiry` is turned into years: ACT/365 (the default), ACT/360, 30/360 or
+    /// BUS/252.
+    #[arg(long)]
+    day_count: Option<DayCount>,
+}
+
+/// The dates behind a derived `t`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ExpiryDates {
+    pub expiry: NaiveDate,
+    pub valuation_date: NaiveDate,
+    #[serde(serialize_with = "display")]
+    pub day_count: DayCount,
+    pub t: f64,
+}
+
+fn display<S: Serializer>(value: &DayCount, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+impl ExpiryOpts {
+    /// Derives `t` from `--expiry`, or returns `None` when `t` was given directly.
+    pub fn resolve(&self) -> Result<Option<ExpiryDates>, String> {
+        // Checked here rather than with clap's `requires`, which clap drops because
+        // `--expiry` conflicts with `-t`.
+        let Some(expiry) = self.expiry else {
+            if self.valuation_date.is_some() || self.day_count.is_some() {
+                return Err("--valuation-date and --day-count need --expiry".to_string());
+            }
+            return Ok(None);
+        };
+        let day_count = self.day_count.unwrap_or(DayCount::Act365Fixed);
+        let valuation_date = self
+            .valuation_date
+            .unwrap_or_else(|| Local::now().date_naive());
+        if expiry <= valuation_date {
+            return Err(format!(
+                "expiry {} is not after the valuation date {}",
+                expiry, valuation_date
+            ));
+        }
+        Ok(Some(ExpiryDates {
+            expiry,
+            valuation_date,
+            day_count,
+            t: time_to_expiry(valuation_date, expiry, day_count),
+        }))
+    }
+}
+
+impl fmt::Display for ExpiryDates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expiry {} from {} ({}): t={:.4}",
+            self.expiry, self.valuation_date, self.day_count, self.t
+        )
+    }
+}
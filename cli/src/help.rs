@@ -0,0 +1,90 @@
+use crate::tabs::Tab;
+use crate::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table},
+    Frame,
+};
+
+/// A key binding: its keys, what it does, and the tabs it works in, or none for all.
+type Binding = (&'static str, &'static str, &'static [Tab]);
+
+/// Tabs whose view follows the model selected in the table.
+const MODEL_TABS: &[Tab] = &[Tab::Models, Tab::Strategy, Tab::Scenarios, Tab::Portfolio];
+
+/// Every key binding of the TUI, in legend order.
+const BINDINGS: [Binding; 14] = [
+    ("↑/↓", "select model", MODEL_TABS),
+    ("c", "toggle call/put", &[Tab::Models, Tab::Scenarios]),
+    ("v", "toggle payoff/Greeks chart", &[Tab::Models]),
+    ("b", "toggle Black-Scholes diff columns", &[Tab::Models]),
+    ("1-4", "toggle delta/gamma/vega/theta", &[Tab::Models]),
+    ("s/S", "sort by next column/reverse", &[Tab::Models]),
+    ("/", "filter models by name", &[Tab::Models]),
+    ("↑/↓", "select strike", &[Tab::Chain]),
+    ("←/→", "select expiry", &[Tab::Chain]),
+    ("g", "next scenario measure", &[Tab::Scenarios]),
+    ("Tab/S-Tab", "next/previous view", &[]),
+    ("t", "next color theme", &[]),
+    ("?", "toggle help", &[]),
+    ("q/Esc", "quit", &[]),
+];
+
+/// The bindings that work in `tab`.
+fn bindings(tab: Tab) -> impl Iterator<Item = &'static Binding> {
+    BINDINGS
+        .iter()
+        .filter(move |(_, _, tabs)| tabs.is_empty() || tabs.contains(&tab))
+}
+
+/// Draws the one-line hint of the keys that work in `tab`.
+pub fn render_footer(f: &mut Frame, area: Rect, tab: Tab, theme: &Theme) {
+    let key = theme.heading();
+    let mut spans = Vec::new();
+    for (keys, action, _) in bindings(tab) {
+        if !spans.is_empty() {
+            spans.push(Span::raw("  "));
+        }
+        spans.push(Span::styled(*keys, key));
+        spans.push(Span::raw(format!(" {}", action)));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Draws the keys that work in `tab` and the current `settings` in a box over the middle
+/// of `area`.
+pub fn render_overlay(
+    f: &mut Frame,
+    area: Rect,
+    tab: Tab,
+    settings: &[(&str, String)],
+    theme: &Theme,
+) {
+    let bold = theme.heading();
+    let keys = bindings(tab)
+        .map(|(keys, action, _)| Row::new([Cell::from(*keys).style(bold), Cell::from(*action)]));
+    let values = settings
+        .iter()
+        .map(|(name, value)| Row::new([Cell::from(*name).style(bold), Cell::from(value.clone())]));
+    let rows: Vec<Row> = keys
+        .chain(std::iter::once(Row::new([""; 2])))
+        .chain(values)
+        .collect();
+
+    let width = 56.min(area.width);
+    let height = (rows.len() as u16 + 2).min(area.height);
+    let overlay = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    let table = Table::new(rows, [Constraint::Length(12), Constraint::Min(0)]).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!("{} keys", tab.title())),
+    );
+    f.render_widget(Clear, overlay);
+    f.render_widget(table, overlay);
+}
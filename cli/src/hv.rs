@@ -0,0 +1,149 @@
+use crate::output::{self, OutputFormat};
+use clap::Args;
+use core::volatility::{Bar, Estimator};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Estimates historical volatility from a price series.
+#[derive(Args)]
+pub struct HvOpts {
+    /// A CSV price series, oldest first, with the columns `open,high,low,close`; other
+    /// columns such as `date` are ignored.
+    file: PathBuf,
+    /// Comma-separated window lengths, in periods.
+    #[arg(long, value_delimiter = ',', default_value = "10,20,60")]
+    windows: Vec<usize>,
+    /// Periods per year the estimates are annualized with; 252 for daily bars.
+    #[arg(long, default_value_t = 252.0)]
+    periods_per_year: f64,
+    #[arg(short, long, value_enum, default_value = "table")]
+    output: OutputFormat,
+}
+
+/// Prices with a historical volatility in place of `--sigma`.
+#[derive(Args, Clone, Debug)]
+pub struct UseHvOpts {
+    /// Price with the volatility estimated from this CSV price series; see `hv`.
+    #[arg(long, conflicts_with = "sigma")]
+    use_hv: Option<PathBuf>,
+    /// The estimator `--use-hv` uses: close-to-close, parkinson, garman-klass or
+    /// yang-zhang.
+    #[arg(long, default_value = "yang-zhang", requires = "use_hv")]
+    hv_estimator: Estimator,
+    /// The window `--use-hv` estimates over, in periods.
+    #[arg(long, default_value_t = 20, requires = "use_hv")]
+    hv_window: usize,
+    /// Periods per year `--use-hv` annualizes with.
+    #[arg(long, default_value_t = 252.0, requires = "use_hv")]
+    hv_periods_per_year: f64,
+}
+
+impl HvOpts {
+    /// The files the price series is read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        vec![self.file.clone()]
+    }
+}
+
+impl UseHvOpts {
+    /// The files the volatility is estimated from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        self.use_hv.iter().cloned().collect()
+    }
+
+    /// Estimates the volatility to price with, or returns `None` without `--use-hv`.
+    pub fn resolve(&self) -> Result<Option<f64>, String> {
+        let Some(path) = &self.use_hv else {
+            return Ok(None);
+        };
+        let bars = read_bars(path)?;
+        self.hv_estimator
+            .estimate(&bars, self.hv_window, self.hv_periods_per_year)
+            .map(Some)
+            .ok_or_else(|| too_short(path, bars.len(), self.hv_window))
+    }
+}
+
+/// One estimate of the report.
+#[derive(Serialize)]
+struct Estimate {
+    estimator: String,
+    window: usize,
+    volatility: f64,
+}
+
+pub fn run(opts: &HvOpts) -> Result<String, String> {
+    let bars = read_bars(&opts.file)?;
+    let mut estimates = Vec::new();
+    for &window in &opts.windows {
+        for estimator in Estimator::ALL {
+            let volatility = estimator
+                .estimate(&bars, window, opts.periods_per_year)
+                .ok_or_else(|| too_short(&opts.file, bars.len(), window))?;
+            estimates.push(Estimate {
+                estimator: estimator.to_string(),
+                window,
+                volatility,
+            });
+        }
+    }
+    Ok(render(opts.output, &opts.windows, &estimates))
+}
+
+fn read_bars(path: &Path) -> Result<Vec<Bar>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    csv::Reader::from_reader(text.as_bytes())
+        .deserialize()
+        .collect::<Result<Vec<Bar>, csv::Error>>()
+        .map_err(|err| format!("invalid CSV in {}: {}", path.display(), err))
+}
+
+fn too_short(path: &Path, bars: usize, window: usize) -> String {
+    format!(
+        "{} has {} bars, too few for a {}-period window",
+        path.display(),
+        bars,
+        window
+    )
+}
+
+/// Formats the estimates; the table has one row per estimator and one column per window.
+fn render(format: OutputFormat, windows: &[usize], estimates: &[Estimate]) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(estimates).expect("estimates serialize as JSON") + "\n"
+        }
+        OutputFormat::Csv => {
+            let rows: Vec<Vec<String>> = estimates
+                .iter()
+                .map(|e| {
+                    vec![
+                        e.estimator.clone(),
+                        e.window.to_string(),
+                        e.volatility.to_string(),
+                    ]
+                })
+                .collect();
+            output::csv(&["estimator", "window", "volatility"], &rows)
+        }
+        OutputFormat::Table => {
+            let titles: Vec<String> = windows.iter().map(|w| format!("{}p", w)).collect();
+            let header: Vec<&str> = std::iter::once("estimator")
+                .chain(titles.iter().map(String::as_str))
+                .collect();
+            let rows: Vec<Vec<String>> = Estimator::ALL
+                .iter()
+                .map(|estimator| {
+                    let name = estimator.to_string();
+                    let vols = estimates
+                        .iter()
+                        .filter(|e| e.estimator == name)
+                        .map(|e| format!("{:.2}%", e.volatility * 100.0));
+                    std::iter::once(name.clone()).chain(vols).collect()
+                })
+                .collect();
+            output::table(&header, &rows)
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::expiry::{ExpiryDates, ExpiryOpts};
+use crate::output::{self, OutputFormat};
+use crate::{model_spec, parse_model_name, ModelConfig};
+use clap::{Args, ValueEnum};
+use core::models::{Greeks, OptionParameters, OptionType};
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Right {
+    Call,
+    Put,
+}
+
+impl From<Right> for OptionType {
+    fn from(right: Right) -> Self {
+        match right {
+            Right::Call => OptionType::Call,
+            Right::Put => OptionType::Put,
+        }
+    }
+}
+
+/// Solves for the volatility at which a model reproduces a market price.
+#[derive(Args)]
+pub struct IvOpts {
+    /// The observed option price.
+    #[arg(long)]
+    price: f64,
+    #[arg(short, long)]
+    s: f64,
+    #[arg(short, long)]
+    k: f64,
+    #[arg(short, long)]
+    r: f64,
+    #[arg(short, long, required_unless_present = "expiry")]
+    t: Option<f64>,
+    #[command(flatten)]
+    dates: ExpiryOpts,
+    #[arg(long = "type", value_enum, default_value = "call")]
+    option_type: Right,
+    /// Also print the Greeks at the implied volatility.
+    #[arg(long)]
+    greeks: bool,
+    /// The model to invert; Black-Scholes by default.
+    #[arg(long, value_parser = parse_model_name, default_value = "black_scholes")]
+    model: String,
+    #[arg(short, long, value_enum, default_value = "table")]
+    output: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct IvReport {
+    model: String,
+    option_type: OptionType,
+    price: f64,
+    implied_vol: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    greeks: Option<Greeks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiry: Option<ExpiryDates>,
+}
+
+pub fn run(opts: &IvOpts, config: &ModelConfig) -> Result<String, String> {
+    let model = model_spec(&opts.model, config)
+        .expect("model names are validated")
+        .build();
+    let option_type = OptionType::from(opts.option_type);
+    let dates = opts.dates.resolve()?;
+    let t = match &dates {
+        Some(dates) => dates.t,
+        None => opts.t.expect("clap requires -t without --expiry"),
+    };
+    let mut params = OptionParameters {
+        s: opts.s,
+        k: opts.k,
+        r: opts.r,
+        sigma: 0.0,
+        t,
+    };
+    let implied_vol = model
+        .implied_volatility(&params, option_type, opts.price)
+        .map_err(|err| format!("no volatility reproduces the price {}: {}", opts.price, err))?;
+    params.sigma = implied_vol;
+    let report = IvReport {
+        model: opts.model.clone(),
+        option_type,
+        price: opts.price,
+        implied_vol,
+        greeks: opts
+            .greeks
+            .then(|| model.option_greeks(&params, option_type)),
+        expiry: dates,
+    };
+    Ok(render(opts.output, &report))
+}
+
+fn render(format: OutputFormat, report: &IvReport) -> String {
+    let mut header = vec!["model", "type", "price", "implied_vol"];
+    let right = if report.option_type == OptionType::Call {
+        "call"
+    } else {
+        "put"
+    };
+    let row = |number: &dyn Fn(f64) -> String| {
+        let mut row = vec![
+            report.model.clone(),
+            right.to_string(),
+            number(report.price),
+            number(report.implied_vol),
+        ];
+        if let Some(g) = &report.greeks {
+            row.extend([g.delta, g.gamma, g.vega, g.theta, g.rho].map(number));
+        }
+        vec![row]
+    };
+    if report.greeks.is_some() {
+        header.extend(["delta", "gamma", "vega", "theta", "rho"]);
+    }
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(report).expect("report serializes as JSON") + "\n"
+        }
+        OutputFormat::Csv => output::csv(&header, &row(&|x| x.to_string())),
+        OutputFormat::Table => {
+            let table = output::table(&header, &row(&|x| format!("{:.4}", x)));
+            match &report.expiry {
+                Some(dates) => format!("{}\n\n{}", dates, table),
+                None => table,
+            }
+        }
+    }
+}
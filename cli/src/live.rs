@@ -0,0 +1,135 @@
+use core::models::OptionParameters;
+use providers::{JsonLinesSource, Quote, QuoteSource, SourceError, WebSocketSource};
+use std::time::Duration;
+use tokio::io::BufReader;
+use tokio::sync::mpsc::{self, error::TryRecvError};
+use tokio::time::Instant;
+
+/// Where a feed stands.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Streaming,
+    Ended,
+    Failed(String),
+}
+
+/// Quotes from a live source, merged and released at most once per throttle interval
+/// so bursts of updates cost one repricing.
+pub struct Feed {
+    quotes: mpsc::UnboundedReceiver<Result<Quote, SourceError>>,
+    throttle: Duration,
+    last_update: Option<Instant>,
+    pending: Option<Quote>,
+    status: Status,
+}
+
+impl Feed {
+    /// Subscribes to `source`: a `ws://` or `wss://` URL, or `-` for JSON lines on stdin.
+    pub async fn open(source: &str, throttle: Duration) -> Result<Self, String> {
+        let mut source: Box<dyn QuoteSource> = if source == "-" {
+            Box::new(JsonLinesSource::new(BufReader::new(tokio::io::stdin())))
+        } else {
+            let socket = WebSocketSource::connect(source)
+                .await
+                .map_err(|err| format!("cannot subscribe to {}: {}", source, err))?;
+            Box::new(socket)
+        };
+        let (sender, quotes) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let quote = match source.next_quote().await {
+                    Ok(Some(quote)) => Ok(quote),
+                    Ok(None) => break,
+                    Err(err) => Err(err),
+                };
+                let failed = quote.is_err();
+                if sender.send(quote).is_err() || failed {
+                    break;
+                }
+            }
+        });
+        Ok(Self {
+            quotes,
+            throttle,
+            last_update: None,
+            pending: None,
+            status: Status::Streaming,
+        })
+    }
+
+    pub fn status(&self) -> &Status {
+        &self.status
+    }
+
+    fn receive(&mut self, quote: Option<Result<Quote, SourceError>>) {
+        match quote {
+            Some(Ok(quote)) => match &mut self.pending {
+                Some(pending) => pending.merge(quote),
+                None => self.pending = Some(quote),
+            },
+            Some(Err(err)) => self.status = Status::Failed(err.to_string()),
+            None => {
+                if self.status == Status::Streaming {
+                    self.status = Status::Ended;
+                }
+            }
+        }
+    }
+
+    /// Returns the update received since the last one, without waiting, once the
+    /// throttle interval has passed.
+    pub fn poll(&mut self) -> Option<Quote> {
+        loop {
+            match self.quotes.try_recv() {
+                Ok(quote) => self.receive(Some(quote)),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.receive(None);
+                    break;
+                }
+            }
+        }
+        let due = self
+            .last_update
+            .is_none_or(|last| last.elapsed() >= self.throttle);
+        if due && self.pending.is_some() {
+            self.last_update = Some(Instant::now());
+            self.pending.take()
+        } else {
+            None
+        }
+    }
+
+    /// Waits for the next throttled update; `None` once the feed has ended and every
+    /// update has been returned.
+    pub async fn next(&mut self) -> Result<Option<Quote>, String> {
+        loop {
+            if let Some(quote) = self.poll() {
+                return Ok(Some(quote));
+            }
+            match &self.status {
+                Status::Streaming => {}
+                _ if self.pending.is_some() => return Ok(self.pending.take()),
+                Status::Failed(message) => return Err(message.clone()),
+                Status::Ended => return Ok(None),
+            }
+            match (self.pending.is_some(), self.last_update) {
+                (true, Some(last)) => tokio::time::sleep_until(last + self.throttle).await,
+                _ => {
+                    let quote = self.quotes.recv().await;
+                    self.receive(quote);
+                }
+            }
+        }
+    }
+}
+
+/// Returns `params` with the fields the quote carries replaced.
+pub fn apply(quote: &Quote, params: &OptionParameters) -> OptionParameters {
+    OptionParameters {
+        s: quote.spot.unwrap_or(params.s),
+        sigma: quote.sigma.unwrap_or(params.sigma),
+        r: quote.rate.unwrap_or(params.r),
+        ..params.clone()
+    }
+}
@@ -1,36 +1,180 @@
-use clap::Parser;
-use core::models::{OptionParameters, OptionPricingModel};
+mod batch;
+mod chain;
+mod chart;
+mod config;
+mod decision;
+mod expiry;
+mod help;
+mod hv;
+mod iv;
+mod live;
+mod output;
+mod params;
+mod portfolio;
+mod price;
+mod pricing;
+mod rows;
+mod scenario;
+mod strategy;
+mod stress;
+mod tabs;
+mod theme;
+mod watch;
+
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use core::portfolio::{ScenarioMeasure, ShockGrid};
+use core::strategies::{ModelSpec, SharedModel, StrategyDefinition};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use expiry::ExpiryDates;
+use output::Columns;
+use pricing::{Pricer, View, ViewInputs, ViewRequest};
 use ratatui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    widgets::{Block, Cell, Row, Table, TableState},
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Cell, Paragraph, Row, Table, TableState},
     Frame, Terminal,
 };
-use std::cell::RefCell;
-use std::io::{self};
-use std::{fs, path::Path};
+use serde::Serialize;
+use std::io::{self, Stdout};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tabs::Tab;
+use theme::{Theme, ThemeName};
 
 #[derive(Parser)]
+#[command(version, about = "Prices options, strategies and chains.")]
 struct Opts {
-    #[arg(short, long)]
-    s: f64,
-    #[arg(short, long)]
-    k: f64,
-    #[arg(short, long)]
-    r: f64,
-    #[arg(short = 'm', long)]
-    sigma: f64,
-    #[arg(short, long)]
-    t: f64,
+    #[command(subcommand)]
+    command: Command,
+    /// A JSON file of CLI settings, e.g. `{"theme": "light"}`.
+    #[arg(long = "config", global = true)]
+    config_file: Option<PathBuf>,
+    /// Rerun the subcommand whenever a file it reads changes.
+    #[arg(long, global = true)]
+    watch: bool,
+    #[command(flatten)]
+    config: ModelConfig,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print every model's call and put prices.
+    Price(price::PriceOpts),
+    /// Print every model's Greeks.
+    Greeks(price::PriceOpts),
+    /// Solve for the implied volatility of an option price.
+    Iv(iv::IvOpts),
+    /// Mark a book of positions and print its value, Greeks, value at risk and positions.
+    Portfolio(portfolio::PortfolioOpts),
+    /// Revalue positions or a strategy under the named shocks of the config file.
+    Stress(stress::StressOpts),
+    /// Price a strategy and print its legs, Greeks, breakevens and profit bounds.
+    Strategy(strategy::StrategyOpts),
+    /// Browse an option chain's implied volatilities and Greeks by strike.
+    Chain(chain::ChainOpts),
+    /// Price many options from a CSV or JSON file into another.
+    Batch(batch::BatchOpts),
+    /// Estimate historical volatility from a price series over several windows.
+    Hv(hv::HvOpts),
+    /// Evaluate a decision flow against a JSON input.
+    Flow(decision::FlowOpts),
+    /// Compare the models interactively.
+    Tui(TuiOpts),
+    /// Print a completion script for a shell.
+    Completions { shell: Shell },
+}
+
+impl Command {
+    /// The files the subcommand reads, which `--watch` reruns it on changes to.
+    fn inputs(&self, config_file: Option<&Path>) -> Vec<PathBuf> {
+        match self {
+            Command::Price(price) | Command::Greeks(price) => price.inputs(),
+            Command::Portfolio(portfolio) => portfolio.inputs(),
+            Command::Stress(stress) => stress.inputs(config_file),
+            Command::Strategy(strategy) => strategy.inputs(),
+            Command::Chain(chain) => chain.inputs(),
+            Command::Batch(batch) => batch.inputs(),
+            Command::Hv(hv) => hv.inputs(),
+            Command::Flow(flow) => flow.inputs(),
+            Command::Iv(_) | Command::Tui(_) | Command::Completions { .. } => Vec::new(),
+        }
+    }
 }
 
-#[derive(Clone, PartialEq, Debug)]
+/// The interactive model table and its views.
+#[derive(Args)]
+struct TuiOpts {
+    #[command(flatten)]
+    option: params::OptionArgs,
+    /// Comma-separated models to show, e.g. `black_scholes,garch`; all by default.
+    #[arg(long, value_delimiter = ',', value_parser = parse_model_name)]
+    models: Vec<String>,
+    /// Chart this strategy file in the Strategy tab, valued with the selected model;
+    /// see `strategy --file`.
+    #[arg(long)]
+    strategy: Option<PathBuf>,
+    /// Mark this book in the Portfolio tab with the selected model: CSV with the columns
+    /// `kind,side,quantity,entry_price,s,k,r,sigma,t` and an optional `underlying`, where
+    /// `kind` is `call`, `put` or `stock` and stock needs only `s`, or a JSON array of
+    /// positions such as `{"instrument": {"stock": {"spot": 100}}, "side": "long",
+    /// "quantity": 10, "entry_price": 95}`.
+    #[arg(long)]
+    portfolio: Option<PathBuf>,
+    #[command(flatten)]
+    var: portfolio::VarOpts,
+    /// Browse this option chain in the Chain tab, valued at `-s` and `-r`; see
+    /// `chain`.
+    #[arg(long)]
+    chain: Option<PathBuf>,
+    /// Reprice continuously from a quote feed: a `ws://` or `wss://` URL, or `-` for one
+    /// JSON quote per line on stdin, e.g. `{"spot": 101.5, "sigma": 0.22}`.
+    #[arg(long)]
+    live: Option<String>,
+    /// Minimum milliseconds between repricings in live mode.
+    #[arg(long, default_value_t = 250, requires = "live")]
+    throttle: u64,
+    /// Highlight prices more than this many basis points from Black-Scholes in the
+    /// baseline-diff columns (toggled with `b`).
+    #[arg(long, default_value_t = 100.0)]
+    outlier_bps: f64,
+    /// Color theme, overriding the config file's; `t` cycles through them.
+    #[arg(long, value_enum)]
+    theme: Option<ThemeName>,
+    #[command(flatten)]
+    scenarios: scenario::ScenarioConfig,
+}
+
+/// Knobs of the numerical models; each model reads only its own.
+#[derive(Args, Clone, Debug, PartialEq, Serialize)]
+struct ModelConfig {
+    /// Time steps of the binomial tree and GARCH lattices.
+    #[arg(long, global = true, default_value_t = 100)]
+    steps: usize,
+    /// Simulated paths per Monte Carlo price.
+    #[arg(long, global = true, default_value_t = 1000)]
+    simulations: usize,
+    /// Seeds the Monte Carlo paths, making its prices reproducible.
+    #[arg(long, global = true)]
+    seed: Option<u64>,
+    /// GARCH(1,1) constant term, in per-period variance units.
+    #[arg(long, global = true, default_value_t = 0.000_02)]
+    omega: f64,
+    /// GARCH(1,1) weight of the last squared innovation.
+    #[arg(long, global = true, default_value_t = 0.1)]
+    alpha: f64,
+    /// GARCH(1,1) weight of the last conditional variance.
+    #[arg(long, global = true, default_value_t = 0.8)]
+    beta: f64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize)]
 struct ModelResults {
     call: f64,
     put: f64,
@@ -41,195 +185,549 @@ struct ModelResults {
     rho: f64,
 }
 
-struct ModelWrapper {
-    name: String,
-    model: Box<dyn OptionPricingModel>,
-    cache: RefCell<Option<ModelResults>>,
-}
-
-impl ModelWrapper {
-    fn get_results(&self, params: &OptionParameters) -> ModelResults {
-        if let Some(cached) = self.cache.borrow().as_ref() {
-            return cached.clone();
+impl ModelResults {
+    fn compute(model: &dyn OptionPricingModel, params: &OptionParameters) -> Self {
+        let (call, put) = model.call_put_price(params);
+        ModelResults {
+            call,
+            put,
+            delta: model.delta(params),
+            gamma: model.gamma(params),
+            vega: model.vega(params),
+            theta: model.theta(params),
+            rho: model.rho(params),
         }
-
-        let results = ModelResults {
-            call: self.model.call_price(params),
-            put: self.model.put_price(params),
-            delta: self.model.delta(params),
-            gamma: self.model.gamma(params),
-            vega: self.model.vega(params),
-            theta: self.model.theta(params),
-            rho: self.model.rho(params),
-        };
-
-        *self.cache.borrow_mut() = Some(results.clone());
-        results
     }
+}
 
-    fn invalidate_cache(&self) {
-        *self.cache.borrow_mut() = None;
-    }
+struct ModelWrapper {
+    name: String,
+    model: SharedModel,
+    /// The results at the current parameters; `None` while they are being computed.
+    results: Option<ModelResults>,
 }
 
 struct App {
     models: Vec<ModelWrapper>,
-    table_state: TableState,
+    /// Index into `models` of the selected model, kept across sorting and filtering.
+    selected: usize,
+    order: rows::RowOrder,
     params: OptionParameters,
     params_changed: bool,
+    pricer: Pricer,
+    /// Redraws since the TUI started, animating the spinners.
+    tick: usize,
+    /// The last view computed for the current tab and pane.
+    view: Option<(ViewRequest, View)>,
+    /// The view being computed, if any.
+    view_pending: Option<ViewRequest>,
+    strategy: Option<Arc<StrategyDefinition>>,
+    ladder: Option<chain::Ladder>,
+    book: Option<Arc<portfolio::Book>>,
+    chart_type: OptionType,
+    tab: Tab,
+    pane: Pane,
+    shocks: ShockGrid,
+    measure: ScenarioMeasure,
+    /// Which of `chart::GREEKS` the Greeks pane plots.
+    greeks_shown: [bool; 4],
+    feed: Option<live::Feed>,
+    dates: Option<ExpiryDates>,
+    show_diff: bool,
+    outlier_bps: f64,
+    show_help: bool,
+    theme: ThemeName,
+}
+
+/// What the pane under the model table of the Models tab shows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pane {
+    Payoff,
+    Greeks,
 }
 
 impl App {
-    fn new(opts: Opts) -> Self {
-        let params = OptionParameters {
-            s: opts.s,
-            k: opts.k,
-            r: opts.r,
-            sigma: opts.sigma,
-            t: opts.t,
-        };
-        let mut table_state = TableState::default();
-        table_state.select(Some(0));
-        App {
-            models: load_models(),
-            table_state,
+    fn new(
+        opts: &TuiOpts,
+        config: &ModelConfig,
+        params: OptionParameters,
+        dates: Option<ExpiryDates>,
+        strategy: Option<StrategyDefinition>,
+        ladder: Option<chain::Ladder>,
+        feed: Option<live::Feed>,
+    ) -> Self {
+        let mut app = App {
+            models: load_models(&opts.models, config),
+            selected: 0,
+            order: rows::RowOrder::default(),
             params,
             params_changed: true,
+            pricer: Pricer::new(),
+            tick: 0,
+            view: None,
+            view_pending: None,
+            strategy: strategy.map(Arc::new),
+            ladder,
+            book: None,
+            chart_type: OptionType::Call,
+            tab: Tab::Models,
+            pane: Pane::Payoff,
+            shocks: opts.scenarios.grid(),
+            measure: ScenarioMeasure::Price,
+            greeks_shown: [true; 4],
+            feed,
+            dates,
+            show_diff: false,
+            outlier_bps: opts.outlier_bps,
+            show_help: false,
+            theme: ThemeName::default(),
+        };
+        app.reprice();
+        app
+    }
+
+    /// Discards every model's results and prices them afresh in the background.
+    fn reprice(&mut self) {
+        self.pricer.next_round();
+        for (i, wrapper) in self.models.iter_mut().enumerate() {
+            wrapper.results = None;
+            self.pricer
+                .spawn(i, wrapper.model.clone(), self.params.clone());
         }
     }
 
-    fn next(&mut self) {
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i >= self.models.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
+    /// Files the results that arrived since the last call; returns whether there were any.
+    fn receive_results(&mut self) -> bool {
+        let mut received = false;
+        while let Some(priced) = self.pricer.poll() {
+            if let Some(wrapper) = self.models.get_mut(priced.index) {
+                wrapper.results = Some(priced.results);
+                received = true;
             }
-            None => 0,
+        }
+        received
+    }
+
+    fn is_pricing(&self) -> bool {
+        self.view_pending.is_some() || self.models.iter().any(|wrapper| wrapper.results.is_none())
+    }
+
+    /// The view the current tab and pane draw, or `None` when they draw none.
+    fn view_request(&self) -> Option<ViewRequest> {
+        let model = self.selected_index()?;
+        let params = self.params.clone();
+        let option_type = self.chart_type;
+        let request = match self.tab {
+            Tab::Models => match self.pane {
+                Pane::Payoff => ViewRequest::Payoff {
+                    model,
+                    params,
+                    option_type,
+                },
+                Pane::Greeks => ViewRequest::Greeks {
+                    model,
+                    params,
+                    option_type,
+                    shown: self.greeks_shown,
+                },
+            },
+            Tab::Strategy if self.strategy.is_some() => ViewRequest::Strategy { model, params },
+            Tab::Scenarios => ViewRequest::Scenarios {
+                model,
+                params,
+                option_type,
+                measure: self.measure,
+            },
+            Tab::Portfolio if self.book.is_some() => ViewRequest::Portfolio { model },
+            _ => return None,
         };
-        self.table_state.select(Some(i));
+        Some(request)
     }
 
-    fn previous(&mut self) {
-        let i = match self.table_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.models.len() - 1
-                } else {
-                    i - 1
-                }
+    /// Starts computing the current view unless it is computed or underway already.
+    fn refresh_view(&mut self) {
+        let Some(request) = self.view_request() else {
+            return;
+        };
+        let computed = self.view.as_ref().is_some_and(|(done, _)| *done == request);
+        if computed || self.view_pending.as_ref() == Some(&request) {
+            return;
+        }
+        let wrapper = &self.models[request.model()];
+        let inputs = ViewInputs {
+            name: wrapper.name.clone(),
+            model: wrapper.model.clone(),
+            strategy: self.strategy.clone(),
+            shocks: self.shocks.clone(),
+            book: self.book.clone(),
+        };
+        self.view_pending = Some(request.clone());
+        self.pricer.spawn_view(request, inputs);
+    }
+
+    /// Keeps the views that arrived since the last call if they are still wanted; returns
+    /// whether one was.
+    fn receive_views(&mut self) -> bool {
+        let mut received = false;
+        while let Some((request, view)) = self.pricer.poll_view() {
+            if self.view_pending.as_ref() == Some(&request) {
+                self.view_pending = None;
+                self.view = Some((request, view));
+                received = true;
             }
-            None => 0,
+        }
+        received
+    }
+
+    /// The view to draw: the one computed for the current inputs or, while that is
+    /// underway, the last one of the same view and model.
+    fn current_view(&self) -> Option<&View> {
+        let request = self.view_request()?;
+        self.view
+            .as_ref()
+            .filter(|(done, _)| done.same_view(&request))
+            .map(|(_, view)| view)
+    }
+
+    fn next_pane(&mut self) {
+        self.pane = match self.pane {
+            Pane::Payoff => Pane::Greeks,
+            Pane::Greeks => Pane::Payoff,
         };
-        self.table_state.select(Some(i));
+    }
+
+    /// Shows or hides the `i`th of `chart::GREEKS` in the Greeks pane.
+    fn toggle_greek(&mut self, i: usize) {
+        if let Some(shown) = self.greeks_shown.get_mut(i) {
+            *shown = !*shown;
+        }
+    }
+
+    /// Switches the charted option between the call and the put.
+    fn toggle_chart_type(&mut self) {
+        self.chart_type = match self.chart_type {
+            OptionType::Call => OptionType::Put,
+            OptionType::Put => OptionType::Call,
+        };
+    }
+
+    /// The model shown in the non-table views: the selected one, or the first listed
+    /// while a filter hides it.
+    fn selected_model(&self) -> Option<&ModelWrapper> {
+        self.selected_index().map(|i| &self.models[i])
+    }
+
+    /// The index into `models` of `selected_model`.
+    fn selected_index(&self) -> Option<usize> {
+        let visible = self.order.visible(&self.models);
+        visible
+            .iter()
+            .find(|&&i| i == self.selected)
+            .or(visible.first())
+            .copied()
+    }
+
+    /// Selects the model `step` rows along the listed ones, wrapping around.
+    fn move_selection(&mut self, step: isize) {
+        let visible = self.order.visible(&self.models);
+        if visible.is_empty() {
+            return;
+        }
+        let row = visible
+            .iter()
+            .position(|&i| i == self.selected)
+            .unwrap_or(0);
+        let len = visible.len() as isize;
+        self.selected = visible[(row as isize + step).rem_euclid(len) as usize];
+    }
+
+    /// Applies a key typed while editing the model filter.
+    fn edit_filter(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char(c) => self.order.filter.push(c),
+            KeyCode::Backspace => {
+                self.order.filter.pop();
+            }
+            KeyCode::Enter => self.order.editing = false,
+            KeyCode::Esc => {
+                self.order.filter.clear();
+                self.order.editing = false;
+            }
+            _ => {}
+        }
     }
 
     fn update_params(&mut self, new_params: OptionParameters) {
         if self.params != new_params {
             self.params = new_params;
             self.params_changed = true;
-            for model in &self.models {
-                model.invalidate_cache();
-            }
+            self.reprice();
         }
     }
 }
 
-fn create_model(model_name: &str) -> Option<Box<dyn OptionPricingModel>> {
-    match model_name {
-        "black_scholes" => Some(Box::new(core::models::BlackScholesModel)),
-        "binomial_tree" => Some(Box::new(core::models::BinomialTreeModel::default())),
-        "garch" => Some(Box::new(core::models::GarchModel::default())),
-        "monte_carlo" => Some(Box::new(core::models::MonteCarloModel {
-            simulations: 1000,
-            time_steps: 10,
-        })),
-        _ => None,
-    }
-}
+type ModelConstructor = fn(&ModelConfig) -> ModelSpec;
 
-fn is_valid_model_file(entry: &fs::DirEntry) -> bool {
-    entry.path().is_file()
-        && entry.path().extension().and_then(|s| s.to_str()) == Some("rs")
-        && entry
-            .path()
-            .file_stem()
-            .and_then(|s| s.to_str())
-            .map(|s| s != "mod")
-            .unwrap_or(false)
-}
+/// Every model the CLI can price with, in display order.
+const MODELS: [(&str, ModelConstructor); 4] = [
+    ("black_scholes", |_| ModelSpec::BlackScholes),
+    ("binomial_tree", |config| ModelSpec::BinomialTree {
+        steps: config.steps,
+    }),
+    ("monte_carlo", |config| ModelSpec::MonteCarlo {
+        simulations: config.simulations,
+        seed: config.seed,
+    }),
+    ("garch", |config| ModelSpec::Garch {
+        steps: config.steps,
+        omega: config.omega,
+        alpha: config.alpha,
+        beta: config.beta,
+    }),
+];
 
-fn create_model_wrapper(entry: &fs::DirEntry) -> Option<ModelWrapper> {
-    entry
-        .path()
-        .file_stem()
-        .and_then(|s| s.to_str())
-        .and_then(|model_name| {
-            create_model(model_name).map(|model| ModelWrapper {
-                name: model_name.to_string(),
-                model,
-                cache: RefCell::new(None),
-            })
-        })
+/// Returns the specification of the registered model `name`, set up from `config`.
+fn model_spec(name: &str, config: &ModelConfig) -> Option<ModelSpec> {
+    MODELS
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, spec)| spec(config))
 }
 
-fn load_models() -> Vec<ModelWrapper> {
-    let model_dir = Path::new("../core/src/models");
+/// Validates a `--models` entry against the registry.
+fn parse_model_name(name: &str) -> Result<String, String> {
+    if MODELS.iter().any(|(known, _)| *known == name) {
+        Ok(name.to_string())
+    } else {
+        let known: Vec<&str> = MODELS.iter().map(|(name, _)| *name).collect();
+        Err(format!(
+            "unknown model {}; expected one of {}",
+            name,
+            known.join(", ")
+        ))
+    }
+}
 
-    fs::read_dir(model_dir)
-        .map(|entries| {
-            entries
-                .filter_map(Result::ok)
-                .filter(is_valid_model_file)
-                .filter_map(|entry| create_model_wrapper(&entry))
-                .collect()
+/// Builds the selected models in registry order, or all of them when none are selected.
+fn load_models(selected: &[String], config: &ModelConfig) -> Vec<ModelWrapper> {
+    MODELS
+        .iter()
+        .filter(|(name, _)| selected.is_empty() || selected.iter().any(|s| s == name))
+        .map(|(name, constructor)| ModelWrapper {
+            name: name.to_string(),
+            model: constructor(config).shared(),
+            results: None,
         })
-        .unwrap_or_else(|_| Vec::new())
+        .collect()
 }
 
 #[tokio::main]
 async fn main() -> Result<(), io::Error> {
     let opts: Opts = Opts::parse();
-    let mut app = App::new(opts);
+    let config = &opts.config;
+    match &opts.command {
+        Command::Tui(tui) => return run_tui(tui, config, opts.config_file.as_deref()).await,
+        Command::Chain(chain) if chain.is_interactive() => {
+            let mut ladder =
+                chain::Ladder::new(chain::load(chain, config).unwrap_or_else(|m| fail(&m)));
+            let mut terminal = enter_terminal()?;
+            let res = chain::run_ladder(&mut terminal, &mut ladder);
+            leave_terminal(&mut terminal)?;
+            if let Err(err) = res {
+                println!("Error: {:?}", err)
+            }
+            return Ok(());
+        }
+        Command::Completions { shell } => {
+            let mut command = Opts::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(*shell, &mut command, name, &mut io::stdout());
+            return Ok(());
+        }
+        _ => {}
+    }
+    if opts.watch {
+        let files = opts.command.inputs(opts.config_file.as_deref());
+        if files.is_empty() {
+            fail("--watch needs a subcommand that reads from files");
+        }
+        watch::run(&files, || {
+            report(&opts.command, config, opts.config_file.as_deref())
+        })
+        .await
+        .unwrap_or_else(|m| fail(&m));
+        return Ok(());
+    }
+    print!(
+        "{}",
+        report(&opts.command, config, opts.config_file.as_deref())
+            .await
+            .unwrap_or_else(|m| fail(&m))
+    );
+    Ok(())
+}
+
+/// Runs a subcommand that prints a report rather than taking over the terminal.
+async fn report(
+    command: &Command,
+    config: &ModelConfig,
+    config_file: Option<&Path>,
+) -> Result<String, String> {
+    match command {
+        Command::Price(price) => price::run(price, config, Columns::Prices)
+            .await
+            .map(|()| String::new()),
+        Command::Greeks(price) => price::run(price, config, Columns::Greeks)
+            .await
+            .map(|()| String::new()),
+        Command::Iv(iv) => iv::run(iv, config),
+        Command::Portfolio(portfolio) => portfolio::run(portfolio, config),
+        Command::Stress(stress) => stress::run(stress, config, config_file),
+        Command::Strategy(strategy) => strategy::run(strategy, config),
+        Command::Chain(chain) => chain::run(chain, config),
+        Command::Batch(batch) => batch::run(batch, config),
+        Command::Hv(hv) => hv::run(hv),
+        Command::Flow(flow) => decision::run(flow).await,
+        Command::Tui(_) | Command::Completions { .. } => {
+            unreachable!("interactive subcommands do not report")
+        }
+    }
+}
+
+/// Loads what the `tui` views need and runs the TUI until it is quit.
+async fn run_tui(
+    opts: &TuiOpts,
+    config: &ModelConfig,
+    config_file: Option<&Path>,
+) -> io::Result<()> {
+    let (params, dates) = opts.option.resolve().unwrap_or_else(|m| fail(&m));
+    let mut theme = opts.theme;
+    if let Some(path) = config_file {
+        let settings = config::read(path).unwrap_or_else(|m| fail(&m));
+        theme = theme.or(settings.theme);
+    }
+    let feed = match &opts.live {
+        Some(source) => Some(
+            live::Feed::open(source, Duration::from_millis(opts.throttle))
+                .await
+                .unwrap_or_else(|m| fail(&m)),
+        ),
+        None => None,
+    };
+    let strategy = opts
+        .strategy
+        .as_deref()
+        .map(|path| strategy::read_definition(path).unwrap_or_else(|m| fail(&m)));
+    let ladder = opts.chain.as_deref().map(|path| {
+        let model = ModelSpec::BlackScholes;
+        let rows = chain::load_file(path, Some(params.s), Some(params.r), &model)
+            .unwrap_or_else(|m| fail(&m));
+        chain::Ladder::new(rows)
+    });
+    let mut app = App::new(opts, config, params, dates, strategy, ladder, feed);
+    app.theme = theme.unwrap_or_default();
+    app.book = opts
+        .portfolio
+        .as_deref()
+        .map(|path| portfolio::Book::load(path, &opts.var).unwrap_or_else(|m| fail(&m)))
+        .map(Arc::new);
+    let mut terminal = enter_terminal()?;
+
+    let res = run_app(&mut terminal, &mut app).await;
+
+    leave_terminal(&mut terminal)?;
+
+    if let Err(err) = res {
+        println!("Error: {:?}", err)
+    }
+
+    Ok(())
+}
+
+/// Reports a command-line error and exits with status 1.
+fn fail(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+/// Switches the terminal to the raw alternate screen the TUI draws on.
+fn enter_terminal() -> io::Result<Terminal<CrosstermBackend<Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
     terminal.hide_cursor()?;
+    Ok(terminal)
+}
 
-    let res = run_app(&mut terminal, &mut app).await;
-
+/// Restores the terminal left by `enter_terminal`.
+fn leave_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Result<()> {
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
         DisableMouseCapture
-    )?;
-
-    if let Err(err) = res {
-        println!("Error: {:?}", err)
-    }
-
-    Ok(())
+    )
 }
 
 async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()> {
     loop {
-        if app.params_changed {
+        if let Some(quote) = app.feed.as_mut().and_then(live::Feed::poll) {
+            app.update_params(live::apply(&quote, &app.params));
+        }
+        if app.receive_results() | app.receive_views() {
+            app.params_changed = true;
+        }
+        app.refresh_view();
+        if app.params_changed || app.is_pricing() {
             terminal.draw(|f| ui(f, app))?;
             app.params_changed = false;
+            app.tick += 1;
         }
 
         if event::poll(std::time::Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Down => app.next(),
-                    KeyCode::Up => app.previous(),
-                    KeyCode::Esc => return Ok(()),
+                if app.order.editing {
+                    app.edit_filter(key.code);
+                    app.params_changed = true;
+                    continue;
+                }
+                match (app.tab, key.code) {
+                    (_, KeyCode::Char('q')) => return Ok(()),
+                    (_, KeyCode::Char('?')) => app.show_help = !app.show_help,
+                    (_, KeyCode::Char('t')) => app.theme = app.theme.next(),
+                    (_, KeyCode::Esc) if app.show_help => app.show_help = false,
+                    (_, KeyCode::Esc) => return Ok(()),
+                    (_, KeyCode::Tab) => app.tab = app.tab.cycle(1),
+                    (_, KeyCode::BackTab) => app.tab = app.tab.cycle(-1),
+                    (Tab::Chain, code) => {
+                        if let Some(ladder) = &mut app.ladder {
+                            match code {
+                                KeyCode::Down => ladder.move_row(1),
+                                KeyCode::Up => ladder.move_row(-1),
+                                KeyCode::Right => ladder.move_expiry(1),
+                                KeyCode::Left => ladder.move_expiry(-1),
+                                _ => {}
+                            }
+                        }
+                    }
+                    (_, KeyCode::Down) => app.move_selection(1),
+                    (_, KeyCode::Up) => app.move_selection(-1),
+                    (_, KeyCode::Char('c')) => app.toggle_chart_type(),
+                    (Tab::Models, KeyCode::Char('v')) => app.next_pane(),
+                    (Tab::Models, KeyCode::Char('b')) => app.show_diff = !app.show_diff,
+                    (Tab::Models, KeyCode::Char('s')) => app.order.next_column(),
+                    (Tab::Models, KeyCode::Char('S')) => {
+                        app.order.descending = !app.order.descending
+                    }
+                    (Tab::Models, KeyCode::Char('/')) => app.order.editing = true,
+                    (Tab::Models, KeyCode::Char(c @ '1'..='4')) => {
+                        app.toggle_greek(c as usize - '1' as usize)
+                    }
+                    (Tab::Scenarios, KeyCode::Char('g')) => {
+                        app.measure = scenario::next_measure(app.measure)
+                    }
                     _ => {}
                 }
                 app.params_changed = true;
@@ -238,28 +736,147 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::R
     }
 }
 
+/// Shows `price` less the Black-Scholes `baseline`, absolute and in basis points of the
+/// baseline, in red when it is further than `outlier_bps` away.
+fn diff_cell(price: f64, baseline: f64, outlier_bps: f64, theme: &Theme) -> Cell<'static> {
+    let diff = price - baseline;
+    let bps = diff / baseline * 10_000.0;
+    if !bps.is_finite() {
+        return Cell::from(format!("{:+.4}", diff));
+    }
+    let cell = Cell::from(format!("{:+.4} ({:+.1}bp)", diff, bps));
+    if bps.abs() > outlier_bps {
+        cell.style(theme.warning())
+    } else {
+        cell
+    }
+}
+
 fn ui(f: &mut Frame, app: &App) {
+    let theme = app.theme.theme();
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
-        .constraints([Constraint::Percentage(100), Constraint::Ratio(1, 8)])
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
         .split(f.area());
+    tabs::render(f, chunks[0], app.tab, &theme);
+    help::render_footer(f, chunks[2], app.tab, &theme);
+    let area = chunks[1];
 
-    let header_cells = [
-        "Models", "Call", "Put", "Delta", "Gamma", "Vega", "Theta", "Rho",
-    ]
-    .iter()
-    .map(|h| {
-        Cell::from(*h).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
-        )
-    });
-    let header = Row::new(header_cells).style(Style::default().bg(Color::Black));
-    let rows = app.models.iter().map(|wrapper| {
-        let results = wrapper.get_results(&app.params);
-        let cells = vec![
+    match app.tab {
+        Tab::Models => render_models(f, area, app),
+        Tab::Strategy if app.strategy.is_some() => render_view(f, area, app),
+        Tab::Strategy => render_empty(f, area, "no strategy loaded; start with --strategy <file>"),
+        Tab::Chain => match &app.ladder {
+            Some(ladder) => chain::render_ladder(f, area, ladder, &theme),
+            None => render_empty(f, area, "no chain loaded; start with --chain <file>"),
+        },
+        Tab::Scenarios => render_view(f, area, app),
+        Tab::Portfolio if app.book.is_some() => render_view(f, area, app),
+        Tab::Portfolio => render_empty(
+            f,
+            area,
+            "no portfolio loaded; start with --portfolio <file>",
+        ),
+    }
+    if app.show_help {
+        help::render_overlay(f, area, app.tab, &settings(app), &theme);
+    }
+}
+
+/// The parameters and view settings listed in the help overlay.
+fn settings(app: &App) -> Vec<(&'static str, String)> {
+    let params = &app.params;
+    let mut settings = vec![
+        ("spot", params.s.to_string()),
+        ("strike", params.k.to_string()),
+        ("rate", params.r.to_string()),
+        ("sigma", params.sigma.to_string()),
+        ("expiry", format!("{}y", params.t)),
+    ];
+    if let Some(wrapper) = app.selected_model() {
+        settings.push(("model", wrapper.name.clone()));
+    }
+    let option = match app.chart_type {
+        OptionType::Call => "call",
+        OptionType::Put => "put",
+    };
+    settings.push(("option", option.to_string()));
+    settings.push(("measure", app.measure.name().to_string()));
+    let theme = app
+        .theme
+        .to_possible_value()
+        .expect("themes are not skipped");
+    settings.push(("theme", theme.get_name().to_string()));
+    settings
+}
+
+/// Draws the current view of the selected model, or a spinner until it is first computed.
+fn render_view(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme.theme();
+    let Some(wrapper) = app.selected_model() else {
+        return;
+    };
+    match app.current_view() {
+        Some(View::Curves(curves)) => curves.render(f, area, &theme),
+        Some(View::Greeks(curves)) => curves.render(f, area, &theme),
+        Some(View::Scenarios(matrix)) => scenario::render(
+            f,
+            area,
+            &wrapper.name,
+            matrix,
+            &app.params,
+            app.chart_type,
+            &theme,
+        ),
+        Some(View::Portfolio(report)) => portfolio::render_tab(f, area, report, &theme),
+        None => render_empty(
+            f,
+            area,
+            &format!("{} computing", pricing::spinner(app.tick)),
+        ),
+    }
+}
+
+/// Draws a tab that has nothing to show, with `message` saying why.
+fn render_empty(f: &mut Frame, area: Rect, message: &str) {
+    f.render_widget(Paragraph::new(message).block(Block::default()), area);
+}
+
+/// Draws the model table and, under it, the selected model's pane.
+fn render_models(f: &mut Frame, area: Rect, app: &App) {
+    let theme = app.theme.theme();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let mut titles: Vec<String> = (0..rows::COLUMNS.len())
+        .map(|i| app.order.title(i))
+        .collect();
+    if app.show_diff {
+        titles.extend(["Call vs BS".to_string(), "Put vs BS".to_string()]);
+    }
+    let header_cells = titles
+        .iter()
+        .map(|h| Cell::from(h.as_str()).style(theme.heading()));
+    let header = Row::new(header_cells).style(theme.header_row());
+    let baseline = BlackScholesModel.call_put_price(&app.params);
+    let visible = app.order.visible(&app.models);
+    let rows = visible.iter().map(|&i| {
+        let wrapper = &app.models[i];
+        let Some(results) = &wrapper.results else {
+            let spinner = pricing::spinner(app.tick);
+            return Row::new(
+                std::iter::once(Cell::from(wrapper.name.as_str()))
+                    .chain(titles[1..].iter().map(|_| Cell::from(spinner))),
+            );
+        };
+        let mut cells = vec![
             Cell::from(wrapper.name.as_str()),
             Cell::from(format!("{:.4}", results.call)),
             Cell::from(format!("{:.4}", results.put)),
@@ -269,25 +886,80 @@ fn ui(f: &mut Frame, app: &App) {
             Cell::from(format!("{:.4}", results.theta)),
             Cell::from(format!("{:.4}", results.rho)),
         ];
+        if app.show_diff {
+            cells.push(diff_cell(results.call, baseline.0, app.outlier_bps, &theme));
+            cells.push(diff_cell(results.put, baseline.1, app.outlier_bps, &theme));
+        }
         Row::new(cells)
     });
 
-    let widths = [
-        Constraint::Percentage(15),
-        Constraint::Percentage(12),
-        Constraint::Percentage(12),
-        Constraint::Percentage(12),
-        Constraint::Percentage(12),
-        Constraint::Percentage(12),
-        Constraint::Percentage(12),
-        Constraint::Percentage(13),
-    ];
+    let widths: Vec<Constraint> = std::iter::once(Constraint::Percentage(15))
+        .chain(titles[1..].iter().map(|title| {
+            // The baseline-diff columns hold both the difference and its basis points.
+            Constraint::Fill(if title.ends_with("vs BS") { 2 } else { 1 })
+        }))
+        .collect();
 
+    let dates = app.dates.as_ref().map(ExpiryDates::to_string);
+    let live = match app.feed.as_ref().map(live::Feed::status) {
+        None => None,
+        Some(live::Status::Streaming) => Some(format!(
+            "live: S={:.4} sigma={:.4} r={:.4}",
+            app.params.s, app.params.sigma, app.params.r
+        )),
+        Some(live::Status::Ended) => Some("live: feed ended".to_string()),
+        Some(live::Status::Failed(message)) => Some(format!("live: {}", message)),
+    };
+    let filter = match (&app.order.filter, app.order.editing) {
+        (filter, true) => Some(format!("filter: {}_", filter)),
+        (filter, false) if !filter.is_empty() => Some(format!("filter: {}", filter)),
+        _ => None,
+    };
+    let title = [dates, live, filter]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" | ");
     let table = Table::new(rows, widths)
         .header(header)
-        .block(Block::default())
-        .highlight_style(Style::default().bg(Color::Yellow).fg(Color::Black))
+        .block(Block::default().title(title))
+        .highlight_style(theme.selected())
         .highlight_symbol("> ");
 
-    f.render_stateful_widget(table, chunks[0], &mut app.table_state.clone());
+    let mut table_state = TableState::default();
+    table_state.select(Some(
+        visible.iter().position(|&i| i == app.selected).unwrap_or(0),
+    ));
+    f.render_stateful_widget(table, chunks[0], &mut table_state);
+
+    render_view(f, chunks[1], app);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition() {
+        Opts::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parses_subcommands() {
+        let opts =
+            Opts::try_parse_from(["cqf", "--config", "cli.json", "stress", "book.csv"]).unwrap();
+        assert_eq!(
+            opts.command.inputs(opts.config_file.as_deref()),
+            vec![PathBuf::from("book.csv"), PathBuf::from("cli.json")]
+        );
+
+        let missing =
+            Opts::try_parse_from(["cqf", "price", "-s", "100", "-k", "100", "-r", "0.05"])
+                .map(|_| ())
+                .unwrap_err();
+        assert_eq!(
+            missing.kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+    }
 }
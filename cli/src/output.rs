@@ -0,0 +1,280 @@
+use crate::expiry::ExpiryDates;
+use crate::{ModelConfig, ModelResults};
+use clap::ValueEnum;
+use core::models::OptionParameters;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+
+/// How results are printed when the CLI runs without the TUI.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Csv,
+    Table,
+}
+
+/// Which of the results `render` prints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Columns {
+    Prices,
+    Greeks,
+}
+
+impl Columns {
+    fn names(self) -> &'static [&'static str] {
+        match self {
+            Columns::Prices => &["call", "put"],
+            Columns::Greeks => &["delta", "gamma", "vega", "theta", "rho"],
+        }
+    }
+}
+
+/// A model's results in the chosen columns, serialized as one flat object.
+struct Row<'a> {
+    model: &'a str,
+    values: Vec<(&'static str, f64)>,
+}
+
+impl Serialize for Row<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.values.len() + 1))?;
+        map.serialize_entry("model", self.model)?;
+        for (name, value) in &self.values {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+/// The result named `name` in `Columns::names`.
+fn value(results: &ModelResults, name: &str) -> f64 {
+    match name {
+        "call" => results.call,
+        "put" => results.put,
+        "delta" => results.delta,
+        "gamma" => results.gamma,
+        "vega" => results.vega,
+        "theta" => results.theta,
+        _ => results.rho,
+    }
+}
+
+#[derive(Serialize)]
+struct Report<'a> {
+    parameters: &'a OptionParameters,
+    settings: &'a ModelConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expiry: Option<&'a ExpiryDates>,
+    models: Vec<Row<'a>>,
+}
+
+/// Formats every model's prices or Greeks for stdout. JSON and table output also
+/// record the model settings and any expiry date `t` came from; CSV stays a bare table
+/// for spreadsheets.
+pub fn render(
+    format: OutputFormat,
+    columns: Columns,
+    params: &OptionParameters,
+    config: &ModelConfig,
+    dates: Option<&ExpiryDates>,
+    results: &[(String, ModelResults)],
+) -> String {
+    let names = columns.names();
+    let header: Vec<&str> = std::iter::once("model")
+        .chain(names.iter().copied())
+        .collect();
+    let rows: Vec<Row> = results
+        .iter()
+        .map(|(model, results)| Row {
+            model,
+            values: names
+                .iter()
+                .map(|&name| (name, value(results, name)))
+                .collect(),
+        })
+        .collect();
+    match format {
+        OutputFormat::Json => {
+            let report = Report {
+                parameters: params,
+                settings: config,
+                expiry: dates,
+                models: rows,
+            };
+            serde_json::to_string_pretty(&report).expect("results serialize as JSON") + "\n"
+        }
+        OutputFormat::Csv => {
+            let cells: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| values(row, |value| value.to_string()))
+                .collect();
+            csv(&header, &cells)
+        }
+        OutputFormat::Table => {
+            let cells: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| values(row, |value| format!("{:.4}", value)))
+                .collect();
+            let seed = config
+                .seed
+                .map_or_else(|| "random".to_string(), |seed| seed.to_string());
+            let mut out = format!(
+                "steps={} simulations={} seed={} omega={} alpha={} beta={}\n",
+                config.steps, config.simulations, seed, config.omega, config.alpha, config.beta
+            );
+            if let Some(dates) = dates {
+                out.push_str(&format!("{}\n", dates));
+            }
+            out.push('\n');
+            out.push_str(&table(&header, &cells));
+            out
+        }
+    }
+}
+
+/// Aligns `rows` under `header` in columns two spaces apart, the first left-aligned and
+/// the rest right-aligned.
+pub fn table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = header
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            rows.iter()
+                .filter_map(|row| row.get(i).map(String::len))
+                .chain([title.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let line = |row: &[&str]| {
+        let padded: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i == 0 {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect();
+        padded.join("  ").trim_end().to_string() + "\n"
+    };
+    let mut out = line(header);
+    for row in rows {
+        out.push_str(&line(&row.iter().map(String::as_str).collect::<Vec<_>>()));
+    }
+    out
+}
+
+/// Writes `rows` under `header` as CSV.
+pub fn csv(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = header.join(",");
+    out.push('\n');
+    for row in rows {
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn values(row: &Row, format: impl Fn(f64) -> String) -> Vec<String> {
+    let mut values = vec![row.model.to_string()];
+    values.extend(row.values.iter().map(|&(_, value)| format(value)));
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn config() -> ModelConfig {
+        ModelConfig {
+            steps: 100,
+            simulations: 1000,
+            seed: Some(7),
+            omega: 0.000_02,
+            alpha: 0.1,
+            beta: 0.8,
+        }
+    }
+
+    fn params() -> OptionParameters {
+        OptionParameters {
+            s: 100.0,
+            k: 100.0,
+            r: 0.05,
+            sigma: 0.2,
+            t: 1.0,
+        }
+    }
+
+    fn results() -> Vec<(String, ModelResults)> {
+        let results = ModelResults {
+            call: 10.450_583_572,
+            put: 5.573_526_022,
+            delta: 0.636_830_651,
+            gamma: 0.018_762_017,
+            vega: 37.524_034,
+            theta: -6.414_027_546,
+            rho: 53.232_481_545,
+        };
+        vec![("black_scholes".to_string(), results)]
+    }
+
+    #[test]
+    fn test_table_aligns_rounded_prices() {
+        let out = render(
+            OutputFormat::Table,
+            Columns::Prices,
+            &params(),
+            &config(),
+            None,
+            &results(),
+        );
+        assert_eq!(
+            out,
+            "steps=100 simulations=1000 seed=7 omega=0.00002 alpha=0.1 beta=0.8\n\n\
+             model             call     put\n\
+             black_scholes  10.4506  5.5735\n"
+        );
+    }
+
+    #[test]
+    fn test_json_records_parameters_settings_and_greeks() {
+        let out = render(
+            OutputFormat::Json,
+            Columns::Greeks,
+            &params(),
+            &config(),
+            None,
+            &results(),
+        );
+        let report: Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(report["parameters"]["sigma"], 0.2);
+        assert_eq!(report["settings"]["seed"], 7);
+        assert!(report.get("expiry").is_none());
+        let model = &report["models"][0];
+        assert_eq!(model["model"], "black_scholes");
+        assert_eq!(model["delta"], 0.636_830_651);
+        assert!(model.get("call").is_none());
+    }
+
+    #[test]
+    fn test_csv_keeps_full_precision() {
+        let out = render(
+            OutputFormat::Csv,
+            Columns::Prices,
+            &params(),
+            &config(),
+            None,
+            &results(),
+        );
+        assert_eq!(
+            out,
+            "model,call,put\nblack_scholes,10.450583572,5.573526022\n"
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::expiry::{ExpiryDates, ExpiryOpts};
+use crate::hv::UseHvOpts;
+use clap::Args;
+use core::models::OptionParameters;
+use std::path::PathBuf;
+
+/// The option to price: spot, strike, rate, volatility and time to expiry, with the
+/// volatility optionally estimated from history and the expiry given as a date.
+#[derive(Args, Clone, Debug)]
+pub struct OptionArgs {
+    /// The spot price of the underlying.
+    #[arg(short, long)]
+    s: f64,
+    /// The strike price.
+    #[arg(short, long)]
+    k: f64,
+    /// The annual risk-free rate, e.g. `0.05`.
+    #[arg(short, long)]
+    r: f64,
+    /// The annual volatility, e.g. `0.2`.
+    #[arg(short = 'm', long, required_unless_present = "use_hv")]
+    sigma: Option<f64>,
+    #[command(flatten)]
+    hv: UseHvOpts,
+    /// The time to expiry in years.
+    #[arg(short, long, required_unless_present = "expiry")]
+    t: Option<f64>,
+    #[command(flatten)]
+    dates: ExpiryOpts,
+}
+
+impl OptionArgs {
+    /// The files the parameters are read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        self.hv.inputs()
+    }
+
+    /// Builds the parameters, deriving `t` from `--expiry` and `sigma` from `--use-hv`
+    /// when given; also returns the dates behind a derived `t`.
+    pub fn resolve(&self) -> Result<(OptionParameters, Option<ExpiryDates>), String> {
+        let dates = self.dates.resolve()?;
+        let t = match &dates {
+            Some(dates) => dates.t,
+            None => self.t.expect("clap requires -t without --expiry"),
+        };
+        let sigma = match self.hv.resolve()? {
+            Some(sigma) => sigma,
+            None => self.sigma.expect("clap requires --sigma without --use-hv"),
+        };
+        let params = OptionParameters {
+            s: self.s,
+            k: self.k,
+            r: self.r,
+            sigma,
+            t,
+        };
+        Ok((params, dates))
+    }
+}
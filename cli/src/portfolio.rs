@@ -0,0 +1,375 @@
+use crate::output::{self, OutputFormat};
+use crate::strategy::name;
+use crate::theme::Theme;
+use crate::{model_spec, parse_model_name, ModelConfig};
+use clap::Args;
+use core::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use core::portfolio::{Instrument, Portfolio, Position, ValueAtRisk};
+use core::strategies::Side;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Marks a book of positions and reports its value, Greeks and value at risk.
+#[derive(Args)]
+pub struct PortfolioOpts {
+    /// The positions; see `--portfolio` of `tui` for the format.
+    file: PathBuf,
+    /// The model to mark the options with.
+    #[arg(long, value_parser = parse_model_name, default_value = "black_scholes")]
+    model: String,
+    #[command(flatten)]
+    var: VarOpts,
+    #[arg(short, long, value_enum, default_value = "table")]
+    output: OutputFormat,
+}
+
+/// How the value at risk of a portfolio is estimated.
+#[derive(Args, Clone, Debug)]
+pub struct VarOpts {
+    /// The value-at-risk confidence level, e.g. `0.99`.
+    #[arg(long, default_value_t = 0.99)]
+    confidence: f64,
+    /// The value-at-risk holding period in trading days.
+    #[arg(long, default_value_t = 1.0)]
+    horizon_days: f64,
+    /// The underlying's volatility for the value at risk; the first option's by default.
+    #[arg(long)]
+    var_volatility: Option<f64>,
+}
+
+/// A position as a CSV row; options need every field, stock only `s`.
+#[derive(Deserialize)]
+struct CsvPosition {
+    /// `call`, `put` or `stock`.
+    kind: String,
+    side: Side,
+    quantity: f64,
+    entry_price: f64,
+    s: f64,
+    k: Option<f64>,
+    r: Option<f64>,
+    sigma: Option<f64>,
+    t: Option<f64>,
+    underlying: Option<String>,
+}
+
+impl CsvPosition {
+    fn position(self) -> Result<Position, String> {
+        let position = if self.kind == "stock" {
+            Position::stock(self.side, self.quantity, self.s, self.entry_price)
+        } else {
+            let option_type = match self.kind.as_str() {
+                "call" => OptionType::Call,
+                "put" => OptionType::Put,
+                kind => {
+                    return Err(format!(
+                        "unknown kind {:?}; expected call, put or stock",
+                        kind
+                    ))
+                }
+            };
+            let (Some(k), Some(r), Some(sigma), Some(t)) = (self.k, self.r, self.sigma, self.t)
+            else {
+                return Err(format!("a {} needs k, r, sigma and t", self.kind));
+            };
+            let params = OptionParameters {
+                s: self.s,
+                k,
+                r,
+                sigma,
+                t,
+            };
+            Position::option(
+                option_type,
+                self.side,
+                self.quantity,
+                params,
+                self.entry_price,
+            )
+        };
+        Ok(match &self.underlying {
+            Some(underlying) if !underlying.is_empty() => position.with_underlying(underlying),
+            _ => position,
+        })
+    }
+}
+
+/// A portfolio read from a file, with the settings its value at risk is estimated with.
+pub struct Book {
+    portfolio: Portfolio,
+    volatility: f64,
+    confidence: f64,
+    /// The holding period in years.
+    horizon: f64,
+}
+
+impl Book {
+    /// Reads the positions at `path` and checks `var` against them.
+    pub fn load(path: &Path, var: &VarOpts) -> Result<Self, String> {
+        if !(var.confidence > 0.5 && var.confidence < 1.0) {
+            return Err(format!(
+                "--confidence must be between 0.5 and 1, not {}",
+                var.confidence
+            ));
+        }
+        let portfolio = read_positions(path)?;
+        let volatility = var
+            .var_volatility
+            .or(portfolio.option_volatility())
+            .ok_or_else(|| {
+                format!(
+                    "{} holds no options to take a volatility from; pass --var-volatility",
+                    path.display()
+                )
+            })?;
+        Ok(Self {
+            portfolio,
+            volatility,
+            confidence: var.confidence,
+            horizon: var.horizon_days / 252.0,
+        })
+    }
+
+    /// Marks every position with `model`.
+    pub fn report(&self, model_name: &str, model: &dyn OptionPricingModel) -> PortfolioReport {
+        let positions = self
+            .portfolio
+            .positions()
+            .map(|(id, position)| {
+                let (kind, strike, expiry) = match &position.instrument {
+                    Instrument::Option {
+                        option_type,
+                        params,
+                    } => (name(option_type), Some(params.k), Some(params.t)),
+                    Instrument::Stock { .. } => ("stock".to_string(), None, None),
+                };
+                PositionReport {
+                    id,
+                    underlying: position.underlying.clone(),
+                    kind,
+                    side: position.side,
+                    quantity: position.quantity,
+                    strike,
+                    expiry,
+                    unit_price: position.unit_price(model),
+                    value: position.value(model),
+                    pnl: position.unrealized_pnl(model),
+                    greeks: position.greeks(model),
+                }
+            })
+            .collect();
+        PortfolioReport {
+            model: model_name.to_string(),
+            value: self.portfolio.value(model),
+            entry_cost: self.portfolio.entry_cost(),
+            pnl: self.portfolio.unrealized_pnl(model),
+            greeks: self.portfolio.greeks(model),
+            var: self.portfolio.value_at_risk(
+                model,
+                self.volatility,
+                self.confidence,
+                self.horizon,
+            ),
+            positions,
+        }
+    }
+}
+
+/// One marked position.
+#[derive(Serialize)]
+struct PositionReport {
+    id: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    underlying: Option<String>,
+    kind: String,
+    side: Side,
+    quantity: f64,
+    strike: Option<f64>,
+    expiry: Option<f64>,
+    unit_price: f64,
+    value: f64,
+    pnl: f64,
+    greeks: Greeks,
+}
+
+/// The marked portfolio.
+#[derive(Serialize)]
+pub struct PortfolioReport {
+    model: String,
+    value: f64,
+    entry_cost: f64,
+    pnl: f64,
+    greeks: Greeks,
+    var: ValueAtRisk,
+    positions: Vec<PositionReport>,
+}
+
+impl PortfolioOpts {
+    /// The files the positions are read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        vec![self.file.clone()]
+    }
+}
+
+pub fn run(opts: &PortfolioOpts, config: &ModelConfig) -> Result<String, String> {
+    let book = Book::load(&opts.file, &opts.var)?;
+    let model = model_spec(&opts.model, config)
+        .expect("model names are validated")
+        .build();
+    Ok(render(
+        opts.output,
+        &book.report(&opts.model, model.as_ref()),
+    ))
+}
+
+/// Reads positions from CSV with the columns `kind,side,quantity,entry_price,s,k,r,sigma,t`
+/// and an optional `underlying`, or from a JSON array of positions.
+pub fn read_positions(path: &Path) -> Result<Portfolio, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let is_csv = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+    let positions: Vec<Position> = if is_csv {
+        csv::Reader::from_reader(text.as_bytes())
+            .deserialize::<CsvPosition>()
+            .enumerate()
+            .map(|(i, row)| {
+                row.map_err(|err| err.to_string())
+                    .and_then(CsvPosition::position)
+                    .map_err(|err| format!("invalid row {} in {}: {}", i + 1, path.display(), err))
+            })
+            .collect::<Result<_, _>>()?
+    } else {
+        serde_json::from_str(&text)
+            .map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))?
+    };
+    let mut portfolio = Portfolio::new();
+    for position in positions {
+        portfolio.add(position);
+    }
+    Ok(portfolio)
+}
+
+const POSITION_COLUMNS: [&str; 15] = [
+    "id",
+    "underlying",
+    "kind",
+    "side",
+    "quantity",
+    "strike",
+    "expiry",
+    "unit",
+    "value",
+    "pnl",
+    "delta",
+    "gamma",
+    "vega",
+    "theta",
+    "rho",
+];
+
+/// Formats the report. CSV carries the per-position table only.
+fn render(format: OutputFormat, report: &PortfolioReport) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(report).expect("report serializes as JSON") + "\n"
+        }
+        OutputFormat::Csv => {
+            output::csv(&POSITION_COLUMNS, &position_rows(report, |x| x.to_string()))
+        }
+        OutputFormat::Table => {
+            let mut out = summary(report).join("\n");
+            out.push_str("\n\n");
+            out.push_str(&output::table(
+                &POSITION_COLUMNS,
+                &position_rows(report, |x| format!("{:.4}", x)),
+            ));
+            out
+        }
+    }
+}
+
+/// The report's totals, one line each.
+fn summary(report: &PortfolioReport) -> Vec<String> {
+    let g = &report.greeks;
+    let var = &report.var;
+    vec![
+        format!("model      {}", report.model),
+        format!("net value  {:.4}", report.value),
+        format!("entry cost {:.4}", report.entry_cost),
+        format!("pnl        {:.4}", report.pnl),
+        format!(
+            "greeks     delta {:.4}  gamma {:.4}  vega {:.4}  theta {:.4}  rho {:.4}",
+            g.delta, g.gamma, g.vega, g.theta, g.rho
+        ),
+        format!(
+            "var        {:.4} at {}% over {} days (spot {:+.2}% at {:.1}% vol)",
+            var.var,
+            var.confidence * 100.0,
+            var.horizon * 252.0,
+            var.spot_shock * 100.0,
+            var.volatility * 100.0
+        ),
+    ]
+}
+
+fn position_rows(report: &PortfolioReport, number: impl Fn(f64) -> String) -> Vec<Vec<String>> {
+    report
+        .positions
+        .iter()
+        .map(|p| {
+            let g = &p.greeks;
+            vec![
+                p.id.to_string(),
+                p.underlying.clone().unwrap_or_default(),
+                p.kind.clone(),
+                name(&p.side),
+                p.quantity.to_string(),
+                p.strike.map_or_else(String::new, &number),
+                p.expiry.map_or_else(String::new, &number),
+                number(p.unit_price),
+                number(p.value),
+                number(p.pnl),
+                number(g.delta),
+                number(g.gamma),
+                number(g.vega),
+                number(g.theta),
+                number(g.rho),
+            ]
+        })
+        .collect()
+}
+
+/// Draws the report's totals over its positions, for the Portfolio tab.
+pub fn render_tab(f: &mut Frame, area: Rect, report: &PortfolioReport, theme: &Theme) {
+    let lines = summary(report);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(lines.len() as u16 + 1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+    f.render_widget(Paragraph::new(lines.join("\n")), chunks[0]);
+
+    let header = Row::new(
+        POSITION_COLUMNS
+            .iter()
+            .map(|title| Cell::from(*title).style(theme.heading())),
+    )
+    .style(theme.header_row());
+    let rows = position_rows(report, |x| format!("{:.4}", x))
+        .into_iter()
+        .map(Row::new);
+    let widths = [Constraint::Ratio(1, POSITION_COLUMNS.len() as u32); 15];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("Positions"));
+    f.render_widget(table, chunks[1]);
+}
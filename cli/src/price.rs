@@ -0,0 +1,65 @@
+use crate::output::{self, Columns, OutputFormat};
+use crate::params::OptionArgs;
+use crate::{live, load_models, parse_model_name, ModelConfig, ModelResults};
+use clap::Args;
+use core::models::OptionParameters;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Prices an option with every selected model.
+#[derive(Args)]
+pub struct PriceOpts {
+    #[command(flatten)]
+    option: OptionArgs,
+    /// Comma-separated models to price with, e.g. `black_scholes,garch`; all by default.
+    #[arg(long, value_delimiter = ',', value_parser = parse_model_name)]
+    models: Vec<String>,
+    #[arg(short, long, value_enum, default_value = "table")]
+    output: OutputFormat,
+    /// Reprint on every update of a quote feed: a `ws://` or `wss://` URL, or `-` for one
+    /// JSON quote per line on stdin, e.g. `{"spot": 101.5, "sigma": 0.22}`.
+    #[arg(long)]
+    live: Option<String>,
+    /// Minimum milliseconds between repricings in live mode.
+    #[arg(long, default_value_t = 250, requires = "live")]
+    throttle: u64,
+}
+
+impl PriceOpts {
+    /// The files the option is read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        self.option.inputs()
+    }
+}
+
+/// Prints the `columns` of every model's results, then again after every live update.
+pub async fn run(opts: &PriceOpts, config: &ModelConfig, columns: Columns) -> Result<(), String> {
+    let (mut params, dates) = opts.option.resolve()?;
+    let models = load_models(&opts.models, config);
+    let render = |params: &OptionParameters| {
+        let results: Vec<(String, ModelResults)> = models
+            .iter()
+            .map(|wrapper| {
+                let results = ModelResults::compute(wrapper.model.as_ref(), params);
+                (wrapper.name.clone(), results)
+            })
+            .collect();
+        output::render(
+            opts.output,
+            columns,
+            params,
+            config,
+            dates.as_ref(),
+            &results,
+        )
+    };
+    print!("{}", render(&params));
+    if let Some(source) = &opts.live {
+        let mut feed = live::Feed::open(source, Duration::from_millis(opts.throttle)).await?;
+        while let Some(quote) = feed.next().await? {
+            params = live::apply(&quote, &params);
+            print!("{}", render(&params));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,329 @@
+use crate::chart::{Curves, GreekCurves, Subject};
+use crate::portfolio::{Book, PortfolioReport};
+use crate::ModelResults;
+use core::models::{OptionParameters, OptionType};
+use core::portfolio::{ScenarioMatrix, ScenarioMeasure, ShockGrid};
+use core::strategies::{SharedModel, StrategyDefinition};
+use std::collections::{HashMap, HashSet};
+use std::mem::discriminant;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// Frames of the spinner shown in place of a result still being computed.
+const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+
+/// Returns the spinner frame for the `tick`th redraw.
+pub fn spinner(tick: usize) -> &'static str {
+    SPINNER[tick % SPINNER.len()]
+}
+
+/// A model's results for one round of parameters.
+pub struct Priced {
+    pub index: usize,
+    pub round: u64,
+    pub results: ModelResults,
+}
+
+/// What a chart or report view is computed from, with `model` indexing the model table.
+/// A computed view is kept under its request and recomputed only when the request
+/// changes.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViewRequest {
+    Payoff {
+        model: usize,
+        params: OptionParameters,
+        option_type: OptionType,
+    },
+    Greeks {
+        model: usize,
+        params: OptionParameters,
+        option_type: OptionType,
+        shown: [bool; 4],
+    },
+    Strategy {
+        model: usize,
+        params: OptionParameters,
+    },
+    Scenarios {
+        model: usize,
+        params: OptionParameters,
+        option_type: OptionType,
+        measure: ScenarioMeasure,
+    },
+    Portfolio {
+        model: usize,
+    },
+}
+
+impl ViewRequest {
+    pub fn model(&self) -> usize {
+        match self {
+            ViewRequest::Payoff { model, .. }
+            | ViewRequest::Greeks { model, .. }
+            | ViewRequest::Strategy { model, .. }
+            | ViewRequest::Scenarios { model, .. }
+            | ViewRequest::Portfolio { model } => *model,
+        }
+    }
+
+    /// Whether `other` draws the same view of the same model, perhaps at other inputs.
+    pub fn same_view(&self, other: &ViewRequest) -> bool {
+        discriminant(self) == discriminant(other) && self.model() == other.model()
+    }
+}
+
+/// What views need besides their request.
+pub struct ViewInputs {
+    pub name: String,
+    pub model: SharedModel,
+    pub strategy: Option<Arc<StrategyDefinition>>,
+    pub shocks: ShockGrid,
+    pub book: Option<Arc<Book>>,
+}
+
+/// A computed chart or report.
+pub enum View {
+    Curves(Curves),
+    Greeks(GreekCurves),
+    Scenarios(ScenarioMatrix),
+    Portfolio(PortfolioReport),
+}
+
+impl View {
+    /// Computes the view `request` asks for, or `None` when its strategy or book is
+    /// missing from `inputs`.
+    pub fn compute(request: &ViewRequest, inputs: &ViewInputs) -> Option<Self> {
+        let model = inputs.model.as_ref();
+        let view = match request {
+            ViewRequest::Payoff {
+                params,
+                option_type,
+                ..
+            } => View::Curves(Curves::new(
+                &Subject::Option(*option_type),
+                &inputs.name,
+                model,
+                params,
+            )),
+            ViewRequest::Greeks {
+                params,
+                option_type,
+                shown,
+                ..
+            } => View::Greeks(GreekCurves::new(
+                &inputs.name,
+                model,
+                params,
+                *option_type,
+                shown,
+            )),
+            ViewRequest::Strategy { params, .. } => View::Curves(Curves::new(
+                &Subject::Strategy(inputs.strategy.as_deref()?),
+                &inputs.name,
+                model,
+                params,
+            )),
+            ViewRequest::Scenarios {
+                params,
+                option_type,
+                measure,
+                ..
+            } => View::Scenarios(ScenarioMatrix::new(
+                model,
+                params,
+                *option_type,
+                &inputs.shocks,
+                *measure,
+            )),
+            ViewRequest::Portfolio { .. } => {
+                View::Portfolio(inputs.book.as_deref()?.report(&inputs.name, model))
+            }
+        };
+        Some(view)
+    }
+}
+
+/// Prices models and computes views on the blocking thread pool and hands the results
+/// back over channels, so a slow model never holds up the render loop.
+///
+/// At most one job per model, and one view, runs at a time. A request made while one is
+/// running waits until it finishes, replacing any request already waiting, so bursts of
+/// parameter changes such as live quotes never pile up work on the pool.
+pub struct Pricer {
+    sender: mpsc::UnboundedSender<Priced>,
+    receiver: mpsc::UnboundedReceiver<Priced>,
+    views: mpsc::UnboundedSender<(ViewRequest, Option<View>)>,
+    computed: mpsc::UnboundedReceiver<(ViewRequest, Option<View>)>,
+    round: u64,
+    /// The models being priced, by index.
+    running: HashSet<usize>,
+    /// The latest request for each model being priced, with its round.
+    waiting: HashMap<usize, (SharedModel, OptionParameters, u64)>,
+    /// Whether a view is being computed.
+    view_running: bool,
+    /// The latest view requested while one was being computed.
+    view_waiting: Option<(ViewRequest, ViewInputs)>,
+}
+
+impl Pricer {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let (views, computed) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver,
+            views,
+            computed,
+            round: 0,
+            running: HashSet::new(),
+            waiting: HashMap::new(),
+            view_running: false,
+            view_waiting: None,
+        }
+    }
+
+    /// Starts a new round, making the results of every earlier one stale.
+    pub fn next_round(&mut self) {
+        self.round += 1;
+    }
+
+    /// Prices `model`, the `index`th of the table, at `params` in the current round, once
+    /// any earlier job for it finishes.
+    pub fn spawn(&mut self, index: usize, model: SharedModel, params: OptionParameters) {
+        if self.running.contains(&index) {
+            self.waiting.insert(index, (model, params, self.round));
+        } else {
+            self.start(index, model, params, self.round);
+        }
+    }
+
+    fn start(&mut self, index: usize, model: SharedModel, params: OptionParameters, round: u64) {
+        self.running.insert(index);
+        let sender = self.sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let results = ModelResults::compute(model.as_ref(), &params);
+            // The receiver outlives every task unless the TUI is exiting.
+            let _ = sender.send(Priced {
+                index,
+                round,
+                results,
+            });
+        });
+    }
+
+    /// Returns a result of the current round that arrived since the last call, without
+    /// waiting; stale results are dropped. Starts the waiting job of each model whose
+    /// job finished.
+    pub fn poll(&mut self) -> Option<Priced> {
+        while let Ok(priced) = self.receiver.try_recv() {
+            self.running.remove(&priced.index);
+            if let Some((model, params, round)) = self.waiting.remove(&priced.index) {
+                self.start(priced.index, model, params, round);
+            }
+            if priced.round == self.round {
+                return Some(priced);
+            }
+        }
+        None
+    }
+
+    /// Computes the view `request` asks for, once any view being computed is done.
+    pub fn spawn_view(&mut self, request: ViewRequest, inputs: ViewInputs) {
+        if self.view_running {
+            self.view_waiting = Some((request, inputs));
+        } else {
+            self.start_view(request, inputs);
+        }
+    }
+
+    fn start_view(&mut self, request: ViewRequest, inputs: ViewInputs) {
+        self.view_running = true;
+        let views = self.views.clone();
+        tokio::task::spawn_blocking(move || {
+            let view = View::compute(&request, &inputs);
+            let _ = views.send((request, view));
+        });
+    }
+
+    /// Returns a view that arrived since the last call, without waiting, and starts the
+    /// view waiting for it.
+    pub fn poll_view(&mut self) -> Option<(ViewRequest, View)> {
+        while let Ok((request, view)) = self.computed.try_recv() {
+            self.view_running = false;
+            if let Some((request, inputs)) = self.view_waiting.take() {
+                self.start_view(request, inputs);
+            }
+            if let Some(view) = view {
+                return Some((request, view));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::models::OptionPricingModel;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Takes a while to price, counting how often it does.
+    struct Slow(AtomicUsize);
+
+    impl OptionPricingModel for Slow {
+        fn call_price(&self, params: &OptionParameters) -> f64 {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(50));
+            params.s
+        }
+        fn put_price(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+        fn delta(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+        fn gamma(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+        fn vega(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+        fn theta(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+        fn rho(&self, _: &OptionParameters) -> f64 {
+            0.0
+        }
+    }
+
+    #[test]
+    fn test_bursts_price_the_first_and_latest_params_only() {
+        // `tokio::test` cannot expand here, where `core` names the pricing crate.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let _guard = runtime.enter();
+        let slow = Arc::new(Slow(AtomicUsize::new(0)));
+        let model: SharedModel = slow.clone();
+        let mut pricer = Pricer::new();
+        for s in 1..=10 {
+            pricer.next_round();
+            let params = OptionParameters {
+                s: s as f64,
+                k: 100.0,
+                r: 0.05,
+                sigma: 0.2,
+                t: 1.0,
+            };
+            pricer.spawn(0, model.clone(), params);
+        }
+        let priced = loop {
+            if let Some(priced) = pricer.poll() {
+                break priced;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        };
+        assert_eq!(priced.results.call, 10.0);
+        assert_eq!(slow.0.load(Ordering::SeqCst), 2);
+        assert!(pricer.running.is_empty() && pricer.waiting.is_empty());
+    }
+}
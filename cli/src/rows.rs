@@ -0,0 +1,158 @@
+use crate::{ModelResults, ModelWrapper};
+
+/// Columns of the model table, in display order.
+pub const COLUMNS: [&str; 8] = [
+    "Models", "Call", "Put", "Delta", "Gamma", "Vega", "Theta", "Rho",
+];
+
+/// Which models the table lists and in what order: a name filter and an optional sort
+/// column.
+#[derive(Clone, Debug, Default)]
+pub struct RowOrder {
+    /// The sorted column of `COLUMNS`, or `None` for registry order.
+    pub column: Option<usize>,
+    pub descending: bool,
+    /// Models are listed only if their name contains this.
+    pub filter: String,
+    /// Whether keys are being typed into the filter.
+    pub editing: bool,
+}
+
+impl RowOrder {
+    /// Sorts by the column after the current one, then goes back to registry order.
+    pub fn next_column(&mut self) {
+        self.column = match self.column {
+            None => Some(0),
+            Some(i) if i + 1 < COLUMNS.len() => Some(i + 1),
+            Some(_) => None,
+        };
+    }
+
+    /// The header of column `i`, marked with the sort direction when sorted by it.
+    pub fn title(&self, i: usize) -> String {
+        match self.column {
+            Some(column) if column == i => {
+                format!("{} {}", COLUMNS[i], if self.descending { "▼" } else { "▲" })
+            }
+            _ => COLUMNS[i].to_string(),
+        }
+    }
+
+    /// Indices into `models` of the listed models, in listing order. Models still
+    /// being priced sort after the others.
+    pub fn visible(&self, models: &[ModelWrapper]) -> Vec<usize> {
+        let mut visible: Vec<usize> = (0..models.len())
+            .filter(|&i| models[i].name.contains(self.filter.as_str()))
+            .collect();
+        let Some(column) = self.column else {
+            return visible;
+        };
+        if column == 0 {
+            visible.sort_by(|&a, &b| models[a].name.cmp(&models[b].name));
+            if self.descending {
+                visible.reverse();
+            }
+            return visible;
+        }
+        visible.sort_by(|&a, &b| {
+            let value = |i: usize| models[i].results.as_ref().map(|r| value(r, column));
+            match (value(a), value(b)) {
+                (Some(a), Some(b)) if self.descending => b.total_cmp(&a),
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            }
+        });
+        visible
+    }
+}
+
+/// The value in column `column` of `COLUMNS`, past the name.
+fn value(results: &ModelResults, column: usize) -> f64 {
+    match column {
+        1 => results.call,
+        2 => results.put,
+        3 => results.delta,
+        4 => results.gamma,
+        5 => results.vega,
+        6 => results.theta,
+        _ => results.rho,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::models::BlackScholesModel;
+    use core::strategies::SharedModel;
+    use std::sync::Arc;
+
+    fn wrapper(name: &str, call: Option<f64>) -> ModelWrapper {
+        let model: SharedModel = Arc::new(BlackScholesModel);
+        ModelWrapper {
+            name: name.to_string(),
+            model,
+            results: call.map(|call| ModelResults {
+                call,
+                put: 0.0,
+                delta: 0.0,
+                gamma: 0.0,
+                vega: 0.0,
+                theta: 0.0,
+                rho: 0.0,
+            }),
+        }
+    }
+
+    fn models() -> Vec<ModelWrapper> {
+        vec![
+            wrapper("monte_carlo", Some(10.6)),
+            wrapper("black_scholes", Some(10.4)),
+            wrapper("garch", None),
+            wrapper("binomial_tree", Some(10.5)),
+        ]
+    }
+
+    #[test]
+    fn test_unsorted_rows_keep_registry_order() {
+        assert_eq!(RowOrder::default().visible(&models()), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_sorts_by_name_and_value_with_unpriced_last() {
+        let mut order = RowOrder {
+            column: Some(0),
+            ..RowOrder::default()
+        };
+        assert_eq!(order.visible(&models()), vec![3, 1, 2, 0]);
+        order.descending = true;
+        assert_eq!(order.visible(&models()), vec![0, 2, 1, 3]);
+
+        order.next_column();
+        assert_eq!(order.title(1), "Call ▼");
+        assert_eq!(order.visible(&models()), vec![0, 3, 1, 2]);
+        order.descending = false;
+        assert_eq!(order.title(1), "Call ▲");
+        assert_eq!(order.visible(&models()), vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_filter_keeps_matching_names() {
+        let order = RowOrder {
+            column: Some(1),
+            filter: "o".to_string(),
+            ..RowOrder::default()
+        };
+        assert_eq!(order.visible(&models()), vec![1, 3, 0]);
+    }
+
+    #[test]
+    fn test_next_column_cycles_back_to_registry_order() {
+        let mut order = RowOrder::default();
+        for _ in 0..COLUMNS.len() {
+            order.next_column();
+        }
+        assert_eq!(order.column, Some(COLUMNS.len() - 1));
+        order.next_column();
+        assert_eq!(order.column, None);
+    }
+}
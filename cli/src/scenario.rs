@@ -0,0 +1,107 @@
+use crate::theme::Theme;
+use clap::Args;
+use core::models::{OptionParameters, OptionType};
+use core::portfolio::{ScenarioMatrix, ScenarioMeasure, ShockGrid};
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Cell, Row, Table},
+    Frame,
+};
+
+/// The shocks of the TUI's scenario matrix.
+#[derive(Args, Clone, Debug, PartialEq)]
+pub struct ScenarioConfig {
+    /// Largest relative move in the underlying, e.g. `0.15` for ±15%.
+    #[arg(long, default_value_t = 0.15)]
+    spot_range: f64,
+    /// Spot shocks on each side of no change.
+    #[arg(long, default_value_t = 5)]
+    spot_steps: usize,
+    /// Largest absolute move in volatility, e.g. `0.1` for ±10 vol points.
+    #[arg(long, default_value_t = 0.1)]
+    vol_range: f64,
+    /// Volatility shocks on each side of no change.
+    #[arg(long, default_value_t = 2)]
+    vol_steps: usize,
+}
+
+impl ScenarioConfig {
+    pub fn grid(&self) -> ShockGrid {
+        ShockGrid::symmetric(
+            self.spot_range,
+            self.spot_steps,
+            self.vol_range,
+            self.vol_steps,
+        )
+    }
+}
+
+/// Returns the measure after `measure`, wrapping around.
+pub fn next_measure(measure: ScenarioMeasure) -> ScenarioMeasure {
+    let all = ScenarioMeasure::ALL;
+    let i = all.iter().position(|m| *m == measure).unwrap_or(0);
+    all[(i + 1) % all.len()]
+}
+
+/// Shades `value` from blue at `min` to red at `max`.
+fn shade(value: f64, (min, max): (f64, f64)) -> Color {
+    let t = if max > min && value.is_finite() {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.5
+    };
+    let mix = |low: u8, high: u8| (low as f64 + (high as f64 - low as f64) * t).round() as u8;
+    Color::Rgb(mix(60, 200), mix(100, 60), mix(200, 60))
+}
+
+/// Draws `matrix`, the scenarios of an option with today's `params`, as a shaded table.
+pub fn render(
+    f: &mut Frame,
+    area: Rect,
+    model_name: &str,
+    matrix: &ScenarioMatrix,
+    params: &OptionParameters,
+    option_type: OptionType,
+    theme: &Theme,
+) {
+    let range = matrix.range().unwrap_or((0.0, 0.0));
+    let bold = theme.heading();
+
+    let header = Row::new(
+        std::iter::once(Cell::from("spot \\ vol")).chain(
+            matrix
+                .volatility_shocks
+                .iter()
+                .map(|shock| Cell::from(format!("{:.1}%", (params.sigma + shock) * 100.0))),
+        ),
+    )
+    .style(bold);
+    let rows = matrix
+        .spot_shocks
+        .iter()
+        .zip(&matrix.values)
+        .map(|(shock, values)| {
+            let spot = Cell::from(format!("{:.2}", params.s * (1.0 + shock))).style(bold);
+            Row::new(std::iter::once(spot).chain(values.iter().map(|&value| {
+                Cell::from(format!("{:.4}", value))
+                    .style(Style::default().fg(Color::White).bg(shade(value, range)))
+            })))
+        });
+    let widths: Vec<Constraint> = std::iter::once(Constraint::Length(11))
+        .chain(matrix.volatility_shocks.iter().map(|_| Constraint::Min(9)))
+        .collect();
+    let kind = match option_type {
+        OptionType::Call => "call",
+        OptionType::Put => "put",
+    };
+    let table = Table::new(rows, widths).header(header).block(
+        Block::default().borders(Borders::TOP).title(format!(
+            "{} {} {} by spot and volatility",
+            model_name,
+            kind,
+            matrix.measure.name()
+        )),
+    );
+    f.render_widget(table, area);
+}
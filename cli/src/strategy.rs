@@ -0,0 +1,254 @@
+use crate::output::{self, OutputFormat};
+use crate::{model_spec, parse_model_name, ModelConfig};
+use clap::Args;
+use core::models::{Greeks, OptionPricingModel};
+use core::strategies::factory::create_definition;
+use core::strategies::{BreakevenDirection, LegBreakdown, StrategyDefinition, StrategyKind};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::path::{Path, PathBuf};
+
+/// Prices a built-in strategy given by flags, or any strategy in a JSON file.
+#[derive(Args)]
+pub struct StrategyOpts {
+    /// The built-in strategy, e.g. `iron_condor`.
+    #[arg(long, required_unless_present = "file")]
+    kind: Option<StrategyKind>,
+    /// A JSON strategy definition (`model` and `legs`), or a built-in strategy as
+    /// `{"kind": ..., "s": ..., ...}`.
+    #[arg(long, conflicts_with = "kind")]
+    file: Option<PathBuf>,
+    /// The spot price of the underlying.
+    #[arg(short, long)]
+    s: Option<f64>,
+    /// The annual risk-free rate, e.g. `0.05`.
+    #[arg(short, long)]
+    r: Option<f64>,
+    /// The annual volatility, e.g. `0.2`.
+    #[arg(short = 'm', long)]
+    sigma: Option<f64>,
+    /// The time to expiry in years.
+    #[arg(short, long)]
+    t: Option<f64>,
+    /// The strike of single-strike strategies.
+    #[arg(short, long)]
+    k: Option<f64>,
+    /// Strikes of multi-strike strategies, in increasing order.
+    #[arg(long)]
+    k1: Option<f64>,
+    #[arg(long)]
+    k2: Option<f64>,
+    #[arg(long)]
+    k3: Option<f64>,
+    #[arg(long)]
+    k4: Option<f64>,
+    /// The far expiry of calendar and diagonal spreads.
+    #[arg(long)]
+    t2: Option<f64>,
+    /// Build with puts where the strategy can use either calls or puts.
+    #[arg(long)]
+    put: bool,
+    /// Build the bearish variant of a vertical spread.
+    #[arg(long)]
+    bear: bool,
+    /// The model to value the legs with; Black-Scholes unless the file names one.
+    #[arg(long, value_parser = parse_model_name)]
+    model: Option<String>,
+    #[arg(short, long, value_enum, default_value = "table")]
+    output: OutputFormat,
+}
+
+#[derive(Serialize)]
+struct BreakevenReport {
+    price: f64,
+    /// `above` when the position profits above the price, `below` otherwise.
+    profit: &'static str,
+}
+
+/// Infinite maximum profits and losses serialize as `null`, meaning unlimited.
+#[derive(Serialize)]
+struct StrategyReport {
+    model: core::strategies::ModelSpec,
+    price: f64,
+    greeks: Greeks,
+    legs: Vec<LegBreakdown>,
+    breakevens: Vec<BreakevenReport>,
+    max_profit: f64,
+    max_loss: f64,
+}
+
+impl StrategyOpts {
+    /// The files the strategy is read from.
+    pub fn inputs(&self) -> Vec<PathBuf> {
+        self.file.iter().cloned().collect()
+    }
+}
+
+pub fn run(opts: &StrategyOpts, config: &ModelConfig) -> Result<String, String> {
+    let mut definition = definition(opts)?;
+    if let Some(name) = &opts.model {
+        definition.model = model_spec(name, config).expect("model names are validated");
+    }
+    let model = definition.model.build();
+    let report = report(&definition, model.as_ref());
+    Ok(render(opts.output, &report))
+}
+
+fn definition(opts: &StrategyOpts) -> Result<StrategyDefinition, String> {
+    match &opts.file {
+        Some(path) => read_definition(path),
+        None => {
+            let kind = opts.kind.expect("clap requires a kind without a file");
+            create_definition(kind, &parameters(opts)).map_err(|err| err.to_string())
+        }
+    }
+}
+
+/// Reads a strategy definition, or a built-in strategy as `{"kind": ..., ...}`, from a
+/// JSON file.
+pub fn read_definition(path: &Path) -> Result<StrategyDefinition, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let value: Value = serde_json::from_str(&text)
+        .map_err(|err| format!("invalid JSON in {}: {}", path.display(), err))?;
+    match value.get("kind").and_then(Value::as_str) {
+        Some(kind) => {
+            let kind: StrategyKind = kind.parse().map_err(|err| format!("{}", err))?;
+            let params = value.as_object().cloned().unwrap_or_default();
+            create_definition(kind, &params).map_err(|err| err.to_string())
+        }
+        None => serde_json::from_value(value)
+            .map_err(|err| format!("invalid strategy definition: {}", err)),
+    }
+}
+
+/// Collects the factory parameters given as flags.
+fn parameters(opts: &StrategyOpts) -> Map<String, Value> {
+    let numbers = [
+        ("s", opts.s),
+        ("r", opts.r),
+        ("sigma", opts.sigma),
+        ("t", opts.t),
+        ("k", opts.k),
+        ("k1", opts.k1),
+        ("k2", opts.k2),
+        ("k3", opts.k3),
+        ("k4", opts.k4),
+        ("t2", opts.t2),
+    ];
+    let mut params: Map<String, Value> = numbers
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name.to_string(), json!(value))))
+        .collect();
+    params.insert("is_call".to_string(), json!(!opts.put));
+    params.insert("is_bull".to_string(), json!(!opts.bear));
+    params
+}
+
+fn report(definition: &StrategyDefinition, model: &dyn OptionPricingModel) -> StrategyReport {
+    let strategy = definition.strategy(model);
+    let breakevens = strategy
+        .breakevens()
+        .into_iter()
+        .map(|breakeven| BreakevenReport {
+            price: breakeven.price,
+            profit: match breakeven.direction {
+                BreakevenDirection::Lower => "above",
+                BreakevenDirection::Upper => "below",
+            },
+        })
+        .collect();
+    StrategyReport {
+        model: definition.model.clone(),
+        price: strategy.price(),
+        greeks: strategy.greeks(),
+        legs: strategy.breakdown(),
+        breakevens,
+        max_profit: strategy.max_profit(),
+        max_loss: strategy.max_loss(),
+    }
+}
+
+const LEG_COLUMNS: [&str; 12] = [
+    "leg", "kind", "side", "quantity", "strike", "unit", "value", "delta", "gamma", "vega",
+    "theta", "rho",
+];
+
+/// Formats the report. CSV carries the per-leg breakdown only.
+fn render(format: OutputFormat, report: &StrategyReport) -> String {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(report).expect("report serializes as JSON") + "\n"
+        }
+        OutputFormat::Csv => output::csv(&LEG_COLUMNS, &leg_rows(report, |x| x.to_string())),
+        OutputFormat::Table => {
+            let g = &report.greeks;
+            let mut out = format!("model      {}\n", report.model.name());
+            out.push_str(&format!("net price  {:.4}\n", report.price));
+            out.push_str(&format!(
+                "greeks     delta {:.4}  gamma {:.4}  vega {:.4}  theta {:.4}  rho {:.4}\n",
+                g.delta, g.gamma, g.vega, g.theta, g.rho
+            ));
+            let breakevens: Vec<String> = report
+                .breakevens
+                .iter()
+                .map(|b| format!("{:.4} (profit {})", b.price, b.profit))
+                .collect();
+            out.push_str(&format!(
+                "breakevens {}\n",
+                if breakevens.is_empty() {
+                    "none".to_string()
+                } else {
+                    breakevens.join(", ")
+                }
+            ));
+            out.push_str(&format!("max profit {}\n", bound(report.max_profit)));
+            out.push_str(&format!("max loss   {}\n\n", bound(report.max_loss)));
+            out.push_str(&output::table(
+                &LEG_COLUMNS,
+                &leg_rows(report, |x| format!("{:.4}", x)),
+            ));
+            out
+        }
+    }
+}
+
+fn bound(value: f64) -> String {
+    if value.is_infinite() {
+        "unlimited".to_string()
+    } else {
+        format!("{:.4}", value)
+    }
+}
+
+/// The serialized name of a unit enum such as `Side::Long`.
+pub fn name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn leg_rows(report: &StrategyReport, number: impl Fn(f64) -> String) -> Vec<Vec<String>> {
+    report
+        .legs
+        .iter()
+        .map(|leg| {
+            let g = &leg.greeks;
+            vec![
+                leg.leg.to_string(),
+                name(&leg.kind),
+                name(&leg.side),
+                leg.quantity.to_string(),
+                leg.strike.map_or_else(String::new, &number),
+                number(leg.unit_price),
+                number(leg.value),
+                number(g.delta),
+                number(g.gamma),
+                number(g.vega),
+                number(g.theta),
+                number(g.rho),
+            ]
+        })
+        .collect()
+}
@@ -0,0 +1,271 @@
+use crate::output::{self, OutputFormat};
+use crate::{config, model_spec, parse_model_name, portfolio, strategy, ModelConfig};
+use clap::Args;
+use core::portfolio::Portfolio;
+use core::strategies::ModelSpec;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Revalues positions or a strategy under the named shocks of the config file.
+#[derive(Args)]
+pub struct StressOpts {
+    /// The positions to stress; see `portfolio`.
+    #[arg(required_unless_present = "strategy")]
+    positions: Option<PathBuf>,
+    /// A strategy file to stress instead; see `strategy --file`.
+    #[arg(long, conflicts_with = "positions")]
+    strategy: Option<PathBuf>,
+    /// Comma-separated names of the shocks to run, in order; all by default.
+    #[arg(long, value_delimiter = ',')]
+    shocks: Vec<String>,
+    /// The model to revalue with; Black-Scholes unless the strategy file names one.
+    #[arg(long, value_parser = parse_model_name)]
+    model: Option<String>,
+    #[arg(short, long, value_enum, default_value = "json")]
+    output: OutputFormat,
+}
+
+/// A market move such as `-10% spot +5 vol`: a relative move in the underlying and an
+/// absolute one in volatility points. Either part may be left out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Shock {
+    /// The relative move in the underlying, e.g. `-0.1`.
+    pub spot: f64,
+    /// The absolute move in volatility, e.g. `0.05`.
+    pub volatility: f64,
+}
+
+impl FromStr for Shock {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        if words.is_empty() || !words.len().is_multiple_of(2) {
+            return Err(format!(
+                "invalid shock {:?}; expected moves such as \"-10% spot +5 vol\"",
+                s
+            ));
+        }
+        let mut shock = Shock::default();
+        let (mut spot, mut volatility) = (false, false);
+        for pair in words.chunks(2) {
+            let (amount, target) = (pair[0], pair[1]);
+            let number = |text: &str| {
+                text.parse::<f64>()
+                    .map_err(|_| format!("invalid amount {:?} in shock {:?}", amount, s))
+            };
+            match target {
+                "spot" if !spot => {
+                    let percent = amount.strip_suffix('%').ok_or_else(|| {
+                        format!("the spot move {:?} needs a % sign, e.g. -10%", amount)
+                    })?;
+                    shock.spot = number(percent)? / 100.0;
+                    spot = true;
+                }
+                "vol" if !volatility => {
+                    shock.volatility = number(amount)? / 100.0;
+                    volatility = true;
+                }
+                "spot" | "vol" => return Err(format!("{} is moved twice in {:?}", target, s)),
+                _ => {
+                    return Err(format!(
+                        "unknown target {:?} in shock {:?}; expected spot or vol",
+                        target, s
+                    ))
+                }
+            }
+        }
+        Ok(shock)
+    }
+}
+
+impl TryFrom<String> for Shock {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// The positions under one shock.
+#[derive(Serialize)]
+struct ScenarioReport {
+    name: String,
+    spot_shock: f64,
+    volatility_shock: f64,
+    value: f64,
+    pnl: f64,
+    /// The P&L of each position, by id.
+    positions: Vec<PositionPnl>,
+}
+
+#[derive(Serialize)]
+struct PositionPnl {
+    id: usize,
+    pnl: f64,
+}
+
+#[derive(Serialize)]
+struct StressReport {
+    model: String,
+    /// The unshocked value.
+    value: f64,
+    scenarios: Vec<ScenarioReport>,
+}
+
+impl StressOpts {
+    /// The files the positions or strategy are read from, and the config file the shocks
+    /// are defined in.
+    pub fn inputs(&self, config_file: Option<&Path>) -> Vec<PathBuf> {
+        self.positions
+            .iter()
+            .chain(&self.strategy)
+            .cloned()
+            .chain(config_file.map(Path::to_path_buf))
+            .collect()
+    }
+}
+
+pub fn run(
+    opts: &StressOpts,
+    config: &ModelConfig,
+    config_file: Option<&Path>,
+) -> Result<String, String> {
+    let defined = match config_file {
+        Some(path) => config::read(path)?.stress,
+        None => Default::default(),
+    };
+    if defined.is_empty() {
+        return Err("no shocks defined; add them to the --config file, e.g. \
+             {\"stress\": {\"crash\": \"-10% spot +5 vol\"}}"
+            .to_string());
+    }
+    let shocks: Vec<(String, Shock)> = if opts.shocks.is_empty() {
+        defined.into_iter().collect()
+    } else {
+        opts.shocks
+            .iter()
+            .map(|name| match defined.get(name) {
+                Some(shock) => Ok((name.clone(), *shock)),
+                None => Err(format!("no shock named {:?} in the config file", name)),
+            })
+            .collect::<Result<_, _>>()?
+    };
+
+    let chosen = opts
+        .model
+        .as_ref()
+        .map(|name| model_spec(name, config).expect("model names are validated"));
+    let (spec, book) = match (&opts.positions, &opts.strategy) {
+        (Some(path), _) => {
+            let spec = chosen.unwrap_or(ModelSpec::BlackScholes);
+            (spec, portfolio::read_positions(path)?)
+        }
+        (None, Some(path)) => {
+            let definition = strategy::read_definition(path)?;
+            let spec = chosen.unwrap_or_else(|| definition.model.clone());
+            let model = spec.build();
+            let mut book = Portfolio::new();
+            book.add_strategy(&definition.strategy(model.as_ref()));
+            (spec, book)
+        }
+        (None, None) => unreachable!("clap requires positions or a strategy"),
+    };
+    let model = spec.build();
+    let model = model.as_ref();
+
+    let value = book.value(model);
+    let scenarios = shocks
+        .into_iter()
+        .map(|(name, shock)| {
+            let positions: Vec<PositionPnl> = book
+                .positions()
+                .map(|(id, position)| PositionPnl {
+                    id,
+                    pnl: position.shocked_value(model, shock.spot, shock.volatility)
+                        - position.value(model),
+                })
+                .collect();
+            let shocked = book.shocked_value(model, shock.spot, shock.volatility);
+            ScenarioReport {
+                name,
+                spot_shock: shock.spot,
+                volatility_shock: shock.volatility,
+                value: shocked,
+                pnl: shocked - value,
+                positions,
+            }
+        })
+        .collect();
+    let report = StressReport {
+        model: spec.name().to_string(),
+        value,
+        scenarios,
+    };
+    Ok(render(opts.output, &report))
+}
+
+const COLUMNS: [&str; 5] = ["scenario", "spot_shock", "volatility_shock", "value", "pnl"];
+
+/// Formats the report. CSV and the table carry the totals of each scenario only.
+fn render(format: OutputFormat, report: &StressReport) -> String {
+    let rows = |number: &dyn Fn(f64) -> String| -> Vec<Vec<String>> {
+        report
+            .scenarios
+            .iter()
+            .map(|s| {
+                vec![
+                    s.name.clone(),
+                    number(s.spot_shock),
+                    number(s.volatility_shock),
+                    number(s.value),
+                    number(s.pnl),
+                ]
+            })
+            .collect()
+    };
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(report).expect("report serializes as JSON") + "\n"
+        }
+        OutputFormat::Csv => output::csv(&COLUMNS, &rows(&|x| x.to_string())),
+        OutputFormat::Table => format!(
+            "model  {}\nvalue  {:.4}\n\n{}",
+            report.model,
+            report.value,
+            output::table(&COLUMNS, &rows(&|x| format!("{:.4}", x)))
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shock_parses_spot_and_vol_moves() {
+        let crash: Shock = "-10% spot +5 vol".parse().unwrap();
+        assert!((crash.spot + 0.1).abs() < 1e-12);
+        assert!((crash.volatility - 0.05).abs() < 1e-12);
+
+        let calm: Shock = "-2 vol".parse().unwrap();
+        assert_eq!(calm.spot, 0.0);
+        assert!((calm.volatility + 0.02).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_shock_rejects_malformed_moves() {
+        for text in [
+            "",
+            "-10%",
+            "-10 spot",
+            "-10% spot -10% spot",
+            "+5 rates",
+            "x% spot",
+        ] {
+            assert!(text.parse::<Shock>().is_err(), "{:?} parsed", text);
+        }
+    }
+}
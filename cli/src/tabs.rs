@@ -0,0 +1,50 @@
+use crate::theme::Theme;
+use ratatui::{layout::Rect, widgets::Tabs, Frame};
+
+/// The views of the TUI, in tab bar order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tab {
+    Models,
+    Strategy,
+    Chain,
+    Scenarios,
+    Portfolio,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 5] = [
+        Tab::Models,
+        Tab::Strategy,
+        Tab::Chain,
+        Tab::Scenarios,
+        Tab::Portfolio,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Models => "Models",
+            Tab::Strategy => "Strategy",
+            Tab::Chain => "Chain",
+            Tab::Scenarios => "Scenarios",
+            Tab::Portfolio => "Portfolio",
+        }
+    }
+
+    fn index(self) -> usize {
+        Tab::ALL.iter().position(|tab| *tab == self).unwrap_or(0)
+    }
+
+    /// Returns the tab `step` places along the bar, wrapping around.
+    pub fn cycle(self, step: isize) -> Tab {
+        let len = Tab::ALL.len() as isize;
+        Tab::ALL[(self.index() as isize + step).rem_euclid(len) as usize]
+    }
+}
+
+/// Draws the tab bar with `selected` highlighted.
+pub fn render(f: &mut Frame, area: Rect, selected: Tab, theme: &Theme) {
+    let tabs = Tabs::new(Tab::ALL.iter().map(|tab| tab.title()))
+        .select(selected.index())
+        .highlight_style(theme.heading());
+    f.render_widget(tabs, area);
+}
@@ -0,0 +1,103 @@
+use clap::ValueEnum;
+use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+
+/// The color themes of the TUI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeName {
+    const ALL: [ThemeName; 3] = [ThemeName::Dark, ThemeName::Light, ThemeName::HighContrast];
+
+    /// Returns the theme after this one, wrapping around.
+    pub fn next(self) -> ThemeName {
+        let i = Self::ALL.iter().position(|name| *name == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    pub fn theme(self) -> Theme {
+        match self {
+            ThemeName::Dark => Theme {
+                accent: Color::Yellow,
+                header_bg: Color::Black,
+                selected_fg: Color::Black,
+                selected_bg: Color::Yellow,
+                warning: Color::Red,
+                series: [Color::Yellow, Color::Cyan, Color::Magenta, Color::Green],
+            },
+            ThemeName::Light => Theme {
+                accent: Color::Blue,
+                header_bg: Color::Gray,
+                selected_fg: Color::White,
+                selected_bg: Color::Blue,
+                warning: Color::Red,
+                series: [Color::Blue, Color::Magenta, Color::Red, Color::Green],
+            },
+            ThemeName::HighContrast => Theme {
+                accent: Color::White,
+                header_bg: Color::Black,
+                selected_fg: Color::Black,
+                selected_bg: Color::White,
+                warning: Color::LightRed,
+                series: [
+                    Color::White,
+                    Color::LightYellow,
+                    Color::LightCyan,
+                    Color::LightMagenta,
+                ],
+            },
+        }
+    }
+}
+
+/// The colors every TUI view draws with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    /// Headers, labels and keys.
+    pub accent: Color,
+    /// Background of table header rows.
+    pub header_bg: Color,
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+    /// Values that need attention, such as outlying prices.
+    pub warning: Color,
+    /// Lines of a chart, in plotting order.
+    pub series: [Color; 4],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        ThemeName::default().theme()
+    }
+}
+
+impl Theme {
+    /// Bold text in the accent color.
+    pub fn heading(&self) -> Style {
+        Style::default()
+            .fg(self.accent)
+            .add_modifier(Modifier::BOLD)
+    }
+
+    /// The background of a table header row.
+    pub fn header_row(&self) -> Style {
+        Style::default().bg(self.header_bg)
+    }
+
+    /// The selected row of a table.
+    pub fn selected(&self) -> Style {
+        Style::default().fg(self.selected_fg).bg(self.selected_bg)
+    }
+
+    pub fn warning(&self) -> Style {
+        Style::default()
+            .fg(self.warning)
+            .add_modifier(Modifier::BOLD)
+    }
+}
@@ -0,0 +1,36 @@
+use flow::reload::FileWatcher;
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// How long to wait for a burst of writes, such as an editor's save, to settle.
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// Prints `report`, then clears the screen and prints it again whenever one of `files`
+/// changes. A failing report is shown in its place, so a half-saved file does not end
+/// the watch.
+pub async fn run<F, R>(files: &[PathBuf], mut report: F) -> Result<(), String>
+where
+    F: FnMut() -> R,
+    R: Future<Output = Result<String, String>>,
+{
+    let (sender, mut changes) = mpsc::unbounded_channel();
+    let _watcher = FileWatcher::watch(files, move |change| {
+        let _ = sender.send(change);
+    })
+    .map_err(|err| format!("cannot watch the input files: {}", err))?;
+    loop {
+        print!("\x1B[2J\x1B[H");
+        match report().await {
+            Ok(report) => print!("{}", report),
+            Err(message) => eprintln!("error: {}", message),
+        }
+        let Some(change) = changes.recv().await else {
+            return Ok(());
+        };
+        change.map_err(|err| format!("watching failed: {}", err))?;
+        tokio::time::sleep(SETTLE).await;
+        while changes.try_recv().is_ok() {}
+    }
+}
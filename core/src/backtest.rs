@@ -0,0 +1,430 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::{Leg, Strategy};
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// One day of underlying history.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    /// The trading date.
+    pub date: NaiveDate,
+    /// The closing price of the underlying.
+    pub close: f64,
+    /// The implied volatility observed that day, if the data has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volatility: Option<f64>,
+}
+
+/// Errors returned when loading history.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BacktestError {
+    /// A line could not be parsed; `line` counts from one.
+    Parse { line: usize, message: String },
+    /// The bars are not in strictly increasing date order.
+    Unordered { line: usize },
+}
+
+impl fmt::Display for BacktestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BacktestError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            BacktestError::Unordered { line } => {
+                write!(f, "line {}: date is not after the previous one", line)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BacktestError {}
+
+impl Bar {
+    /// Parses history from CSV text with `date,close[,volatility]` columns.
+    ///
+    /// Dates are `YYYY-MM-DD`. A first line that does not start with a date is treated
+    /// as a header; blank lines are skipped.
+    pub fn from_csv(text: &str) -> Result<Vec<Bar>, BacktestError> {
+        let mut bars: Vec<Bar> = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let date = match NaiveDate::parse_from_str(fields[0], "%Y-%m-%d") {
+                Ok(date) => date,
+                Err(_) if i == 0 => continue,
+                Err(err) => {
+                    return Err(BacktestError::Parse {
+                        line: line_no,
+                        message: err.to_string(),
+                    })
+                }
+            };
+            let number = |field: &str| {
+                field.parse::<f64>().map_err(|err| BacktestError::Parse {
+                    line: line_no,
+                    message: err.to_string(),
+                })
+            };
+            let close = match fields.get(1) {
+                Some(field) => number(field)?,
+                None => {
+                    return Err(BacktestError::Parse {
+                        line: line_no,
+                        message: "missing close".to_string(),
+                    })
+                }
+            };
+            let volatility = match fields.get(2) {
+                Some(field) if !field.is_empty() => Some(number(field)?),
+                _ => None,
+            };
+            if bars.last().is_some_and(|last| last.date >= date) {
+                return Err(BacktestError::Unordered { line: line_no });
+            }
+            bars.push(Bar {
+                date,
+                close,
+                volatility,
+            });
+        }
+        Ok(bars)
+    }
+}
+
+/// Market assumptions for a backtest.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BacktestConfig {
+    /// The risk-free rate.
+    pub rate: f64,
+    /// The volatility used on bars that carry none.
+    pub volatility: f64,
+    /// The calendar days to expiry of newly opened strategies.
+    pub days_to_expiry: f64,
+    /// The equity before the first trade.
+    pub initial_capital: f64,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            rate: 0.05,
+            volatility: 0.2,
+            days_to_expiry: 30.0,
+            initial_capital: 0.0,
+        }
+    }
+}
+
+/// When to close an open strategy. Every limit is optional.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExitRule {
+    /// Close once the P&L reaches this fraction of the absolute entry premium, e.g.
+    /// `0.5` to take profit at half the credit of a short condor.
+    pub profit_target: Option<f64>,
+    /// Close once the loss reaches this fraction of the absolute entry premium.
+    pub stop_loss: Option<f64>,
+    /// Close after this many calendar days.
+    pub max_days: Option<i64>,
+    /// Close once this many calendar days or fewer remain to the first expiry.
+    pub days_before_expiry: Option<f64>,
+}
+
+/// Why a trade was closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitReason {
+    ProfitTarget,
+    StopLoss,
+    MaxDays,
+    BeforeExpiry,
+    /// A leg reached expiry: expired legs settle at intrinsic value and any others are
+    /// marked to model.
+    Expiry,
+    /// The history ran out with the trade still open; it is marked to model.
+    EndOfData,
+}
+
+/// A completed trade.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    pub entry_date: NaiveDate,
+    pub exit_date: NaiveDate,
+    /// The net premium at entry: positive for a debit, negative for a credit.
+    pub entry_value: f64,
+    /// The net value at exit.
+    pub exit_value: f64,
+    /// The realized P&L, `exit_value - entry_value`.
+    pub pnl: f64,
+    pub reason: ExitReason,
+}
+
+/// The account on one bar.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EquityPoint {
+    pub date: NaiveDate,
+    /// Initial capital plus realized and unrealized P&L.
+    pub equity: f64,
+    /// The net Greeks of the open trades.
+    pub greeks: Greeks,
+    /// The number of open trades.
+    pub open_trades: usize,
+}
+
+/// The results of a backtest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BacktestReport {
+    /// The closed trades in the order they were closed.
+    pub trades: Vec<Trade>,
+    /// The account after each bar.
+    pub equity_curve: Vec<EquityPoint>,
+    /// The realized P&L of all trades.
+    pub total_pnl: f64,
+    /// The fraction of trades with a positive P&L; zero without trades.
+    pub win_rate: f64,
+    /// The largest peak-to-trough fall in equity.
+    pub max_drawdown: f64,
+}
+
+/// An open trade: its legs as of entry, and when it was opened.
+struct OpenTrade {
+    legs: Vec<Leg>,
+    entry_date: NaiveDate,
+    entry_value: f64,
+}
+
+/// An open trade valued on one bar.
+struct Mark {
+    value: f64,
+    greeks: Greeks,
+    /// Calendar days to the first expiry; zero or less once a leg has expired.
+    days_left: f64,
+}
+
+/// Returns an entry rule that opens one trade on every `weekday`.
+pub fn on_weekday(weekday: Weekday) -> impl Fn(&Bar, usize) -> bool {
+    move |bar, _| bar.date.weekday() == weekday
+}
+
+/// Replays underlying history, opening strategies by rule and closing them by an
+/// `ExitRule`.
+///
+/// Options are priced by the model from each bar's close and volatility; there is no
+/// option chain, so fills are at model value. Expiries count down in calendar days
+/// between bars, and legs that reach expiry settle at intrinsic value.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, NaiveDate, Weekday};
+/// use core::backtest::{on_weekday, BacktestConfig, Backtester, Bar, ExitRule};
+/// use core::models::BlackScholesModel;
+/// use core::strategies::iron_condor::IronCondor;
+///
+/// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+/// let bars: Vec<Bar> = (0..60)
+///     .map(|day| Bar {
+///         date: start + Duration::days(day),
+///         close: 100.0 + (day as f64 / 5.0).sin(),
+///         volatility: None,
+///     })
+///     .collect();
+/// // Every Monday, sell a 30-delta iron condor with 15-delta wings.
+/// let model = BlackScholesModel;
+/// let backtester = Backtester::new(&model, BacktestConfig::default());
+/// let exit = ExitRule {
+///     profit_target: Some(0.5),
+///     ..ExitRule::default()
+/// };
+/// let build = |model: &BlackScholesModel, params| {
+///     let condor = IronCondor::from_deltas(model, params, 0.30, 0.15).ok()?;
+///     Some(condor.strategy().legs)
+/// };
+/// let report = backtester.run(&bars, on_weekday(Weekday::Mon), build, &exit);
+/// assert!(!report.trades.is_empty());
+/// ```
+pub struct Backtester<'a, T: OptionPricingModel + ?Sized> {
+    /// The option pricing model used to value positions.
+    pub model: &'a T,
+    /// The market assumptions.
+    pub config: BacktestConfig,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Backtester<'a, T> {
+    /// Creates a new `Backtester`.
+    pub fn new(model: &'a T, config: BacktestConfig) -> Self {
+        Self { model, config }
+    }
+
+    /// Runs the backtest.
+    ///
+    /// # Arguments
+    ///
+    /// * `bars` - The history, in date order.
+    /// * `entry` - Decides on each bar, given the number of open trades, whether to open
+    ///   a new one.
+    /// * `build` - Builds the legs of a new trade from ATM parameters on the entry bar
+    ///   (`k` equal to the close); returning `None` skips the entry.
+    /// * `exit` - When to close open trades.
+    pub fn run<E, B>(&self, bars: &[Bar], entry: E, build: B, exit: &ExitRule) -> BacktestReport
+    where
+        E: Fn(&Bar, usize) -> bool,
+        B: Fn(&T, OptionParameters) -> Option<Vec<Leg>>,
+    {
+        let mut open: Vec<OpenTrade> = Vec::new();
+        let mut trades: Vec<Trade> = Vec::new();
+        let mut equity_curve = Vec::with_capacity(bars.len());
+        let mut realized = 0.0;
+
+        for (i, bar) in bars.iter().enumerate() {
+            let volatility = bar.volatility.unwrap_or(self.config.volatility);
+            let last = i + 1 == bars.len();
+
+            let mut still_open = Vec::with_capacity(open.len());
+            for trade in open.drain(..) {
+                let mark = self.mark(&trade, bar, volatility);
+                match self.exit_reason(&trade, &mark, bar, exit, last) {
+                    Some(reason) => {
+                        let value = mark.value;
+                        let pnl = value - trade.entry_value;
+                        realized += pnl;
+                        trades.push(Trade {
+                            entry_date: trade.entry_date,
+                            exit_date: bar.date,
+                            entry_value: trade.entry_value,
+                            exit_value: value,
+                            pnl,
+                            reason,
+                        });
+                    }
+                    None => still_open.push(trade),
+                }
+            }
+            open = still_open;
+
+            if !last && entry(bar, open.len()) {
+                let params = OptionParameters {
+                    s: bar.close,
+                    k: bar.close,
+                    r: self.config.rate,
+                    sigma: volatility,
+                    t: self.config.days_to_expiry / 365.0,
+                };
+                if let Some(legs) = build(self.model, params) {
+                    let strategy = Strategy {
+                        model: self.model,
+                        legs,
+                        stock: Vec::new(),
+                    };
+                    open.push(OpenTrade {
+                        entry_value: strategy.price(),
+                        legs: strategy.legs,
+                        entry_date: bar.date,
+                    });
+                }
+            }
+
+            let mut unrealized = 0.0;
+            let mut greeks = Greeks::default();
+            for trade in &open {
+                let mark = self.mark(trade, bar, volatility);
+                unrealized += mark.value - trade.entry_value;
+                greeks = greeks + mark.greeks;
+            }
+            equity_curve.push(EquityPoint {
+                date: bar.date,
+                equity: self.config.initial_capital + realized + unrealized,
+                greeks,
+                open_trades: open.len(),
+            });
+        }
+
+        let wins = trades.iter().filter(|trade| trade.pnl > 0.0).count();
+        let win_rate = if trades.is_empty() {
+            0.0
+        } else {
+            wins as f64 / trades.len() as f64
+        };
+        BacktestReport {
+            total_pnl: realized,
+            win_rate,
+            max_drawdown: max_drawdown(&equity_curve),
+            trades,
+            equity_curve,
+        }
+    }
+
+    /// Marks an open trade on `bar`, settling expired legs at intrinsic value.
+    fn mark(&self, trade: &OpenTrade, bar: &Bar, volatility: f64) -> Mark {
+        let elapsed = (bar.date - trade.entry_date).num_days() as f64 / 365.0;
+        let mut mark = Mark {
+            value: 0.0,
+            greeks: Greeks::default(),
+            days_left: f64::INFINITY,
+        };
+        for leg in &trade.legs {
+            let t = leg.params.t - elapsed;
+            mark.days_left = mark.days_left.min(t * 365.0);
+            if t <= 0.0 {
+                mark.value += leg.payoff(bar.close);
+                continue;
+            }
+            let mut leg = leg.clone();
+            leg.params.s = bar.close;
+            leg.params.sigma = volatility;
+            leg.params.t = t;
+            mark.value += leg.value(self.model);
+            mark.greeks = mark.greeks + leg.greeks(self.model);
+        }
+        mark
+    }
+
+    /// Decides whether a marked trade should close on `bar`.
+    fn exit_reason(
+        &self,
+        trade: &OpenTrade,
+        mark: &Mark,
+        bar: &Bar,
+        exit: &ExitRule,
+        last: bool,
+    ) -> Option<ExitReason> {
+        if mark.days_left <= 0.0 {
+            return Some(ExitReason::Expiry);
+        }
+        let pnl = mark.value - trade.entry_value;
+        let premium = trade.entry_value.abs();
+        if exit.profit_target.is_some_and(|f| pnl >= f * premium) {
+            return Some(ExitReason::ProfitTarget);
+        }
+        if exit.stop_loss.is_some_and(|f| pnl <= -f * premium) {
+            return Some(ExitReason::StopLoss);
+        }
+        let held = (bar.date - trade.entry_date).num_days();
+        if exit.max_days.is_some_and(|days| held >= days) {
+            return Some(ExitReason::MaxDays);
+        }
+        if exit
+            .days_before_expiry
+            .is_some_and(|days| mark.days_left <= days)
+        {
+            return Some(ExitReason::BeforeExpiry);
+        }
+        if last {
+            return Some(ExitReason::EndOfData);
+        }
+        None
+    }
+}
+
+/// Returns the largest peak-to-trough fall along the equity curve.
+fn max_drawdown(curve: &[EquityPoint]) -> f64 {
+    let mut peak = f64::NEG_INFINITY;
+    let mut drawdown: f64 = 0.0;
+    for point in curve {
+        peak = peak.max(point.equity);
+        drawdown = drawdown.max(peak - point.equity);
+    }
+    drawdown
+}
@@ -0,0 +1,184 @@
+use crate::chain::{OptionChain, OptionQuote};
+use crate::math::interp::{Curve, Extrapolation, Kind};
+use crate::models::{OptionPricingModel, OptionType};
+use serde::Serialize;
+
+/// A quote with the volatility and Delta implied by its mid price.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ImpliedQuote {
+    /// The quote.
+    pub quote: OptionQuote,
+    /// The implied volatility, or `None` if the mid price has none (e.g. below
+    /// intrinsic value).
+    pub implied_vol: Option<f64>,
+    /// The Delta at the implied volatility.
+    pub delta: Option<f64>,
+}
+
+/// Smile metrics for one expiry, from out-of-the-money quotes.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct ExpiryAnalytics {
+    /// The time to expiry in years.
+    pub expiry: f64,
+    /// The implied volatility at the forward, interpolated in strike.
+    pub atm_vol: Option<f64>,
+    /// The implied volatility of the 25-delta put, interpolated in Delta.
+    pub put_25d_vol: Option<f64>,
+    /// The implied volatility of the 25-delta call, interpolated in Delta.
+    pub call_25d_vol: Option<f64>,
+    /// The 25-delta skew (risk reversal): put volatility less call volatility.
+    pub skew_25d: Option<f64>,
+    /// The 25-delta butterfly, a measure of smile curvature (kurtosis): the average
+    /// wing volatility less the ATM volatility.
+    pub butterfly_25d: Option<f64>,
+}
+
+/// Implied volatilities and smile metrics across a chain.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ChainAnalytics {
+    /// Every quote with its implied volatility, in chain order.
+    pub quotes: Vec<ImpliedQuote>,
+    /// Smile metrics per expiry, shortest first.
+    pub expiries: Vec<ExpiryAnalytics>,
+    /// The least-squares slope of ATM volatility against expiry, per year; positive in
+    /// contango. `None` with fewer than two ATM volatilities.
+    pub term_slope: Option<f64>,
+}
+
+/// Interpolates `(x, y)` points linearly with flat extrapolation, averaging duplicates.
+fn interpolate(mut points: Vec<(f64, f64)>, x: f64) -> Option<f64> {
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut xs: Vec<f64> = Vec::new();
+    let mut ys: Vec<f64> = Vec::new();
+    let mut counts: Vec<f64> = Vec::new();
+    for (px, py) in points {
+        if xs.last() == Some(&px) {
+            let last = ys.len() - 1;
+            ys[last] += py;
+            counts[last] += 1.0;
+        } else {
+            xs.push(px);
+            ys.push(py);
+            counts.push(1.0);
+        }
+    }
+    for (y, n) in ys.iter_mut().zip(&counts) {
+        *y /= n;
+    }
+    match xs.len() {
+        0 => None,
+        1 => Some(ys[0]),
+        _ => Curve::new(xs, ys, Kind::Linear, Extrapolation::Flat)
+            .ok()
+            .map(|curve| curve.value(x)),
+    }
+}
+
+/// Returns the least-squares slope of `ys` against `xs`.
+fn slope(points: &[(f64, f64)]) -> Option<f64> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    if sxx == 0.0 {
+        None
+    } else {
+        Some(sxy / sxx)
+    }
+}
+
+impl OptionChain {
+    /// Implies a volatility and Delta from the mid price of every quote.
+    pub fn implied_vols<T: OptionPricingModel + ?Sized>(&self, model: &T) -> Vec<ImpliedQuote> {
+        self.quotes
+            .iter()
+            .map(|quote| {
+                let params = self.params(quote, 0.0);
+                let implied_vol = model
+                    .implied_volatility(&params, quote.option_type, quote.mid())
+                    .ok();
+                let delta = implied_vol
+                    .map(|sigma| model.option_delta(&self.params(quote, sigma), quote.option_type));
+                ImpliedQuote {
+                    quote: quote.clone(),
+                    implied_vol,
+                    delta,
+                }
+            })
+            .collect()
+    }
+
+    /// Computes implied volatilities, per-expiry smile metrics and the ATM term
+    /// structure.
+    ///
+    /// Only out-of-the-money quotes (puts struck below the forward, calls at or above
+    /// it) feed the smile metrics, since they carry the liquid, informative prices.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to imply volatilities.
+    pub fn analytics<T: OptionPricingModel + ?Sized>(&self, model: &T) -> ChainAnalytics {
+        let quotes = self.implied_vols(model);
+        let expiries: Vec<ExpiryAnalytics> = self
+            .expiries()
+            .into_iter()
+            .map(|expiry| {
+                let forward = self.forward(expiry);
+                let otm: Vec<(&ImpliedQuote, f64, f64)> = quotes
+                    .iter()
+                    .filter(|q| q.quote.expiry == expiry)
+                    .filter(|q| match q.quote.option_type {
+                        OptionType::Put => q.quote.strike < forward,
+                        OptionType::Call => q.quote.strike >= forward,
+                    })
+                    .filter_map(|q| Some((q, q.implied_vol?, q.delta?)))
+                    .collect();
+
+                let by_strike = otm
+                    .iter()
+                    .map(|(q, vol, _)| (q.quote.strike, *vol))
+                    .collect();
+                let wing = |option_type: OptionType| {
+                    let points: Vec<(f64, f64)> = otm
+                        .iter()
+                        .filter(|(q, _, _)| q.quote.option_type == option_type)
+                        .map(|(_, vol, delta)| (delta.abs(), *vol))
+                        .collect();
+                    interpolate(points, 0.25)
+                };
+                let atm_vol = interpolate(by_strike, forward);
+                let put_25d_vol = wing(OptionType::Put);
+                let call_25d_vol = wing(OptionType::Call);
+                let (skew_25d, butterfly_25d) = match (put_25d_vol, call_25d_vol, atm_vol) {
+                    (Some(put), Some(call), Some(atm)) => {
+                        (Some(put - call), Some(0.5 * (put + call) - atm))
+                    }
+                    (Some(put), Some(call), None) => (Some(put - call), None),
+                    _ => (None, None),
+                };
+                ExpiryAnalytics {
+                    expiry,
+                    atm_vol,
+                    put_25d_vol,
+                    call_25d_vol,
+                    skew_25d,
+                    butterfly_25d,
+                }
+            })
+            .collect();
+
+        let term: Vec<(f64, f64)> = expiries
+            .iter()
+            .filter_map(|e| Some((e.expiry, e.atm_vol?)))
+            .collect();
+        ChainAnalytics {
+            quotes,
+            expiries,
+            term_slope: slope(&term),
+        }
+    }
+}
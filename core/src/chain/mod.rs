@@ -0,0 +1,124 @@
+pub mod analytics;
+pub mod positioning;
+
+pub use analytics::{ChainAnalytics, ExpiryAnalytics, ImpliedQuote};
+pub use positioning::{GammaExposure, PutCallRatio};
+
+use crate::models::{OptionParameters, OptionType};
+use serde::{Deserialize, Serialize};
+
+/// One listed option with its market data.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OptionQuote {
+    /// Call or put.
+    pub option_type: OptionType,
+    /// The strike price.
+    pub strike: f64,
+    /// The time to expiry in years.
+    pub expiry: f64,
+    /// The best bid.
+    pub bid: f64,
+    /// The best offer.
+    pub ask: f64,
+    /// The contracts traded today.
+    #[serde(default)]
+    pub volume: f64,
+    /// The contracts outstanding.
+    #[serde(default)]
+    pub open_interest: f64,
+}
+
+impl OptionQuote {
+    /// Creates a quote without volume or open interest.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_type` - Call or put.
+    /// * `strike` - The strike price.
+    /// * `expiry` - The time to expiry in years.
+    /// * `bid` - The best bid.
+    /// * `ask` - The best offer.
+    pub fn new(option_type: OptionType, strike: f64, expiry: f64, bid: f64, ask: f64) -> Self {
+        Self {
+            option_type,
+            strike,
+            expiry,
+            bid,
+            ask,
+            volume: 0.0,
+            open_interest: 0.0,
+        }
+    }
+
+    /// Records the day's volume and the open interest.
+    pub fn with_activity(mut self, volume: f64, open_interest: f64) -> Self {
+        self.volume = volume;
+        self.open_interest = open_interest;
+        self
+    }
+
+    /// Returns the midpoint of the bid and the offer.
+    pub fn mid(&self) -> f64 {
+        0.5 * (self.bid + self.ask)
+    }
+}
+
+/// The listed options on one underlying at one point in time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OptionChain {
+    /// The underlying price.
+    pub spot: f64,
+    /// The risk-free rate.
+    pub rate: f64,
+    /// The quotes, in any order.
+    pub quotes: Vec<OptionQuote>,
+}
+
+impl OptionChain {
+    /// Creates an empty chain.
+    pub fn new(spot: f64, rate: f64) -> Self {
+        Self {
+            spot,
+            rate,
+            quotes: Vec::new(),
+        }
+    }
+
+    /// Adds a quote.
+    pub fn with_quote(mut self, quote: OptionQuote) -> Self {
+        self.quotes.push(quote);
+        self
+    }
+
+    /// Returns the distinct expiries, shortest first.
+    pub fn expiries(&self) -> Vec<f64> {
+        let mut expiries: Vec<f64> = self.quotes.iter().map(|quote| quote.expiry).collect();
+        expiries.sort_by(|a, b| a.total_cmp(b));
+        expiries.dedup();
+        expiries
+    }
+
+    /// Returns the distinct strikes, lowest first.
+    pub fn strikes(&self) -> Vec<f64> {
+        let mut strikes: Vec<f64> = self.quotes.iter().map(|quote| quote.strike).collect();
+        strikes.sort_by(|a, b| a.total_cmp(b));
+        strikes.dedup();
+        strikes
+    }
+
+    /// Returns the forward price for `expiry`.
+    pub fn forward(&self, expiry: f64) -> f64 {
+        self.spot * (self.rate * expiry).exp()
+    }
+
+    /// Returns the pricing parameters of `quote` at volatility `sigma`.
+    pub fn params(&self, quote: &OptionQuote, sigma: f64) -> OptionParameters {
+        OptionParameters {
+            s: self.spot,
+            k: quote.strike,
+            r: self.rate,
+            sigma,
+            t: quote.expiry,
+        }
+    }
+}
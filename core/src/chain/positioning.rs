@@ -0,0 +1,155 @@
+use crate::chain::OptionChain;
+use crate::models::{OptionPricingModel, OptionType};
+use serde::Serialize;
+
+/// Put/call ratios of traded volume and open interest.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct PutCallRatio {
+    /// Put volume over call volume, or `None` without call volume.
+    pub volume: Option<f64>,
+    /// Put open interest over call open interest, or `None` without call open interest.
+    pub open_interest: Option<f64>,
+}
+
+/// The gamma exposure at one strike.
+///
+/// Values are in currency per 1% move of the underlying, under the usual dealer
+/// convention: dealers are long the calls and short the puts that customers hold, so
+/// call gamma counts positive and put gamma negative.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct GammaExposure {
+    /// The strike price.
+    pub strike: f64,
+    /// The call gamma exposure.
+    pub call: f64,
+    /// The put gamma exposure (zero or negative).
+    pub put: f64,
+    /// The sum of the call and put exposures.
+    pub net: f64,
+}
+
+fn ratio(puts: f64, calls: f64) -> Option<f64> {
+    if calls > 0.0 {
+        Some(puts / calls)
+    } else {
+        None
+    }
+}
+
+impl OptionChain {
+    /// Returns the put/call volume and open-interest ratios across the whole chain.
+    pub fn put_call_ratio(&self) -> PutCallRatio {
+        let (mut put_volume, mut call_volume, mut put_oi, mut call_oi) = (0.0, 0.0, 0.0, 0.0);
+        for quote in &self.quotes {
+            match quote.option_type {
+                OptionType::Call => {
+                    call_volume += quote.volume;
+                    call_oi += quote.open_interest;
+                }
+                OptionType::Put => {
+                    put_volume += quote.volume;
+                    put_oi += quote.open_interest;
+                }
+            }
+        }
+        PutCallRatio {
+            volume: ratio(put_volume, call_volume),
+            open_interest: ratio(put_oi, call_oi),
+        }
+    }
+
+    /// Returns the max-pain strike for `expiry`: the listed strike at which the open
+    /// interest would pay option holders the least at expiration.
+    ///
+    /// Returns `None` if no quote at `expiry` carries open interest.
+    ///
+    /// # Arguments
+    ///
+    /// * `expiry` - The time to expiry in years of the contracts to consider.
+    pub fn max_pain(&self, expiry: f64) -> Option<f64> {
+        let quotes: Vec<_> = self
+            .quotes
+            .iter()
+            .filter(|quote| quote.expiry == expiry && quote.open_interest > 0.0)
+            .collect();
+        if quotes.is_empty() {
+            return None;
+        }
+        let payout = |settle: f64| -> f64 {
+            quotes
+                .iter()
+                .map(|quote| {
+                    let intrinsic = match quote.option_type {
+                        OptionType::Call => (settle - quote.strike).max(0.0),
+                        OptionType::Put => (quote.strike - settle).max(0.0),
+                    };
+                    intrinsic * quote.open_interest
+                })
+                .sum()
+        };
+        self.strikes()
+            .into_iter()
+            .map(|strike| (strike, payout(strike)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(strike, _)| strike)
+    }
+
+    /// Returns the gamma exposure profile by strike, lowest strike first.
+    ///
+    /// Each quote's Gamma is taken at the volatility implied by its mid price; quotes
+    /// without an implied volatility are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to imply volatilities and Gamma.
+    /// * `multiplier` - The number of shares per contract.
+    pub fn gamma_exposure<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        multiplier: f64,
+    ) -> Vec<GammaExposure> {
+        let mut profile: Vec<GammaExposure> = self
+            .strikes()
+            .into_iter()
+            .map(|strike| GammaExposure {
+                strike,
+                call: 0.0,
+                put: 0.0,
+                net: 0.0,
+            })
+            .collect();
+        let scale = multiplier * self.spot * self.spot * 0.01;
+        for implied in self.implied_vols(model) {
+            let quote = &implied.quote;
+            let Some(sigma) = implied.implied_vol else {
+                continue;
+            };
+            let gamma = model.gamma(&self.params(quote, sigma));
+            let exposure = gamma * quote.open_interest * scale;
+            let Some(entry) = profile
+                .iter_mut()
+                .find(|entry| entry.strike == quote.strike)
+            else {
+                continue;
+            };
+            match quote.option_type {
+                OptionType::Call => entry.call += exposure,
+                OptionType::Put => entry.put -= exposure,
+            }
+            entry.net = entry.call + entry.put;
+        }
+        profile
+    }
+
+    /// Returns the net gamma exposure summed over every strike.
+    pub fn total_gamma_exposure<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        multiplier: f64,
+    ) -> f64 {
+        self.gamma_exposure(model, multiplier)
+            .iter()
+            .map(|entry| entry.net)
+            .sum()
+    }
+}
@@ -1,2 +1,10 @@
+pub mod backtest;
+pub mod chain;
+pub mod math;
 pub mod models;
+pub mod portfolio;
+pub mod rates;
+pub mod sanity;
 pub mod strategies;
+pub mod time;
+pub mod volatility;
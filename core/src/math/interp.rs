@@ -0,0 +1,281 @@
+use std::fmt;
+
+/// How a curve is evaluated outside its first and last knots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Hold the end value constant.
+    Flat,
+    /// Continue along the slope at the end knot.
+    Linear,
+}
+
+/// The interpolation scheme between knots.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Piecewise linear.
+    Linear,
+    /// Shape-preserving piecewise cubic (Fritsch-Butland slopes): never overshoots the data,
+    /// so monotone inputs such as discount factors or total variance stay monotone.
+    MonotoneCubic,
+    /// Natural cubic spline: twice continuously differentiable, zero curvature at the ends.
+    NaturalCubic,
+}
+
+/// Errors returned when building an interpolator.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InterpError {
+    /// Fewer than two knots were given.
+    TooFewPoints,
+    /// The abscissae and ordinates have different lengths.
+    LengthMismatch { xs: usize, ys: usize },
+    /// The abscissae are not strictly increasing at `index`.
+    NotIncreasing { index: usize },
+    /// A knot is NaN or infinite.
+    NonFinite,
+}
+
+impl fmt::Display for InterpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpError::TooFewPoints => write!(f, "at least two points are required"),
+            InterpError::LengthMismatch { xs, ys } => {
+                write!(f, "{} abscissae but {} ordinates", xs, ys)
+            }
+            InterpError::NotIncreasing { index } => {
+                write!(
+                    f,
+                    "abscissae are not strictly increasing at index {}",
+                    index
+                )
+            }
+            InterpError::NonFinite => write!(f, "points must be finite"),
+        }
+    }
+}
+
+impl std::error::Error for InterpError {}
+
+/// A one-dimensional interpolated curve.
+///
+/// # Example
+///
+/// ```
+/// use core::math::interp::{Curve, Extrapolation, Kind};
+/// let times = vec![0.25, 0.5, 1.0];
+/// let vols = vec![0.22, 0.21, 0.2];
+/// let curve = Curve::new(times, vols, Kind::MonotoneCubic, Extrapolation::Flat).unwrap();
+/// let vol = curve.value(0.75);
+/// assert!(vol < 0.21 && vol > 0.2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Curve {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    /// First derivative at each knot (cubic kinds) or of each end segment (linear).
+    slopes: Vec<f64>,
+    kind: Kind,
+    extrapolation: Extrapolation,
+}
+
+impl Curve {
+    /// Builds a curve through the knots `(xs[i], ys[i])`.
+    ///
+    /// # Arguments
+    ///
+    /// * `xs` - The strictly increasing abscissae.
+    /// * `ys` - The ordinates.
+    /// * `kind` - The interpolation scheme.
+    /// * `extrapolation` - The behaviour outside `[xs[0], xs[n-1]]`.
+    pub fn new(
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        kind: Kind,
+        extrapolation: Extrapolation,
+    ) -> Result<Self, InterpError> {
+        if xs.len() != ys.len() {
+            return Err(InterpError::LengthMismatch {
+                xs: xs.len(),
+                ys: ys.len(),
+            });
+        }
+        if xs.len() < 2 {
+            return Err(InterpError::TooFewPoints);
+        }
+        if xs.iter().chain(&ys).any(|v| !v.is_finite()) {
+            return Err(InterpError::NonFinite);
+        }
+        if let Some(index) = (1..xs.len()).find(|&i| xs[i] <= xs[i - 1]) {
+            return Err(InterpError::NotIncreasing { index });
+        }
+
+        let secants: Vec<f64> = (0..xs.len() - 1)
+            .map(|i| (ys[i + 1] - ys[i]) / (xs[i + 1] - xs[i]))
+            .collect();
+        let slopes = match kind {
+            Kind::Linear => {
+                let mut slopes = secants.clone();
+                slopes.push(secants[secants.len() - 1]);
+                slopes
+            }
+            Kind::MonotoneCubic => monotone_slopes(&xs, &secants),
+            Kind::NaturalCubic => natural_spline_slopes(&xs, &secants),
+        };
+
+        Ok(Self {
+            xs,
+            ys,
+            slopes,
+            kind,
+            extrapolation,
+        })
+    }
+
+    /// Returns the knot abscissae.
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    /// Returns the knot ordinates.
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// Evaluates the curve at `x`.
+    pub fn value(&self, x: f64) -> f64 {
+        let n = self.xs.len();
+        if x < self.xs[0] || x > self.xs[n - 1] {
+            let (end, slope) = if x < self.xs[0] {
+                (0, self.slopes[0])
+            } else {
+                (n - 1, self.slopes[n - 1])
+            };
+            return match self.extrapolation {
+                Extrapolation::Flat => self.ys[end],
+                Extrapolation::Linear => self.ys[end] + slope * (x - self.xs[end]),
+            };
+        }
+
+        // Index of the segment [xs[i], xs[i + 1]] containing x.
+        let i = self.xs.partition_point(|&k| k <= x).clamp(1, n - 1) - 1;
+        let h = self.xs[i + 1] - self.xs[i];
+        let t = (x - self.xs[i]) / h;
+        match self.kind {
+            Kind::Linear => self.ys[i] + t * (self.ys[i + 1] - self.ys[i]),
+            Kind::MonotoneCubic | Kind::NaturalCubic => {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                (2.0 * t3 - 3.0 * t2 + 1.0) * self.ys[i]
+                    + (t3 - 2.0 * t2 + t) * h * self.slopes[i]
+                    + (-2.0 * t3 + 3.0 * t2) * self.ys[i + 1]
+                    + (t3 - t2) * h * self.slopes[i + 1]
+            }
+        }
+    }
+}
+
+/// Fritsch-Butland knot slopes: zero at local extrema, a weighted harmonic mean elsewhere.
+fn monotone_slopes(xs: &[f64], secants: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let mut slopes = vec![0.0; n];
+    slopes[0] = secants[0];
+    slopes[n - 1] = secants[n - 2];
+    for i in 1..n - 1 {
+        let (d0, d1) = (secants[i - 1], secants[i]);
+        if d0 * d1 > 0.0 {
+            let (h0, h1) = (xs[i] - xs[i - 1], xs[i + 1] - xs[i]);
+            slopes[i] = 3.0 * (h0 + h1) / ((2.0 * h1 + h0) / d0 + (h1 + 2.0 * h0) / d1);
+        }
+    }
+    slopes
+}
+
+/// Knot slopes of the natural cubic spline, from its second derivatives.
+fn natural_spline_slopes(xs: &[f64], secants: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let h: Vec<f64> = (0..n - 1).map(|i| xs[i + 1] - xs[i]).collect();
+
+    // Solve the tridiagonal system for the interior second derivatives (Thomas algorithm);
+    // the natural end conditions pin both end curvatures to zero.
+    let mut second = vec![0.0; n];
+    if n > 2 {
+        let m = n - 2;
+        let mut diag: Vec<f64> = (0..m).map(|i| 2.0 * (h[i] + h[i + 1])).collect();
+        let mut rhs: Vec<f64> = (0..m)
+            .map(|i| 6.0 * (secants[i + 1] - secants[i]))
+            .collect();
+        for i in 1..m {
+            let w = h[i] / diag[i - 1];
+            diag[i] -= w * h[i];
+            rhs[i] -= w * rhs[i - 1];
+        }
+        second[m] = rhs[m - 1] / diag[m - 1];
+        for i in (0..m - 1).rev() {
+            second[i + 1] = (rhs[i] - h[i + 1] * second[i + 2]) / diag[i];
+        }
+    }
+
+    let mut slopes: Vec<f64> = (0..n - 1)
+        .map(|i| secants[i] - h[i] * (2.0 * second[i] + second[i + 1]) / 6.0)
+        .collect();
+    slopes.push(secants[n - 2] + h[n - 2] * (second[n - 2] + 2.0 * second[n - 1]) / 6.0);
+    slopes
+}
+
+/// A two-dimensional surface on a rectangular grid, interpolated as a tensor product of
+/// one-dimensional curves (bilinear for `Kind::Linear`, bicubic for the cubic kinds).
+///
+/// `values[i][j]` is the value at `(xs[i], ys[j])`: for a volatility surface `xs` would be
+/// expiries and `ys` strikes.
+#[derive(Clone, Debug)]
+pub struct Surface {
+    xs: Vec<f64>,
+    rows: Vec<Curve>,
+    kind: Kind,
+    extrapolation: Extrapolation,
+}
+
+impl Surface {
+    /// Builds a surface over the grid `xs` × `ys`.
+    ///
+    /// # Arguments
+    ///
+    /// * `xs` - The strictly increasing first-axis knots.
+    /// * `ys` - The strictly increasing second-axis knots.
+    /// * `values` - One row per `xs` entry, each with one value per `ys` entry.
+    /// * `kind` - The interpolation scheme along both axes.
+    /// * `extrapolation` - The behaviour outside the grid along both axes.
+    pub fn new(
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        values: Vec<Vec<f64>>,
+        kind: Kind,
+        extrapolation: Extrapolation,
+    ) -> Result<Self, InterpError> {
+        if values.len() != xs.len() {
+            return Err(InterpError::LengthMismatch {
+                xs: xs.len(),
+                ys: values.len(),
+            });
+        }
+        // Validates the first axis once so evaluation can build column curves freely.
+        Curve::new(xs.clone(), vec![0.0; xs.len()], kind, extrapolation)?;
+        let rows = values
+            .into_iter()
+            .map(|row| Curve::new(ys.clone(), row, kind, extrapolation))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            xs,
+            rows,
+            kind,
+            extrapolation,
+        })
+    }
+
+    /// Evaluates the surface at `(x, y)`.
+    pub fn value(&self, x: f64, y: f64) -> f64 {
+        let column = self.rows.iter().map(|row| row.value(y)).collect();
+        Curve::new(self.xs.clone(), column, self.kind, self.extrapolation)
+            .map(|curve| curve.value(x))
+            .unwrap_or(f64::NAN)
+    }
+}
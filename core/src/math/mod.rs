@@ -0,0 +1,4 @@
+pub mod interp;
+pub mod optimize;
+pub mod random;
+pub mod roots;
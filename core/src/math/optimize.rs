@@ -0,0 +1,480 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Box constraints, one `(lower, upper)` pair per variable.
+///
+/// Use `f64::NEG_INFINITY` / `f64::INFINITY` for unbounded sides.
+pub type Bounds = [(f64, f64)];
+
+/// Errors returned by the minimizers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptimizeError {
+    /// The starting point has no variables.
+    Empty,
+    /// The bounds do not have one entry per variable.
+    DimensionMismatch { expected: usize, found: usize },
+    /// A bound pair has `lower > upper`.
+    InvalidBounds { index: usize },
+    /// The objective is not finite at the (projected) starting point.
+    NonFinite,
+}
+
+impl fmt::Display for OptimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptimizeError::Empty => write!(f, "starting point has no variables"),
+            OptimizeError::DimensionMismatch { expected, found } => {
+                write!(f, "expected {} bounds, found {}", expected, found)
+            }
+            OptimizeError::InvalidBounds { index } => {
+                write!(f, "lower bound exceeds upper bound for variable {}", index)
+            }
+            OptimizeError::NonFinite => write!(f, "objective is not finite at the start"),
+        }
+    }
+}
+
+impl std::error::Error for OptimizeError {}
+
+/// The outcome of a minimization.
+///
+/// # Fields
+///
+/// * `x` - The best point found.
+/// * `value` - The objective at `x`.
+/// * `iterations` - The number of iterations performed.
+/// * `converged` - Whether the tolerance was met before the iteration limit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minimum {
+    pub x: Vec<f64>,
+    pub value: f64,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Validates `bounds` against `x0`, defaulting to unbounded when none are given.
+fn resolve_bounds(x0: &[f64], bounds: Option<&Bounds>) -> Result<Vec<(f64, f64)>, OptimizeError> {
+    if x0.is_empty() {
+        return Err(OptimizeError::Empty);
+    }
+    match bounds {
+        None => Ok(vec![(f64::NEG_INFINITY, f64::INFINITY); x0.len()]),
+        Some(bounds) if bounds.len() != x0.len() => Err(OptimizeError::DimensionMismatch {
+            expected: x0.len(),
+            found: bounds.len(),
+        }),
+        Some(bounds) => match bounds.iter().position(|(lo, hi)| lo > hi) {
+            Some(index) => Err(OptimizeError::InvalidBounds { index }),
+            None => Ok(bounds.to_vec()),
+        },
+    }
+}
+
+/// Clamps every coordinate of `x` into its bounds.
+fn project(x: &mut [f64], bounds: &Bounds) {
+    for (xi, &(lo, hi)) in x.iter_mut().zip(bounds) {
+        *xi = xi.clamp(lo, hi);
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Central finite-difference gradient of `f` at `x`.
+///
+/// # Arguments
+///
+/// * `f` - The objective.
+/// * `x` - The point at which to differentiate.
+/// * `h` - The relative bump size; each coordinate is bumped by `h * max(|x_i|, 1)`.
+pub fn numerical_gradient<F: FnMut(&[f64]) -> f64>(mut f: F, x: &[f64], h: f64) -> Vec<f64> {
+    let mut point = x.to_vec();
+    (0..x.len())
+        .map(|i| {
+            let step = h * x[i].abs().max(1.0);
+            point[i] = x[i] + step;
+            let up = f(&point);
+            point[i] = x[i] - step;
+            let down = f(&point);
+            point[i] = x[i];
+            (up - down) / (2.0 * step)
+        })
+        .collect()
+}
+
+/// Derivative-free Nelder-Mead simplex minimizer.
+///
+/// Trial points are projected onto the bounds, which is sufficient for the smooth,
+/// low-dimensional calibration problems this crate solves.
+/// ref:<https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method>
+pub struct NelderMead {
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+    /// Stop once the spread of objective values across the simplex falls below this.
+    pub tol: f64,
+    /// Initial simplex size, relative to each coordinate of the starting point.
+    pub initial_step: f64,
+}
+
+impl Default for NelderMead {
+    fn default() -> Self {
+        Self {
+            max_iter: 2_000,
+            tol: 1e-10,
+            initial_step: 0.05,
+        }
+    }
+}
+
+impl NelderMead {
+    /// Minimizes `f` starting from `x0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The objective.
+    /// * `x0` - The starting point.
+    /// * `bounds` - Optional box constraints.
+    ///
+    /// # Returns
+    ///
+    /// Returns the best point found.
+    pub fn minimize<F: FnMut(&[f64]) -> f64>(
+        &self,
+        mut f: F,
+        x0: &[f64],
+        bounds: Option<&Bounds>,
+    ) -> Result<Minimum, OptimizeError> {
+        const REFLECT: f64 = 1.0;
+        const EXPAND: f64 = 2.0;
+        const CONTRACT: f64 = 0.5;
+        const SHRINK: f64 = 0.5;
+
+        let bounds = resolve_bounds(x0, bounds)?;
+        let n = x0.len();
+
+        let mut start = x0.to_vec();
+        project(&mut start, &bounds);
+        let mut simplex = vec![start.clone()];
+        for i in 0..n {
+            let mut vertex = start.clone();
+            let step = if start[i] != 0.0 {
+                self.initial_step * start[i].abs()
+            } else {
+                0.00025
+            };
+            vertex[i] += step;
+            project(&mut vertex, &bounds);
+            if vertex[i] == start[i] {
+                // Pinned at the upper bound: step inwards instead.
+                vertex[i] -= step;
+                project(&mut vertex, &bounds);
+            }
+            simplex.push(vertex);
+        }
+        let mut values: Vec<f64> = simplex.iter().map(|v| f(v)).collect();
+        if !values[0].is_finite() {
+            return Err(OptimizeError::NonFinite);
+        }
+
+        let point = |from: &[f64], to: &[f64], coef: f64| {
+            let mut p: Vec<f64> = from
+                .iter()
+                .zip(to)
+                .map(|(a, b)| a + coef * (b - a))
+                .collect();
+            project(&mut p, &bounds);
+            p
+        };
+
+        let mut iterations = 0;
+        let mut converged = false;
+        while iterations < self.max_iter {
+            let mut order: Vec<usize> = (0..=n).collect();
+            order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+            simplex = order.iter().map(|&i| simplex[i].clone()).collect();
+            values = order.iter().map(|&i| values[i]).collect();
+
+            if (values[n] - values[0]).abs() <= self.tol {
+                converged = true;
+                break;
+            }
+            iterations += 1;
+
+            let mut centroid = vec![0.0; n];
+            for vertex in &simplex[..n] {
+                for (c, v) in centroid.iter_mut().zip(vertex) {
+                    *c += v / n as f64;
+                }
+            }
+
+            let reflected = point(&centroid, &simplex[n], -REFLECT);
+            let fr = f(&reflected);
+            if fr < values[0] {
+                let expanded = point(&centroid, &reflected, EXPAND);
+                let fe = f(&expanded);
+                if fe < fr {
+                    simplex[n] = expanded;
+                    values[n] = fe;
+                } else {
+                    simplex[n] = reflected;
+                    values[n] = fr;
+                }
+                continue;
+            }
+            if fr < values[n - 1] {
+                simplex[n] = reflected;
+                values[n] = fr;
+                continue;
+            }
+
+            let (contracted, fc) = if fr < values[n] {
+                let p = point(&centroid, &reflected, CONTRACT);
+                let v = f(&p);
+                (p, v)
+            } else {
+                let p = point(&centroid, &simplex[n], CONTRACT);
+                let v = f(&p);
+                (p, v)
+            };
+            if fc < fr.min(values[n]) {
+                simplex[n] = contracted;
+                values[n] = fc;
+                continue;
+            }
+
+            let best = simplex[0].clone();
+            for i in 1..=n {
+                simplex[i] = point(&best, &simplex[i], SHRINK);
+                values[i] = f(&simplex[i]);
+            }
+        }
+
+        let best = (0..=n)
+            .min_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap_or(0);
+        Ok(Minimum {
+            x: simplex[best].clone(),
+            value: values[best],
+            iterations,
+            converged,
+        })
+    }
+}
+
+/// Limited-memory BFGS with box constraints.
+///
+/// A projected quasi-Newton method in the spirit of L-BFGS-B: variables held at a bound by
+/// the gradient are frozen, the two-loop recursion builds a search direction over the free
+/// variables, and a backtracking line search runs along the projected path.
+/// ref:<https://en.wikipedia.org/wiki/Limited-memory_BFGS>
+pub struct Lbfgsb {
+    /// Number of correction pairs kept.
+    pub memory: usize,
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+    /// Stop once the infinity norm of the projected gradient falls below this.
+    pub gtol: f64,
+    /// Stop once the relative reduction of the objective falls below this.
+    pub ftol: f64,
+}
+
+impl Default for Lbfgsb {
+    fn default() -> Self {
+        Self {
+            memory: 10,
+            max_iter: 500,
+            gtol: 1e-8,
+            ftol: 1e-14,
+        }
+    }
+}
+
+impl Lbfgsb {
+    /// Minimizes `f` starting from `x0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The objective.
+    /// * `grad` - The gradient of `f`; `numerical_gradient` can be used when none is known.
+    /// * `x0` - The starting point.
+    /// * `bounds` - Optional box constraints.
+    ///
+    /// # Returns
+    ///
+    /// Returns the best point found.
+    pub fn minimize<F, G>(
+        &self,
+        mut f: F,
+        mut grad: G,
+        x0: &[f64],
+        bounds: Option<&Bounds>,
+    ) -> Result<Minimum, OptimizeError>
+    where
+        F: FnMut(&[f64]) -> f64,
+        G: FnMut(&[f64]) -> Vec<f64>,
+    {
+        const ARMIJO: f64 = 1e-4;
+        const CURVATURE: f64 = 0.9;
+        const MAX_LINE_SEARCH: usize = 60;
+
+        let bounds = resolve_bounds(x0, bounds)?;
+        let n = x0.len();
+
+        let mut x = x0.to_vec();
+        project(&mut x, &bounds);
+        let mut fx = f(&x);
+        if !fx.is_finite() {
+            return Err(OptimizeError::NonFinite);
+        }
+        let mut g = grad(&x);
+
+        let mut history: VecDeque<(Vec<f64>, Vec<f64>)> = VecDeque::with_capacity(self.memory);
+        let mut iterations = 0;
+        let mut converged = false;
+
+        while iterations < self.max_iter {
+            let projected_gradient = x
+                .iter()
+                .zip(&g)
+                .zip(&bounds)
+                .map(|((xi, gi), &(lo, hi))| ((xi - gi).clamp(lo, hi) - xi).abs())
+                .fold(0.0, f64::max);
+            if projected_gradient <= self.gtol {
+                converged = true;
+                break;
+            }
+            iterations += 1;
+
+            // Variables pressed against a bound by the gradient stay fixed this iteration.
+            let free: Vec<bool> = (0..n)
+                .map(|i| {
+                    let (lo, hi) = bounds[i];
+                    !((x[i] <= lo && g[i] > 0.0) || (x[i] >= hi && g[i] < 0.0))
+                })
+                .collect();
+            let mask = |v: &[f64]| -> Vec<f64> {
+                v.iter()
+                    .zip(&free)
+                    .map(|(vi, &fr)| if fr { *vi } else { 0.0 })
+                    .collect()
+            };
+
+            // Two-loop recursion over the free variables.
+            let mut q = mask(&g);
+            let mut alphas = Vec::with_capacity(history.len());
+            for (s, y) in history.iter().rev() {
+                let (s, y) = (mask(s), mask(y));
+                let sy = dot(&s, &y);
+                if sy <= 0.0 {
+                    alphas.push(0.0);
+                    continue;
+                }
+                let a = dot(&s, &q) / sy;
+                q.iter_mut().zip(&y).for_each(|(qi, yi)| *qi -= a * yi);
+                alphas.push(a);
+            }
+            if let Some((s, y)) = history.back() {
+                let (s, y) = (mask(s), mask(y));
+                let yy = dot(&y, &y);
+                if yy > 0.0 {
+                    let gamma = dot(&s, &y) / yy;
+                    q.iter_mut().for_each(|qi| *qi *= gamma);
+                }
+            }
+            for ((s, y), a) in history.iter().zip(alphas.iter().rev()) {
+                let (s, y) = (mask(s), mask(y));
+                let sy = dot(&s, &y);
+                if sy <= 0.0 {
+                    continue;
+                }
+                let b = dot(&y, &q) / sy;
+                q.iter_mut()
+                    .zip(&s)
+                    .for_each(|(qi, si)| *qi += (a - b) * si);
+            }
+            let mut direction: Vec<f64> = q.iter().map(|qi| -qi).collect();
+            if dot(&direction, &g) >= 0.0 {
+                // Not a descent direction: fall back to steepest descent.
+                history.clear();
+                direction = mask(&g).iter().map(|gi| -gi).collect();
+            }
+
+            // Weak Wolfe line search along the projected path: bisect between a step that
+            // is too long (no sufficient decrease) and one that is too short (curvature not
+            // yet satisfied), expanding while no upper limit is known.
+            let (mut lower, mut upper) = (0.0, f64::INFINITY);
+            let mut step = 1.0;
+            let mut accepted: Option<(Vec<f64>, f64, Vec<f64>)> = None;
+            for _ in 0..MAX_LINE_SEARCH {
+                let mut trial: Vec<f64> = x
+                    .iter()
+                    .zip(&direction)
+                    .map(|(xi, di)| xi + step * di)
+                    .collect();
+                project(&mut trial, &bounds);
+                let ft = f(&trial);
+                let slope = |gradient: &[f64]| -> f64 {
+                    gradient
+                        .iter()
+                        .zip(&trial)
+                        .zip(&x)
+                        .map(|((gi, t), xi)| gi * (t - xi))
+                        .sum()
+                };
+                let decrease = slope(&g);
+                if !ft.is_finite() || ft > fx + ARMIJO * decrease {
+                    upper = step;
+                } else {
+                    let gt = grad(&trial);
+                    // Once the projection stops the path from moving, expanding is pointless.
+                    let at_bound =
+                        matches!(&accepted, Some((previous, _, _)) if *previous == trial);
+                    let done = slope(&gt) >= CURVATURE * decrease || at_bound;
+                    accepted = Some((trial, ft, gt));
+                    if done {
+                        break;
+                    }
+                    lower = step;
+                }
+                step = if upper.is_finite() {
+                    0.5 * (lower + upper)
+                } else {
+                    2.0 * lower
+                };
+            }
+            let Some((x_new, f_new, g_new)) = accepted else {
+                if history.is_empty() {
+                    break;
+                }
+                history.clear();
+                continue;
+            };
+
+            let s: Vec<f64> = x_new.iter().zip(&x).map(|(a, b)| a - b).collect();
+            let y: Vec<f64> = g_new.iter().zip(&g).map(|(a, b)| a - b).collect();
+            if dot(&s, &y) > 1e-12 {
+                if history.len() == self.memory {
+                    history.pop_front();
+                }
+                history.push_back((s, y));
+            }
+
+            let reduction = (fx - f_new) / fx.abs().max(f_new.abs()).max(1.0);
+            x = x_new;
+            fx = f_new;
+            g = g_new;
+            if reduction <= self.ftol {
+                converged = true;
+                break;
+            }
+        }
+
+        Ok(Minimum {
+            x,
+            value: fx,
+            iterations,
+            converged,
+        })
+    }
+}
@@ -0,0 +1,439 @@
+use rand::rngs::ThreadRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Tolerance used when checking symmetry and the unit diagonal of a correlation matrix.
+const STRUCTURE_TOLERANCE: f64 = 1e-10;
+
+/// Smallest eigenvalue kept when repairing a correlation matrix, so the result is strictly
+/// positive definite and admits a Cholesky factor.
+const MIN_EIGENVALUE: f64 = 1e-8;
+
+/// Errors returned when validating or factorising a correlation matrix.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CorrelationError {
+    /// The matrix has no rows.
+    Empty,
+    /// Row `row` does not have as many entries as the matrix has rows.
+    NotSquare { row: usize },
+    /// An entry is NaN, infinite or outside `[-1, 1]`.
+    InvalidEntry { row: usize, column: usize },
+    /// A diagonal entry is not one.
+    NonUnitDiagonal { index: usize },
+    /// Entries `(row, column)` and `(column, row)` differ.
+    NotSymmetric { row: usize, column: usize },
+    /// The Cholesky pivot at `index` is not positive.
+    NotPositiveDefinite { index: usize },
+}
+
+impl fmt::Display for CorrelationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CorrelationError::Empty => write!(f, "correlation matrix is empty"),
+            CorrelationError::NotSquare { row } => {
+                write!(f, "correlation matrix is not square at row {}", row)
+            }
+            CorrelationError::InvalidEntry { row, column } => {
+                write!(f, "correlation ({}, {}) is not in [-1, 1]", row, column)
+            }
+            CorrelationError::NonUnitDiagonal { index } => {
+                write!(f, "diagonal entry {} is not one", index)
+            }
+            CorrelationError::NotSymmetric { row, column } => {
+                write!(
+                    f,
+                    "correlation matrix is not symmetric at ({}, {})",
+                    row, column
+                )
+            }
+            CorrelationError::NotPositiveDefinite { index } => {
+                write!(f, "matrix is not positive definite (pivot {})", index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CorrelationError {}
+
+/// Computes the Cholesky factor `L` of a symmetric positive-definite matrix, so that
+/// `matrix = L Lᵀ`.
+///
+/// # Arguments
+///
+/// * `matrix` - The symmetric positive-definite matrix, row by row. Only the lower triangle
+///   is read.
+///
+/// # Returns
+///
+/// Returns the lower-triangular factor, or an error if a pivot is not positive.
+pub fn cholesky(matrix: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, CorrelationError> {
+    check_square(matrix)?;
+    let n = matrix.len();
+    let mut lower = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let pivot = matrix[i][i] - sum;
+                if pivot <= 0.0 || pivot.is_nan() {
+                    return Err(CorrelationError::NotPositiveDefinite { index: i });
+                }
+                lower[i][i] = pivot.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+    Ok(lower)
+}
+
+/// Checks that `matrix` is a valid correlation matrix apart from definiteness: square,
+/// finite, symmetric, entries in `[-1, 1]` and ones on the diagonal.
+pub fn validate_correlation(matrix: &[Vec<f64>]) -> Result<(), CorrelationError> {
+    check_square(matrix)?;
+    for (i, row) in matrix.iter().enumerate() {
+        if (row[i] - 1.0).abs() > STRUCTURE_TOLERANCE {
+            return Err(CorrelationError::NonUnitDiagonal { index: i });
+        }
+        for (j, &value) in row.iter().enumerate() {
+            if !value.is_finite() || value.abs() > 1.0 + STRUCTURE_TOLERANCE {
+                return Err(CorrelationError::InvalidEntry { row: i, column: j });
+            }
+            if (value - matrix[j][i]).abs() > STRUCTURE_TOLERANCE {
+                return Err(CorrelationError::NotSymmetric { row: i, column: j });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_square(matrix: &[Vec<f64>]) -> Result<(), CorrelationError> {
+    if matrix.is_empty() {
+        return Err(CorrelationError::Empty);
+    }
+    match matrix.iter().position(|row| row.len() != matrix.len()) {
+        Some(row) => Err(CorrelationError::NotSquare { row }),
+        None => Ok(()),
+    }
+}
+
+/// Finds the nearest correlation matrix (in the Frobenius norm) to a symmetric matrix using
+/// Higham's alternating projections with Dykstra's correction.
+///
+/// The result is symmetric with a unit diagonal and strictly positive definite, so it can
+/// always be passed to `cholesky`.
+/// ref:<https://doi.org/10.1093/imanum/22.3.329>
+///
+/// # Arguments
+///
+/// * `matrix` - A symmetric matrix with a unit diagonal, e.g. a pairwise-estimated
+///   correlation matrix that is not positive semi-definite.
+/// * `tol` - The relative change between iterates at which the projection stops.
+/// * `max_iter` - The maximum number of projection rounds.
+pub fn nearest_correlation(matrix: &[Vec<f64>], tol: f64, max_iter: usize) -> Vec<Vec<f64>> {
+    let n = matrix.len();
+    let mut y = matrix.to_vec();
+    let mut correction = vec![vec![0.0; n]; n];
+    for _ in 0..max_iter {
+        let r: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| y[i][j] - correction[i][j]).collect())
+            .collect();
+        let x = clip_eigenvalues(&r, 0.0);
+        for i in 0..n {
+            for j in 0..n {
+                correction[i][j] = x[i][j] - r[i][j];
+            }
+        }
+        let mut next = x.clone();
+        for (i, row) in next.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        let change = frobenius_distance(&next, &y) / frobenius_norm(&next);
+        y = next;
+        if change < tol {
+            break;
+        }
+    }
+
+    // Lift the smallest eigenvalues off zero and rescale back to a unit diagonal.
+    let x = clip_eigenvalues(&y, MIN_EIGENVALUE);
+    let scale: Vec<f64> = (0..n).map(|i| x[i][i].sqrt()).collect();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    if i == j {
+                        1.0
+                    } else {
+                        x[i][j] / (scale[i] * scale[j])
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn frobenius_norm(a: &[Vec<f64>]) -> f64 {
+    a.iter().flatten().map(|v| v * v).sum::<f64>().sqrt()
+}
+
+fn frobenius_distance(a: &[Vec<f64>], b: &[Vec<f64>]) -> f64 {
+    a.iter()
+        .flatten()
+        .zip(b.iter().flatten())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Projects a symmetric matrix onto the matrices with eigenvalues of at least `floor`.
+fn clip_eigenvalues(a: &[Vec<f64>], floor: f64) -> Vec<Vec<f64>> {
+    let n = a.len();
+    let (values, vectors) = symmetric_eigen(a);
+    let clipped: Vec<f64> = values.iter().map(|v| v.max(floor)).collect();
+    (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    (0..n)
+                        .map(|k| vectors[i][k] * clipped[k] * vectors[j][k])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Eigen-decomposes a symmetric matrix with cyclic Jacobi rotations.
+///
+/// Returns the eigenvalues and a matrix whose columns are the matching eigenvectors.
+fn symmetric_eigen(a: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = a.len();
+    let mut m = a.to_vec();
+    let mut v: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let scale = frobenius_norm(a).max(f64::MIN_POSITIVE);
+
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| m[i][j] * m[i][j])
+            .sum::<f64>()
+            .sqrt();
+        if off <= 1e-15 * scale {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                if m[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for row in m.iter_mut() {
+                    let (mkp, mkq) = (row[p], row[q]);
+                    row[p] = c * mkp - s * mkq;
+                    row[q] = s * mkp + c * mkq;
+                }
+                let (row_p, row_q) = (m[p].clone(), m[q].clone());
+                for k in 0..n {
+                    m[p][k] = c * row_p[k] - s * row_q[k];
+                    m[q][k] = s * row_p[k] + c * row_q[k];
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..n).map(|i| m[i][i]).collect(), v)
+}
+
+/// A sampler of standard normal vectors with a given correlation matrix.
+///
+/// # Example
+///
+/// ```
+/// use core::math::random::CorrelatedNormal;
+/// let sampler = CorrelatedNormal::new(vec![vec![1.0, 0.5], vec![0.5, 1.0]]).unwrap();
+/// let mut z = [0.0; 2];
+/// sampler.sample(&mut rand::thread_rng(), &mut z);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CorrelatedNormal {
+    lower: Vec<Vec<f64>>,
+    repaired: Option<Vec<Vec<f64>>>,
+}
+
+impl CorrelatedNormal {
+    /// Creates a sampler, rejecting matrices that are not positive definite.
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation` - The correlation matrix, row by row.
+    pub fn new(correlation: Vec<Vec<f64>>) -> Result<Self, CorrelationError> {
+        validate_correlation(&correlation)?;
+        Ok(Self {
+            lower: cholesky(&correlation)?,
+            repaired: None,
+        })
+    }
+
+    /// Creates a sampler, replacing a matrix that is not positive definite with its nearest
+    /// correlation matrix. The replacement is available from `repaired`.
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation` - The correlation matrix, row by row.
+    pub fn with_repair(correlation: Vec<Vec<f64>>) -> Result<Self, CorrelationError> {
+        validate_correlation(&correlation)?;
+        match cholesky(&correlation) {
+            Ok(lower) => Ok(Self {
+                lower,
+                repaired: None,
+            }),
+            Err(CorrelationError::NotPositiveDefinite { .. }) => {
+                let repaired = nearest_correlation(&correlation, 1e-12, 1000);
+                Ok(Self {
+                    lower: cholesky(&repaired)?,
+                    repaired: Some(repaired),
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the number of correlated variates per sample.
+    pub fn dimension(&self) -> usize {
+        self.lower.len()
+    }
+
+    /// Returns the Cholesky factor of the correlation matrix in use.
+    pub fn cholesky_factor(&self) -> &[Vec<f64>] {
+        &self.lower
+    }
+
+    /// Returns the repaired correlation matrix, if the input had to be replaced.
+    pub fn repaired(&self) -> Option<&[Vec<f64>]> {
+        self.repaired.as_deref()
+    }
+
+    /// Correlates independent standard normals: `out = L z`.
+    ///
+    /// # Arguments
+    ///
+    /// * `z` - Independent standard normal draws, one per dimension.
+    /// * `out` - Receives the correlated draws.
+    pub fn correlate(&self, z: &[f64], out: &mut [f64]) {
+        for (i, row) in self.lower.iter().enumerate() {
+            out[i] = row[..=i].iter().zip(z).map(|(l, z)| l * z).sum();
+        }
+    }
+
+    /// Draws one correlated standard normal vector into `out`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator.
+    /// * `out` - Receives the correlated draws; its length must equal `dimension()`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R, out: &mut [f64]) {
+        let z: Vec<f64> = (0..self.dimension())
+            .map(|_| rng.sample(StandardNormal))
+            .collect();
+        self.correlate(&z, out);
+    }
+}
+
+/// A source of random number generators for simulation engines.
+///
+/// Engines ask for one generator per independent stream (e.g. one per pricing run or per
+/// worker thread), so a seeded source makes every simulation reproducible and parallel
+/// streams never overlap.
+pub trait RngSource {
+    /// The generator handed out for each stream.
+    type Rng: Rng;
+
+    /// Returns the generator for stream `stream`.
+    fn stream(&self, stream: u64) -> Self::Rng;
+}
+
+/// The thread-local, OS-seeded generator: a fresh, non-reproducible sequence every time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRngSource;
+
+impl RngSource for ThreadRngSource {
+    type Rng = ThreadRng;
+
+    fn stream(&self, _stream: u64) -> ThreadRng {
+        rand::thread_rng()
+    }
+}
+
+/// A deterministic source built from any seedable generator (e.g. `StdRng`, PCG64 from
+/// `rand_pcg` or xoshiro from `rand_xoshiro`).
+///
+/// Each stream is seeded from the master seed and the stream index, so the same seed always
+/// reproduces the same draws.
+///
+/// # Example
+///
+/// ```
+/// use core::math::random::SeededSource;
+/// use rand::rngs::StdRng;
+/// let source = SeededSource::<StdRng>::new(42);
+/// assert_eq!(source.seed(), 42);
+/// ```
+pub struct SeededSource<R> {
+    seed: u64,
+    rng: PhantomData<fn() -> R>,
+}
+
+impl<R> SeededSource<R> {
+    /// Creates a source with the given master seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: PhantomData,
+        }
+    }
+
+    /// Returns the master seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl<R> Clone for SeededSource<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for SeededSource<R> {}
+
+impl<R> fmt::Debug for SeededSource<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeededSource")
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+impl<R: Rng + SeedableRng> RngSource for SeededSource<R> {
+    type Rng = R;
+
+    fn stream(&self, stream: u64) -> R {
+        // Spread consecutive stream indices across the seed space (golden-ratio increment).
+        R::seed_from_u64(
+            self.seed
+                .wrapping_add(stream.wrapping_mul(0x9E37_79B9_7F4A_7C15)),
+        )
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt;
+
+/// Errors returned by the root finders.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RootError {
+    /// `f(a)` and `f(b)` have the same sign, so the interval is not known to contain a root.
+    NotBracketed { a: f64, b: f64 },
+    /// The iteration limit was reached; `best` is the last estimate.
+    MaxIterations { best: f64 },
+    /// The derivative vanished (or was not finite) during a Newton step.
+    ZeroDerivative { x: f64 },
+    /// The function returned NaN or an infinite value at `x`.
+    NonFinite { x: f64 },
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NotBracketed { a, b } => {
+                write!(f, "root is not bracketed by [{}, {}]", a, b)
+            }
+            RootError::MaxIterations { best } => {
+                write!(f, "maximum iterations reached (best estimate {})", best)
+            }
+            RootError::ZeroDerivative { x } => write!(f, "derivative vanished at {}", x),
+            RootError::NonFinite { x } => write!(f, "function is not finite at {}", x),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// Evaluates `f` at `x`, rejecting NaN and infinite values.
+fn finite<F: FnMut(f64) -> f64>(f: &mut F, x: f64) -> Result<f64, RootError> {
+    let y = f(x);
+    if y.is_finite() {
+        Ok(y)
+    } else {
+        Err(RootError::NonFinite { x })
+    }
+}
+
+/// Finds a root of `f` in `[a, b]` by bisection.
+///
+/// # Arguments
+///
+/// * `f` - The function whose root is sought.
+/// * `a` - The lower end of the bracketing interval.
+/// * `b` - The upper end of the bracketing interval.
+/// * `tol` - The interval width at which the search stops.
+/// * `max_iter` - The maximum number of halvings.
+///
+/// # Returns
+///
+/// Returns the root, or an error if `[a, b]` does not bracket one.
+pub fn bisection<F: FnMut(f64) -> f64>(
+    mut f: F,
+    mut a: f64,
+    mut b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, RootError> {
+    let mut fa = finite(&mut f, a)?;
+    let fb = finite(&mut f, b)?;
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() {
+        return Err(RootError::NotBracketed { a, b });
+    }
+
+    for _ in 0..max_iter {
+        let mid = 0.5 * (a + b);
+        let fm = finite(&mut f, mid)?;
+        if fm == 0.0 || 0.5 * (b - a).abs() < tol {
+            return Ok(mid);
+        }
+        if fm.signum() == fa.signum() {
+            a = mid;
+            fa = fm;
+        } else {
+            b = mid;
+        }
+    }
+    Err(RootError::MaxIterations {
+        best: 0.5 * (a + b),
+    })
+}
+
+/// Finds a root of `f` by Newton-Raphson iteration.
+///
+/// # Arguments
+///
+/// * `f` - The function whose root is sought.
+/// * `df` - The derivative of `f`.
+/// * `x0` - The starting point.
+/// * `tol` - The step size at which the iteration stops.
+/// * `max_iter` - The maximum number of Newton steps.
+///
+/// # Returns
+///
+/// Returns the root, or an error if the derivative vanishes or the iteration does not converge.
+pub fn newton_raphson<F, D>(
+    mut f: F,
+    mut df: D,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, RootError>
+where
+    F: FnMut(f64) -> f64,
+    D: FnMut(f64) -> f64,
+{
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let fx = finite(&mut f, x)?;
+        let dfx = df(x);
+        if dfx == 0.0 || !dfx.is_finite() {
+            return Err(RootError::ZeroDerivative { x });
+        }
+        let step = fx / dfx;
+        x -= step;
+        if step.abs() < tol {
+            return Ok(x);
+        }
+    }
+    Err(RootError::MaxIterations { best: x })
+}
+
+/// Finds a root of `f` in `[a, b]` with Brent's method.
+///
+/// Combines bisection, the secant method and inverse quadratic interpolation: it keeps the
+/// guaranteed convergence of bisection while usually converging superlinearly.
+/// ref:<https://en.wikipedia.org/wiki/Brent%27s_method>
+///
+/// # Arguments
+///
+/// * `f` - The function whose root is sought.
+/// * `a` - The lower end of the bracketing interval.
+/// * `b` - The upper end of the bracketing interval.
+/// * `tol` - The absolute tolerance on the root.
+/// * `max_iter` - The maximum number of iterations.
+///
+/// # Returns
+///
+/// Returns the root, or an error if `[a, b]` does not bracket one.
+///
+/// # Example
+///
+/// ```
+/// use core::math::roots::brent;
+/// let root = brent(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+/// assert!((root - 2.0_f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn brent<F: FnMut(f64) -> f64>(
+    mut f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, RootError> {
+    let (mut a, mut b) = (a, b);
+    let mut fa = finite(&mut f, a)?;
+    let mut fb = finite(&mut f, b)?;
+    if fa == 0.0 {
+        return Ok(a);
+    }
+    if fb == 0.0 {
+        return Ok(b);
+    }
+    if fa.signum() == fb.signum() {
+        return Err(RootError::NotBracketed { a, b });
+    }
+
+    // `b` is the best estimate, `a` the previous one and `c` the contrapoint with f(c) of
+    // opposite sign to f(b).
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+
+    for _ in 0..max_iter {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tol1 = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol1 || fb == 0.0 {
+            return Ok(b);
+        }
+
+        if e.abs() >= tol1 && fa.abs() > fb.abs() {
+            // Attempt interpolation: secant if only two distinct points, otherwise inverse
+            // quadratic.
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2.0 * p < (3.0 * m * q - (tol1 * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol1 { d } else { tol1.copysign(m) };
+        fb = finite(&mut f, b)?;
+    }
+    Err(RootError::MaxIterations { best: b })
+}
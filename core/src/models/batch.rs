@@ -0,0 +1,102 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// One option to price in a batch.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PricingRequest {
+    /// A label carried through to the result, e.g. a trade id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Call or put.
+    pub option_type: OptionType,
+    pub s: f64,
+    pub k: f64,
+    pub r: f64,
+    pub sigma: f64,
+    pub t: f64,
+}
+
+impl PricingRequest {
+    /// Returns the pricing parameters of the request.
+    pub fn params(&self) -> OptionParameters {
+        OptionParameters {
+            s: self.s,
+            k: self.k,
+            r: self.r,
+            sigma: self.sigma,
+            t: self.t,
+        }
+    }
+}
+
+/// The price and Greeks of one request.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PricedOption {
+    pub request: PricingRequest,
+    pub price: f64,
+    pub greeks: Greeks,
+}
+
+/// Prices every request on up to `threads` threads.
+///
+/// Requests are handed out one at a time, so slow models (lattices, simulations) stay
+/// evenly spread across the threads.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model.
+/// * `requests` - The options to price.
+/// * `threads` - The most threads to use; `0` is treated as `1`.
+/// * `progress` - Called with the number of requests priced so far after each one.
+///
+/// # Returns
+///
+/// Returns one result per request, in the order of `requests`.
+pub fn price_batch<T, F>(
+    model: &T,
+    requests: &[PricingRequest],
+    threads: usize,
+    progress: F,
+) -> Vec<PricedOption>
+where
+    T: OptionPricingModel + Sync + ?Sized,
+    F: Fn(usize) + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let worker = || {
+        let mut priced = Vec::new();
+        loop {
+            let i = next.fetch_add(1, Ordering::Relaxed);
+            let Some(request) = requests.get(i) else {
+                return priced;
+            };
+            let params = request.params();
+            priced.push((
+                i,
+                PricedOption {
+                    request: request.clone(),
+                    price: model.option_price(&params, request.option_type),
+                    greeks: model.option_greeks(&params, request.option_type),
+                },
+            ));
+            progress(done.fetch_add(1, Ordering::Relaxed) + 1);
+        }
+    };
+
+    let threads = threads.clamp(1, requests.len().max(1));
+    let mut results: Vec<Option<PricedOption>> = vec![None; requests.len()];
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads).map(|_| scope.spawn(worker)).collect();
+        for handle in handles {
+            for (i, priced) in handle.join().expect("pricing thread panicked") {
+                results[i] = Some(priced);
+            }
+        }
+    });
+    results
+        .into_iter()
+        .map(|priced| priced.expect("every request is priced"))
+        .collect()
+}
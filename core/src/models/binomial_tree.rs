@@ -1,3 +1,4 @@
+use crate::models::lattice::{with_scratch, LatticeScratch};
 use crate::models::{OptionParameters, OptionPricingModel};
 
 // <https://www.kent.ac.uk/learning/documents/slas-documents/Binomial_models.pdf >
@@ -9,11 +10,6 @@ pub struct BinomialTreeModel {
     pub epsilon: f64,
 }
 
-enum OptionType {
-    Call,
-    Put,
-}
-
 impl BinomialTreeModel {
     /// Creates a new `BinomialTreeModel` with a specified number of steps and epsilon.
     ///
@@ -25,58 +21,47 @@ impl BinomialTreeModel {
         Self { steps, epsilon }
     }
 
-    /// Initializes the prices vector for call or put options.
+    /// Prices the call and the put from a single traversal of the lattice.
+    ///
+    /// Both payoffs share the same terminal stock prices, discount factor and risk-neutral
+    /// probability, so they are rolled back together in the thread's reusable scratch space.
     ///
     /// # Arguments
     ///
     /// * `params` - A reference to `OptionParameters` containing the parameters for the option.
-    /// * `option_type` - A value indicating the type of option (`Call` or `Put`).
     ///
     /// # Returns
     ///
-    /// A vector containing the prices of the option at each node.
-    fn initialize_prices(&self, params: &OptionParameters, option_type: OptionType) -> Vec<f64> {
+    /// The `(call, put)` prices.
+    fn evaluate(&self, params: &OptionParameters) -> (f64, f64) {
         let n = self.steps; // Number of steps in the binomial tree
         let dt = params.t / (n as f64); // Time step size
-        let u = f64::exp(params.sigma * (dt as f64).sqrt()); // Up factor
+        let u = f64::exp(params.sigma * dt.sqrt()); // Up factor
         let d = 1.0 / u; // Down factor
+        let q = (f64::exp(params.r * dt) - d) / (u - d); // Risk-neutral probability
+        let discount = f64::exp(-params.r * dt);
 
-        // Terminal prices
-        (0..=n)
-            .map(|i| {
-                let price = params.s * u.powi((n - i) as i32) * d.powi(i as i32);
-                match option_type {
-                    OptionType::Call => (price - params.k).max(0.0),
-                    OptionType::Put => (params.k - price).max(0.0),
-                }
-            })
-            .collect()
-    }
+        with_scratch(|scratch| {
+            scratch.reset(n + 1);
+            let LatticeScratch { calls, puts, .. } = scratch;
 
-    /// Performs backward induction to calculate option price.
-    ///
-    /// # Arguments
-    ///
-    /// * `prices` - A mutable vector containing the prices of the option at each node.
-    /// * `params` - A reference to `OptionParameters` containing the parameters for the option.
-    ///
-    /// # Returns
-    ///
-    /// The calculated option price.
-    fn backward_induction(&self, prices: &mut Vec<f64>, params: &OptionParameters) -> f64 {
-        let n = self.steps; // Number of steps in the binomial tree
-        let dt = params.t / (n as f64); // Time step size
-        let u = f64::exp(params.sigma * (dt as f64).sqrt()); // Up factor
-        let d = 1.0 / u; // Down factor
-        let q = (f64::exp(params.r * dt as f64) - d) / (u - d); // Risk-neutral probability
+            // Terminal payoffs
+            for i in 0..=n {
+                let price = params.s * u.powi((n - i) as i32) * d.powi(i as i32);
+                calls[i] = (price - params.k).max(0.0);
+                puts[i] = (params.k - price).max(0.0);
+            }
 
-        for j in (0..n).rev() {
-            for i in 0..=j {
-                prices[i] =
-                    f64::exp(-params.r * dt as f64) * (q * prices[i] + (1.0 - q) * prices[i + 1]);
+            // Backward induction
+            for j in (0..n).rev() {
+                for i in 0..=j {
+                    calls[i] = discount * (q * calls[i] + (1.0 - q) * calls[i + 1]);
+                    puts[i] = discount * (q * puts[i] + (1.0 - q) * puts[i + 1]);
+                }
             }
-        }
-        prices[0]
+
+            (calls[0], puts[0])
+        })
     }
 }
 
@@ -100,8 +85,7 @@ impl OptionPricingModel for BinomialTreeModel {
     ///
     /// The calculated call option price.
     fn call_price(&self, params: &OptionParameters) -> f64 {
-        let mut prices = self.initialize_prices(params, OptionType::Call);
-        self.backward_induction(&mut prices, params)
+        self.evaluate(params).0
     }
 
     /// Calculates the put option price using the binomial tree model.
@@ -114,8 +98,20 @@ impl OptionPricingModel for BinomialTreeModel {
     ///
     /// The calculated put option price.
     fn put_price(&self, params: &OptionParameters) -> f64 {
-        let mut prices = self.initialize_prices(params, OptionType::Put);
-        self.backward_induction(&mut prices, params)
+        self.evaluate(params).1
+    }
+
+    /// Calculates the call and put prices from one lattice traversal.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A reference to `OptionParameters` containing the parameters for the option.
+    ///
+    /// # Returns
+    ///
+    /// The `(call, put)` prices.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        self.evaluate(params)
     }
 
     /// Calculates the delta of the option using the binomial tree model.
@@ -130,7 +126,7 @@ impl OptionPricingModel for BinomialTreeModel {
     fn delta(&self, params: &OptionParameters) -> f64 {
         let n = self.steps;
         let dt = params.t / (n as f64);
-        let u = f64::exp(params.sigma * (dt as f64).sqrt());
+        let u = f64::exp(params.sigma * dt.sqrt());
         let d = 1.0 / u;
 
         let up_params = OptionParameters {
@@ -160,7 +156,7 @@ impl OptionPricingModel for BinomialTreeModel {
     fn gamma(&self, params: &OptionParameters) -> f64 {
         let n = self.steps;
         let dt = params.t / (n as f64);
-        let u = f64::exp(params.sigma * (dt as f64).sqrt());
+        let u = f64::exp(params.sigma * dt.sqrt());
         let d = 1.0 / u;
 
         let delta_up = self.delta(&OptionParameters {
@@ -234,4 +230,3 @@ impl OptionPricingModel for BinomialTreeModel {
         (call_price_r2 - call_price_r1) / self.epsilon
     }
 }
-
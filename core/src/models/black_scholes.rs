@@ -15,11 +15,7 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the price of the European call option.
     fn call_price(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let d2 = d1 - params.sigma * params.t.sqrt();
-        params.s * standard_normal_cdf(d1)
-            - params.k * (-params.r * params.t).exp() * standard_normal_cdf(d2)
+        BsIntermediates::new(params).call_price()
     }
 
     /// Calculates the price of a European put option using the Black-Scholes formula.
@@ -32,11 +28,21 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the price of the European put option.
     fn put_price(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let d2 = d1 - params.sigma * params.t.sqrt();
-        params.k * (-params.r * params.t).exp() * standard_normal_cdf(-d2)
-            - params.s * standard_normal_cdf(-d1)
+        BsIntermediates::new(params).put_price()
+    }
+
+    /// Calculates the call and put prices from one set of intermediates.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `(call, put)` prices.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        let bs = BsIntermediates::new(params);
+        (bs.call_price(), bs.put_price())
     }
 
     /// Calculates the Delta of the option using the Black-Scholes formula.
@@ -49,9 +55,7 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the Delta of the option.
     fn delta(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        standard_normal_cdf(d1)
+        BsIntermediates::new(params).delta()
     }
 
     /// Calculates the Gamma of the option using the Black-Scholes formula.
@@ -64,10 +68,7 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the Gamma of the option.
     fn gamma(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let normal_pdf = (1.0 / (2.0 * std::f64::consts::PI).sqrt()) * (-0.5 * d1.powi(2)).exp();
-        normal_pdf / (params.s * params.sigma * params.t.sqrt())
+        BsIntermediates::new(params).gamma()
     }
 
     /// Calculates the Vega of the option using the Black-Scholes formula.
@@ -80,10 +81,7 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the Vega of the option.
     fn vega(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let normal_pdf = (1.0 / (2.0 * std::f64::consts::PI).sqrt()) * (-0.5 * d1.powi(2)).exp();
-        params.s * normal_pdf * params.t.sqrt()
+        BsIntermediates::new(params).vega()
     }
 
     /// Calculates the Theta of the option using the Black-Scholes formula.
@@ -96,13 +94,7 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the Theta of the option.
     fn theta(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
-            * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let d2 = d1 - params.sigma * params.t.sqrt();
-        let normal_pdf = (1.0 / (2.0 * std::f64::consts::PI).sqrt()) * (-0.5 * d1.powi(2)).exp();
-        let theta_call = -((params.s * normal_pdf * params.sigma) / (2.0 * params.t.sqrt()))
-            - params.r * params.k * (-params.r * params.t).exp() * standard_normal_cdf(d2);
-        theta_call / 365.0 // Annualize to daily
+        BsIntermediates::new(params).theta()
     }
 
     /// Calculates the Rho of the option using the Black-Scholes formula.
@@ -115,10 +107,141 @@ impl OptionPricingModel for BlackScholesModel {
     ///
     /// Returns the Rho of the option.
     fn rho(&self, params: &OptionParameters) -> f64 {
-        let d1 = (1.0 / (params.sigma * params.t.sqrt()))
+        BsIntermediates::new(params).rho()
+    }
+
+    /// Calculates the daily Theta of the put using the Black-Scholes formula.
+    fn put_theta(&self, params: &OptionParameters) -> f64 {
+        BsIntermediates::new(params).put_theta()
+    }
+
+    /// Calculates the Rho of the put, per 1% change in the rate.
+    fn put_rho(&self, params: &OptionParameters) -> f64 {
+        BsIntermediates::new(params).put_rho()
+    }
+}
+
+/// Intermediate quantities shared by the Black-Scholes price and all of its Greeks.
+///
+/// Every formula needs `d1`, `d2`, the normal density at `d1` and the discount factor, so
+/// building them once and reading them from each method avoids recomputing the same logs,
+/// square roots and exponentials for every Greek.
+///
+/// # Example
+///
+/// ```
+/// use core::models::black_scholes::BsIntermediates;
+/// use core::models::OptionParameters;
+/// let params = OptionParameters {
+///     s: 100.0,
+///     k: 100.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let bs = BsIntermediates::new(&params);
+/// let (call, delta, gamma) = (bs.call_price(), bs.delta(), bs.gamma());
+/// assert!(call > 0.0 && delta > 0.5 && gamma > 0.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BsIntermediates {
+    /// The current stock price.
+    pub s: f64,
+    /// The strike price.
+    pub k: f64,
+    /// The risk-free interest rate.
+    pub r: f64,
+    /// The volatility.
+    pub sigma: f64,
+    /// The time to maturity in years.
+    pub t: f64,
+    /// Square root of the time to maturity.
+    pub sqrt_t: f64,
+    /// The `d1` term of the Black-Scholes formula.
+    pub d1: f64,
+    /// The `d2` term of the Black-Scholes formula (`d1 - sigma * sqrt(t)`).
+    pub d2: f64,
+    /// Standard normal density evaluated at `d1`.
+    pub pdf_d1: f64,
+    /// Discount factor `exp(-r * t)`.
+    pub discount: f64,
+}
+
+impl BsIntermediates {
+    /// Computes the shared intermediates for the given parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option.
+    pub fn new(params: &OptionParameters) -> Self {
+        let sqrt_t = params.t.sqrt();
+        let sigma_sqrt_t = params.sigma * sqrt_t;
+        let d1 = (1.0 / sigma_sqrt_t)
             * ((params.s / params.k).ln() + (params.r + 0.5 * params.sigma.powi(2)) * params.t);
-        let d2 = d1 - params.sigma * params.t.sqrt();
-        params.k * params.t * (-params.r * params.t).exp() * standard_normal_cdf(d2) / 100.0
+        let d2 = d1 - sigma_sqrt_t;
+        Self {
+            s: params.s,
+            k: params.k,
+            r: params.r,
+            sigma: params.sigma,
+            t: params.t,
+            sqrt_t,
+            d1,
+            d2,
+            pdf_d1: standard_normal_pdf(d1),
+            discount: (-params.r * params.t).exp(),
+        }
+    }
+
+    /// Returns the price of the European call option.
+    pub fn call_price(&self) -> f64 {
+        self.s * standard_normal_cdf(self.d1)
+            - self.k * self.discount * standard_normal_cdf(self.d2)
+    }
+
+    /// Returns the price of the European put option.
+    pub fn put_price(&self) -> f64 {
+        self.k * self.discount * standard_normal_cdf(-self.d2)
+            - self.s * standard_normal_cdf(-self.d1)
+    }
+
+    /// Returns the Delta of the call option.
+    pub fn delta(&self) -> f64 {
+        standard_normal_cdf(self.d1)
+    }
+
+    /// Returns the Gamma of the option.
+    pub fn gamma(&self) -> f64 {
+        self.pdf_d1 / (self.s * self.sigma * self.sqrt_t)
+    }
+
+    /// Returns the Vega of the option.
+    pub fn vega(&self) -> f64 {
+        self.s * self.pdf_d1 * self.sqrt_t
+    }
+
+    /// Returns the daily Theta of the call option.
+    pub fn theta(&self) -> f64 {
+        let theta_call = -((self.s * self.pdf_d1 * self.sigma) / (2.0 * self.sqrt_t))
+            - self.r * self.k * self.discount * standard_normal_cdf(self.d2);
+        theta_call / 365.0 // Annualize to daily
+    }
+
+    /// Returns the Rho of the call option, per 1% change in the rate.
+    pub fn rho(&self) -> f64 {
+        self.k * self.t * self.discount * standard_normal_cdf(self.d2) / 100.0
+    }
+
+    /// Returns the daily Theta of the put option.
+    pub fn put_theta(&self) -> f64 {
+        let theta_put = -((self.s * self.pdf_d1 * self.sigma) / (2.0 * self.sqrt_t))
+            + self.r * self.k * self.discount * standard_normal_cdf(-self.d2);
+        theta_put / 365.0
+    }
+
+    /// Returns the Rho of the put option, per 1% change in the rate.
+    pub fn put_rho(&self) -> f64 {
+        -self.k * self.t * self.discount * standard_normal_cdf(-self.d2) / 100.0
     }
 }
 
@@ -131,10 +254,23 @@ impl OptionPricingModel for BlackScholesModel {
 /// # Returns
 ///
 /// Returns the CDF value for the standard normal distribution.
-fn standard_normal_cdf(x: f64) -> f64 {
+pub fn standard_normal_cdf(x: f64) -> f64 {
     (1.0 + erf(x / 2.0_f64.sqrt())) / 2.0
 }
 
+/// Calculates the probability density function (PDF) of the standard normal distribution.
+///
+/// # Arguments
+///
+/// * `x` - The value for which to compute the PDF.
+///
+/// # Returns
+///
+/// Returns the PDF value for the standard normal distribution.
+pub(crate) fn standard_normal_pdf(x: f64) -> f64 {
+    (1.0 / (2.0 * std::f64::consts::PI).sqrt()) * (-0.5 * x.powi(2)).exp()
+}
+
 /// Computes the error function (erf), which is used in the standard normal CDF calculation.
 ///
 /// # Arguments
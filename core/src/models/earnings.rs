@@ -0,0 +1,214 @@
+use crate::models::black_scholes::BsIntermediates;
+use crate::models::{OptionParameters, OptionPricingModel};
+use std::fmt;
+
+/// Black-Scholes with a single scheduled event jump, such as an earnings release.
+///
+/// `params.sigma` is the diffusive (ex-event) volatility. On the event date the log price
+/// jumps by a normal amount with standard deviation `event_move`, so an expiry after the
+/// event carries total variance
+///
+/// \[
+/// w(T) = \sigma_d^2 T + m^2
+/// \]
+///
+/// and is priced by Black-Scholes at `σ_eff(T) = sqrt(w(T) / T)`. Expiries before the event
+/// see only the diffusive volatility, so pre- and post-event expirations are priced
+/// consistently from the same two inputs.
+pub struct EarningsModel {
+    /// Time of the event in years from today.
+    pub event_time: f64,
+    /// Standard deviation of the event's log return (e.g. 0.06 for a 6% move).
+    pub event_move: f64,
+}
+
+/// Errors returned by `EarningsModel::calibrate`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventCalibrationError {
+    /// The expiries are not strictly increasing.
+    ExpiriesNotOrdered,
+    /// The later expiry does not fall after the event.
+    NoExpiryAfterEvent,
+    /// The implied vols imply a negative diffusive or event variance.
+    NegativeVariance,
+}
+
+impl fmt::Display for EventCalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventCalibrationError::ExpiriesNotOrdered => {
+                write!(f, "expiries must be strictly increasing")
+            }
+            EventCalibrationError::NoExpiryAfterEvent => {
+                write!(f, "the second expiry must fall after the event")
+            }
+            EventCalibrationError::NegativeVariance => {
+                write!(
+                    f,
+                    "implied vols are inconsistent with a non-negative event variance"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventCalibrationError {}
+
+impl EarningsModel {
+    /// Creates a new `EarningsModel`.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_time` - Time of the event in years.
+    /// * `event_move` - Standard deviation of the event log return.
+    pub fn new(event_time: f64, event_move: f64) -> Self {
+        Self {
+            event_time,
+            event_move,
+        }
+    }
+
+    /// Returns the event variance included in an expiry at time `t`.
+    fn event_variance(&self, t: f64) -> f64 {
+        if self.event_time >= 0.0 && self.event_time < t {
+            self.event_move * self.event_move
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the Black-Scholes implied volatility of an expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `diffusive_vol` - The ex-event volatility.
+    /// * `t` - The expiry in years.
+    pub fn implied_vol(&self, diffusive_vol: f64, t: f64) -> f64 {
+        (diffusive_vol * diffusive_vol + self.event_variance(t) / t).sqrt()
+    }
+
+    /// Returns the expected absolute event move, `m sqrt(2/π)`, the number usually quoted
+    /// as the "implied move".
+    pub fn expected_absolute_move(&self) -> f64 {
+        self.event_move * (2.0 / std::f64::consts::PI).sqrt()
+    }
+
+    /// Extracts the diffusive volatility and the event model from the implied vols of two
+    /// expiries.
+    ///
+    /// With both expiries after the event, the total variance difference isolates the
+    /// diffusive vol and the remainder is the event variance. With the first expiry before
+    /// the event its vol is the diffusive vol directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `event_time` - Time of the event in years.
+    /// * `first` - `(expiry, implied vol)` of the nearer expiry.
+    /// * `second` - `(expiry, implied vol)` of the later expiry, after the event.
+    ///
+    /// # Returns
+    ///
+    /// Returns the diffusive vol and the calibrated model.
+    pub fn calibrate(
+        event_time: f64,
+        first: (f64, f64),
+        second: (f64, f64),
+    ) -> Result<(f64, Self), EventCalibrationError> {
+        let ((t1, vol1), (t2, vol2)) = (first, second);
+        if !(t1 > 0.0 && t2 > t1) {
+            return Err(EventCalibrationError::ExpiriesNotOrdered);
+        }
+        if t2 <= event_time {
+            return Err(EventCalibrationError::NoExpiryAfterEvent);
+        }
+        let (w1, w2) = (vol1 * vol1 * t1, vol2 * vol2 * t2);
+        let diffusive_variance = if t1 <= event_time {
+            vol1 * vol1
+        } else {
+            (w2 - w1) / (t2 - t1)
+        };
+        let event_variance = w2 - diffusive_variance * t2;
+        if diffusive_variance < 0.0 || event_variance < 0.0 {
+            return Err(EventCalibrationError::NegativeVariance);
+        }
+        Ok((
+            diffusive_variance.sqrt(),
+            Self::new(event_time, event_variance.sqrt()),
+        ))
+    }
+
+    /// Returns the Black-Scholes intermediates at the effective volatility of the expiry.
+    fn intermediates(&self, params: &OptionParameters) -> BsIntermediates {
+        BsIntermediates::new(&OptionParameters {
+            sigma: self.implied_vol(params.sigma, params.t),
+            ..params.clone()
+        })
+    }
+}
+
+impl OptionPricingModel for EarningsModel {
+    /// Calculates the call price at the expiry's effective volatility.
+    fn call_price(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).call_price()
+    }
+
+    /// Calculates the put price at the expiry's effective volatility.
+    fn put_price(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).put_price()
+    }
+
+    /// Calculates both prices from one set of intermediates.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        let bs = self.intermediates(params);
+        (bs.call_price(), bs.put_price())
+    }
+
+    /// Calculates the call delta.
+    fn delta(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).delta()
+    }
+
+    /// Calculates the gamma.
+    fn gamma(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).gamma()
+    }
+
+    /// Calculates the vega with respect to the diffusive volatility.
+    fn vega(&self, params: &OptionParameters) -> f64 {
+        let effective = self.implied_vol(params.sigma, params.t);
+        self.intermediates(params).vega() * params.sigma / effective
+    }
+
+    /// Calculates the daily call theta: the change in price after one day, with the event
+    /// one day closer. The event variance does not decay until the event has passed.
+    fn theta(&self, params: &OptionParameters) -> f64 {
+        let day = 1.0 / 365.0;
+        let tomorrow = Self::new(self.event_time - day, self.event_move);
+        let later = OptionParameters {
+            t: params.t - day,
+            ..params.clone()
+        };
+        tomorrow.call_price(&later) - self.call_price(params)
+    }
+
+    /// Calculates the call rho, per 1% change in the rate.
+    fn rho(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).rho()
+    }
+
+    /// Calculates the daily put theta, with the event one day closer.
+    fn put_theta(&self, params: &OptionParameters) -> f64 {
+        let day = 1.0 / 365.0;
+        let tomorrow = Self::new(self.event_time - day, self.event_move);
+        let later = OptionParameters {
+            t: params.t - day,
+            ..params.clone()
+        };
+        tomorrow.put_price(&later) - self.put_price(params)
+    }
+
+    /// Calculates the put rho, per 1% change in the rate.
+    fn put_rho(&self, params: &OptionParameters) -> f64 {
+        self.intermediates(params).put_rho()
+    }
+}
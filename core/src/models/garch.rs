@@ -1,15 +1,32 @@
+use crate::models::lattice::{with_scratch, LatticeScratch};
 use crate::models::{OptionParameters, OptionPricingModel};
 
 /// A GARCH(1,1) model for option pricing.
+///
+/// The conditional per-period variance follows
+///
+/// \[
+/// h_{k+1} = \omega + \alpha \varepsilon_k^2 + \beta h_k
+/// \]
+///
+/// Historical innovations (demeaned per-period log returns) are filtered through the
+/// recursion, seeded with `params.sigma`, to obtain today's conditional variance. Over the
+/// life of the option the innovations are unknown, so the lattice uses the expected
+/// variance term structure, which mean-reverts towards `omega / (1 - alpha - beta)`.
+/// Without a history the model starts directly from `params.sigma`.
 pub struct GarchModel {
     /// Number of steps in the model.
     pub steps: usize,
-    /// GARCH model parameters.
+    /// GARCH model parameters, in per-period variance units.
     pub omega: f64,
     pub alpha: f64,
     pub beta: f64,
     /// Epsilon value for numerical differentiation.
     pub epsilon: f64,
+    /// Historical innovations, oldest first, driving the variance recursion.
+    pub innovations: Vec<f64>,
+    /// Number of innovation periods per year (252 for daily returns).
+    pub periods_per_year: f64,
 }
 
 impl GarchModel {
@@ -28,18 +45,151 @@ impl GarchModel {
             alpha,
             beta,
             epsilon,
+            innovations: Vec::new(),
+            periods_per_year: 252.0,
         }
     }
+
+    /// Sets the historical innovation series used to filter today's conditional variance.
+    ///
+    /// # Arguments
+    ///
+    /// * `innovations` - Demeaned per-period log returns, oldest first.
+    /// * `periods_per_year` - Number of innovation periods per year.
+    pub fn with_innovations(mut self, innovations: Vec<f64>, periods_per_year: f64) -> Self {
+        self.innovations = innovations;
+        self.periods_per_year = periods_per_year;
+        self
+    }
+
+    /// Runs the GARCH(1,1) recursion over the historical innovations.
+    ///
+    /// # Arguments
+    ///
+    /// * `sigma` - The annualized volatility used to seed the recursion.
+    ///
+    /// # Returns
+    ///
+    /// Today's conditional per-period variance.
+    pub fn conditional_variance(&self, sigma: f64) -> f64 {
+        let seed = sigma * sigma / self.periods_per_year;
+        self.innovations.iter().fold(seed, |h, eps| {
+            self.omega + self.alpha * eps * eps + self.beta * h
+        })
+    }
+
+    /// Expected per-period variance `k` periods ahead, given a current variance of `h0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `h0` - The current conditional per-period variance.
+    /// * `k` - The number of periods ahead (need not be whole).
+    pub fn expected_variance(&self, h0: f64, k: f64) -> f64 {
+        let persistence = self.alpha + self.beta;
+        if (persistence - 1.0).abs() < f64::EPSILON {
+            // Integrated GARCH: shocks never decay and variance drifts by omega per period.
+            h0 + self.omega * k
+        } else {
+            let long_run = self.omega / (1.0 - persistence);
+            long_run + persistence.powf(k) * (h0 - long_run)
+        }
+    }
+
+    /// Prices the call and the put from a single traversal of the GARCH lattice.
+    ///
+    /// The expected variance is accumulated over `steps` equal calendar intervals, and the
+    /// lattice steps are then placed so that each carries the same share of the total
+    /// variance. This keeps the up/down factors constant (the tree recombines) while the
+    /// step lengths, discount factors and risk-neutral probabilities follow the GARCH
+    /// variance term structure.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A reference to `OptionParameters` containing the parameters for the option.
+    ///
+    /// # Returns
+    ///
+    /// The `(call, put)` prices.
+    fn evaluate(&self, params: &OptionParameters) -> (f64, f64) {
+        let n = self.steps; // Number of steps in the lattice
+        let dt = params.t / (n as f64); // Calendar step size
+        let h0 = self.conditional_variance(params.sigma);
+
+        with_scratch(|scratch| {
+            scratch.reset(n + 1);
+            let LatticeScratch {
+                calls,
+                puts,
+                variance,
+                discount,
+                q,
+            } = scratch;
+
+            // Expected variance accrued over each calendar step, sampled at its midpoint.
+            let mut total = 0.0;
+            for (j, v) in variance.iter_mut().take(n).enumerate() {
+                let k = (j as f64 + 0.5) * dt * self.periods_per_year;
+                *v = self.expected_variance(h0, k) * self.periods_per_year * dt;
+                total += *v;
+            }
+
+            let step_variance = total / (n as f64);
+            let u = step_variance.sqrt().exp(); // Up factor
+            let d = 1.0 / u; // Down factor
+
+            // Place each lattice step where the next equal share of variance has accrued.
+            let mut j = 0;
+            let mut accrued = 0.0;
+            let mut previous = 0.0;
+            for i in 0..n {
+                let target = step_variance * (i + 1) as f64;
+                while j + 1 < n && accrued + variance[j] < target {
+                    accrued += variance[j];
+                    j += 1;
+                }
+                let time = if i + 1 == n {
+                    params.t
+                } else if variance[j] > 0.0 {
+                    (j as f64 + ((target - accrued) / variance[j]).clamp(0.0, 1.0)) * dt
+                } else {
+                    (j as f64 + 1.0) * dt
+                };
+                let length = time - previous;
+                previous = time;
+                discount[i] = f64::exp(-params.r * length);
+                q[i] = (f64::exp(params.r * length) - d) / (u - d);
+            }
+
+            // Terminal payoffs
+            for i in 0..=n {
+                let price = params.s * u.powi((n - i) as i32) * d.powi(i as i32);
+                calls[i] = (price - params.k).max(0.0);
+                puts[i] = (params.k - price).max(0.0);
+            }
+
+            // Backward induction
+            for j in (0..n).rev() {
+                for i in 0..=j {
+                    calls[i] = discount[j] * (q[j] * calls[i] + (1.0 - q[j]) * calls[i + 1]);
+                    puts[i] = discount[j] * (q[j] * puts[i] + (1.0 - q[j]) * puts[i + 1]);
+                }
+            }
+
+            (calls[0], puts[0])
+        })
+    }
 }
 
 impl Default for GarchModel {
     fn default() -> Self {
         Self {
             steps: 100,
-            omega: 0.1,
+            omega: 0.000_02,
             alpha: 0.1,
             beta: 0.8,
             epsilon: 1e-5,
+            innovations: Vec::new(),
+            periods_per_year: 252.0,
         }
     }
 }
@@ -55,36 +205,7 @@ impl OptionPricingModel for GarchModel {
     ///
     /// The calculated call option price.
     fn call_price(&self, params: &OptionParameters) -> f64 {
-        let n = self.steps; // Number of steps in the binomial tree
-        let dt = params.t / (n as f64); // Time step size
-        let mut sigma2 = vec![params.sigma * params.sigma; n + 1];
-        let mut prices = vec![0.0; n + 1];
-        let mut u = vec![0.0; n + 1];
-        let mut d = vec![0.0; n + 1];
-        let mut q = vec![0.0; n + 1];
-
-        for i in 1..=n {
-            sigma2[i] =
-                self.omega + self.alpha * params.sigma * params.sigma + self.beta * sigma2[i - 1];
-            u[i] = f64::exp(sigma2[i].sqrt() * (dt as f64).sqrt());
-            d[i] = 1.0 / u[i];
-            q[i] = (f64::exp(params.r * dt as f64) - d[i]) / (u[i] - d[i]);
-        }
-
-        for i in 0..=n {
-            prices[i] = (params.s * u[n - i].powi(i as i32) * d[n - i].powi((n - i) as i32)
-                - params.k)
-                .max(0.0);
-        }
-
-        for j in (0..n).rev() {
-            for i in 0..=j {
-                prices[i] = f64::exp(-params.r * dt as f64)
-                    * (q[j + 1] * prices[i] + (1.0 - q[j + 1]) * prices[i + 1]);
-            }
-        }
-
-        prices[0]
+        self.evaluate(params).0
     }
 
     /// Calculates the put option price using the GARCH(1,1) model.
@@ -97,36 +218,20 @@ impl OptionPricingModel for GarchModel {
     ///
     /// The calculated put option price.
     fn put_price(&self, params: &OptionParameters) -> f64 {
-        let n = self.steps; // Number of steps in the binomial tree
-        let dt = params.t / (n as f64); // Time step size
-        let mut sigma2 = vec![params.sigma * params.sigma; n + 1];
-        let mut prices = vec![0.0; n + 1];
-        let mut u = vec![0.0; n + 1];
-        let mut d = vec![0.0; n + 1];
-        let mut q = vec![0.0; n + 1];
-
-        for i in 1..=n {
-            sigma2[i] =
-                self.omega + self.alpha * params.sigma * params.sigma + self.beta * sigma2[i - 1];
-            u[i] = f64::exp(sigma2[i].sqrt() * (dt as f64).sqrt());
-            d[i] = 1.0 / u[i];
-            q[i] = (f64::exp(params.r * dt as f64) - d[i]) / (u[i] - d[i]);
-        }
-
-        for i in 0..=n {
-            prices[i] = (params.k
-                - params.s * u[n - i].powi(i as i32) * d[n - i].powi((n - i) as i32))
-            .max(0.0);
-        }
-
-        for j in (0..n).rev() {
-            for i in 0..=j {
-                prices[i] = f64::exp(-params.r * dt as f64)
-                    * (q[j + 1] * prices[i] + (1.0 - q[j + 1]) * prices[i + 1]);
-            }
-        }
+        self.evaluate(params).1
+    }
 
-        prices[0]
+    /// Calculates the call and put prices from one traversal of the GARCH(1,1) lattice.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - A reference to `OptionParameters` containing the parameters for the option.
+    ///
+    /// # Returns
+    ///
+    /// The `(call, put)` prices.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        self.evaluate(params)
     }
 
     /// Calculates the delta of the option using the GARCH(1,1) model.
@@ -142,7 +247,7 @@ impl OptionPricingModel for GarchModel {
         let n = self.steps;
         let dt = params.t / (n as f64);
         let sigma2 = params.sigma * params.sigma;
-        let u = f64::exp(sigma2.sqrt() * (dt as f64).sqrt());
+        let u = f64::exp(sigma2.sqrt() * dt.sqrt());
         let d = 1.0 / u;
 
         let up_params = OptionParameters {
@@ -158,7 +263,7 @@ impl OptionPricingModel for GarchModel {
         let call_down = self.call_price(&down_params);
 
         let delta = (call_up - call_down) / (params.s * (u - d));
-        delta.min(1.0).max(-1.0)
+        delta.clamp(-1.0, 1.0)
     }
     /// Calculates the gamma of the option using the GARCH(1,1) model.
     ///
@@ -172,7 +277,7 @@ impl OptionPricingModel for GarchModel {
     fn gamma(&self, params: &OptionParameters) -> f64 {
         let n = self.steps;
         let dt = params.t / (n as f64);
-        let u = f64::exp(params.sigma * (dt as f64).sqrt());
+        let u = f64::exp(params.sigma * dt.sqrt());
         let d = 1.0 / u;
 
         let up_params = OptionParameters {
@@ -254,4 +359,3 @@ impl OptionPricingModel for GarchModel {
         rho.max(0.0)
     }
 }
-
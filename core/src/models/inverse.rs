@@ -0,0 +1,166 @@
+use crate::models::{OptionParameters, OptionPricingModel};
+
+/// Prices inverse (coin-settled) options such as those listed on Deribit.
+///
+/// An inverse call pays `max(S_T - K, 0) / S_T` coins at expiry, which is worth exactly
+/// `max(S_T - K, 0)` dollars, so the coin premium today is the dollar price of the wrapped
+/// model divided by the spot. Prices and Greeks returned through `OptionPricingModel` are
+/// in coins and are the derivatives of the coin premium, keeping the wrapped model's units
+/// (e.g. daily theta for Black-Scholes):
+///
+/// \[
+/// V_c = \frac{C}{S}, \quad
+/// \frac{\partial V_c}{\partial S} = \frac{\Delta}{S} - \frac{C}{S^2}, \quad
+/// \frac{\partial^2 V_c}{\partial S^2} = \frac{\Gamma}{S} - \frac{2\Delta}{S^2} + \frac{2C}{S^3}
+/// \]
+///
+/// Exchanges quote the premium-adjusted delta instead; see `premium_adjusted_delta`.
+///
+/// # Example
+///
+/// ```
+/// use core::models::{BlackScholesModel, InverseOptionModel, OptionParameters, OptionPricingModel};
+/// let params = OptionParameters {
+///     s: 100.0,
+///     k: 100.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let model = InverseOptionModel::new(BlackScholesModel);
+/// let premium_in_btc = model.call_price(&params);
+/// assert!(premium_in_btc > 0.0 && premium_in_btc < 1.0);
+/// ```
+pub struct InverseOptionModel<M> {
+    /// The dollar-settled model pricing the equivalent linear option.
+    pub model: M,
+}
+
+impl<M: OptionPricingModel> InverseOptionModel<M> {
+    /// Creates a new `InverseOptionModel` around a dollar-settled model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model pricing the equivalent dollar-settled option.
+    pub fn new(model: M) -> Self {
+        Self { model }
+    }
+
+    /// Calculates the premium-adjusted delta in coins, as quoted by coin-settled venues.
+    ///
+    /// It is the dollar delta less the premium paid in coins, `Δ - V_c`, i.e. the coin
+    /// amount to sell to hedge a long option whose premium is held in the same coin.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option.
+    /// * `is_call` - `true` for a call, `false` for a put.
+    pub fn premium_adjusted_delta(&self, params: &OptionParameters, is_call: bool) -> f64 {
+        let (call, put) = self.model.call_put_price(params);
+        if is_call {
+            self.model.delta(params) - call / params.s
+        } else {
+            self.model.put_delta(params) - put / params.s
+        }
+    }
+}
+
+/// Converts a dollar amount into coins at the given spot.
+///
+/// # Arguments
+///
+/// * `usd` - The dollar amount.
+/// * `spot` - The coin price in dollars.
+pub fn usd_to_coin(usd: f64, spot: f64) -> f64 {
+    usd / spot
+}
+
+/// Calculates the coin payoff of an inverse option at expiry.
+///
+/// # Arguments
+///
+/// * `spot` - The settlement price.
+/// * `strike` - The strike price.
+/// * `is_call` - `true` for a call, `false` for a put.
+pub fn inverse_payoff(spot: f64, strike: f64, is_call: bool) -> f64 {
+    let usd = if is_call {
+        (spot - strike).max(0.0)
+    } else {
+        (strike - spot).max(0.0)
+    };
+    usd_to_coin(usd, spot)
+}
+
+impl<M: OptionPricingModel> OptionPricingModel for InverseOptionModel<M> {
+    /// Calculates the call premium in coins.
+    fn call_price(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.call_price(params), params.s)
+    }
+
+    /// Calculates the put premium in coins.
+    fn put_price(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.put_price(params), params.s)
+    }
+
+    /// Calculates both premiums in coins with a single call into the wrapped model.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        let (call, put) = self.model.call_put_price(params);
+        (usd_to_coin(call, params.s), usd_to_coin(put, params.s))
+    }
+
+    /// Calculates the derivative of the coin call premium with respect to the spot.
+    fn delta(&self, params: &OptionParameters) -> f64 {
+        let s = params.s;
+        self.model.delta(params) / s - self.model.call_price(params) / (s * s)
+    }
+
+    /// Calculates the second derivative of the coin call premium with respect to the spot.
+    fn gamma(&self, params: &OptionParameters) -> f64 {
+        let s = params.s;
+        self.model.gamma(params) / s - 2.0 * self.model.delta(params) / (s * s)
+            + 2.0 * self.model.call_price(params) / (s * s * s)
+    }
+
+    /// Calculates the call vega in coins.
+    fn vega(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.vega(params), params.s)
+    }
+
+    /// Calculates the call theta in coins.
+    fn theta(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.theta(params), params.s)
+    }
+
+    /// Calculates the call rho in coins.
+    fn rho(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.rho(params), params.s)
+    }
+
+    /// Calculates the derivative of the coin put premium with respect to the spot.
+    fn put_delta(&self, params: &OptionParameters) -> f64 {
+        let s = params.s;
+        self.model.put_delta(params) / s - self.model.put_price(params) / (s * s)
+    }
+
+    /// Calculates the second derivative of the coin put premium with respect to the spot.
+    fn put_gamma(&self, params: &OptionParameters) -> f64 {
+        let s = params.s;
+        self.model.put_gamma(params) / s - 2.0 * self.model.put_delta(params) / (s * s)
+            + 2.0 * self.model.put_price(params) / (s * s * s)
+    }
+
+    /// Calculates the put vega in coins.
+    fn put_vega(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.put_vega(params), params.s)
+    }
+
+    /// Calculates the put theta in coins.
+    fn put_theta(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.put_theta(params), params.s)
+    }
+
+    /// Calculates the put rho in coins.
+    fn put_rho(&self, params: &OptionParameters) -> f64 {
+        usd_to_coin(self.model.put_rho(params), params.s)
+    }
+}
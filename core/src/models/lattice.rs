@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+
+/// Working storage shared by the lattice models.
+///
+/// Pricing a tree needs a handful of `steps + 1` sized buffers. Allocating them on every
+/// call dominates the cost of small trees, and the Greeks reprice the tree several times,
+/// so the buffers are kept in a thread-local arena and reused across calls.
+#[derive(Default)]
+pub(crate) struct LatticeScratch {
+    /// Call values at each node of the current time slice.
+    pub calls: Vec<f64>,
+    /// Put values at each node of the current time slice.
+    pub puts: Vec<f64>,
+    /// Variance accrued over each calendar step (time-varying lattices only).
+    pub variance: Vec<f64>,
+    /// Per-step discount factors (time-varying lattices only).
+    pub discount: Vec<f64>,
+    /// Per-step risk-neutral probabilities (time-varying lattices only).
+    pub q: Vec<f64>,
+}
+
+impl LatticeScratch {
+    /// Resizes every buffer to `len` entries, reusing the existing allocations.
+    pub fn reset(&mut self, len: usize) {
+        for buffer in [
+            &mut self.calls,
+            &mut self.puts,
+            &mut self.variance,
+            &mut self.discount,
+            &mut self.q,
+        ] {
+            buffer.clear();
+            buffer.resize(len, 0.0);
+        }
+    }
+}
+
+thread_local! {
+    static SCRATCH: RefCell<LatticeScratch> = RefCell::new(LatticeScratch::default());
+}
+
+/// Runs `f` with the calling thread's scratch space.
+///
+/// Falls back to a fresh allocation if the scratch space is already borrowed, which only
+/// happens when a lattice is priced from inside another lattice evaluation.
+pub(crate) fn with_scratch<R>(f: impl FnOnce(&mut LatticeScratch) -> R) -> R {
+    SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+        Ok(mut scratch) => f(&mut scratch),
+        Err(_) => f(&mut LatticeScratch::default()),
+    })
+}
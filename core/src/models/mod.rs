@@ -1,12 +1,26 @@
+pub mod batch;
 pub mod binomial_tree;
 pub mod black_scholes;
+pub mod earnings;
+pub mod employee_stock_option;
 pub mod garch;
+pub mod inverse;
+mod lattice;
 pub mod monte_carlo;
+pub mod perpetual_american;
 
+pub use batch::{price_batch, PricedOption, PricingRequest};
 pub use binomial_tree::BinomialTreeModel;
 pub use black_scholes::BlackScholesModel;
+pub use earnings::EarningsModel;
+pub use employee_stock_option::EmployeeStockOptionModel;
 pub use garch::GarchModel;
+pub use inverse::InverseOptionModel;
 pub use monte_carlo::MonteCarloModel;
+pub use perpetual_american::PerpetualAmericanModel;
+
+use crate::math::roots::{brent, RootError};
+use serde::{Deserialize, Serialize};
 
 /// Parameters for option pricing models
 ///
@@ -17,7 +31,7 @@ pub use monte_carlo::MonteCarloModel;
 /// * `r` - The risk-free interest rate (annualized).
 /// * `sigma` - The volatility of the stock (annualized).
 /// * `t` - The time to maturity in years.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OptionParameters {
     pub s: f64,
     pub k: f64,
@@ -26,6 +40,66 @@ pub struct OptionParameters {
     pub t: f64,
 }
 
+/// The right conferred by an option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
+/// The Greeks of one option (or the net Greeks of a position), in the units of the model
+/// that produced them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+}
+
+impl Greeks {
+    /// Returns the Greeks multiplied by `factor` (e.g. a signed quantity).
+    pub fn scale(&self, factor: f64) -> Self {
+        Self {
+            delta: self.delta * factor,
+            gamma: self.gamma * factor,
+            vega: self.vega * factor,
+            theta: self.theta * factor,
+            rho: self.rho * factor,
+        }
+    }
+}
+
+impl std::ops::Add for Greeks {
+    type Output = Greeks;
+
+    fn add(self, other: Greeks) -> Greeks {
+        Greeks {
+            delta: self.delta + other.delta,
+            gamma: self.gamma + other.gamma,
+            vega: self.vega + other.vega,
+            theta: self.theta + other.theta,
+            rho: self.rho + other.rho,
+        }
+    }
+}
+
+impl std::ops::Sub for Greeks {
+    type Output = Greeks;
+
+    fn sub(self, other: Greeks) -> Greeks {
+        self + other.scale(-1.0)
+    }
+}
+
+impl std::iter::Sum for Greeks {
+    fn sum<I: Iterator<Item = Greeks>>(iter: I) -> Greeks {
+        iter.fold(Greeks::default(), |acc, greeks| acc + greeks)
+    }
+}
+
 /// A trait for option pricing models.
 ///
 /// This trait defines the methods required for calculating option prices and the Greeks
@@ -37,6 +111,14 @@ pub trait OptionPricingModel {
     /// Calculates the price of a European put option.
     fn put_price(&self, params: &OptionParameters) -> f64;
 
+    /// Calculates the call and put prices together, returned as `(call, put)`.
+    ///
+    /// Models that can price both sides from a single evaluation (e.g. one lattice
+    /// traversal) should override this; the default prices each side separately.
+    fn call_put_price(&self, params: &OptionParameters) -> (f64, f64) {
+        (self.call_price(params), self.put_price(params))
+    }
+
     /// Calculates the Delta of the option.
     fn delta(&self, params: &OptionParameters) -> f64;
 
@@ -51,4 +133,147 @@ pub trait OptionPricingModel {
 
     /// Calculates the Rho of the option.
     fn rho(&self, params: &OptionParameters) -> f64;
+
+    /// Calculates the Delta of the put. The default uses European put-call parity.
+    fn put_delta(&self, params: &OptionParameters) -> f64 {
+        self.delta(params) - 1.0
+    }
+
+    /// Calculates the Gamma of the put. The default uses European put-call parity.
+    fn put_gamma(&self, params: &OptionParameters) -> f64 {
+        self.gamma(params)
+    }
+
+    /// Calculates the Vega of the put. The default uses European put-call parity.
+    fn put_vega(&self, params: &OptionParameters) -> f64 {
+        self.vega(params)
+    }
+
+    /// Calculates the Theta of the put, per year. The default revalues the put one day
+    /// closer to expiry; models quoting theta in other units should override it.
+    fn put_theta(&self, params: &OptionParameters) -> f64 {
+        let day = 1.0 / 365.0;
+        let later = OptionParameters {
+            t: params.t - day,
+            ..params.clone()
+        };
+        (self.put_price(&later) - self.put_price(params)) / day
+    }
+
+    /// Calculates the Rho of the put, per unit change in the rate. The default uses
+    /// central differences; models quoting rho in other units should override it.
+    fn put_rho(&self, params: &OptionParameters) -> f64 {
+        let h = 1e-4;
+        let up = OptionParameters {
+            r: params.r + h,
+            ..params.clone()
+        };
+        let down = OptionParameters {
+            r: params.r - h,
+            ..params.clone()
+        };
+        (self.put_price(&up) - self.put_price(&down)) / (2.0 * h)
+    }
+
+    /// Calculates the price of a call or a put.
+    fn option_price(&self, params: &OptionParameters, option_type: OptionType) -> f64 {
+        match option_type {
+            OptionType::Call => self.call_price(params),
+            OptionType::Put => self.put_price(params),
+        }
+    }
+
+    /// Calculates the Delta of a call or a put.
+    fn option_delta(&self, params: &OptionParameters, option_type: OptionType) -> f64 {
+        match option_type {
+            OptionType::Call => self.delta(params),
+            OptionType::Put => self.put_delta(params),
+        }
+    }
+
+    /// Finds the strike whose delta has the magnitude `target`, e.g. `0.16` for both a
+    /// 16-delta call and a 16-delta put; `params.k` is ignored.
+    ///
+    /// The default searches with Brent's method over strikes within eight standard
+    /// deviations of the forward, relying on |Δ| being monotonic in the strike.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The spot, rate, volatility and expiry of the option.
+    /// * `option_type` - Call or put.
+    /// * `target` - The absolute delta sought, between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strike, or an error if no strike in the search range matches.
+    fn strike_for_delta(
+        &self,
+        params: &OptionParameters,
+        option_type: OptionType,
+        target: f64,
+    ) -> Result<f64, RootError> {
+        let forward = params.s * (params.r * params.t).exp();
+        let width = 8.0 * params.sigma * params.t.sqrt();
+        let (low, high) = (forward * (-width).exp(), forward * width.exp());
+        let miss = |k: f64| {
+            let at = OptionParameters {
+                k,
+                ..params.clone()
+            };
+            self.option_delta(&at, option_type).abs() - target.abs()
+        };
+        brent(miss, low, high, 1e-10 * params.s, 200)
+    }
+
+    /// Finds the volatility at which the model prices the option at `price`;
+    /// `params.sigma` is ignored.
+    ///
+    /// The default searches with Brent's method over volatilities from 0.01% to 500%,
+    /// relying on the price being increasing in volatility.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The spot, strike, rate and expiry of the option.
+    /// * `option_type` - Call or put.
+    /// * `price` - The observed option price.
+    ///
+    /// # Returns
+    ///
+    /// Returns the implied volatility, or an error if no volatility in the search range
+    /// reproduces the price (e.g. a price below intrinsic value).
+    fn implied_volatility(
+        &self,
+        params: &OptionParameters,
+        option_type: OptionType,
+        price: f64,
+    ) -> Result<f64, RootError> {
+        let miss = |sigma: f64| {
+            let at = OptionParameters {
+                sigma,
+                ..params.clone()
+            };
+            self.option_price(&at, option_type) - price
+        };
+        brent(miss, 1e-4, 5.0, 1e-12, 200)
+    }
+
+    /// Calculates all the Greeks of a call or a put.
+    fn option_greeks(&self, params: &OptionParameters, option_type: OptionType) -> Greeks {
+        match option_type {
+            OptionType::Call => Greeks {
+                delta: self.delta(params),
+                gamma: self.gamma(params),
+                vega: self.vega(params),
+                theta: self.theta(params),
+                rho: self.rho(params),
+            },
+            OptionType::Put => Greeks {
+                delta: self.put_delta(params),
+                gamma: self.put_gamma(params),
+                vega: self.put_vega(params),
+                theta: self.put_theta(params),
+                rho: self.put_rho(params),
+            },
+        }
+    }
 }
@@ -1,29 +1,67 @@
 extern crate rand;
+use crate::math::random::{RngSource, ThreadRngSource};
 use crate::models::{OptionParameters, OptionPricingModel};
 use rand::Rng;
 use rand_distr::StandardNormal;
 
 /// A Monte Carlo simulation model for pricing European call and put options.
-pub struct MonteCarloModel {
+///
+/// The random numbers come from `rng`, which defaults to the thread-local generator. Supply a
+/// seeded source to make prices reproducible; every pricing run then draws from the same
+/// stream, so bump-and-revalue Greeks use common random numbers.
+///
+/// # Example
+///
+/// ```
+/// use core::math::random::SeededSource;
+/// use core::models::MonteCarloModel;
+/// use rand::rngs::StdRng;
+/// let model = MonteCarloModel::new(100_000, 0.01).with_rng(SeededSource::<StdRng>::new(7));
+/// ```
+pub struct MonteCarloModel<S = ThreadRngSource> {
     /// The number of simulations to run for the Monte Carlo method.
     pub simulations: usize,
 
     /// The epsilon value used for finite difference calculations in Greeks.
     pub epsilon: f64,
+
+    /// The source of random number generators for the simulated paths.
+    pub rng: S,
 }
 
-impl OptionPricingModel for MonteCarloModel {
-    /// Calculates the price of a European call option using Monte Carlo simulation.
+impl MonteCarloModel {
+    /// Creates a model drawing from the thread-local generator.
     ///
     /// # Arguments
     ///
-    /// * `params` - The parameters for the option.
+    /// * `simulations` - The number of simulated paths per price.
+    /// * `epsilon` - The bump size for finite-difference Greeks.
+    pub fn new(simulations: usize, epsilon: f64) -> Self {
+        Self {
+            simulations,
+            epsilon,
+            rng: ThreadRngSource,
+        }
+    }
+}
+
+impl<S: RngSource> MonteCarloModel<S> {
+    /// Replaces the random number source.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Returns the estimated price of the European call option.
-    fn call_price(&self, params: &OptionParameters) -> f64 {
-        let mut rng = rand::thread_rng();
+    /// * `rng` - The new source, e.g. a `SeededSource` for reproducible prices.
+    pub fn with_rng<T: RngSource>(self, rng: T) -> MonteCarloModel<T> {
+        MonteCarloModel {
+            simulations: self.simulations,
+            epsilon: self.epsilon,
+            rng,
+        }
+    }
+
+    /// Averages the discounted payoff over simulated terminal prices.
+    fn simulate(&self, params: &OptionParameters, payoff: impl Fn(f64) -> f64) -> f64 {
+        let mut rng = self.rng.stream(0);
         let mut payoff_sum = 0.0;
 
         for _ in 0..self.simulations {
@@ -32,11 +70,26 @@ impl OptionPricingModel for MonteCarloModel {
                 * ((params.r - 0.5 * params.sigma.powi(2)) * params.t
                     + params.sigma * params.t.sqrt() * z)
                     .exp();
-            payoff_sum += (st - params.k).max(0.0);
+            payoff_sum += payoff(st);
         }
 
         (payoff_sum / self.simulations as f64) * (-params.r * params.t).exp()
     }
+}
+
+impl<S: RngSource> OptionPricingModel for MonteCarloModel<S> {
+    /// Calculates the price of a European call option using Monte Carlo simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option.
+    ///
+    /// # Returns
+    ///
+    /// Returns the estimated price of the European call option.
+    fn call_price(&self, params: &OptionParameters) -> f64 {
+        self.simulate(params, |st| (st - params.k).max(0.0))
+    }
 
     /// Calculates the price of a European put option using Monte Carlo simulation.
     ///
@@ -48,19 +101,7 @@ impl OptionPricingModel for MonteCarloModel {
     ///
     /// Returns the estimated price of the European put option.
     fn put_price(&self, params: &OptionParameters) -> f64 {
-        let mut rng = rand::thread_rng();
-        let mut payoff_sum = 0.0;
-
-        for _ in 0..self.simulations {
-            let z: f64 = rng.sample(StandardNormal);
-            let st = params.s
-                * ((params.r - 0.5 * params.sigma.powi(2)) * params.t
-                    + params.sigma * params.t.sqrt() * z)
-                    .exp();
-            payoff_sum += (params.k - st).max(0.0);
-        }
-
-        (payoff_sum / self.simulations as f64) * (-params.r * params.t).exp()
+        self.simulate(params, |st| (params.k - st).max(0.0))
     }
 
     /// Calculates the Delta of the option using Monte Carlo simulation.
@@ -153,4 +194,3 @@ impl OptionPricingModel for MonteCarloModel {
         (price_up - price_down) / (2.0 * self.epsilon)
     }
 }
-
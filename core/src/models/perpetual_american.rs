@@ -0,0 +1,205 @@
+use crate::models::{OptionParameters, OptionPricingModel};
+
+/// Closed-form pricer for perpetual American options (Merton, 1973).
+///
+/// A perpetual option never expires, so `params.t` is ignored and theta is zero. The value
+/// depends on the continuous dividend yield: without one an American call is never
+/// exercised early and its perpetual value is the stock price itself. Typical uses are
+/// real options such as the option to invest in or abandon a project.
+///
+/// With `b = r - q` and `h = 1/2 - b/σ² ± sqrt((b/σ² - 1/2)² + 2r/σ²)`, the call (`h₁ > 1`)
+/// is exercised once the stock reaches `H₁ = K h₁ / (h₁ - 1)` and is worth
+/// `K / (h₁ - 1) · ((h₁ - 1) / h₁ · S / K)^h₁` below it; the put (`h₂ < 0`) is exercised at
+/// `H₂ = K h₂ / (h₂ - 1)`.
+/// ref:<https://doi.org/10.2307/3003143>
+pub struct PerpetualAmericanModel {
+    /// Continuous dividend yield (or convenience yield / opportunity cost for real options).
+    pub dividend_yield: f64,
+    /// Epsilon value for numerical differentiation of vega and rho.
+    pub epsilon: f64,
+}
+
+impl PerpetualAmericanModel {
+    /// Creates a new `PerpetualAmericanModel` with the given dividend yield.
+    ///
+    /// # Arguments
+    ///
+    /// * `dividend_yield` - The continuous dividend yield.
+    pub fn new(dividend_yield: f64) -> Self {
+        Self {
+            dividend_yield,
+            epsilon: 1e-5,
+        }
+    }
+
+    /// Returns the roots `(h₁, h₂)` of the perpetual option ODE's characteristic equation.
+    fn exponents(&self, params: &OptionParameters) -> (f64, f64) {
+        let variance = params.sigma * params.sigma;
+        let a = (params.r - self.dividend_yield) / variance - 0.5;
+        let root = (a * a + 2.0 * params.r / variance).sqrt();
+        (-a + root, -a - root)
+    }
+
+    /// Calculates the stock price at which the call should be exercised.
+    ///
+    /// # Returns
+    ///
+    /// Returns the exercise boundary, or infinity if early exercise is never optimal.
+    pub fn call_boundary(&self, params: &OptionParameters) -> f64 {
+        let (h1, _) = self.exponents(params);
+        if h1 <= 1.0 {
+            f64::INFINITY
+        } else {
+            params.k * h1 / (h1 - 1.0)
+        }
+    }
+
+    /// Calculates the stock price at which the put should be exercised.
+    pub fn put_boundary(&self, params: &OptionParameters) -> f64 {
+        let (_, h2) = self.exponents(params);
+        params.k * h2 / (h2 - 1.0)
+    }
+}
+
+impl Default for PerpetualAmericanModel {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl OptionPricingModel for PerpetualAmericanModel {
+    /// Calculates the perpetual American call price.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option; `t` is ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the call price.
+    fn call_price(&self, params: &OptionParameters) -> f64 {
+        let (h1, _) = self.exponents(params);
+        if h1 <= 1.0 {
+            return params.s;
+        }
+        if params.s >= self.call_boundary(params) {
+            return params.s - params.k;
+        }
+        params.k / (h1 - 1.0) * ((h1 - 1.0) / h1 * params.s / params.k).powf(h1)
+    }
+
+    /// Calculates the perpetual American put price.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The parameters for the option; `t` is ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the put price.
+    fn put_price(&self, params: &OptionParameters) -> f64 {
+        let (_, h2) = self.exponents(params);
+        if params.s <= self.put_boundary(params) {
+            return params.k - params.s;
+        }
+        params.k / (1.0 - h2) * ((h2 - 1.0) / h2 * params.s / params.k).powf(h2)
+    }
+
+    /// Calculates the call delta analytically.
+    fn delta(&self, params: &OptionParameters) -> f64 {
+        let (h1, _) = self.exponents(params);
+        if h1 <= 1.0 || params.s >= self.call_boundary(params) {
+            return 1.0;
+        }
+        h1 * self.call_price(params) / params.s
+    }
+
+    /// Calculates the call gamma analytically.
+    fn gamma(&self, params: &OptionParameters) -> f64 {
+        let (h1, _) = self.exponents(params);
+        if h1 <= 1.0 || params.s >= self.call_boundary(params) {
+            return 0.0;
+        }
+        h1 * (h1 - 1.0) * self.call_price(params) / (params.s * params.s)
+    }
+
+    /// Calculates the call vega by central differences.
+    fn vega(&self, params: &OptionParameters) -> f64 {
+        let up = self.call_price(&OptionParameters {
+            sigma: params.sigma + self.epsilon,
+            ..params.clone()
+        });
+        let down = self.call_price(&OptionParameters {
+            sigma: params.sigma - self.epsilon,
+            ..params.clone()
+        });
+        (up - down) / (2.0 * self.epsilon)
+    }
+
+    /// A perpetual option does not decay: theta is always zero.
+    fn theta(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+
+    /// Calculates the call rho by central differences.
+    fn rho(&self, params: &OptionParameters) -> f64 {
+        let up = self.call_price(&OptionParameters {
+            r: params.r + self.epsilon,
+            ..params.clone()
+        });
+        let down = self.call_price(&OptionParameters {
+            r: params.r - self.epsilon,
+            ..params.clone()
+        });
+        (up - down) / (2.0 * self.epsilon)
+    }
+
+    /// Calculates the put delta analytically.
+    fn put_delta(&self, params: &OptionParameters) -> f64 {
+        let (_, h2) = self.exponents(params);
+        if params.s <= self.put_boundary(params) {
+            return -1.0;
+        }
+        h2 * self.put_price(params) / params.s
+    }
+
+    /// Calculates the put gamma analytically.
+    fn put_gamma(&self, params: &OptionParameters) -> f64 {
+        let (_, h2) = self.exponents(params);
+        if params.s <= self.put_boundary(params) {
+            return 0.0;
+        }
+        h2 * (h2 - 1.0) * self.put_price(params) / (params.s * params.s)
+    }
+
+    /// Calculates the put vega by central differences.
+    fn put_vega(&self, params: &OptionParameters) -> f64 {
+        let up = self.put_price(&OptionParameters {
+            sigma: params.sigma + self.epsilon,
+            ..params.clone()
+        });
+        let down = self.put_price(&OptionParameters {
+            sigma: params.sigma - self.epsilon,
+            ..params.clone()
+        });
+        (up - down) / (2.0 * self.epsilon)
+    }
+
+    /// A perpetual option does not decay: theta is always zero.
+    fn put_theta(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+
+    /// Calculates the put rho by central differences.
+    fn put_rho(&self, params: &OptionParameters) -> f64 {
+        let up = self.put_price(&OptionParameters {
+            r: params.r + self.epsilon,
+            ..params.clone()
+        });
+        let down = self.put_price(&OptionParameters {
+            r: params.r - self.epsilon,
+            ..params.clone()
+        });
+        (up - down) / (2.0 * self.epsilon)
+    }
+}
@@ -0,0 +1,139 @@
+use crate::models::{OptionParameters, OptionPricingModel, OptionType};
+use crate::portfolio::{Instrument, Portfolio, Position};
+use serde::{Deserialize, Serialize};
+
+/// The move in the market between two valuations.
+///
+/// All positions are assumed to share one underlying.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketChange {
+    /// The underlying price at the end of the period.
+    pub spot: f64,
+    /// The change in every option's volatility, e.g. `0.01` for one vol point.
+    pub volatility: f64,
+    /// The change in the risk-free rate, e.g. `0.0025` for 25 basis points.
+    pub rate: f64,
+    /// The calendar days elapsed.
+    pub days: f64,
+}
+
+/// A P&L explained by Greeks, with whatever they miss left in `residual` (ε).
+///
+/// \[
+/// \Delta\,dS + \tfrac{1}{2}\Gamma\,dS^2 + \mathcal{V}\,d\sigma + \Theta\,dt + \rho\,dr + \epsilon
+/// = \text{total}
+/// \]
+///
+/// Terms use the crate's Greek conventions: Theta per calendar day, Vega per unit of
+/// volatility and Rho per 1% move in the rate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PnlExplain {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+    /// The part of the P&L the Greeks do not explain: higher-order and cross terms.
+    pub residual: f64,
+    /// The actual change in value from full revaluation.
+    pub total: f64,
+}
+
+impl std::ops::Add for PnlExplain {
+    type Output = PnlExplain;
+
+    fn add(self, other: PnlExplain) -> PnlExplain {
+        PnlExplain {
+            delta: self.delta + other.delta,
+            gamma: self.gamma + other.gamma,
+            vega: self.vega + other.vega,
+            theta: self.theta + other.theta,
+            rho: self.rho + other.rho,
+            residual: self.residual + other.residual,
+            total: self.total + other.total,
+        }
+    }
+}
+
+/// Revalues one option after `change`, settling it at intrinsic value if it expired.
+fn reprice<T: OptionPricingModel + ?Sized>(
+    model: &T,
+    option_type: OptionType,
+    params: &OptionParameters,
+    change: &MarketChange,
+) -> f64 {
+    let t = params.t - change.days / 365.0;
+    if t <= 0.0 {
+        return match option_type {
+            OptionType::Call => (change.spot - params.k).max(0.0),
+            OptionType::Put => (params.k - change.spot).max(0.0),
+        };
+    }
+    let moved = OptionParameters {
+        s: change.spot,
+        r: params.r + change.rate,
+        sigma: params.sigma + change.volatility,
+        t,
+        ..params.clone()
+    };
+    model.option_price(&moved, option_type)
+}
+
+impl Position {
+    /// Explains the change in the position's value over `change` with its starting
+    /// Greeks.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used for the Greeks and both valuations.
+    /// * `change` - The market move.
+    pub fn explain_pnl<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        change: &MarketChange,
+    ) -> PnlExplain {
+        let start_spot = match &self.instrument {
+            Instrument::Option { params, .. } => params.s,
+            Instrument::Stock { spot } => *spot,
+        };
+        let end_price = match &self.instrument {
+            Instrument::Option {
+                option_type,
+                params,
+            } => reprice(model, *option_type, params, change),
+            Instrument::Stock { .. } => change.spot,
+        };
+        let total = self.signed_quantity() * end_price - self.value(model);
+
+        let greeks = self.greeks(model);
+        let ds = change.spot - start_spot;
+        let delta = greeks.delta * ds;
+        let gamma = 0.5 * greeks.gamma * ds * ds;
+        let vega = greeks.vega * change.volatility;
+        let theta = greeks.theta * change.days;
+        let rho = greeks.rho * change.rate * 100.0;
+        PnlExplain {
+            delta,
+            gamma,
+            vega,
+            theta,
+            rho,
+            residual: total - (delta + gamma + vega + theta + rho),
+            total,
+        }
+    }
+}
+
+impl Portfolio {
+    /// Explains the change in the portfolio's value over `change`; the sum of
+    /// `Position::explain_pnl` over every position.
+    pub fn explain_pnl<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        change: &MarketChange,
+    ) -> PnlExplain {
+        self.positions()
+            .map(|(_, position)| position.explain_pnl(model, change))
+            .fold(PnlExplain::default(), |acc, explain| acc + explain)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::models::{Greeks, OptionPricingModel};
+use crate::portfolio::{Instrument, Portfolio};
+use serde::Serialize;
+
+/// One rung of a risk ladder: the options whose bucketed quantity falls in
+/// `[lower, upper)`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LadderBucket {
+    /// The inclusive lower edge.
+    pub lower: f64,
+    /// The exclusive upper edge, or `None` for the open-ended last bucket.
+    pub upper: Option<f64>,
+    /// The net Greeks of the options in the bucket.
+    pub greeks: Greeks,
+    /// The number of option positions in the bucket.
+    pub positions: usize,
+}
+
+/// Portfolio Greeks bucketed by expiry and by moneyness.
+///
+/// Moneyness is strike over spot, so a band of `[0.95, 1.05)` holds the options struck
+/// within 5% of the underlying. Stock positions have neither an expiry nor a strike and
+/// are reported separately.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct GreeksLadder {
+    /// Buckets by time to expiry in years.
+    pub by_expiry: Vec<LadderBucket>,
+    /// Buckets by strike over spot.
+    pub by_moneyness: Vec<LadderBucket>,
+    /// The net Greeks of each expiry and moneyness pair, indexed `[expiry][moneyness]`.
+    pub grid: Vec<Vec<Greeks>>,
+    /// The Greeks of the stock positions.
+    pub stock: Greeks,
+    /// The net Greeks of the whole portfolio.
+    pub total: Greeks,
+}
+
+/// Returns empty buckets for `edges`: one below the first edge, one between each pair,
+/// and one from the last edge up.
+fn buckets(edges: &[f64]) -> Vec<LadderBucket> {
+    let lowers = std::iter::once(0.0).chain(edges.iter().copied());
+    let uppers = edges.iter().copied().map(Some).chain(std::iter::once(None));
+    lowers
+        .zip(uppers)
+        .map(|(lower, upper)| LadderBucket {
+            lower,
+            upper,
+            greeks: Greeks::default(),
+            positions: 0,
+        })
+        .collect()
+}
+
+/// Returns the index of the bucket containing `x`.
+fn bucket_index(edges: &[f64], x: f64) -> usize {
+    edges.partition_point(|&edge| edge <= x)
+}
+
+impl Portfolio {
+    /// Buckets the portfolio Greeks into a risk ladder.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to calculate the Greeks.
+    /// * `expiry_edges` - Increasing bucket edges in years, e.g. `[0.25, 0.5, 1.0]`.
+    /// * `moneyness_edges` - Increasing bucket edges of strike over spot, e.g.
+    ///   `[0.9, 0.95, 1.05, 1.1]`.
+    pub fn greeks_ladder<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        expiry_edges: &[f64],
+        moneyness_edges: &[f64],
+    ) -> GreeksLadder {
+        let mut by_expiry = buckets(expiry_edges);
+        let mut by_moneyness = buckets(moneyness_edges);
+        let mut grid = vec![vec![Greeks::default(); by_moneyness.len()]; by_expiry.len()];
+        let mut stock = Greeks::default();
+
+        for (_, position) in self.positions() {
+            let greeks = position.greeks(model);
+            match &position.instrument {
+                Instrument::Option { params, .. } => {
+                    let i = bucket_index(expiry_edges, params.t);
+                    let j = bucket_index(moneyness_edges, params.k / params.s);
+                    by_expiry[i].greeks = by_expiry[i].greeks + greeks;
+                    by_expiry[i].positions += 1;
+                    by_moneyness[j].greeks = by_moneyness[j].greeks + greeks;
+                    by_moneyness[j].positions += 1;
+                    grid[i][j] = grid[i][j] + greeks;
+                }
+                Instrument::Stock { .. } => stock = stock + greeks,
+            }
+        }
+
+        let options: Greeks = by_expiry.iter().map(|bucket| bucket.greeks).sum();
+        GreeksLadder {
+            by_expiry,
+            by_moneyness,
+            grid,
+            stock,
+            total: options + stock,
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use crate::models::{OptionPricingModel, OptionType};
+use crate::portfolio::{Instrument, Portfolio, Position};
+use crate::strategies::Side;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// What a ledger matches closes against: the parts of a holding that do not move with
+/// the market.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Contract {
+    /// An option on `underlying` with a given type, strike and expiry date.
+    Option {
+        underlying: Option<String>,
+        option_type: OptionType,
+        strike: f64,
+        expiry: NaiveDate,
+    },
+    /// Shares of `underlying`.
+    Stock { underlying: Option<String> },
+}
+
+impl Contract {
+    /// Returns the contract `position` holds.
+    ///
+    /// # Returns
+    ///
+    /// Returns an error for an option position without an expiry date; see
+    /// `Position::with_expiry`.
+    pub fn of(position: &Position) -> Result<Self, LedgerError> {
+        let underlying = position.underlying.clone();
+        match &position.instrument {
+            Instrument::Option {
+                option_type,
+                params,
+            } => Ok(Contract::Option {
+                underlying,
+                option_type: *option_type,
+                strike: params.k,
+                expiry: position.expiry.ok_or(LedgerError::MissingExpiry)?,
+            }),
+            Instrument::Stock { .. } => Ok(Contract::Stock { underlying }),
+        }
+    }
+}
+
+/// A trade recorded in a `Ledger`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    /// A new lot opened at its entry price.
+    Open(Position),
+    /// Part or all of the open lots of one contract and side closed at `price`.
+    Close {
+        contract: Contract,
+        side: Side,
+        quantity: f64,
+        price: f64,
+    },
+    /// A close and an open executed together, e.g. moving a short call out in time.
+    Roll {
+        contract: Contract,
+        side: Side,
+        quantity: f64,
+        price: f64,
+        to: Position,
+    },
+}
+
+/// Errors returned when a ledger event cannot be applied.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LedgerError {
+    /// A close asked for more than the open lots hold.
+    InsufficientQuantity { requested: f64, available: f64 },
+    /// A quantity is not a positive finite number.
+    InvalidQuantity { quantity: f64 },
+    /// An option lot has no expiry date to identify its contract by.
+    MissingExpiry,
+}
+
+impl fmt::Display for LedgerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LedgerError::InsufficientQuantity {
+                requested,
+                available,
+            } => write!(
+                f,
+                "cannot close {} when only {} is open",
+                requested, available
+            ),
+            LedgerError::InvalidQuantity { quantity } => {
+                write!(f, "quantity must be positive, got {}", quantity)
+            }
+            LedgerError::MissingExpiry => write!(f, "an option lot needs an expiry date"),
+        }
+    }
+}
+
+impl std::error::Error for LedgerError {}
+
+/// An audited record of trades with FIFO lot matching.
+///
+/// Every open adds a lot; every close consumes the oldest open lots of the same
+/// contract and side first, realizing the difference between the close price and each
+/// lot's entry price. Contracts are identified by their underlying and what does not move
+/// with the market (see `Contract`): options by type, strike and expiry date, so a lot
+/// opened months ago matches a close quoted with today's shorter time to expiry. Option
+/// lots therefore need `Position::expiry`. The events are the source of truth:
+/// `from_events` rebuilds the lots and realized P&L from a persisted event list.
+///
+/// # Example
+///
+/// ```
+/// use core::portfolio::ledger::{Contract, Ledger};
+/// use core::portfolio::Position;
+/// use core::strategies::Side;
+/// let mut ledger = Ledger::new();
+/// ledger.open(Position::stock(Side::Long, 100.0, 50.0, 50.0)).unwrap();
+/// let contract = Contract::Stock { underlying: None };
+/// let realized = ledger.close(contract, Side::Long, 40.0, 55.0).unwrap();
+/// assert_eq!(realized, 200.0);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    events: Vec<Event>,
+    lots: Vec<Position>,
+    realized: f64,
+}
+
+impl Ledger {
+    /// Creates an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuilds a ledger by replaying `events` in order.
+    pub fn from_events(events: Vec<Event>) -> Result<Self, LedgerError> {
+        let mut ledger = Self::new();
+        for event in events {
+            ledger.apply(event)?;
+        }
+        Ok(ledger)
+    }
+
+    /// Applies one event.
+    ///
+    /// # Returns
+    ///
+    /// Returns the P&L realized by the event: zero for an open.
+    pub fn apply(&mut self, event: Event) -> Result<f64, LedgerError> {
+        let realized = match &event {
+            Event::Open(position) => {
+                check_quantity(position.quantity)?;
+                Contract::of(position)?;
+                self.lots.push(position.clone());
+                0.0
+            }
+            Event::Close {
+                contract,
+                side,
+                quantity,
+                price,
+            } => self.match_lots(contract, *side, *quantity, *price)?,
+            Event::Roll {
+                contract,
+                side,
+                quantity,
+                price,
+                to,
+            } => {
+                check_quantity(to.quantity)?;
+                Contract::of(to)?;
+                let realized = self.match_lots(contract, *side, *quantity, *price)?;
+                self.lots.push(to.clone());
+                realized
+            }
+        };
+        self.realized += realized;
+        self.events.push(event);
+        Ok(realized)
+    }
+
+    /// Opens a new lot.
+    pub fn open(&mut self, position: Position) -> Result<(), LedgerError> {
+        self.apply(Event::Open(position)).map(|_| ())
+    }
+
+    /// Closes `quantity` of the open lots of `contract` on `side` at `price`, oldest
+    /// first.
+    ///
+    /// # Returns
+    ///
+    /// Returns the realized P&L, or an error (leaving the ledger unchanged) if less than
+    /// `quantity` is open.
+    pub fn close(
+        &mut self,
+        contract: Contract,
+        side: Side,
+        quantity: f64,
+        price: f64,
+    ) -> Result<f64, LedgerError> {
+        self.apply(Event::Close {
+            contract,
+            side,
+            quantity,
+            price,
+        })
+    }
+
+    /// Closes `quantity` of `contract` at `price` and opens `to` in its place.
+    ///
+    /// # Returns
+    ///
+    /// Returns the P&L realized by the closing half; see `close`.
+    pub fn roll(
+        &mut self,
+        contract: Contract,
+        side: Side,
+        quantity: f64,
+        price: f64,
+        to: Position,
+    ) -> Result<f64, LedgerError> {
+        self.apply(Event::Roll {
+            contract,
+            side,
+            quantity,
+            price,
+            to,
+        })
+    }
+
+    /// Returns the events applied so far.
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Returns the open lots, oldest first.
+    pub fn lots(&self) -> &[Position] {
+        &self.lots
+    }
+
+    /// Returns the P&L realized by closes and rolls.
+    pub fn realized_pnl(&self) -> f64 {
+        self.realized
+    }
+
+    /// Calculates the P&L of the open lots marked with `model`.
+    pub fn unrealized_pnl<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.lots.iter().map(|lot| lot.unrealized_pnl(model)).sum()
+    }
+
+    /// Returns the open lots as a portfolio, for valuation and risk.
+    pub fn portfolio(&self) -> Portfolio {
+        let mut portfolio = Portfolio::new();
+        for lot in &self.lots {
+            portfolio.add(lot.clone());
+        }
+        portfolio
+    }
+
+    /// Consumes open lots FIFO and returns the realized P&L.
+    fn match_lots(
+        &mut self,
+        contract: &Contract,
+        side: Side,
+        quantity: f64,
+        price: f64,
+    ) -> Result<f64, LedgerError> {
+        check_quantity(quantity)?;
+        let matches =
+            |lot: &Position| lot.side == side && Contract::of(lot).as_ref() == Ok(contract);
+        let available: f64 = self
+            .lots
+            .iter()
+            .filter(|lot| matches(lot))
+            .map(|lot| lot.quantity)
+            .sum();
+        if quantity > available * (1.0 + 1e-12) {
+            return Err(LedgerError::InsufficientQuantity {
+                requested: quantity,
+                available,
+            });
+        }
+
+        let mut remaining = quantity;
+        let mut realized = 0.0;
+        for lot in self.lots.iter_mut().filter(|lot| matches(lot)) {
+            if remaining <= 0.0 {
+                break;
+            }
+            let filled = remaining.min(lot.quantity);
+            realized += side.sign() * filled * (price - lot.entry_price);
+            lot.quantity -= filled;
+            remaining -= filled;
+        }
+        self.lots.retain(|lot| lot.quantity > 1e-12);
+        Ok(realized)
+    }
+}
+
+/// Checks that a quantity is positive and finite.
+fn check_quantity(quantity: f64) -> Result<(), LedgerError> {
+    if quantity.is_finite() && quantity > 0.0 {
+        Ok(())
+    } else {
+        Err(LedgerError::InvalidQuantity { quantity })
+    }
+}
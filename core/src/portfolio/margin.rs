@@ -0,0 +1,165 @@
+use crate::models::OptionPricingModel;
+use crate::portfolio::{Portfolio, Position};
+use serde::{Deserialize, Serialize};
+
+/// The market scenarios a margin calculation revalues the portfolio under.
+///
+/// Every spot shock is combined with every volatility shock.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShockGrid {
+    /// Relative moves in the underlying, e.g. `-0.15` for a 15% fall.
+    pub spot_shocks: Vec<f64>,
+    /// Absolute moves in every option's volatility, e.g. `0.05` for five vol points.
+    pub volatility_shocks: Vec<f64>,
+}
+
+impl ShockGrid {
+    /// Returns the standard grid: the underlying from -15% to +15% in 3% steps, with
+    /// volatility down five points, unchanged and up five points.
+    pub fn standard() -> Self {
+        Self {
+            spot_shocks: (-5..=5).map(|i| 0.03 * i as f64).collect(),
+            volatility_shocks: vec![-0.05, 0.0, 0.05],
+        }
+    }
+
+    /// Returns a grid of evenly spaced shocks symmetric around no change.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_range` - The largest relative move in the underlying, e.g. `0.15`.
+    /// * `spot_steps` - The number of spot shocks on each side of zero.
+    /// * `volatility_range` - The largest absolute move in volatility, e.g. `0.05`.
+    /// * `volatility_steps` - The number of volatility shocks on each side of zero.
+    pub fn symmetric(
+        spot_range: f64,
+        spot_steps: usize,
+        volatility_range: f64,
+        volatility_steps: usize,
+    ) -> Self {
+        let shocks = |range: f64, steps: usize| -> Vec<f64> {
+            if steps == 0 {
+                return vec![0.0];
+            }
+            let steps = steps as i64;
+            (-steps..=steps)
+                .map(|i| range * i as f64 / steps as f64)
+                .collect()
+        };
+        Self {
+            spot_shocks: shocks(spot_range, spot_steps),
+            volatility_shocks: shocks(volatility_range, volatility_steps),
+        }
+    }
+}
+
+impl Default for ShockGrid {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// The P&L of a group of positions under one scenario.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    /// The relative move in the underlying.
+    pub spot_shock: f64,
+    /// The absolute move in volatility.
+    pub volatility_shock: f64,
+    /// The change in value from today.
+    pub pnl: f64,
+}
+
+/// The margin requirement of the positions on one underlying.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnderlyingMargin {
+    /// The underlying symbol; `None` groups the untagged positions.
+    pub underlying: Option<String>,
+    /// The requirement: the worst scenario loss, or zero if no scenario loses.
+    pub margin: f64,
+    /// The scenario with the worst P&L.
+    pub worst: Scenario,
+    /// Every scenario, spot shocks outermost.
+    pub scenarios: Vec<Scenario>,
+}
+
+/// A scenario-based margin calculation for a whole portfolio.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarginReport {
+    /// One entry per underlying, in order of first appearance.
+    pub underlyings: Vec<UnderlyingMargin>,
+    /// The sum of the per-underlying requirements; losses on one underlying are not
+    /// offset by gains on another.
+    pub total: f64,
+}
+
+impl Portfolio {
+    /// Calculates a portfolio margin requirement in the style of SPAN and exchange
+    /// portfolio margining.
+    ///
+    /// The positions on each underlying are revalued instantly under every scenario of
+    /// `grid`, and the largest loss becomes that underlying's requirement. Positions are
+    /// grouped by `Position::underlying`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to revalue the options.
+    /// * `grid` - The scenarios, usually `ShockGrid::standard()`.
+    pub fn margin<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        grid: &ShockGrid,
+    ) -> MarginReport {
+        let mut groups: Vec<(Option<String>, Vec<&Position>)> = Vec::new();
+        for (_, position) in self.positions() {
+            match groups
+                .iter_mut()
+                .find(|(underlying, _)| *underlying == position.underlying)
+            {
+                Some((_, members)) => members.push(position),
+                None => groups.push((position.underlying.clone(), vec![position])),
+            }
+        }
+
+        let underlyings: Vec<UnderlyingMargin> = groups
+            .into_iter()
+            .map(|(underlying, members)| {
+                let today: f64 = members.iter().map(|p| p.value(model)).sum();
+                let mut scenarios = Vec::new();
+                for &spot_shock in &grid.spot_shocks {
+                    for &volatility_shock in &grid.volatility_shocks {
+                        let value: f64 = members
+                            .iter()
+                            .map(|p| p.shocked_value(model, spot_shock, volatility_shock))
+                            .sum();
+                        scenarios.push(Scenario {
+                            spot_shock,
+                            volatility_shock,
+                            pnl: value - today,
+                        });
+                    }
+                }
+                let worst = scenarios
+                    .iter()
+                    .copied()
+                    .min_by(|a, b| a.pnl.total_cmp(&b.pnl))
+                    .unwrap_or(Scenario {
+                        spot_shock: 0.0,
+                        volatility_shock: 0.0,
+                        pnl: 0.0,
+                    });
+                UnderlyingMargin {
+                    underlying,
+                    margin: (-worst.pnl).max(0.0),
+                    worst,
+                    scenarios,
+                }
+            })
+            .collect();
+
+        MarginReport {
+            total: underlyings.iter().map(|u| u.margin).sum(),
+            underlyings,
+        }
+    }
+}
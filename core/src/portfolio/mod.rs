@@ -0,0 +1,270 @@
+pub mod attribution;
+pub mod ladder;
+pub mod ledger;
+pub mod margin;
+pub mod scenario;
+pub mod var;
+
+pub use attribution::{MarketChange, PnlExplain};
+pub use ladder::{GreeksLadder, LadderBucket};
+pub use ledger::{Contract, Event, Ledger, LedgerError};
+pub use margin::{MarginReport, ShockGrid};
+pub use scenario::{ScenarioMatrix, ScenarioMeasure};
+pub use var::ValueAtRisk;
+
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::{Side, Strategy};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What a position holds.
+///
+/// Serialized externally tagged, e.g. `{"stock": {"spot": 100.0}}`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Instrument {
+    /// A European option.
+    Option {
+        option_type: OptionType,
+        params: OptionParameters,
+    },
+    /// Shares of the underlying at the current `spot`.
+    Stock { spot: f64 },
+}
+
+/// A holding of one instrument opened at a known price.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    /// The instrument held.
+    pub instrument: Instrument,
+    /// Bought or sold.
+    pub side: Side,
+    /// Number of contracts or shares (positive; the direction comes from `side`).
+    pub quantity: f64,
+    /// The price per contract or share at which the position was opened.
+    pub entry_price: f64,
+    /// The symbol of the underlying, for reports that group positions by underlying.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlying: Option<String>,
+    /// The calendar date an option expires on. Unlike `params.t` it does not shrink as
+    /// time passes, so a `Ledger` identifies option contracts by it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<NaiveDate>,
+}
+
+impl Position {
+    /// Creates an option position.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_type` - Call or put.
+    /// * `side` - Long or short.
+    /// * `quantity` - The number of contracts.
+    /// * `params` - The option parameters.
+    /// * `entry_price` - The premium per contract at entry.
+    pub fn option(
+        option_type: OptionType,
+        side: Side,
+        quantity: f64,
+        params: OptionParameters,
+        entry_price: f64,
+    ) -> Self {
+        Self {
+            instrument: Instrument::Option {
+                option_type,
+                params,
+            },
+            side,
+            quantity,
+            entry_price,
+            underlying: None,
+            expiry: None,
+        }
+    }
+
+    /// Creates a stock position.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Long or short.
+    /// * `quantity` - The number of shares.
+    /// * `spot` - The current price of the underlying.
+    /// * `entry_price` - The price per share at entry.
+    pub fn stock(side: Side, quantity: f64, spot: f64, entry_price: f64) -> Self {
+        Self {
+            instrument: Instrument::Stock { spot },
+            side,
+            quantity,
+            entry_price,
+            underlying: None,
+            expiry: None,
+        }
+    }
+
+    /// Tags the position with the symbol of its underlying.
+    pub fn with_underlying(mut self, underlying: &str) -> Self {
+        self.underlying = Some(underlying.to_string());
+        self
+    }
+
+    /// Records the calendar date the option expires on.
+    pub fn with_expiry(mut self, expiry: NaiveDate) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// Returns the quantity with the sign of the side.
+    pub fn signed_quantity(&self) -> f64 {
+        self.side.sign() * self.quantity
+    }
+
+    /// Calculates the price of one contract or share under `model`.
+    pub fn unit_price<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        match &self.instrument {
+            Instrument::Option {
+                option_type,
+                params,
+            } => model.option_price(params, *option_type),
+            Instrument::Stock { spot } => *spot,
+        }
+    }
+
+    /// Calculates the signed market value of the position under `model`.
+    pub fn value<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.signed_quantity() * self.unit_price(model)
+    }
+
+    /// Calculates the signed Greeks of the position. Each share contributes a Delta of one.
+    pub fn greeks<T: OptionPricingModel + ?Sized>(&self, model: &T) -> Greeks {
+        let unit = match &self.instrument {
+            Instrument::Option {
+                option_type,
+                params,
+            } => model.option_greeks(params, *option_type),
+            Instrument::Stock { .. } => Greeks {
+                delta: 1.0,
+                ..Greeks::default()
+            },
+        };
+        unit.scale(self.signed_quantity())
+    }
+
+    /// Returns the signed cost at entry.
+    pub fn entry_value(&self) -> f64 {
+        self.signed_quantity() * self.entry_price
+    }
+
+    /// Calculates the unrealized P&L: the market value less the entry cost.
+    pub fn unrealized_pnl<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.value(model) - self.entry_value()
+    }
+}
+
+/// A collection of option and stock positions valued together.
+///
+/// Positions are keyed by an id assigned when they are added, so removing one does not
+/// disturb the others. Unlike a `Strategy`, a portfolio does not hold a model: valuation
+/// methods take one, so the same book can be marked under any pricing model.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Portfolio {
+    positions: BTreeMap<usize, Position>,
+    next_id: usize,
+}
+
+impl Portfolio {
+    /// Creates an empty portfolio.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a position and returns its id.
+    pub fn add(&mut self, position: Position) -> usize {
+        let id = self.next_id;
+        self.positions.insert(id, position);
+        self.next_id += 1;
+        id
+    }
+
+    /// Adds every leg of `strategy` as a position and returns the new ids, option legs
+    /// first. Legs without an entry price are entered at today's model price.
+    pub fn add_strategy<T: OptionPricingModel + ?Sized>(
+        &mut self,
+        strategy: &Strategy<'_, T>,
+    ) -> Vec<usize> {
+        let options: Vec<Position> = strategy
+            .legs
+            .iter()
+            .map(|leg| {
+                let entry_price = leg
+                    .entry_price
+                    .unwrap_or_else(|| strategy.model.option_price(&leg.params, leg.option_type));
+                Position::option(
+                    leg.option_type,
+                    leg.side,
+                    leg.quantity,
+                    leg.params.clone(),
+                    entry_price,
+                )
+            })
+            .collect();
+        let stock = strategy.stock.iter().map(|leg| {
+            Position::stock(
+                leg.side,
+                leg.quantity,
+                leg.spot,
+                leg.entry_price.unwrap_or(leg.spot),
+            )
+        });
+        options
+            .into_iter()
+            .chain(stock)
+            .map(|position| self.add(position))
+            .collect()
+    }
+
+    /// Removes a position, returning it if the id was present.
+    pub fn remove(&mut self, id: usize) -> Option<Position> {
+        self.positions.remove(&id)
+    }
+
+    /// Returns the position with the given id.
+    pub fn get(&self, id: usize) -> Option<&Position> {
+        self.positions.get(&id)
+    }
+
+    /// Iterates over the positions and their ids in the order they were added.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, &Position)> {
+        self.positions.iter().map(|(&id, position)| (id, position))
+    }
+
+    /// Returns the number of positions.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns `true` if the portfolio holds no positions.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Calculates the net market value of all positions under `model`.
+    pub fn value<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.positions.values().map(|p| p.value(model)).sum()
+    }
+
+    /// Calculates the net Greeks of all positions under `model`.
+    pub fn greeks<T: OptionPricingModel + ?Sized>(&self, model: &T) -> Greeks {
+        self.positions.values().map(|p| p.greeks(model)).sum()
+    }
+
+    /// Returns the net cost of all positions at entry.
+    pub fn entry_cost(&self) -> f64 {
+        self.positions.values().map(Position::entry_value).sum()
+    }
+
+    /// Calculates the unrealized P&L of all positions under `model`.
+    pub fn unrealized_pnl<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.value(model) - self.entry_cost()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::models::{OptionParameters, OptionPricingModel, OptionType};
+use crate::portfolio::{Instrument, Portfolio, Position, ShockGrid};
+use serde::{Deserialize, Serialize};
+
+/// Floor applied to shocked volatilities so a downward shock never reaches zero.
+const MIN_VOLATILITY: f64 = 1e-4;
+
+/// Returns `params` with the underlying moved by `spot_shock` (relative) and the
+/// volatility by `volatility_shock` (absolute, floored just above zero).
+pub fn shocked(
+    params: &OptionParameters,
+    spot_shock: f64,
+    volatility_shock: f64,
+) -> OptionParameters {
+    OptionParameters {
+        s: params.s * (1.0 + spot_shock),
+        sigma: (params.sigma + volatility_shock).max(MIN_VOLATILITY),
+        ..params.clone()
+    }
+}
+
+impl Position {
+    /// Revalues the position instantly with the underlying moved by `spot_shock`
+    /// (relative) and, for options, the volatility by `volatility_shock` (absolute).
+    pub fn shocked_value<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        spot_shock: f64,
+        volatility_shock: f64,
+    ) -> f64 {
+        let price = match &self.instrument {
+            Instrument::Option {
+                option_type,
+                params,
+            } => model.option_price(&shocked(params, spot_shock, volatility_shock), *option_type),
+            Instrument::Stock { spot } => spot * (1.0 + spot_shock),
+        };
+        self.signed_quantity() * price
+    }
+}
+
+impl Portfolio {
+    /// Revalues every position instantly under one scenario; see
+    /// `Position::shocked_value`. All positions are moved together, whatever their
+    /// underlying.
+    pub fn shocked_value<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        spot_shock: f64,
+        volatility_shock: f64,
+    ) -> f64 {
+        self.positions()
+            .map(|(_, p)| p.shocked_value(model, spot_shock, volatility_shock))
+            .sum()
+    }
+}
+
+/// The quantity a scenario matrix reports for each scenario.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScenarioMeasure {
+    Price,
+    Delta,
+    Gamma,
+    Vega,
+    Theta,
+    Rho,
+}
+
+impl ScenarioMeasure {
+    /// Every measure, in display order.
+    pub const ALL: [ScenarioMeasure; 6] = [
+        ScenarioMeasure::Price,
+        ScenarioMeasure::Delta,
+        ScenarioMeasure::Gamma,
+        ScenarioMeasure::Vega,
+        ScenarioMeasure::Theta,
+        ScenarioMeasure::Rho,
+    ];
+
+    /// Returns the lowercase name of the measure.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScenarioMeasure::Price => "price",
+            ScenarioMeasure::Delta => "delta",
+            ScenarioMeasure::Gamma => "gamma",
+            ScenarioMeasure::Vega => "vega",
+            ScenarioMeasure::Theta => "theta",
+            ScenarioMeasure::Rho => "rho",
+        }
+    }
+
+    fn evaluate<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        params: &OptionParameters,
+        option_type: OptionType,
+    ) -> f64 {
+        let greeks = || model.option_greeks(params, option_type);
+        match self {
+            ScenarioMeasure::Price => model.option_price(params, option_type),
+            ScenarioMeasure::Delta => greeks().delta,
+            ScenarioMeasure::Gamma => greeks().gamma,
+            ScenarioMeasure::Vega => greeks().vega,
+            ScenarioMeasure::Theta => greeks().theta,
+            ScenarioMeasure::Rho => greeks().rho,
+        }
+    }
+}
+
+/// A single option's price or Greek under every spot and volatility shock of a grid.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioMatrix {
+    /// What `values` holds.
+    pub measure: ScenarioMeasure,
+    /// The relative moves in the underlying, one per row.
+    pub spot_shocks: Vec<f64>,
+    /// The absolute moves in volatility, one per column.
+    pub volatility_shocks: Vec<f64>,
+    /// `values[i][j]` is the measure under `spot_shocks[i]` and `volatility_shocks[j]`.
+    pub values: Vec<Vec<f64>>,
+}
+
+impl ScenarioMatrix {
+    /// Revalues an option under every scenario of `grid`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model.
+    /// * `params` - The unshocked option parameters.
+    /// * `option_type` - Call or put.
+    /// * `grid` - The spot and volatility shocks.
+    /// * `measure` - The price or the Greek to report.
+    pub fn new<T: OptionPricingModel + ?Sized>(
+        model: &T,
+        params: &OptionParameters,
+        option_type: OptionType,
+        grid: &ShockGrid,
+        measure: ScenarioMeasure,
+    ) -> Self {
+        let values = grid
+            .spot_shocks
+            .iter()
+            .map(|&spot_shock| {
+                grid.volatility_shocks
+                    .iter()
+                    .map(|&volatility_shock| {
+                        let params = shocked(params, spot_shock, volatility_shock);
+                        measure.evaluate(model, &params, option_type)
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            measure,
+            spot_shocks: grid.spot_shocks.clone(),
+            volatility_shocks: grid.volatility_shocks.clone(),
+            values,
+        }
+    }
+
+    /// Returns the smallest and largest finite values, or `None` if there are none.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| match range {
+                None => Some((value, value)),
+                Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+            })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::math::roots::brent;
+use crate::models::black_scholes::standard_normal_cdf;
+use crate::models::OptionPricingModel;
+use crate::portfolio::{Instrument, Portfolio};
+use serde::{Deserialize, Serialize};
+
+/// A value-at-risk estimate for a whole portfolio.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ValueAtRisk {
+    /// The probability the loss is not exceeded, e.g. `0.99`.
+    pub confidence: f64,
+    /// The holding period in years.
+    pub horizon: f64,
+    /// The annualized volatility of the underlying.
+    pub volatility: f64,
+    /// The relative move in the underlying that produces the loss.
+    pub spot_shock: f64,
+    /// The loss at the quantile move, or zero if neither move loses.
+    pub var: f64,
+}
+
+/// Returns the `p` quantile of the standard normal distribution.
+fn standard_normal_quantile(p: f64) -> f64 {
+    brent(|x| standard_normal_cdf(x) - p, -10.0, 10.0, 1e-12, 200)
+        .expect("the standard normal CDF is bracketed on [-10, 10]")
+}
+
+impl Portfolio {
+    /// Returns the volatility of the first option, a stand-in for the underlying's when
+    /// none is given; `None` if the portfolio holds no options.
+    pub fn option_volatility(&self) -> Option<f64> {
+        self.positions()
+            .find_map(|(_, position)| match &position.instrument {
+                Instrument::Option { params, .. } => Some(params.sigma),
+                Instrument::Stock { .. } => None,
+            })
+    }
+
+    /// Calculates a one-factor value at risk by full revaluation.
+    ///
+    /// The underlying is assumed lognormal with `volatility`, and every position is
+    /// revalued instantly with it moved to the `confidence` quantile, down and up. The
+    /// worse of the two losses is the value at risk. All positions are assumed to share
+    /// the one underlying; option volatilities are left unshocked.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to revalue the options.
+    /// * `volatility` - The annualized volatility of the underlying.
+    /// * `confidence` - The confidence level, strictly between 0.5 and 1.
+    /// * `horizon` - The holding period in years, e.g. `1.0 / 252.0` for one day.
+    pub fn value_at_risk<T: OptionPricingModel + ?Sized>(
+        &self,
+        model: &T,
+        volatility: f64,
+        confidence: f64,
+        horizon: f64,
+    ) -> ValueAtRisk {
+        let today = self.value(model);
+        let move_size = standard_normal_quantile(confidence) * volatility * horizon.sqrt();
+        let (spot_shock, pnl) = [(-move_size).exp_m1(), move_size.exp_m1()]
+            .into_iter()
+            .map(|spot_shock| {
+                (
+                    spot_shock,
+                    self.shocked_value(model, spot_shock, 0.0) - today,
+                )
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("there are two scenarios");
+        ValueAtRisk {
+            confidence,
+            horizon,
+            volatility,
+            spot_shock,
+            var: (-pnl).max(0.0),
+        }
+    }
+}
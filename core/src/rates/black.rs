@@ -0,0 +1,93 @@
+use crate::models::black_scholes::{standard_normal_cdf, standard_normal_pdf};
+
+/// The quoting convention of a rates volatility.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolatilityType {
+    /// Lognormal (Black-76) volatility: rates are lognormal, so forward and strike must be
+    /// positive.
+    Lognormal,
+    /// Normal (Bachelier) volatility in absolute rate units: works with zero and negative
+    /// rates.
+    Normal,
+}
+
+impl VolatilityType {
+    /// Prices an option on a forward under this convention, undiscounted.
+    ///
+    /// # Arguments
+    ///
+    /// * `forward` - The forward rate.
+    /// * `strike` - The strike rate.
+    /// * `vol` - The volatility in this convention.
+    /// * `expiry` - The time to the fixing in years.
+    /// * `is_call` - `true` for a call (caplet, payer swaption), `false` for a put.
+    pub fn price(&self, forward: f64, strike: f64, vol: f64, expiry: f64, is_call: bool) -> f64 {
+        match self {
+            VolatilityType::Lognormal => black(forward, strike, vol, expiry, is_call),
+            VolatilityType::Normal => bachelier(forward, strike, vol, expiry, is_call),
+        }
+    }
+}
+
+/// Payoff of an option on a forward at expiry.
+fn intrinsic(forward: f64, strike: f64, is_call: bool) -> f64 {
+    if is_call {
+        (forward - strike).max(0.0)
+    } else {
+        (strike - forward).max(0.0)
+    }
+}
+
+/// Calculates the undiscounted Black-76 price of an option on a forward.
+///
+/// # Arguments
+///
+/// * `forward` - The forward rate (positive).
+/// * `strike` - The strike rate.
+/// * `vol` - The lognormal volatility.
+/// * `expiry` - The time to the fixing in years.
+/// * `is_call` - `true` for a call, `false` for a put.
+///
+/// # Returns
+///
+/// Returns the price per unit of notional and accrual, before discounting.
+pub fn black(forward: f64, strike: f64, vol: f64, expiry: f64, is_call: bool) -> f64 {
+    let std_dev = vol * expiry.max(0.0).sqrt();
+    if std_dev <= 0.0 || strike <= 0.0 || forward <= 0.0 {
+        return intrinsic(forward, strike, is_call);
+    }
+    let d1 = ((forward / strike).ln() + 0.5 * std_dev * std_dev) / std_dev;
+    let d2 = d1 - std_dev;
+    if is_call {
+        forward * standard_normal_cdf(d1) - strike * standard_normal_cdf(d2)
+    } else {
+        strike * standard_normal_cdf(-d2) - forward * standard_normal_cdf(-d1)
+    }
+}
+
+/// Calculates the undiscounted Bachelier (normal model) price of an option on a forward.
+///
+/// # Arguments
+///
+/// * `forward` - The forward rate.
+/// * `strike` - The strike rate.
+/// * `vol` - The normal volatility, in rate units per square-root year.
+/// * `expiry` - The time to the fixing in years.
+/// * `is_call` - `true` for a call, `false` for a put.
+///
+/// # Returns
+///
+/// Returns the price per unit of notional and accrual, before discounting.
+pub fn bachelier(forward: f64, strike: f64, vol: f64, expiry: f64, is_call: bool) -> f64 {
+    let std_dev = vol * expiry.max(0.0).sqrt();
+    if std_dev <= 0.0 {
+        return intrinsic(forward, strike, is_call);
+    }
+    let moneyness = if is_call {
+        forward - strike
+    } else {
+        strike - forward
+    };
+    let d = moneyness / std_dev;
+    moneyness * standard_normal_cdf(d) + std_dev * standard_normal_pdf(d)
+}
@@ -0,0 +1,77 @@
+use crate::math::interp::{Curve, Extrapolation, InterpError, Kind};
+
+/// A zero-coupon yield curve with continuously compounded zero rates.
+///
+/// Interpolation is linear in `r(t)·t` (log discount factors), which gives piecewise-flat
+/// instantaneous forwards between the pillars and a flat forward beyond the last one.
+///
+/// # Example
+///
+/// ```
+/// use core::rates::YieldCurve;
+/// let curve = YieldCurve::new(vec![0.5, 1.0, 2.0, 5.0], vec![0.040, 0.042, 0.045, 0.047])
+///     .unwrap();
+/// let df = curve.discount_factor(3.0);
+/// assert!(df > 0.86 && df < 0.88);
+/// ```
+#[derive(Clone, Debug)]
+pub struct YieldCurve {
+    /// Interpolates `r(t)·t`, with a knot at `t = 0` so short maturities are well defined.
+    log_discount: Curve,
+}
+
+impl YieldCurve {
+    /// Builds a curve from pillar maturities and zero rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `times` - Strictly increasing positive maturities in years.
+    /// * `zero_rates` - Continuously compounded zero rates, one per maturity.
+    pub fn new(times: Vec<f64>, zero_rates: Vec<f64>) -> Result<Self, InterpError> {
+        if times.len() != zero_rates.len() {
+            return Err(InterpError::LengthMismatch {
+                xs: times.len(),
+                ys: zero_rates.len(),
+            });
+        }
+        let mut xs = vec![0.0];
+        let mut ys = vec![0.0];
+        xs.extend(&times);
+        ys.extend(times.iter().zip(&zero_rates).map(|(t, r)| t * r));
+        Ok(Self {
+            log_discount: Curve::new(xs, ys, Kind::Linear, Extrapolation::Linear)?,
+        })
+    }
+
+    /// Builds a flat curve at a single continuously compounded rate.
+    pub fn flat(rate: f64) -> Self {
+        Self {
+            log_discount: Curve::new(
+                vec![0.0, 1.0],
+                vec![0.0, rate],
+                Kind::Linear,
+                Extrapolation::Linear,
+            )
+            .expect("a flat curve has two increasing finite knots"),
+        }
+    }
+
+    /// Returns the discount factor for maturity `t`.
+    pub fn discount_factor(&self, t: f64) -> f64 {
+        (-self.log_discount.value(t)).exp()
+    }
+
+    /// Returns the continuously compounded zero rate for maturity `t`.
+    pub fn zero_rate(&self, t: f64) -> f64 {
+        if t <= 0.0 {
+            return self.forward_rate(0.0, 1e-6);
+        }
+        self.log_discount.value(t) / t
+    }
+
+    /// Returns the simply compounded forward rate between `start` and `end`, the fixing of a
+    /// floating-rate period accruing over `[start, end]`.
+    pub fn forward_rate(&self, start: f64, end: f64) -> f64 {
+        (self.discount_factor(start) / self.discount_factor(end) - 1.0) / (end - start)
+    }
+}
@@ -0,0 +1,195 @@
+use crate::rates::{VolatilityType, YieldCurve};
+
+/// A single caplet or floorlet on a simply compounded rate fixing at `start` and paid at
+/// `end`. Times are year fractions from today; the accrual is `end - start`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Caplet {
+    /// Fixing time of the floating rate.
+    pub start: f64,
+    /// Payment time.
+    pub end: f64,
+    /// Strike rate.
+    pub strike: f64,
+    /// Notional amount.
+    pub notional: f64,
+}
+
+impl Caplet {
+    /// Returns the accrual period in years.
+    pub fn accrual(&self) -> f64 {
+        self.end - self.start
+    }
+
+    /// Returns the forward rate for the period implied by `curve`.
+    pub fn forward(&self, curve: &YieldCurve) -> f64 {
+        curve.forward_rate(self.start, self.end)
+    }
+
+    /// Prices the caplet, a call on the forward rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The discount and forwarding curve.
+    /// * `vol` - The caplet volatility.
+    /// * `vol_type` - Whether `vol` is lognormal or normal.
+    pub fn caplet_price(&self, curve: &YieldCurve, vol: f64, vol_type: VolatilityType) -> f64 {
+        self.price(curve, vol, vol_type, true)
+    }
+
+    /// Prices the floorlet, a put on the forward rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The discount and forwarding curve.
+    /// * `vol` - The floorlet volatility.
+    /// * `vol_type` - Whether `vol` is lognormal or normal.
+    pub fn floorlet_price(&self, curve: &YieldCurve, vol: f64, vol_type: VolatilityType) -> f64 {
+        self.price(curve, vol, vol_type, false)
+    }
+
+    fn price(&self, curve: &YieldCurve, vol: f64, vol_type: VolatilityType, is_call: bool) -> f64 {
+        let undiscounted =
+            vol_type.price(self.forward(curve), self.strike, vol, self.start, is_call);
+        self.notional * self.accrual() * curve.discount_factor(self.end) * undiscounted
+    }
+}
+
+/// An interest-rate cap or floor: a strip of caplets or floorlets on consecutive periods.
+///
+/// # Example
+///
+/// ```
+/// use core::rates::{CapFloor, VolatilityType, YieldCurve};
+/// let cap = CapFloor::cap(0.045, 1_000_000.0, 5.0, 4.0);
+/// let premium = cap.price(&YieldCurve::flat(0.04), 0.2, VolatilityType::Lognormal);
+/// assert!(premium > 0.0);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CapFloor {
+    /// Strike rate shared by every period.
+    pub strike: f64,
+    /// Notional amount.
+    pub notional: f64,
+    /// Period boundaries: period `i` fixes at `schedule[i]` and pays at `schedule[i + 1]`.
+    pub schedule: Vec<f64>,
+    /// `true` for a cap, `false` for a floor.
+    pub is_cap: bool,
+}
+
+impl CapFloor {
+    /// Creates a spot-starting cap on a regular schedule.
+    ///
+    /// The first period is excluded, as its rate is already fixed today.
+    ///
+    /// # Arguments
+    ///
+    /// * `strike` - The cap rate.
+    /// * `notional` - The notional amount.
+    /// * `maturity` - The final payment time in years.
+    /// * `frequency` - The number of periods per year (e.g. 4 for quarterly).
+    pub fn cap(strike: f64, notional: f64, maturity: f64, frequency: f64) -> Self {
+        Self {
+            strike,
+            notional,
+            schedule: regular_schedule(1.0 / frequency, maturity, frequency),
+            is_cap: true,
+        }
+    }
+
+    /// Creates a spot-starting floor on a regular schedule; see `cap`.
+    pub fn floor(strike: f64, notional: f64, maturity: f64, frequency: f64) -> Self {
+        Self {
+            is_cap: false,
+            ..Self::cap(strike, notional, maturity, frequency)
+        }
+    }
+
+    /// Returns the individual caplets (or floorlets).
+    pub fn caplets(&self) -> Vec<Caplet> {
+        self.schedule
+            .windows(2)
+            .map(|period| Caplet {
+                start: period[0],
+                end: period[1],
+                strike: self.strike,
+                notional: self.notional,
+            })
+            .collect()
+    }
+
+    /// Prices the cap or floor with a flat volatility across all periods.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The discount and forwarding curve.
+    /// * `vol` - The flat cap volatility.
+    /// * `vol_type` - Whether `vol` is lognormal or normal.
+    pub fn price(&self, curve: &YieldCurve, vol: f64, vol_type: VolatilityType) -> f64 {
+        self.caplets()
+            .iter()
+            .map(|caplet| caplet.price(curve, vol, vol_type, self.is_cap))
+            .sum()
+    }
+}
+
+/// A European swaption: the right to enter, at `expiry`, a swap of length `tenor` paying
+/// (payer) or receiving (receiver) the fixed `strike`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Swaption {
+    /// Option expiry and swap start, in years.
+    pub expiry: f64,
+    /// Length of the underlying swap in years.
+    pub tenor: f64,
+    /// Fixed-leg payments per year.
+    pub frequency: f64,
+    /// Fixed rate of the underlying swap.
+    pub strike: f64,
+    /// Notional amount.
+    pub notional: f64,
+    /// `true` for a payer swaption (a call on the swap rate), `false` for a receiver.
+    pub payer: bool,
+}
+
+impl Swaption {
+    /// Returns the fixed-leg payment times.
+    fn payment_times(&self) -> Vec<f64> {
+        regular_schedule(self.expiry, self.expiry + self.tenor, self.frequency)
+    }
+
+    /// Calculates the annuity: the present value of one unit paid on the fixed leg.
+    pub fn annuity(&self, curve: &YieldCurve) -> f64 {
+        self.payment_times()
+            .windows(2)
+            .map(|period| (period[1] - period[0]) * curve.discount_factor(period[1]))
+            .sum()
+    }
+
+    /// Calculates the forward par swap rate of the underlying swap.
+    pub fn forward_swap_rate(&self, curve: &YieldCurve) -> f64 {
+        let end = self.expiry + self.tenor;
+        (curve.discount_factor(self.expiry) - curve.discount_factor(end)) / self.annuity(curve)
+    }
+
+    /// Prices the swaption.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - The discount and forwarding curve.
+    /// * `vol` - The swaption volatility.
+    /// * `vol_type` - Whether `vol` is lognormal or normal.
+    pub fn price(&self, curve: &YieldCurve, vol: f64, vol_type: VolatilityType) -> f64 {
+        let forward = self.forward_swap_rate(curve);
+        let undiscounted = vol_type.price(forward, self.strike, vol, self.expiry, self.payer);
+        self.notional * self.annuity(curve) * undiscounted
+    }
+}
+
+/// Returns `start, start + 1/frequency, …` up to and including `end`; the last period is
+/// shortened if `end - start` is not a whole number of periods.
+fn regular_schedule(start: f64, end: f64, frequency: f64) -> Vec<f64> {
+    let step = 1.0 / frequency;
+    let periods = ((end - start) * frequency - 1e-9).ceil().max(0.0) as usize;
+    let mut schedule: Vec<f64> = (0..periods).map(|i| start + i as f64 * step).collect();
+    schedule.push(end);
+    schedule
+}
@@ -0,0 +1,7 @@
+pub mod black;
+pub mod curve;
+pub mod instruments;
+
+pub use black::{bachelier, black, VolatilityType};
+pub use curve::YieldCurve;
+pub use instruments::{CapFloor, Caplet, Swaption};
@@ -0,0 +1,267 @@
+use crate::models::{OptionParameters, OptionPricingModel};
+use std::fmt;
+
+/// The no-arbitrage relation a violation refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// `C - P = S - K e^{-rT}`.
+    PutCallParity,
+    /// `max(S - K e^{-rT}, 0) <= C <= S`.
+    CallBounds,
+    /// `max(K e^{-rT} - S, 0) <= P <= K e^{-rT}`.
+    PutBounds,
+    /// Call prices must not increase with strike.
+    CallMonotonicity,
+    /// Put prices must not decrease with strike.
+    PutMonotonicity,
+    /// Call prices must be convex in strike.
+    CallConvexity,
+    /// Put prices must be convex in strike.
+    PutConvexity,
+}
+
+/// A single failed check.
+///
+/// # Fields
+///
+/// * `check` - The relation that was violated.
+/// * `strike` - The strike at which it was violated (the middle strike for convexity).
+/// * `amount` - How far outside the allowed region the model's output lies.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    pub check: Check,
+    pub strike: f64,
+    pub amount: f64,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} violated at K={:.4} by {:.6}",
+            self.check, self.strike, self.amount
+        )
+    }
+}
+
+/// The result of validating a model over a strike grid.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SanityReport {
+    pub violations: Vec<Violation>,
+}
+
+impl SanityReport {
+    /// Returns `true` if no check failed.
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the violations of one particular check.
+    pub fn of(&self, check: Check) -> impl Iterator<Item = &Violation> {
+        self.violations.iter().filter(move |v| v.check == check)
+    }
+}
+
+/// Checks put-call parity at the strike in `params`.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model under test.
+/// * `params` - The parameters for the option.
+/// * `tolerance` - The absolute price error allowed before a violation is reported.
+///
+/// # Returns
+///
+/// Returns the violation, if any.
+pub fn check_put_call_parity<M: OptionPricingModel + ?Sized>(
+    model: &M,
+    params: &OptionParameters,
+    tolerance: f64,
+) -> Option<Violation> {
+    let (call, put) = model.call_put_price(params);
+    let forward = params.s - params.k * (-params.r * params.t).exp();
+    let error = (call - put - forward).abs();
+    (error > tolerance).then_some(Violation {
+        check: Check::PutCallParity,
+        strike: params.k,
+        amount: error,
+    })
+}
+
+/// Checks the model-free lower and upper price bounds at the strike in `params`.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model under test.
+/// * `params` - The parameters for the option.
+/// * `tolerance` - The absolute price error allowed before a violation is reported.
+///
+/// # Returns
+///
+/// Returns the call and put bound violations, if any.
+pub fn check_bounds<M: OptionPricingModel + ?Sized>(
+    model: &M,
+    params: &OptionParameters,
+    tolerance: f64,
+) -> Vec<Violation> {
+    let (call, put) = model.call_put_price(params);
+    let discounted_k = params.k * (-params.r * params.t).exp();
+
+    let outside = |value: f64, lower: f64, upper: f64| {
+        if value < lower - tolerance {
+            Some(lower - value)
+        } else if value > upper + tolerance {
+            Some(value - upper)
+        } else {
+            None
+        }
+    };
+
+    [
+        (
+            Check::CallBounds,
+            outside(call, (params.s - discounted_k).max(0.0), params.s),
+        ),
+        (
+            Check::PutBounds,
+            outside(put, (discounted_k - params.s).max(0.0), discounted_k),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(check, amount)| {
+        amount.map(|amount| Violation {
+            check,
+            strike: params.k,
+            amount,
+        })
+    })
+    .collect()
+}
+
+/// Checks monotonicity and convexity of call and put prices across `strikes`.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model under test.
+/// * `params` - The parameters for the option; its strike is replaced by each of `strikes`.
+/// * `strikes` - The strike grid, in increasing order.
+/// * `tolerance` - The absolute price error allowed before a violation is reported.
+///
+/// # Returns
+///
+/// Returns every monotonicity and convexity violation found on the grid.
+pub fn check_strike_shape<M: OptionPricingModel + ?Sized>(
+    model: &M,
+    params: &OptionParameters,
+    strikes: &[f64],
+    tolerance: f64,
+) -> Vec<Violation> {
+    let prices: Vec<(f64, f64)> = strikes
+        .iter()
+        .map(|&k| {
+            model.call_put_price(&OptionParameters {
+                k,
+                ..params.clone()
+            })
+        })
+        .collect();
+    let mut violations = Vec::new();
+
+    for i in 1..strikes.len() {
+        let (c0, p0) = prices[i - 1];
+        let (c1, p1) = prices[i];
+        if c1 - c0 > tolerance {
+            violations.push(Violation {
+                check: Check::CallMonotonicity,
+                strike: strikes[i],
+                amount: c1 - c0,
+            });
+        }
+        if p0 - p1 > tolerance {
+            violations.push(Violation {
+                check: Check::PutMonotonicity,
+                strike: strikes[i],
+                amount: p0 - p1,
+            });
+        }
+    }
+
+    for i in 1..strikes.len().saturating_sub(1) {
+        let (k0, k1, k2) = (strikes[i - 1], strikes[i], strikes[i + 1]);
+        // Weight of the lower strike when k1 is written as a mix of k0 and k2.
+        let w = (k2 - k1) / (k2 - k0);
+        for (check, v0, v1, v2) in [
+            (
+                Check::CallConvexity,
+                prices[i - 1].0,
+                prices[i].0,
+                prices[i + 1].0,
+            ),
+            (
+                Check::PutConvexity,
+                prices[i - 1].1,
+                prices[i].1,
+                prices[i + 1].1,
+            ),
+        ] {
+            let excess = v1 - (w * v0 + (1.0 - w) * v2);
+            if excess > tolerance {
+                violations.push(Violation {
+                    check,
+                    strike: k1,
+                    amount: excess,
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+/// Runs every check over a strike grid.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model under test.
+/// * `params` - The parameters for the option; its strike is replaced by each of `strikes`.
+/// * `strikes` - The strike grid, in increasing order.
+/// * `tolerance` - The absolute price error allowed before a violation is reported.
+///
+/// # Returns
+///
+/// Returns a `SanityReport` listing every violation.
+///
+/// # Example
+///
+/// ```
+/// use core::models::{BlackScholesModel, OptionParameters};
+/// use core::sanity;
+/// let params = OptionParameters {
+///     s: 100.0,
+///     k: 100.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let strikes: Vec<f64> = (80..=120).step_by(5).map(f64::from).collect();
+/// let report = sanity::validate(&BlackScholesModel, &params, &strikes, 1e-6);
+/// assert!(report.is_clean());
+/// ```
+pub fn validate<M: OptionPricingModel + ?Sized>(
+    model: &M,
+    params: &OptionParameters,
+    strikes: &[f64],
+    tolerance: f64,
+) -> SanityReport {
+    let mut violations = Vec::new();
+    for &k in strikes {
+        let params = OptionParameters {
+            k,
+            ..params.clone()
+        };
+        violations.extend(check_put_call_parity(model, &params, tolerance));
+        violations.extend(check_bounds(model, &params, tolerance));
+    }
+    violations.extend(check_strike_shape(model, params, strikes, tolerance));
+    SanityReport { violations }
+}
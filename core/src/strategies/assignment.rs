@@ -0,0 +1,116 @@
+use crate::models::{OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Side, Strategy};
+
+/// |Delta| at or above which a short in-the-money option is flagged as deep in the money.
+const DEEP_ITM_DELTA: f64 = 0.9;
+
+/// A cash dividend paid before (or after) the options expire.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Dividend {
+    /// The dividend per share.
+    pub amount: f64,
+    /// The time to the ex-dividend date in years.
+    pub ex_date: f64,
+}
+
+/// How likely a short leg is to be assigned before expiry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AssignmentRisk {
+    /// Early exercise is not yet rational but the leg is deep in the money.
+    Elevated,
+    /// Early exercise is rational for the holder.
+    High,
+}
+
+/// Why a short leg was flagged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AssignmentReason {
+    /// A short call whose extrinsic value is below the upcoming dividend: the holder
+    /// captures the dividend by exercising the day before the ex-date.
+    Dividend,
+    /// A short put whose extrinsic value is below the interest earned on the strike: the
+    /// holder is better off exercising and investing the proceeds.
+    Carry,
+    /// A short in-the-money option with |Delta| of at least 0.9.
+    DeepInTheMoney,
+}
+
+/// An early-assignment warning for one short option leg.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AssignmentWarning {
+    /// The index of the leg in `Strategy::legs`.
+    pub leg: usize,
+    /// The estimated risk.
+    pub risk: AssignmentRisk,
+    /// The reason the leg was flagged.
+    pub reason: AssignmentReason,
+    /// The model value of one option less its intrinsic value.
+    pub extrinsic: f64,
+    /// The Delta of one option.
+    pub delta: f64,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Estimates the early-assignment risk of every short option leg.
+    ///
+    /// A holder of an American option exercises early when the time value left in the
+    /// option is worth less than what exercising gains: the dividend for a call, the
+    /// interest on the strike for a put. The time value is taken from the strategy's
+    /// model, which prices European options, so this is a screening heuristic rather than
+    /// an American exercise boundary.
+    ///
+    /// # Arguments
+    ///
+    /// * `dividend` - The next cash dividend, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns one warning per flagged leg, in leg order; long legs and legs at low risk
+    /// are omitted.
+    pub fn assignment_risk(&self, dividend: Option<Dividend>) -> Vec<AssignmentWarning> {
+        self.legs
+            .iter()
+            .enumerate()
+            .filter(|(_, leg)| leg.side == Side::Short)
+            .filter_map(|(i, leg)| {
+                let params = &leg.params;
+                let (intrinsic, exercise_gain) = match leg.option_type {
+                    OptionType::Call => {
+                        let dividend = dividend
+                            .filter(|d| d.ex_date > 0.0 && d.ex_date <= params.t)
+                            .map_or(0.0, |d| d.amount);
+                        ((params.s - params.k).max(0.0), dividend)
+                    }
+                    OptionType::Put => {
+                        let carry = params.k * (1.0 - (-params.r * params.t).exp());
+                        ((params.k - params.s).max(0.0), carry)
+                    }
+                };
+                if intrinsic <= 0.0 {
+                    return None;
+                }
+
+                let extrinsic = self.model.option_price(params, leg.option_type) - intrinsic;
+                let delta = self.model.option_delta(params, leg.option_type);
+                let (risk, reason) = if exercise_gain > 0.0 && extrinsic < exercise_gain {
+                    let reason = match leg.option_type {
+                        OptionType::Call => AssignmentReason::Dividend,
+                        OptionType::Put => AssignmentReason::Carry,
+                    };
+                    (AssignmentRisk::High, reason)
+                } else if delta.abs() >= DEEP_ITM_DELTA {
+                    (AssignmentRisk::Elevated, AssignmentReason::DeepInTheMoney)
+                } else {
+                    return None;
+                };
+                Some(AssignmentWarning {
+                    leg: i,
+                    risk,
+                    reason,
+                    extrinsic,
+                    delta,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, validated, StrategyError};
+use crate::strategies::OptionStrategy;
+
+/// Represents a long box spread: a bull call spread plus a bear put spread on the same
+/// two strikes.
+///
+/// Whatever the underlying does, the box pays `k2 - k1` at expiry, so by put-call parity it
+/// is worth the discounted strike width and behaves as a zero-coupon bond. Buying a box
+/// lends at its implied rate; selling one borrows.
+pub struct BoxSpread<'a, T: OptionPricingModel> {
+    /// The option pricing model used to price the options.
+    pub model: &'a T,
+
+    /// The parameters for the options; `params.k` is the lower strike.
+    pub params: OptionParameters,
+
+    /// The upper strike.
+    pub k2: f64,
+}
+
+impl<'a, T: OptionPricingModel> BoxSpread<'a, T> {
+    /// Creates a new `BoxSpread` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The parameters for the options at the lower strike.
+    /// * `k2` - The upper strike.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of `BoxSpread`.
+    pub fn new(model: &'a T, params: OptionParameters, k2: f64) -> Self {
+        Self { model, params, k2 }
+    }
+
+    /// Returns the amount the box pays at expiry, `k2 - k1`.
+    pub fn face_value(&self) -> f64 {
+        self.k2 - self.params.k
+    }
+
+    /// Builds the equivalent leg-based strategy: long call and short put at `k1`, short call
+    /// and long put at `k2`.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let upper = OptionParameters {
+            k: self.k2,
+            ..self.params.clone()
+        };
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params.clone()))
+            .with_leg(Leg::call(Side::Short, upper.clone()))
+            .with_leg(Leg::put(Side::Long, upper))
+            .with_leg(Leg::put(Side::Short, self.params.clone()))
+    }
+
+    /// Calculates the continuously compounded financing rate implied by a market price.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_price` - The price paid for the box.
+    pub fn implied_rate(&self, market_price: f64) -> f64 {
+        implied_box_rate(market_price, self.params.k, self.k2, self.params.t)
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails unless `k1 < k2`.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params.k, self.k2])
+    }
+
+    /// Creates a new `BoxSpread`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(model: &'a T, params: OptionParameters, k2: f64) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params, k2), Self::validate)
+    }
+}
+
+/// Backs out the continuously compounded rate at which a box spread lends or borrows.
+///
+/// \[
+/// r = -\frac{1}{T} \ln\left(\frac{P}{K_2 - K_1}\right)
+/// \]
+///
+/// # Arguments
+///
+/// * `market_price` - The price of the box.
+/// * `k1` - The lower strike.
+/// * `k2` - The upper strike.
+/// * `t` - The time to expiry in years.
+///
+/// # Returns
+///
+/// Returns the implied rate, or NaN if the price or the strike width is not positive.
+pub fn implied_box_rate(market_price: f64, k1: f64, k2: f64, t: f64) -> f64 {
+    let width = k2 - k1;
+    if market_price <= 0.0 || width <= 0.0 {
+        return f64::NAN;
+    }
+    -(market_price / width).ln() / t
+}
+
+impl<'a, T: OptionPricingModel> OptionStrategy for BoxSpread<'a, T> {
+    /// Calculates the price of the box spread.
+    ///
+    /// \[
+    /// \text{Price} = C(K_1) - C(K_2) + P(K_2) - P(K_1) = (K_2 - K_1) e^{-rT}
+    /// \]
+    ///
+    /// The identity holds for any model that respects put-call parity.
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
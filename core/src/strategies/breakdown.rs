@@ -0,0 +1,91 @@
+use crate::models::{Greeks, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Side, Strategy};
+use serde::Serialize;
+
+/// What a line of a breakdown refers to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LegKind {
+    Call,
+    Put,
+    Stock,
+}
+
+/// One leg's share of a strategy's value and Greeks.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LegBreakdown {
+    /// The leg index; option legs come first, followed by stock legs.
+    pub leg: usize,
+    /// The instrument.
+    pub kind: LegKind,
+    /// Long or short.
+    pub side: Side,
+    /// The number of contracts or shares.
+    pub quantity: f64,
+    /// The strike, or `None` for stock.
+    pub strike: Option<f64>,
+    /// The price of one contract or share.
+    pub unit_price: f64,
+    /// The signed value of the leg: quantity times unit price, negative when short.
+    pub value: f64,
+    /// The signed Greeks of the leg.
+    pub greeks: Greeks,
+    /// The leg's value as a fraction of the net value; `NaN` when the net is zero.
+    pub contribution: f64,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Breaks the net price and Greeks down by leg.
+    ///
+    /// The `value` and `greeks` of the lines sum to `price()` and `greeks()`.
+    pub fn breakdown(&self) -> Vec<LegBreakdown> {
+        let net = self.price();
+        let options = self.legs.iter().map(|leg| {
+            let kind = match leg.option_type {
+                OptionType::Call => LegKind::Call,
+                OptionType::Put => LegKind::Put,
+            };
+            let unit_price = self.model.option_price(&leg.params, leg.option_type);
+            (
+                kind,
+                leg.side,
+                leg.quantity,
+                Some(leg.params.k),
+                unit_price,
+                leg.greeks(self.model),
+            )
+        });
+        let stock = self.stock.iter().map(|leg| {
+            let greeks = Greeks {
+                delta: leg.signed_quantity(),
+                ..Greeks::default()
+            };
+            (
+                LegKind::Stock,
+                leg.side,
+                leg.quantity,
+                None,
+                leg.spot,
+                greeks,
+            )
+        });
+        options
+            .chain(stock)
+            .enumerate()
+            .map(|(i, (kind, side, quantity, strike, unit_price, greeks))| {
+                let value = side.sign() * quantity * unit_price;
+                LegBreakdown {
+                    leg: i,
+                    kind,
+                    side,
+                    quantity,
+                    strike,
+                    unit_price,
+                    value,
+                    greeks,
+                    contribution: if net == 0.0 { f64::NAN } else { value / net },
+                }
+            })
+            .collect()
+    }
+}
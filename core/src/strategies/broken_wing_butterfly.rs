@@ -0,0 +1,141 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Breakeven, Leg, Side, Strategy};
+use crate::strategies::validation::{positive, validated, StrategyError};
+use crate::strategies::OptionStrategy;
+
+/// Represents a broken-wing (skip-strike) butterfly.
+///
+/// Like a butterfly, it buys one option below the body, sells two at the body and buys one
+/// above, but the two wings have different widths. Widening the wing on the side away from
+/// the expected move turns the trade into a small debit or even a credit, at the cost of
+/// extra risk on that side. For calls the wider upper wing carries the risk; for puts the
+/// wider lower wing does.
+pub struct BrokenWingButterfly<'a, T: OptionPricingModel> {
+    /// The option pricing model used to price the options.
+    pub model: &'a T,
+
+    /// The parameters for the body; `params.k` is the short strike.
+    pub params: OptionParameters,
+
+    /// The distance from the body down to the lower long strike.
+    pub lower_width: f64,
+
+    /// The distance from the body up to the upper long strike.
+    pub upper_width: f64,
+
+    /// Whether the butterfly is built from calls (`true`) or puts (`false`).
+    pub is_call: bool,
+}
+
+impl<'a, T: OptionPricingModel> BrokenWingButterfly<'a, T> {
+    /// Creates a new `BrokenWingButterfly` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The parameters for the body options.
+    /// * `lower_width` - The distance from the body to the lower wing.
+    /// * `upper_width` - The distance from the body to the upper wing.
+    /// * `is_call` - `true` for a call butterfly, `false` for a put butterfly.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of `BrokenWingButterfly`.
+    pub fn new(
+        model: &'a T,
+        params: OptionParameters,
+        lower_width: f64,
+        upper_width: f64,
+        is_call: bool,
+    ) -> Self {
+        Self {
+            model,
+            params,
+            lower_width,
+            upper_width,
+            is_call,
+        }
+    }
+
+    /// Returns the lower, body and upper strikes.
+    pub fn strikes(&self) -> (f64, f64, f64) {
+        let body = self.params.k;
+        (body - self.lower_width, body, body + self.upper_width)
+    }
+
+    /// Builds the equivalent leg-based strategy: long one lower wing, short two at the body
+    /// and long one upper wing.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let option_type = if self.is_call {
+            OptionType::Call
+        } else {
+            OptionType::Put
+        };
+        let (lower, body, upper) = self.strikes();
+        let at = |k: f64| OptionParameters {
+            k,
+            ..self.params.clone()
+        };
+        Strategy::new(self.model)
+            .with_leg(Leg::new(option_type, Side::Long, 1.0, at(lower)))
+            .with_leg(Leg::new(option_type, Side::Short, 2.0, at(body)))
+            .with_leg(Leg::new(option_type, Side::Long, 1.0, at(upper)))
+    }
+
+    /// Finds the expiry breakevens. Depending on the wings and the premium there may be one
+    /// or two.
+    pub fn breakevens(&self) -> Vec<Breakeven> {
+        self.strategy().breakevens()
+    }
+
+    /// Calculates the largest expiry profit, reached at the body strike.
+    pub fn max_profit(&self) -> f64 {
+        self.strategy().max_profit()
+    }
+
+    /// Calculates the largest expiry loss as a positive number.
+    ///
+    /// With wings `w_l` and `w_u` and net debit `D`, a call butterfly loses `D` below the
+    /// lower strike and `D + w_u - w_l` above the upper one; the larger of the two is the
+    /// maximum loss. Put butterflies mirror this.
+    pub fn max_loss(&self) -> f64 {
+        self.strategy().max_loss()
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails unless both wing widths are
+    /// positive and the lower strike is above zero.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        positive(0, "lower width", self.lower_width)?;
+        positive(0, "upper width", self.upper_width)?;
+        self.strategy().validate()
+    }
+
+    /// Creates a new `BrokenWingButterfly`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params: OptionParameters,
+        lower_width: f64,
+        upper_width: f64,
+        is_call: bool,
+    ) -> Result<Self, StrategyError> {
+        validated(
+            Self::new(model, params, lower_width, upper_width, is_call),
+            Self::validate,
+        )
+    }
+}
+
+impl<'a, T: OptionPricingModel> OptionStrategy for BrokenWingButterfly<'a, T> {
+    /// Calculates the net debit of the broken-wing butterfly (negative for a net credit).
+    ///
+    /// \[
+    /// \text{Price} = V(K - w_l) - 2 V(K) + V(K + w_u)
+    /// \]
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
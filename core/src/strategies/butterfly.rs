@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a butterfly spread option strategy.
@@ -42,6 +44,44 @@ impl<'a, T: OptionPricingModel> ButterflySpread<'a, T> {
             k3,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: one long call at `k1`, two short calls at `k2` and one long call at `k3`.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params.clone()))
+            .with_leg(Leg::new(
+                OptionType::Call,
+                Side::Short,
+                2.0,
+                OptionParameters {
+                    k: self.k2,
+                    ..self.params.clone()
+                },
+            ))
+            .with_leg(Leg::call(
+                Side::Long,
+                OptionParameters {
+                    k: self.k3,
+                    ..self.params.clone()
+                },
+            ))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the strikes are not strictly
+    /// increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params.k, self.k2, self.k3])
+    }
+
+    /// Creates a new `ButterflySpread`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params: OptionParameters,
+        k2: f64,
+        k3: f64,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params, k2, k3), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for ButterflySpread<'a, T> {
@@ -64,8 +104,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for ButterflySpread<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::BlackScholesModel;
-    /// use crate::strategies::ButterflySpread;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::butterfly::ButterflySpread;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params = OptionParameters {
     ///     s: 100.0,
@@ -77,19 +119,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for ButterflySpread<'a, T> {
     /// let spread = ButterflySpread::new(&model, params, 100.0, 110.0);
     /// let price = spread.price();
     /// println!("Butterfly Spread Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        let params1 = self.params.clone();
-        let mut params2 = self.params.clone();
-        let mut params3 = self.params.clone();
-
-        params2.k = self.k2;
-        params3.k = self.k3;
-
-        let c1 = self.model.call_price(&params1);
-        let c2 = self.model.call_price(&params2);
-        let c3 = self.model.call_price(&params3);
+        self.strategy().price()
+    }
 
-        // Calculate the price of the butterfly spread
-        c1 - 2.0 * c2 + c3
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{near_before_far, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a calendar spread option strategy.
@@ -36,6 +38,37 @@ impl<'a, T: OptionPricingModel> CalendarSpread<'a, T> {
             far_params,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: a long far-term call and a short near-term call.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.far_params.clone()))
+            .with_leg(Leg::call(Side::Short, self.near_params.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the strikes differ or the near
+    /// leg does not expire first.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()?;
+        near_before_far(&self.near_params, &self.far_params)?;
+        if self.far_params.k != self.near_params.k {
+            return Err(StrategyError::StrikeMismatch {
+                leg: 0,
+                expected: self.near_params.k,
+                found: self.far_params.k,
+            });
+        }
+        Ok(())
+    }
+
+    /// Creates a new `CalendarSpread`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        near_params: OptionParameters,
+        far_params: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, near_params, far_params), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for CalendarSpread<'a, T> {
@@ -57,8 +90,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for CalendarSpread<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::BlackScholesModel;
-    /// use crate::strategies::CalendarSpread;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::calendar::CalendarSpread;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let near_params = OptionParameters {
     ///     s: 100.0,
@@ -77,11 +112,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for CalendarSpread<'a, T> {
     /// let spread = CalendarSpread::new(&model, near_params, far_params);
     /// let price = spread.price();
     /// println!("Calendar Spread Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        let near_leg = self.model.call_price(&self.near_params);
-        let far_leg = self.model.call_price(&self.far_params);
+        self.strategy().price()
+    }
 
-        // Calculate the price of the calendar spread
-        far_leg - near_leg
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
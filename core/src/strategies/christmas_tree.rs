@@ -0,0 +1,102 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, validated, StrategyError};
+use crate::strategies::OptionStrategy;
+
+/// Represents a christmas-tree butterfly (a 1x3x2 ratio butterfly).
+///
+/// The call version buys one call at `k1`, sells three at `k2` and buys two at `k3`; the
+/// put version mirrors it, buying one put at `k3`, selling three at `k2` and buying two at
+/// `k1`. It is cheaper than a butterfly and peaks at `k2`, expressing a moderately
+/// directional view. Strikes are usually chosen with a skipped strike between `k1` and
+/// `k2`.
+pub struct ChristmasTree<'a, T: OptionPricingModel> {
+    /// The option pricing model used to price the options.
+    pub model: &'a T,
+
+    /// The parameters for the options; `params.k` is the lowest strike.
+    pub params: OptionParameters,
+
+    /// The middle (short) strike.
+    pub k2: f64,
+
+    /// The highest strike.
+    pub k3: f64,
+
+    /// Whether the tree is built from calls (`true`) or puts (`false`).
+    pub is_call: bool,
+}
+
+impl<'a, T: OptionPricingModel> ChristmasTree<'a, T> {
+    /// Creates a new `ChristmasTree` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The parameters for the options at the lowest strike.
+    /// * `k2` - The middle strike, sold three times.
+    /// * `k3` - The highest strike.
+    /// * `is_call` - `true` for calls, `false` for puts.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of `ChristmasTree`.
+    pub fn new(model: &'a T, params: OptionParameters, k2: f64, k3: f64, is_call: bool) -> Self {
+        Self {
+            model,
+            params,
+            k2,
+            k3,
+            is_call,
+        }
+    }
+
+    /// Builds the equivalent leg-based strategy with 1x3x2 quantities.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let at = |k: f64| OptionParameters {
+            k,
+            ..self.params.clone()
+        };
+        let (option_type, near, far) = if self.is_call {
+            (OptionType::Call, self.params.k, self.k3)
+        } else {
+            (OptionType::Put, self.k3, self.params.k)
+        };
+        Strategy::new(self.model)
+            .with_leg(Leg::new(option_type, Side::Long, 1.0, at(near)))
+            .with_leg(Leg::new(option_type, Side::Short, 3.0, at(self.k2)))
+            .with_leg(Leg::new(option_type, Side::Long, 2.0, at(far)))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the strikes are not strictly
+    /// increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params.k, self.k2, self.k3])
+    }
+
+    /// Creates a new `ChristmasTree`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params: OptionParameters,
+        k2: f64,
+        k3: f64,
+        is_call: bool,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params, k2, k3, is_call), Self::validate)
+    }
+}
+
+impl<'a, T: OptionPricingModel> OptionStrategy for ChristmasTree<'a, T> {
+    /// Calculates the net debit of the christmas tree.
+    ///
+    /// \[
+    /// \text{Price} = C(K_1) - 3C(K_2) + 2C(K_3)
+    /// \]
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a collar option strategy.
@@ -52,6 +54,32 @@ impl<'a, T: OptionPricingModel> Collar<'a, T> {
             },
         }
     }
+
+    /// Builds the equivalent leg-based strategy: a long put and a short call (the stock is not included).
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::put(Side::Long, self.put_params.clone()))
+            .with_leg(Leg::call(Side::Short, self.call_params.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the put strike is not below the
+    /// call strike.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.put_params.k, self.call_params.k])
+    }
+
+    /// Creates a new `Collar`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        s: f64,
+        k1: f64,
+        k2: f64,
+        r: f64,
+        sigma: f64,
+        t: f64,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, s, k1, k2, r, sigma, t), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for Collar<'a, T> {
@@ -76,20 +104,20 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Collar<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::BlackScholesModel;
-    /// use crate::strategies::Collar;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::collar::Collar;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let collar = Collar::new(&model, 100.0, 95.0, 105.0, 0.05, 0.2, 0.5);
     /// let price = collar.price();
     /// println!("Collar Strategy Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the protective put option.
-        let put_price = self.model.put_price(&self.put_params);
-
-        // Calculate the price of the covered call option.
-        let call_price = self.model.call_price(&self.call_params);
+        self.strategy().price()
+    }
 
-        // The collar strategy price is the cost of the put minus the proceeds from the call.
-        put_price - call_price
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -0,0 +1,109 @@
+use crate::models::{BlackScholesModel, Greeks, OptionPricingModel};
+use crate::strategies::definition::{ModelSpec, StrategyDefinition};
+use crate::strategies::strategy::Strategy;
+use serde::Serialize;
+
+/// A strategy valued under one model, with its differences from the reference model.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ModelValuation {
+    /// The name of the model.
+    pub model: String,
+    /// The signed value of each option leg.
+    pub legs: Vec<f64>,
+    /// The net value of the strategy, stock included.
+    pub price: f64,
+    /// The net Greeks of the strategy.
+    pub greeks: Greeks,
+    /// Each leg value less the reference model's.
+    pub leg_differences: Vec<f64>,
+    /// The net value less the reference model's.
+    pub price_difference: f64,
+    /// The net Greeks less the reference model's.
+    pub greeks_difference: Greeks,
+}
+
+/// One strategy valued under several models.
+///
+/// The first model is the reference: its differences are all zero and every other model
+/// is measured against it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ModelComparison {
+    /// One valuation per model, in the order the models were given.
+    pub valuations: Vec<ModelValuation>,
+}
+
+impl ModelComparison {
+    /// Returns the largest absolute difference in net value from the reference model.
+    pub fn max_price_difference(&self) -> f64 {
+        self.valuations
+            .iter()
+            .map(|valuation| valuation.price_difference.abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Serializes the comparison to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Values the strategy's legs under each of `models`, ignoring the strategy's own
+    /// model.
+    ///
+    /// # Arguments
+    ///
+    /// * `models` - Named models; the first is the reference.
+    pub fn compare_models(&self, models: &[(&str, &dyn OptionPricingModel)]) -> ModelComparison {
+        let mut valuations: Vec<ModelValuation> = Vec::with_capacity(models.len());
+        for &(name, model) in models {
+            let strategy = Strategy {
+                model,
+                legs: self.legs.clone(),
+                stock: self.stock.clone(),
+            };
+            let legs: Vec<f64> = self.legs.iter().map(|leg| leg.value(model)).collect();
+            let price = strategy.price();
+            let greeks = strategy.greeks();
+            let (leg_differences, price_difference, greeks_difference) = match valuations.first() {
+                Some(reference) => (
+                    legs.iter()
+                        .zip(&reference.legs)
+                        .map(|(value, base)| value - base)
+                        .collect(),
+                    price - reference.price,
+                    greeks - reference.greeks,
+                ),
+                None => (vec![0.0; legs.len()], 0.0, Greeks::default()),
+            };
+            valuations.push(ModelValuation {
+                model: name.to_string(),
+                legs,
+                price,
+                greeks,
+                leg_differences,
+                price_difference,
+                greeks_difference,
+            });
+        }
+        ModelComparison { valuations }
+    }
+}
+
+impl StrategyDefinition {
+    /// Builds each of `specs` and compares the strategy under them; see
+    /// `Strategy::compare_models`. Models are named by their `type` tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `specs` - The models to compare; the first is the reference.
+    pub fn compare_models(&self, specs: &[ModelSpec]) -> ModelComparison {
+        let built: Vec<Box<dyn OptionPricingModel>> = specs.iter().map(ModelSpec::build).collect();
+        let models: Vec<(&str, &dyn OptionPricingModel)> = specs
+            .iter()
+            .zip(&built)
+            .map(|(spec, model)| (spec.name(), model.as_ref()))
+            .collect();
+        self.strategy(&BlackScholesModel).compare_models(&models)
+    }
+}
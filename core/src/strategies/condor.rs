@@ -1,4 +1,8 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{
+    legs_and_strikes, same_expiry, validated, wings_not_before, StrategyError,
+};
 use crate::strategies::OptionStrategy;
 
 /// Represents a condor option strategy.
@@ -7,46 +11,120 @@ use crate::strategies::OptionStrategy;
 /// It is a market-neutral strategy that aims to profit from low volatility in the underlying asset.
 /// The condor spread consists of four legs: buying a lower strike call, selling two middle strike calls,
 /// and buying a higher strike call.
+///
+/// Each leg carries its own parameters, so the long legs may expire after the short ones
+/// (a calendarized condor). `from_strikes` builds the classic single-expiry condor.
 pub struct Condor<'a, T: OptionPricingModel> {
     /// The option pricing model used to price the options.
     pub model: &'a T,
 
-    /// The parameters for the lowest strike call option.
+    /// The parameters for the lowest strike call option (long).
     pub params1: OptionParameters,
 
-    /// The strike price of the second lowest strike call.
-    pub k2: f64,
+    /// The parameters for the second lowest strike call option (short).
+    pub params2: OptionParameters,
 
-    /// The strike price of the second highest strike call.
-    pub k3: f64,
+    /// The parameters for the second highest strike call option (short).
+    pub params3: OptionParameters,
 
-    /// The strike price of the highest strike call.
-    pub k4: f64,
+    /// The parameters for the highest strike call option (long).
+    pub params4: OptionParameters,
 }
 
 impl<'a, T: OptionPricingModel> Condor<'a, T> {
-    /// Creates a new `Condor` instance.
+    /// Creates a new `Condor` instance from the parameters of each leg.
     ///
     /// # Arguments
     ///
     /// * `model` - The option pricing model to be used.
     /// * `params1` - The parameters for the lowest strike call.
-    /// * `k2` - The strike price of the second lowest strike call.
-    /// * `k3` - The strike price of the second highest strike call.
-    /// * `k4` - The strike price of the highest strike call.
+    /// * `params2` - The parameters for the second lowest strike call.
+    /// * `params3` - The parameters for the second highest strike call.
+    /// * `params4` - The parameters for the highest strike call.
     ///
     /// # Returns
     ///
     /// Returns a new instance of `Condor`.
-    pub fn new(model: &'a T, params1: OptionParameters, k2: f64, k3: f64, k4: f64) -> Self {
+    pub fn new(
+        model: &'a T,
+        params1: OptionParameters,
+        params2: OptionParameters,
+        params3: OptionParameters,
+        params4: OptionParameters,
+    ) -> Self {
         Self {
             model,
             params1,
-            k2,
-            k3,
-            k4,
+            params2,
+            params3,
+            params4,
         }
     }
+
+    /// Creates a single-expiry `Condor` whose legs share `params1` apart from the strike.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params1` - The parameters for the lowest strike call.
+    /// * `k2` - The strike price of the second lowest strike call.
+    /// * `k3` - The strike price of the second highest strike call.
+    /// * `k4` - The strike price of the highest strike call.
+    pub fn from_strikes(
+        model: &'a T,
+        params1: OptionParameters,
+        k2: f64,
+        k3: f64,
+        k4: f64,
+    ) -> Self {
+        let at = |k: f64| OptionParameters {
+            k,
+            ..params1.clone()
+        };
+        let (params2, params3, params4) = (at(k2), at(k3), at(k4));
+        Self::new(model, params1, params2, params3, params4)
+    }
+
+    /// Builds the equivalent leg-based strategy: calls at `k1` to `k4`, long the outer two
+    /// and short the inner two.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params1.clone()))
+            .with_leg(Leg::call(Side::Short, self.params2.clone()))
+            .with_leg(Leg::call(Side::Short, self.params3.clone()))
+            .with_leg(Leg::call(Side::Long, self.params4.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the short legs expire at
+    /// different times, a long leg expires before them, or the strikes are not strictly
+    /// increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(
+            &self.strategy(),
+            &[
+                self.params1.k,
+                self.params2.k,
+                self.params3.k,
+                self.params4.k,
+            ],
+        )?;
+        same_expiry(&[(1, &self.params2), (2, &self.params3)])?;
+        wings_not_before(&self.params2, &[(0, &self.params1), (3, &self.params4)])
+    }
+
+    /// Creates a new `Condor`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params1: OptionParameters,
+        params2: OptionParameters,
+        params3: OptionParameters,
+        params4: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(
+            Self::new(model, params1, params2, params3, params4),
+            Self::validate,
+        )
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for Condor<'a, T> {
@@ -55,7 +133,7 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Condor<'a, T> {
     /// The condor strategy price is calculated as:
     ///
     /// \[
-    /// \text{Price} = C1 - C2 + C3 - C4
+    /// \text{Price} = C1 - C2 - C3 + C4
     /// \]
     ///
     /// Where:
@@ -65,8 +143,8 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Condor<'a, T> {
     /// - \( C4 \) is the price of the call option with strike price \( k4 \).
     ///
     /// This formula reflects the cost of implementing the condor spread strategy, which
-    /// involves buying a lower strike call, selling two middle strike calls, and buying
-    /// a higher strike call.
+    /// involves buying the lowest strike call, selling the two middle strike calls, and
+    /// buying the highest strike call.
     ///
     /// # Returns
     ///
@@ -74,8 +152,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Condor<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::Condor;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::condor::Condor;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params1 = OptionParameters {
     ///     s: 100.0,
@@ -84,33 +164,15 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Condor<'a, T> {
     ///     sigma: 0.2,
     ///     t: 0.5,
     /// };
-    /// let condor = Condor::new(&model, params1, 95.0, 105.0, 110.0);
+    /// let condor = Condor::from_strikes(&model, params1, 95.0, 105.0, 110.0);
     /// let price = condor.price();
     /// println!("Condor Strategy Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the call option with the lowest strike price.
-        let c1 = self.model.call_price(&self.params1);
-
-        // Create parameters for the other call options.
-        let params2 = OptionParameters {
-            k: self.k2,
-            ..self.params1
-        };
-        let params3 = OptionParameters {
-            k: self.k3,
-            ..self.params1
-        };
-        let params4 = OptionParameters {
-            k: self.k4,
-            ..self.params1
-        };
-
-        // Calculate the price of the call options with the other strike prices.
-        let c2 = self.model.call_price(&params2);
-        let c3 = self.model.call_price(&params3);
-        let c4 = self.model.call_price(&params4);
+        self.strategy().price()
+    }
 
-        // The condor strategy price is the cost of the long call positions minus the proceeds from the short calls.
-        c1 - c2 + c3 - c4
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
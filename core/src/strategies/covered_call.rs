@@ -1,4 +1,7 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::math::roots::RootError;
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a covered call option strategy.
@@ -29,6 +32,43 @@ impl<'a, T: OptionPricingModel> CoveredCall<'a, T> {
     pub fn new(model: &'a T, params: OptionParameters) -> Self {
         Self { model, params }
     }
+
+    /// Creates a `CoveredCall` writing the call with the given delta, e.g. `0.30`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The spot, rate, volatility and expiry; the strike is solved for.
+    /// * `delta` - The delta of the call to sell.
+    ///
+    /// # Returns
+    ///
+    /// Returns the covered call, or an error if no strike has that delta.
+    pub fn from_delta(
+        model: &'a T,
+        params: OptionParameters,
+        delta: f64,
+    ) -> Result<Self, RootError> {
+        let k = model.strike_for_delta(&params, OptionType::Call, delta)?;
+        Ok(Self::new(model, OptionParameters { k, ..params }))
+    }
+
+    /// Builds the equivalent leg-based strategy: one long share and a short call.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_stock(Side::Long, 1.0, self.params.s)
+            .with_leg(Leg::call(Side::Short, self.params.clone()))
+    }
+
+    /// Checks the legs; see `Strategy::validate`.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()
+    }
+
+    /// Creates a new `CoveredCall`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(model: &'a T, params: OptionParameters) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for CoveredCall<'a, T> {
@@ -54,8 +94,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for CoveredCall<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::CoveredCall;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::covered_call::CoveredCall;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params = OptionParameters {
     ///     s: 100.0,
@@ -67,12 +109,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for CoveredCall<'a, T> {
     /// let covered_call = CoveredCall::new(&model, params);
     /// let price = covered_call.price();
     /// println!("Covered Call Strategy Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the call option with strike price `k`.
-        let call_price = self.model.call_price(&self.params);
+        self.strategy().price()
+    }
 
-        // The covered call strategy price is the current value of the underlying asset minus
-        // the price of the call option.
-        self.params.s - call_price
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a `Dance` option strategy.
@@ -46,6 +48,29 @@ impl<'a, T: OptionPricingModel> Dance<'a, T> {
             params3,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: three long calls.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params1.clone()))
+            .with_leg(Leg::call(Side::Long, self.params2.clone()))
+            .with_leg(Leg::call(Side::Long, self.params3.clone()))
+    }
+
+    /// Checks the legs; see `Strategy::validate`.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()
+    }
+
+    /// Creates a new `Dance`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params1: OptionParameters,
+        params2: OptionParameters,
+        params3: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params1, params2, params3), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for Dance<'a, T> {
@@ -70,8 +95,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Dance<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::Dance;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::dance::Dance;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params1 = OptionParameters {
     ///     s: 100.0,
@@ -97,13 +124,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Dance<'a, T> {
     /// let dance_strategy = Dance::new(&model, params1, params2, params3);
     /// let price = dance_strategy.price();
     /// println!("Dance Strategy Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of each call option with strike prices `k1`, `k2`, and `k3`.
-        let call_price1 = self.model.call_price(&self.params1);
-        let call_price2 = self.model.call_price(&self.params2);
-        let call_price3 = self.model.call_price(&self.params3);
+        self.strategy().price()
+    }
 
-        // The total price of the Dance strategy is the sum of the call option prices.
-        call_price1 + call_price2 + call_price3
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -0,0 +1,175 @@
+use crate::math::random::SeededSource;
+use crate::models::{
+    BinomialTreeModel, BlackScholesModel, GarchModel, MonteCarloModel, OptionPricingModel,
+};
+use crate::strategies::shared::{SharedModel, SharedStrategy};
+use crate::strategies::strategy::{Leg, StockLeg, Strategy};
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The pricing model named in a strategy definition.
+///
+/// Serialized with a `type` tag, e.g. `{"type": "binomial_tree", "steps": 200}`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModelSpec {
+    /// Closed-form Black-Scholes.
+    #[default]
+    BlackScholes,
+    /// Cox-Ross-Rubinstein binomial tree.
+    BinomialTree { steps: usize },
+    /// Monte Carlo simulation, reproducible when `seed` is given.
+    MonteCarlo {
+        simulations: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed: Option<u64>,
+    },
+    /// GARCH(1,1) simulation with per-period variance parameters.
+    Garch {
+        steps: usize,
+        omega: f64,
+        alpha: f64,
+        beta: f64,
+    },
+}
+
+impl ModelSpec {
+    /// Returns the `type` tag of the specification, e.g. `"binomial_tree"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ModelSpec::BlackScholes => "black_scholes",
+            ModelSpec::BinomialTree { .. } => "binomial_tree",
+            ModelSpec::MonteCarlo { .. } => "monte_carlo",
+            ModelSpec::Garch { .. } => "garch",
+        }
+    }
+
+    /// Builds the pricing model, using the crate defaults for any setting the
+    /// specification does not carry.
+    pub fn build(&self) -> Box<dyn OptionPricingModel> {
+        self.boxed()
+    }
+
+    /// Builds the pricing model behind an `Arc`, for strategies that own their model; see
+    /// `SharedStrategy`.
+    pub fn shared(&self) -> SharedModel {
+        Arc::from(self.boxed())
+    }
+
+    /// Builds the pricing model as a thread-safe trait object.
+    fn boxed(&self) -> Box<dyn OptionPricingModel + Send + Sync> {
+        match *self {
+            ModelSpec::BlackScholes => Box::new(BlackScholesModel),
+            ModelSpec::BinomialTree { steps } => Box::new(BinomialTreeModel {
+                steps,
+                ..BinomialTreeModel::default()
+            }),
+            ModelSpec::MonteCarlo { simulations, seed } => {
+                let model = MonteCarloModel::new(simulations, 0.01);
+                match seed {
+                    Some(seed) => Box::new(model.with_rng(SeededSource::<StdRng>::new(seed))),
+                    None => Box::new(model),
+                }
+            }
+            ModelSpec::Garch {
+                steps,
+                omega,
+                alpha,
+                beta,
+            } => Box::new(GarchModel {
+                steps,
+                omega,
+                alpha,
+                beta,
+                ..GarchModel::default()
+            }),
+        }
+    }
+}
+
+/// A serializable strategy: the model choice plus its legs.
+///
+/// This is the interchange format for strategies. A `Strategy` borrows its model, so a
+/// definition is turned into one in two steps:
+///
+/// use core::strategies::StrategyDefinition;
+/// let definition = StrategyDefinition::from_json(json)?;
+/// let model = definition.model.build();
+/// let strategy = definition.strategy(model.as_ref());
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrategyDefinition {
+    /// The pricing model; Black-Scholes when omitted.
+    #[serde(default)]
+    pub model: ModelSpec,
+    /// The option legs.
+    pub legs: Vec<Leg>,
+    /// The stock legs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stock: Vec<StockLeg>,
+}
+
+impl StrategyDefinition {
+    /// Parses a definition from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes the definition to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Builds a strategy from the legs, valued with `model`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model to value the legs with, usually `self.model.build()`.
+    pub fn strategy<'a, T: OptionPricingModel + ?Sized>(&self, model: &'a T) -> Strategy<'a, T> {
+        Strategy {
+            model,
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        }
+    }
+
+    /// Builds a strategy that owns the model named in the definition.
+    pub fn shared(&self) -> SharedStrategy {
+        SharedStrategy {
+            model: self.model.shared(),
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        }
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Parses a strategy definition and values its legs with `model`, ignoring the model
+    /// named in the JSON. Use `StrategyDefinition` to honour that choice instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The strategy definition.
+    /// * `model` - The model to value the legs with.
+    pub fn from_json(json: &str, model: &'a T) -> Result<Self, serde_json::Error> {
+        Ok(StrategyDefinition::from_json(json)?.strategy(model))
+    }
+
+    /// Returns the serializable definition of the strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The specification of the model the strategy is valued with.
+    pub fn definition(&self, model: ModelSpec) -> StrategyDefinition {
+        StrategyDefinition {
+            model,
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        }
+    }
+
+    /// Serializes the strategy to JSON; see `definition`.
+    pub fn to_json(&self, model: ModelSpec) -> Result<String, serde_json::Error> {
+        self.definition(model).to_json()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{near_before_far, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a `DiagonalSpread` option strategy.
@@ -35,6 +37,29 @@ impl<'a, T: OptionPricingModel> DiagonalSpread<'a, T> {
             far_params,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: a long far-term call and a short near-term call.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.far_params.clone()))
+            .with_leg(Leg::call(Side::Short, self.near_params.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the near leg does not expire
+    /// first.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()?;
+        near_before_far(&self.near_params, &self.far_params)
+    }
+
+    /// Creates a new `DiagonalSpread`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        near_params: OptionParameters,
+        far_params: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, near_params, far_params), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for DiagonalSpread<'a, T> {
@@ -60,8 +85,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for DiagonalSpread<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::DiagonalSpread;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::diagonal::DiagonalSpread;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let near_params = OptionParameters {
     ///     s: 100.0,
@@ -80,14 +107,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for DiagonalSpread<'a, T> {
     /// let diagonal_spread = DiagonalSpread::new(&model, near_params, far_params);
     /// let price = diagonal_spread.price();
     /// println!("Diagonal Spread Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the near-term (short) call option.
-        let near_leg = self.model.call_price(&self.near_params);
-
-        // Calculate the price of the far-term (long) call option.
-        let far_leg = self.model.call_price(&self.far_params);
+        self.strategy().price()
+    }
 
-        // The total price of the Diagonal Spread strategy is the difference between the long and short call option prices.
-        far_leg - near_leg
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
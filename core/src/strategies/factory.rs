@@ -0,0 +1,416 @@
+use crate::models::{BlackScholesModel, OptionParameters, OptionPricingModel};
+use crate::strategies::box_spread::BoxSpread;
+use crate::strategies::broken_wing_butterfly::BrokenWingButterfly;
+use crate::strategies::butterfly::ButterflySpread;
+use crate::strategies::calendar::CalendarSpread;
+use crate::strategies::christmas_tree::ChristmasTree;
+use crate::strategies::collar::Collar;
+use crate::strategies::condor::Condor;
+use crate::strategies::covered_call::CoveredCall;
+use crate::strategies::dance::Dance;
+use crate::strategies::definition::{ModelSpec, StrategyDefinition};
+use crate::strategies::diagonal::DiagonalSpread;
+use crate::strategies::guts::Guts;
+use crate::strategies::iron_butterfly::IronButterfly;
+use crate::strategies::iron_condor::IronCondor;
+use crate::strategies::ladder::Ladder;
+use crate::strategies::single_leg::SingleLegOption;
+use crate::strategies::straddle::Straddle;
+use crate::strategies::strangle::Strangle;
+use crate::strategies::strategy::Strategy;
+use crate::strategies::validation::StrategyError;
+use crate::strategies::vertical::VerticalSpread;
+use crate::strategies::OptionStrategy;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+
+/// The built-in strategies, named in snake case (e.g. `"iron_condor"`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StrategyKind {
+    SingleLeg,
+    CoveredCall,
+    Straddle,
+    Strangle,
+    Guts,
+    VerticalSpread,
+    Butterfly,
+    BrokenWingButterfly,
+    ChristmasTree,
+    Ladder,
+    Condor,
+    IronButterfly,
+    IronCondor,
+    BoxSpread,
+    Collar,
+    CalendarSpread,
+    DiagonalSpread,
+    Dance,
+}
+
+impl StrategyKind {
+    /// Every built-in strategy.
+    pub const ALL: [StrategyKind; 18] = [
+        StrategyKind::SingleLeg,
+        StrategyKind::CoveredCall,
+        StrategyKind::Straddle,
+        StrategyKind::Strangle,
+        StrategyKind::Guts,
+        StrategyKind::VerticalSpread,
+        StrategyKind::Butterfly,
+        StrategyKind::BrokenWingButterfly,
+        StrategyKind::ChristmasTree,
+        StrategyKind::Ladder,
+        StrategyKind::Condor,
+        StrategyKind::IronButterfly,
+        StrategyKind::IronCondor,
+        StrategyKind::BoxSpread,
+        StrategyKind::Collar,
+        StrategyKind::CalendarSpread,
+        StrategyKind::DiagonalSpread,
+        StrategyKind::Dance,
+    ];
+
+    /// Returns the snake-case name of the strategy.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StrategyKind::SingleLeg => "single_leg",
+            StrategyKind::CoveredCall => "covered_call",
+            StrategyKind::Straddle => "straddle",
+            StrategyKind::Strangle => "strangle",
+            StrategyKind::Guts => "guts",
+            StrategyKind::VerticalSpread => "vertical_spread",
+            StrategyKind::Butterfly => "butterfly",
+            StrategyKind::BrokenWingButterfly => "broken_wing_butterfly",
+            StrategyKind::ChristmasTree => "christmas_tree",
+            StrategyKind::Ladder => "ladder",
+            StrategyKind::Condor => "condor",
+            StrategyKind::IronButterfly => "iron_butterfly",
+            StrategyKind::IronCondor => "iron_condor",
+            StrategyKind::BoxSpread => "box_spread",
+            StrategyKind::Collar => "collar",
+            StrategyKind::CalendarSpread => "calendar_spread",
+            StrategyKind::DiagonalSpread => "diagonal_spread",
+            StrategyKind::Dance => "dance",
+        }
+    }
+
+    /// Returns the parameters the strategy requires besides the shared `s`, `r`, `sigma`
+    /// and `t`.
+    ///
+    /// Strikes are `k` for single-strike strategies and `k1`, `k2`, ... in increasing
+    /// order otherwise; `t2` is the far expiry of calendar and diagonal spreads. The
+    /// optional flags `is_call` and `is_bull` default to `true`.
+    pub fn parameters(&self) -> &'static [&'static str] {
+        match self {
+            StrategyKind::SingleLeg | StrategyKind::CoveredCall | StrategyKind::Straddle => &["k"],
+            StrategyKind::Strangle
+            | StrategyKind::Guts
+            | StrategyKind::VerticalSpread
+            | StrategyKind::BoxSpread
+            | StrategyKind::Collar => &["k1", "k2"],
+            StrategyKind::Butterfly
+            | StrategyKind::BrokenWingButterfly
+            | StrategyKind::ChristmasTree
+            | StrategyKind::Ladder
+            | StrategyKind::IronButterfly
+            | StrategyKind::Dance => &["k1", "k2", "k3"],
+            StrategyKind::Condor | StrategyKind::IronCondor => &["k1", "k2", "k3", "k4"],
+            StrategyKind::CalendarSpread => &["k", "t2"],
+            StrategyKind::DiagonalSpread => &["k1", "k2", "t2"],
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = FactoryError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        StrategyKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| FactoryError::UnknownKind(name.to_string()))
+    }
+}
+
+/// Errors returned when a strategy cannot be built from a name and parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FactoryError {
+    /// The name does not match any built-in strategy.
+    UnknownKind(String),
+    /// A required parameter is absent.
+    MissingParameter(&'static str),
+    /// A parameter has the wrong JSON type.
+    WrongType(&'static str),
+    /// The input is not a JSON object of parameters.
+    Json(String),
+    /// The parameters describe an inconsistent strategy.
+    Strategy(StrategyError),
+}
+
+impl fmt::Display for FactoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactoryError::UnknownKind(name) => write!(f, "unknown strategy {:?}", name),
+            FactoryError::MissingParameter(name) => write!(f, "missing parameter {}", name),
+            FactoryError::WrongType(name) => write!(f, "parameter {} has the wrong type", name),
+            FactoryError::Json(message) => write!(f, "invalid strategy JSON: {}", message),
+            FactoryError::Strategy(err) => write!(f, "invalid strategy: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FactoryError {}
+
+impl From<StrategyError> for FactoryError {
+    fn from(err: StrategyError) -> Self {
+        FactoryError::Strategy(err)
+    }
+}
+
+/// Typed access to a JSON parameter map.
+struct Parameters<'p> {
+    map: &'p Map<String, Value>,
+}
+
+impl Parameters<'_> {
+    /// Reads a required number.
+    fn number(&self, name: &'static str) -> Result<f64, FactoryError> {
+        match self.map.get(name) {
+            Some(value) => value.as_f64().ok_or(FactoryError::WrongType(name)),
+            None => Err(FactoryError::MissingParameter(name)),
+        }
+    }
+
+    /// Reads an optional flag, `true` when absent.
+    fn flag(&self, name: &'static str) -> Result<bool, FactoryError> {
+        match self.map.get(name) {
+            Some(value) => value.as_bool().ok_or(FactoryError::WrongType(name)),
+            None => Ok(true),
+        }
+    }
+
+    /// Builds option parameters at the strike named `strike`, expiring at `t`.
+    fn option(&self, strike: &'static str) -> Result<OptionParameters, FactoryError> {
+        Ok(OptionParameters {
+            s: self.number("s")?,
+            k: self.number(strike)?,
+            r: self.number("r")?,
+            sigma: self.number("sigma")?,
+            t: self.number("t")?,
+        })
+    }
+}
+
+/// Builds a built-in strategy from its kind and a JSON object of parameters; see
+/// `StrategyKind::parameters` for the keys each kind reads.
+///
+/// The strategy is validated with its `try_new` constructor.
+///
+/// # Arguments
+///
+/// * `model` - The option pricing model used to value the strategy.
+/// * `kind` - The strategy to build.
+/// * `params` - The parameters, e.g. `{"s": 100, "r": 0.05, "sigma": 0.2, "t": 1, "k": 100}`.
+///
+/// # Example
+///
+/// ```
+/// use core::models::BlackScholesModel;
+/// use core::strategies::factory::{create_strategy, StrategyKind};
+/// use serde_json::json;
+/// let params = json!({"s": 100, "r": 0.05, "sigma": 0.2, "t": 1, "k": 100});
+/// let straddle = create_strategy(
+///     &BlackScholesModel,
+///     StrategyKind::Straddle,
+///     params.as_object().unwrap(),
+/// )
+/// .unwrap();
+/// assert!(straddle.price() > 0.0);
+/// ```
+pub fn create_strategy<'a, T: OptionPricingModel>(
+    model: &'a T,
+    kind: StrategyKind,
+    params: &Map<String, Value>,
+) -> Result<Box<dyn OptionStrategy + 'a>, FactoryError> {
+    build(model, kind, params).map(|(strategy, _)| strategy)
+}
+
+/// Builds the legs of a built-in strategy from its kind and parameters, as a definition
+/// valued with Black-Scholes; replace its `model` to value the legs otherwise.
+///
+/// The legs are validated like those of `create_strategy`, and the resulting `Strategy`
+/// offers what the built-in types do not, such as the per-leg breakdown, breakevens and
+/// maximum profit and loss. It values the position, so a structure that the built-in
+/// type quotes as a credit, such as an iron condor, has a negative price.
+///
+/// # Arguments
+///
+/// * `kind` - The strategy to build.
+/// * `params` - The parameters; see `create_strategy`.
+pub fn create_definition(
+    kind: StrategyKind,
+    params: &Map<String, Value>,
+) -> Result<StrategyDefinition, FactoryError> {
+    build(&BlackScholesModel, kind, params).map(|(_, legs)| legs.definition(ModelSpec::default()))
+}
+
+/// Builds a strategy both as its built-in type and as the equivalent leg-based strategy.
+#[allow(clippy::type_complexity)]
+fn build<'a, T: OptionPricingModel>(
+    model: &'a T,
+    kind: StrategyKind,
+    params: &Map<String, Value>,
+) -> Result<(Box<dyn OptionStrategy + 'a>, Strategy<'a, T>), FactoryError> {
+    macro_rules! built {
+        ($strategy:expr) => {{
+            let strategy = $strategy;
+            let legs = strategy.strategy();
+            (Box::new(strategy) as Box<dyn OptionStrategy + 'a>, legs)
+        }};
+    }
+    let p = Parameters { map: params };
+    let built = match kind {
+        StrategyKind::SingleLeg => built!(SingleLegOption::try_new(
+            model,
+            p.option("k")?,
+            p.flag("is_call")?,
+        )?),
+        StrategyKind::CoveredCall => built!(CoveredCall::try_new(model, p.option("k")?)?),
+        StrategyKind::Straddle => built!(Straddle::try_new(model, p.option("k")?)?),
+        StrategyKind::Strangle => {
+            built!(Strangle::try_new(model, p.option("k2")?, p.option("k1")?)?)
+        }
+        StrategyKind::Guts => built!(Guts::try_new(model, p.option("k1")?, p.option("k2")?)?),
+        StrategyKind::VerticalSpread => built!(VerticalSpread::try_new(
+            model,
+            p.option("k1")?,
+            p.option("k2")?,
+            p.flag("is_bull")?,
+        )?),
+        StrategyKind::Butterfly => built!(ButterflySpread::try_new(
+            model,
+            p.option("k1")?,
+            p.number("k2")?,
+            p.number("k3")?,
+        )?),
+        StrategyKind::BrokenWingButterfly => {
+            let body = p.option("k2")?;
+            built!(BrokenWingButterfly::try_new(
+                model,
+                body.clone(),
+                body.k - p.number("k1")?,
+                p.number("k3")? - body.k,
+                p.flag("is_call")?,
+            )?)
+        }
+        StrategyKind::ChristmasTree => built!(ChristmasTree::try_new(
+            model,
+            p.option("k1")?,
+            p.number("k2")?,
+            p.number("k3")?,
+            p.flag("is_call")?,
+        )?),
+        StrategyKind::Ladder => built!(Ladder::try_new(
+            model,
+            p.option("k1")?,
+            p.number("k2")?,
+            p.number("k3")?,
+            p.flag("is_call")?,
+        )?),
+        StrategyKind::Condor => built!(Condor::try_new(
+            model,
+            p.option("k1")?,
+            p.option("k2")?,
+            p.option("k3")?,
+            p.option("k4")?,
+        )?),
+        StrategyKind::IronButterfly => built!(IronButterfly::try_new(
+            model,
+            p.option("k1")?,
+            p.option("k2")?,
+            p.option("k2")?,
+            p.option("k3")?,
+        )?),
+        StrategyKind::IronCondor => built!(IronCondor::try_new(
+            model,
+            p.option("k1")?,
+            p.option("k2")?,
+            p.option("k3")?,
+            p.option("k4")?,
+        )?),
+        StrategyKind::BoxSpread => {
+            built!(BoxSpread::try_new(model, p.option("k1")?, p.number("k2")?)?)
+        }
+        StrategyKind::Collar => built!(Collar::try_new(
+            model,
+            p.number("s")?,
+            p.number("k1")?,
+            p.number("k2")?,
+            p.number("r")?,
+            p.number("sigma")?,
+            p.number("t")?,
+        )?),
+        StrategyKind::CalendarSpread => {
+            let near = p.option("k")?;
+            let far = OptionParameters {
+                t: p.number("t2")?,
+                ..near.clone()
+            };
+            built!(CalendarSpread::try_new(model, near, far)?)
+        }
+        StrategyKind::DiagonalSpread => {
+            let near = p.option("k1")?;
+            let far = OptionParameters {
+                t: p.number("t2")?,
+                ..p.option("k2")?
+            };
+            built!(DiagonalSpread::try_new(model, near, far)?)
+        }
+        StrategyKind::Dance => built!(Dance::try_new(
+            model,
+            p.option("k1")?,
+            p.option("k2")?,
+            p.option("k3")?,
+        )?),
+    };
+    Ok(built)
+}
+
+/// Builds a built-in strategy from its name and a JSON object of parameters; see
+/// `create_strategy`.
+pub fn create_strategy_by_name<'a, T: OptionPricingModel>(
+    model: &'a T,
+    name: &str,
+    params: &Map<String, Value>,
+) -> Result<Box<dyn OptionStrategy + 'a>, FactoryError> {
+    create_strategy(model, name.parse()?, params)
+}
+
+/// Builds a built-in strategy from a JSON object naming it under `kind` alongside its
+/// parameters, e.g. `{"kind": "straddle", "s": 100, "k": 100, ...}`; see
+/// `create_strategy`.
+pub fn create_strategy_from_json<'a, T: OptionPricingModel>(
+    model: &'a T,
+    json: &str,
+) -> Result<Box<dyn OptionStrategy + 'a>, FactoryError> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|err| FactoryError::Json(err.to_string()))?;
+    let map = value
+        .as_object()
+        .ok_or_else(|| FactoryError::Json("expected an object".to_string()))?;
+    let name = match map.get("kind") {
+        Some(Value::String(name)) => name,
+        Some(_) => return Err(FactoryError::WrongType("kind")),
+        None => return Err(FactoryError::MissingParameter("kind")),
+    };
+    create_strategy_by_name(model, name, map)
+}
@@ -0,0 +1,128 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strangle::Strangle;
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, same_expiry, validated, StrategyError};
+use crate::strategies::OptionStrategy;
+
+/// Represents a long guts strangle: an in-the-money call and an in-the-money put.
+///
+/// The call strike sits below the put strike, so the position always returns at least
+/// `k_put - k_call` at expiry. It has the same risk profile as the out-of-the-money
+/// strangle on the swapped strikes; the difference is mostly paid for in intrinsic value.
+pub struct Guts<'a, T: OptionPricingModel> {
+    /// The option pricing model used to price the options.
+    pub model: &'a T,
+
+    /// The parameters for the call option (lower strike).
+    pub params_call: OptionParameters,
+
+    /// The parameters for the put option (higher strike).
+    pub params_put: OptionParameters,
+}
+
+/// Extrinsic values of a guts and of the equivalent out-of-the-money strangle.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GutsComparison {
+    /// Price less intrinsic value of the guts.
+    pub guts_extrinsic: f64,
+    /// Price less intrinsic value of the strangle on the swapped strikes.
+    pub strangle_extrinsic: f64,
+    /// `guts_extrinsic - strangle_extrinsic`. By put-call parity this is
+    /// `(k_put - k_call)(e^{-rT} - 1)`, the carry on the guaranteed intrinsic value.
+    pub difference: f64,
+}
+
+impl<'a, T: OptionPricingModel> Guts<'a, T> {
+    /// Creates a new `Guts` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params_call` - The parameters for the in-the-money call.
+    /// * `params_put` - The parameters for the in-the-money put.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of `Guts`.
+    pub fn new(model: &'a T, params_call: OptionParameters, params_put: OptionParameters) -> Self {
+        Self {
+            model,
+            params_call,
+            params_put,
+        }
+    }
+
+    /// Builds the equivalent leg-based strategy: a long call and a long put, with the call
+    /// strike below the put strike.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params_call.clone()))
+            .with_leg(Leg::put(Side::Long, self.params_put.clone()))
+    }
+
+    /// Returns the out-of-the-money strangle with the strikes swapped.
+    pub fn equivalent_strangle(&self) -> Strangle<'a, T> {
+        Strangle::new(
+            self.model,
+            OptionParameters {
+                k: self.params_put.k,
+                ..self.params_call.clone()
+            },
+            OptionParameters {
+                k: self.params_call.k,
+                ..self.params_put.clone()
+            },
+        )
+    }
+
+    /// Compares the extrinsic value of the guts with that of the equivalent strangle.
+    ///
+    /// Both structures pay the same at expiry apart from a fixed `k_put - k_call`, so a
+    /// difference beyond the carry on that amount flags mispriced in-the-money options.
+    pub fn extrinsic_comparison(&self) -> GutsComparison {
+        let guts_extrinsic = self.price() - intrinsic(&self.strategy());
+        let strangle = self.equivalent_strangle();
+        let strangle_extrinsic = strangle.price() - intrinsic(&strangle.strategy());
+        GutsComparison {
+            guts_extrinsic,
+            strangle_extrinsic,
+            difference: guts_extrinsic - strangle_extrinsic,
+        }
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the legs expire at different
+    /// times or the call strike is not below the put strike.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params_call.k, self.params_put.k])?;
+        same_expiry(&[(0, &self.params_call), (1, &self.params_put)])
+    }
+
+    /// Creates a new `Guts`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params_call: OptionParameters,
+        params_put: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params_call, params_put), Self::validate)
+    }
+}
+
+/// Returns the value of exercising every leg at today's spot.
+fn intrinsic<T: OptionPricingModel + ?Sized>(strategy: &Strategy<'_, T>) -> f64 {
+    strategy
+        .legs
+        .iter()
+        .map(|leg| leg.payoff(leg.params.s))
+        .sum()
+}
+
+impl<'a, T: OptionPricingModel> OptionStrategy for Guts<'a, T> {
+    /// Calculates the price of the guts, the sum of the call and put prices.
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
@@ -0,0 +1,98 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, StockLeg, Strategy};
+
+/// Trades that neutralize a strategy's Delta, and optionally its Gamma.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hedge {
+    /// The underlying contracts to trade: positive to buy, negative to sell.
+    pub underlying: f64,
+    /// The option position that offsets Gamma, if one was requested.
+    pub option: Option<Leg>,
+    /// The net Greeks after the hedge, in units of the underlying.
+    pub residual: Greeks,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Calculates the underlying trade that makes the strategy Delta-neutral.
+    ///
+    /// Option Greeks are scaled by `option_multiplier` (shares per contract); stock legs
+    /// count one unit of Delta per share. The hedge is expressed in contracts of
+    /// `underlying_multiplier` shares each, e.g. 1 for stock or the contract size of a
+    /// future.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_multiplier` - The number of shares one option contract delivers.
+    /// * `underlying_multiplier` - The number of shares one hedge contract represents.
+    pub fn hedge(&self, option_multiplier: f64, underlying_multiplier: f64) -> Hedge {
+        let exposure = self.exposure(option_multiplier);
+        Hedge {
+            underlying: -exposure.delta / underlying_multiplier,
+            option: None,
+            residual: Greeks {
+                delta: 0.0,
+                ..exposure
+            },
+        }
+    }
+
+    /// Calculates the option trade that makes the strategy Gamma-neutral and the
+    /// underlying trade that then makes it Delta-neutral.
+    ///
+    /// The hedge option is bought or sold in whatever quantity cancels the strategy's
+    /// Gamma; its own Delta is included in the underlying trade. Both option positions
+    /// use the same `option_multiplier`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_type` - The type of the hedge option.
+    /// * `params` - The parameters of the hedge option.
+    /// * `option_multiplier` - The number of shares one option contract delivers.
+    /// * `underlying_multiplier` - The number of shares one hedge contract represents.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if the hedge option has no Gamma.
+    pub fn hedge_gamma(
+        &self,
+        option_type: OptionType,
+        params: OptionParameters,
+        option_multiplier: f64,
+        underlying_multiplier: f64,
+    ) -> Option<Hedge> {
+        let per_contract = self
+            .model
+            .option_greeks(&params, option_type)
+            .scale(option_multiplier);
+        if per_contract.gamma == 0.0 || !per_contract.gamma.is_finite() {
+            return None;
+        }
+
+        let exposure = self.exposure(option_multiplier);
+        let contracts = -exposure.gamma / per_contract.gamma;
+        let hedged = exposure + per_contract.scale(contracts);
+        let side = if contracts < 0.0 {
+            Side::Short
+        } else {
+            Side::Long
+        };
+        Some(Hedge {
+            underlying: -hedged.delta / underlying_multiplier,
+            option: Some(Leg::new(option_type, side, contracts.abs(), params)),
+            residual: Greeks {
+                delta: 0.0,
+                gamma: 0.0,
+                ..hedged
+            },
+        })
+    }
+
+    /// Returns the net Greeks in units of the underlying.
+    fn exposure(&self, option_multiplier: f64) -> Greeks {
+        let options: Greeks = self.legs.iter().map(|leg| leg.greeks(self.model)).sum();
+        let shares: f64 = self.stock.iter().map(StockLeg::signed_quantity).sum();
+        let mut exposure = options.scale(option_multiplier);
+        exposure.delta += shares;
+        exposure
+    }
+}
@@ -0,0 +1,189 @@
+use crate::math::roots::{brent, RootError};
+use crate::models::black_scholes::standard_normal_cdf;
+use crate::models::{OptionParameters, OptionPricingModel};
+use crate::strategies::straddle::Straddle;
+use crate::strategies::strangle::Strangle;
+use crate::strategies::strategy::Strategy;
+use crate::strategies::validation::{same_expiry, StrategyError};
+use std::fmt;
+
+/// Volatility range searched when implying a volatility.
+const VOLATILITY_BOUNDS: (f64, f64) = (1e-4, 5.0);
+
+/// The move in the underlying priced in by a straddle or strangle, by expiry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ImpliedMove {
+    /// The expected absolute move, `E|S_T - F|`, in price units.
+    pub absolute: f64,
+    /// The expected absolute move as a percentage of the spot price.
+    pub percent: f64,
+    /// The volatility at which the position reprices to the market.
+    pub volatility: f64,
+}
+
+/// Errors returned when a position's implied move cannot be found.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ImpliedMoveError {
+    /// The strategy has no option legs to imply a volatility from.
+    NoOptionLegs,
+    /// A leg is invalid, or the legs differ in spot or expiry.
+    Strategy(StrategyError),
+    /// No volatility in the searched range reprices the position.
+    Root(RootError),
+}
+
+impl fmt::Display for ImpliedMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImpliedMoveError::NoOptionLegs => write!(f, "the strategy has no option legs"),
+            ImpliedMoveError::Strategy(err) => write!(f, "invalid strategy: {}", err),
+            ImpliedMoveError::Root(err) => write!(f, "no implied volatility: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ImpliedMoveError {}
+
+impl From<StrategyError> for ImpliedMoveError {
+    fn from(err: StrategyError) -> Self {
+        ImpliedMoveError::Strategy(err)
+    }
+}
+
+impl From<RootError> for ImpliedMoveError {
+    fn from(err: RootError) -> Self {
+        ImpliedMoveError::Root(err)
+    }
+}
+
+/// Calculates the expected absolute move of the underlying by expiry under lognormal
+/// dynamics.
+///
+/// \[
+/// E|S_T - F| = 2F\left(2N\left(\tfrac{\sigma\sqrt{T}}{2}\right) - 1\right), \quad F = S e^{rT}
+/// \]
+///
+/// This is the undiscounted value of a straddle struck at the forward.
+///
+/// # Arguments
+///
+/// * `params` - The spot, rate and expiry; the strike and volatility are ignored.
+/// * `sigma` - The volatility.
+pub fn expected_move(params: &OptionParameters, sigma: f64) -> f64 {
+    let forward = params.s * (params.r * params.t).exp();
+    let half_width = 0.5 * sigma * params.t.sqrt();
+    2.0 * forward * (2.0 * standard_normal_cdf(half_width) - 1.0)
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Converts the market price of a long-volatility position, typically an ATM straddle
+    /// or a strangle, into the move it implies.
+    ///
+    /// The volatility at which the model reprices every leg to `market_price` is found
+    /// with Brent's method, and the expected move at that volatility is reported; see
+    /// `expected_move`. The legs must share their spot and expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `market_price` - The market price of the position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy has no option legs, the legs are invalid or differ
+    /// in spot or expiry, or no volatility reprices the position.
+    pub fn implied_move(&self, market_price: f64) -> Result<ImpliedMove, ImpliedMoveError> {
+        let params = self.move_params()?;
+        let (low, high) = VOLATILITY_BOUNDS;
+        let volatility = brent(
+            |sigma| self.with_volatility(sigma).price() - market_price,
+            low,
+            high,
+            1e-12,
+            200,
+        )?;
+        let absolute = expected_move(params, volatility);
+        Ok(ImpliedMove {
+            absolute,
+            percent: 100.0 * absolute / params.s,
+            volatility,
+        })
+    }
+
+    /// Prices the position at the volatility that implies an expected move of
+    /// `absolute_move` by expiry; the inverse of `implied_move`.
+    ///
+    /// # Arguments
+    ///
+    /// * `absolute_move` - The target expected move in price units.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the strategy has no option legs, the legs are invalid or differ
+    /// in spot or expiry, or no volatility implies the move.
+    pub fn price_for_move(&self, absolute_move: f64) -> Result<f64, ImpliedMoveError> {
+        let params = self.move_params()?;
+        let (low, high) = VOLATILITY_BOUNDS;
+        let volatility = brent(
+            |sigma| expected_move(params, sigma) - absolute_move,
+            low,
+            high,
+            1e-12,
+            200,
+        )?;
+        Ok(self.with_volatility(volatility).price())
+    }
+
+    /// Returns the parameters the expected move is taken from, once the legs are known to
+    /// share their spot and expiry.
+    fn move_params(&self) -> Result<&OptionParameters, ImpliedMoveError> {
+        let first = self.legs.first().ok_or(ImpliedMoveError::NoOptionLegs)?;
+        self.validate()?;
+        let legs: Vec<(usize, &OptionParameters)> = self
+            .legs
+            .iter()
+            .enumerate()
+            .map(|(i, leg)| (i, &leg.params))
+            .collect();
+        same_expiry(&legs)?;
+        Ok(&first.params)
+    }
+
+    /// Returns a copy of the strategy with every leg at volatility `sigma`.
+    fn with_volatility(&self, sigma: f64) -> Strategy<'a, T> {
+        let mut strategy = Strategy {
+            model: self.model,
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        };
+        for leg in &mut strategy.legs {
+            leg.params.sigma = sigma;
+        }
+        strategy
+    }
+}
+
+impl<'a, T: OptionPricingModel> Straddle<'a, T> {
+    /// Converts the market price of the straddle into the move it implies; see
+    /// `Strategy::implied_move`.
+    pub fn implied_move(&self, market_price: f64) -> Result<ImpliedMove, ImpliedMoveError> {
+        self.strategy().implied_move(market_price)
+    }
+
+    /// Prices the straddle for a target expected move; see `Strategy::price_for_move`.
+    pub fn price_for_move(&self, absolute_move: f64) -> Result<f64, ImpliedMoveError> {
+        self.strategy().price_for_move(absolute_move)
+    }
+}
+
+impl<'a, T: OptionPricingModel> Strangle<'a, T> {
+    /// Converts the market price of the strangle into the move it implies; see
+    /// `Strategy::implied_move`.
+    pub fn implied_move(&self, market_price: f64) -> Result<ImpliedMove, ImpliedMoveError> {
+        self.strategy().implied_move(market_price)
+    }
+
+    /// Prices the strangle for a target expected move; see `Strategy::price_for_move`.
+    pub fn price_for_move(&self, absolute_move: f64) -> Result<f64, ImpliedMoveError> {
+        self.strategy().price_for_move(absolute_move)
+    }
+}
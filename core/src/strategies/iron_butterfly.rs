@@ -1,4 +1,8 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{
+    legs_and_strikes, same_expiry, validated, wings_not_before, StrategyError,
+};
 use crate::strategies::OptionStrategy;
 
 /// Represents an `IronButterfly` option strategy.
@@ -10,6 +14,10 @@ use crate::strategies::OptionStrategy;
 /// - A short (near-term) put option with strike price `k2` (center strike).
 /// - A long call option with strike price `k3` (higher strike).
 /// - A long put option with strike price `k1` (lower strike).
+///
+/// Each leg carries its own parameters: the short call and put must share a strike and
+/// expiry, while the long wings may expire later (a calendarized iron butterfly).
+/// `from_body` builds the classic form from a single set of body parameters.
 pub struct IronButterfly<'a, T: OptionPricingModel> {
     /// The option pricing model used to price the options.
     pub model: &'a T,
@@ -17,22 +25,27 @@ pub struct IronButterfly<'a, T: OptionPricingModel> {
     /// The parameters for the long (lower strike) put option.
     pub params1: OptionParameters,
 
-    /// The parameters for the short (center strike) call and put options.
+    /// The parameters for the short (center strike) put option.
     pub params2: OptionParameters,
 
-    /// The parameters for the long (higher strike) call option.
+    /// The parameters for the short (center strike) call option.
     pub params3: OptionParameters,
+
+    /// The parameters for the long (higher strike) call option.
+    pub params4: OptionParameters,
 }
 
 impl<'a, T: OptionPricingModel> IronButterfly<'a, T> {
-    /// Creates a new `IronButterfly` option strategy instance.
+    /// Creates a new `IronButterfly` option strategy instance from the parameters of each
+    /// leg.
     ///
     /// # Arguments
     ///
     /// * `model` - The option pricing model to be used.
     /// * `params1` - The parameters for the long (lower strike) put option.
-    /// * `params2` - The parameters for the short (center strike) call and put options.
-    /// * `params3` - The parameters for the long (higher strike) call option.
+    /// * `params2` - The parameters for the short (center strike) put option.
+    /// * `params3` - The parameters for the short (center strike) call option.
+    /// * `params4` - The parameters for the long (higher strike) call option.
     ///
     /// # Returns
     ///
@@ -42,23 +55,84 @@ impl<'a, T: OptionPricingModel> IronButterfly<'a, T> {
         params1: OptionParameters,
         params2: OptionParameters,
         params3: OptionParameters,
+        params4: OptionParameters,
     ) -> Self {
         Self {
             model,
             params1,
             params2,
             params3,
+            params4,
         }
     }
+
+    /// Creates an `IronButterfly` whose short call and put share the body parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params1` - The parameters for the long (lower strike) put option.
+    /// * `body` - The parameters for the short (center strike) call and put options.
+    /// * `params4` - The parameters for the long (higher strike) call option.
+    pub fn from_body(
+        model: &'a T,
+        params1: OptionParameters,
+        body: OptionParameters,
+        params4: OptionParameters,
+    ) -> Self {
+        Self::new(model, params1, body.clone(), body, params4)
+    }
+
+    /// Builds the equivalent leg-based strategy: a long put below, a short put and call at
+    /// the centre strike and a long call above.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::put(Side::Long, self.params1.clone()))
+            .with_leg(Leg::put(Side::Short, self.params2.clone()))
+            .with_leg(Leg::call(Side::Short, self.params3.clone()))
+            .with_leg(Leg::call(Side::Long, self.params4.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the short legs differ in
+    /// strike or expiry, a wing expires before them, or the strikes are not increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(
+            &self.strategy(),
+            &[self.params1.k, self.params2.k, self.params4.k],
+        )?;
+        same_expiry(&[(1, &self.params2), (2, &self.params3)])?;
+        if self.params3.k != self.params2.k {
+            return Err(StrategyError::StrikeMismatch {
+                leg: 2,
+                expected: self.params2.k,
+                found: self.params3.k,
+            });
+        }
+        wings_not_before(&self.params2, &[(0, &self.params1), (3, &self.params4)])
+    }
+
+    /// Creates a new `IronButterfly`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params1: OptionParameters,
+        params2: OptionParameters,
+        params3: OptionParameters,
+        params4: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(
+            Self::new(model, params1, params2, params3, params4),
+            Self::validate,
+        )
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for IronButterfly<'a, T> {
     /// Calculates the price of the `IronButterfly` option strategy.
     ///
     /// The `IronButterfly` strategy is composed of four legs:
-    /// - A short (center strike) call option with strike price `params2.k`.
     /// - A short (center strike) put option with strike price `params2.k`.
-    /// - A long call option with strike price `params3.k`.
+    /// - A short (center strike) call option with strike price `params3.k`.
+    /// - A long call option with strike price `params4.k`.
     /// - A long put option with strike price `params1.k`.
     ///
     /// The price of the strategy is calculated as:
@@ -79,8 +153,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for IronButterfly<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::IronButterfly;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::iron_butterfly::IronButterfly;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params1 = OptionParameters {
     ///     s: 100.0,
@@ -103,23 +179,16 @@ impl<'a, T: OptionPricingModel> OptionStrategy for IronButterfly<'a, T> {
     ///     sigma: 0.2,
     ///     t: 0.5,
     /// };
-    /// let iron_butterfly = IronButterfly::new(&model, params1, params2, params3);
+    /// let iron_butterfly = IronButterfly::from_body(&model, params1, params2, params3);
     /// let price = iron_butterfly.price();
     /// println!("Iron Butterfly Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the short (center strike) call option with strike price `params2.k`.
-        let call_price = self.model.call_price(&self.params2);
-
-        // Calculate the price of the short (center strike) put option with strike price `params2.k`.
-        let put_price = self.model.put_price(&self.params2);
-
-        // Calculate the price of the long (higher strike) call option with strike price `params3.k`.
-        let long_call_price = self.model.call_price(&self.params3);
-
-        // Calculate the price of the long (lower strike) put option with strike price `params1.k`.
-        let long_put_price = self.model.put_price(&self.params1);
+        // Quoted as the net credit received for selling the structure.
+        -self.strategy().price()
+    }
 
-        // The total price of the Iron Butterfly strategy is the sum of the prices of the short options minus the prices of the long options.
-        call_price + put_price - long_call_price - long_put_price
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
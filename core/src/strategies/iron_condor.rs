@@ -1,4 +1,9 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::math::roots::RootError;
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{
+    legs_and_strikes, same_expiry, validated, wings_not_before, StrategyError,
+};
 use crate::strategies::OptionStrategy;
 
 /// Represents an `IronCondor` option strategy.
@@ -9,6 +14,9 @@ use crate::strategies::OptionStrategy;
 /// - A long call option with strike price `k3` (higher call strike).
 /// - A short put option with strike price `k1` (lower put strike).
 /// - A long put option with strike price `k4` (higher put strike).
+///
+/// The short body must share one expiry; the long wings may expire later (a calendarized
+/// iron condor).
 pub struct IronCondor<'a, T: OptionPricingModel> {
     /// The option pricing model used to price the options.
     pub model: &'a T,
@@ -55,6 +63,79 @@ impl<'a, T: OptionPricingModel> IronCondor<'a, T> {
             params4,
         }
     }
+
+    /// Creates an `IronCondor` from delta targets instead of strikes, e.g. a 16-delta iron
+    /// condor with 5-delta wings for `(0.16, 0.05)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The spot, rate, volatility and expiry; the strikes are solved for.
+    /// * `short_delta` - The absolute delta of the short put and call.
+    /// * `wing_delta` - The absolute delta of the long wings, below `short_delta`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the iron condor, or an error if a delta cannot be matched.
+    pub fn from_deltas(
+        model: &'a T,
+        params: OptionParameters,
+        short_delta: f64,
+        wing_delta: f64,
+    ) -> Result<Self, RootError> {
+        let strike = |option_type, delta| model.strike_for_delta(&params, option_type, delta);
+        let at = |k| OptionParameters {
+            k,
+            ..params.clone()
+        };
+        Ok(Self::new(
+            model,
+            at(strike(OptionType::Put, wing_delta)?),
+            at(strike(OptionType::Put, short_delta)?),
+            at(strike(OptionType::Call, short_delta)?),
+            at(strike(OptionType::Call, wing_delta)?),
+        ))
+    }
+
+    /// Builds the equivalent leg-based strategy: a long put, a short put, a short call and a long call at increasing strikes.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::put(Side::Long, self.params1.clone()))
+            .with_leg(Leg::put(Side::Short, self.params2.clone()))
+            .with_leg(Leg::call(Side::Short, self.params3.clone()))
+            .with_leg(Leg::call(Side::Long, self.params4.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the short legs expire at
+    /// different times, a wing expires before them, or the strikes are not strictly
+    /// increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(
+            &self.strategy(),
+            &[
+                self.params1.k,
+                self.params2.k,
+                self.params3.k,
+                self.params4.k,
+            ],
+        )?;
+        same_expiry(&[(1, &self.params2), (2, &self.params3)])?;
+        wings_not_before(&self.params2, &[(0, &self.params1), (3, &self.params4)])
+    }
+
+    /// Creates a new `IronCondor`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params1: OptionParameters,
+        params2: OptionParameters,
+        params3: OptionParameters,
+        params4: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(
+            Self::new(model, params1, params2, params3, params4),
+            Self::validate,
+        )
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for IronCondor<'a, T> {
@@ -84,8 +165,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for IronCondor<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::IronCondor;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::iron_condor::IronCondor;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params1 = OptionParameters {
     ///     s: 100.0,
@@ -118,20 +201,13 @@ impl<'a, T: OptionPricingModel> OptionStrategy for IronCondor<'a, T> {
     /// let iron_condor = IronCondor::new(&model, params1, params2, params3, params4);
     /// let price = iron_condor.price();
     /// println!("Iron Condor Price: {}", price);
+    /// ```
     fn price(&self) -> f64 {
-        // Calculate the price of the short put option with strike price `params2.k`.
-        let put_price1 = self.model.put_price(&self.params2);
-
-        // Calculate the price of the long put option with strike price `params1.k`.
-        let put_price2 = self.model.put_price(&self.params1);
-
-        // Calculate the price of the short call option with strike price `params3.k`.
-        let call_price1 = self.model.call_price(&self.params3);
-
-        // Calculate the price of the long call option with strike price `params4.k`.
-        let call_price2 = self.model.call_price(&self.params4);
+        // Quoted as the net credit received for selling the structure.
+        -self.strategy().price()
+    }
 
-        // The total price of the Iron Condor strategy is the sum of the price differences of the puts and calls.
-        put_price1 - put_price2 + call_price1 - call_price2
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
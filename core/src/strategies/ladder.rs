@@ -0,0 +1,100 @@
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, validated, StrategyError};
+use crate::strategies::OptionStrategy;
+
+/// Represents a call or put ladder (a 1x1x1 "Christmas ladder").
+///
+/// The bull call ladder buys one call at `k1` and sells one each at `k2` and `k3`: a bull
+/// call spread financed by a further short call, so the loss is unbounded above `k3`. The
+/// bear put ladder mirrors it, buying one put at `k3` and selling one each at `k2` and `k1`.
+pub struct Ladder<'a, T: OptionPricingModel> {
+    /// The option pricing model used to price the options.
+    pub model: &'a T,
+
+    /// The parameters for the options; `params.k` is the lowest strike.
+    pub params: OptionParameters,
+
+    /// The middle strike.
+    pub k2: f64,
+
+    /// The highest strike.
+    pub k3: f64,
+
+    /// Whether the ladder is built from calls (`true`) or puts (`false`).
+    pub is_call: bool,
+}
+
+impl<'a, T: OptionPricingModel> Ladder<'a, T> {
+    /// Creates a new `Ladder` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The parameters for the options at the lowest strike.
+    /// * `k2` - The middle strike.
+    /// * `k3` - The highest strike.
+    /// * `is_call` - `true` for a call ladder, `false` for a put ladder.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of `Ladder`.
+    pub fn new(model: &'a T, params: OptionParameters, k2: f64, k3: f64, is_call: bool) -> Self {
+        Self {
+            model,
+            params,
+            k2,
+            k3,
+            is_call,
+        }
+    }
+
+    /// Builds the equivalent leg-based strategy: long the first strike, short the other two.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let at = |k: f64| OptionParameters {
+            k,
+            ..self.params.clone()
+        };
+        let (option_type, long, far) = if self.is_call {
+            (OptionType::Call, self.params.k, self.k3)
+        } else {
+            (OptionType::Put, self.k3, self.params.k)
+        };
+        Strategy::new(self.model)
+            .with_leg(Leg::new(option_type, Side::Long, 1.0, at(long)))
+            .with_leg(Leg::new(option_type, Side::Short, 1.0, at(self.k2)))
+            .with_leg(Leg::new(option_type, Side::Short, 1.0, at(far)))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the strikes are not strictly
+    /// increasing.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params.k, self.k2, self.k3])
+    }
+
+    /// Creates a new `Ladder`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params: OptionParameters,
+        k2: f64,
+        k3: f64,
+        is_call: bool,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params, k2, k3, is_call), Self::validate)
+    }
+}
+
+impl<'a, T: OptionPricingModel> OptionStrategy for Ladder<'a, T> {
+    /// Calculates the net debit of the ladder (negative for a net credit).
+    ///
+    /// \[
+    /// \text{Price} = C(K_1) - C(K_2) - C(K_3)
+    /// \]
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
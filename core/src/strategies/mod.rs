@@ -1,17 +1,51 @@
+pub mod assignment;
+pub mod box_spread;
+pub mod breakdown;
+pub mod broken_wing_butterfly;
 pub mod butterfly;
 pub mod calendar;
+pub mod christmas_tree;
 pub mod collar;
+pub mod comparison;
 pub mod condor;
 pub mod covered_call;
 pub mod dance;
+pub mod definition;
 pub mod diagonal;
+pub mod factory;
+pub mod guts;
+pub mod hedge;
+pub mod implied_move;
 pub mod iron_butterfly;
 pub mod iron_condor;
+pub mod ladder;
+pub mod optimizer;
+pub mod roll;
+pub mod shared;
 pub mod single_leg;
 pub mod straddle;
 pub mod strangle;
+pub mod strategy;
+pub mod validation;
 pub mod vertical;
 
+pub use breakdown::{LegBreakdown, LegKind};
+pub use comparison::{ModelComparison, ModelValuation};
+pub use definition::{ModelSpec, StrategyDefinition};
+pub use factory::{FactoryError, StrategyKind};
+pub use roll::RollAnalysis;
+pub use shared::{SharedModel, SharedStrategy};
+pub use strategy::{
+    spot_grid, Breakeven, BreakevenDirection, Leg, PnlSurface, Side, StockLeg, Strategy,
+};
+pub use validation::StrategyError;
+
+use crate::models::Greeks;
+
 pub trait OptionStrategy {
     fn price(&self) -> f64;
+
+    /// Returns the net delta, gamma, vega, theta and rho of the position, with short legs
+    /// contributing negatively. Units follow the underlying pricing model.
+    fn greeks(&self) -> Greeks;
 }
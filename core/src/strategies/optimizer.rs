@@ -0,0 +1,360 @@
+use crate::models::{OptionParameters, OptionPricingModel, OptionType};
+use crate::portfolio::{Portfolio, ShockGrid};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use std::fmt;
+
+/// One leg of a strategy shape, with its strike left open.
+///
+/// `strike` indexes the shape's strike slots: slot 0 receives the lowest strike of each
+/// candidate, slot 1 the next, and so on, so slots are always filled in increasing order.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LegTemplate {
+    /// Call or put.
+    pub option_type: OptionType,
+    /// Bought or sold.
+    pub side: Side,
+    /// Number of contracts.
+    pub quantity: f64,
+    /// The strike slot.
+    pub strike: usize,
+}
+
+impl LegTemplate {
+    /// Creates a one-lot leg template.
+    pub fn new(option_type: OptionType, side: Side, strike: usize) -> Self {
+        Self {
+            option_type,
+            side,
+            quantity: 1.0,
+            strike,
+        }
+    }
+}
+
+/// Returns the shape of a short iron condor: long put, short put, short call, long call.
+pub fn iron_condor_template() -> Vec<LegTemplate> {
+    vec![
+        LegTemplate::new(OptionType::Put, Side::Long, 0),
+        LegTemplate::new(OptionType::Put, Side::Short, 1),
+        LegTemplate::new(OptionType::Call, Side::Short, 2),
+        LegTemplate::new(OptionType::Call, Side::Long, 3),
+    ]
+}
+
+/// Returns the shape of a put credit spread: long the lower put, short the higher put.
+pub fn put_credit_spread_template() -> Vec<LegTemplate> {
+    vec![
+        LegTemplate::new(OptionType::Put, Side::Long, 0),
+        LegTemplate::new(OptionType::Put, Side::Short, 1),
+    ]
+}
+
+/// Returns the shape of a call credit spread: short the lower call, long the higher call.
+pub fn call_credit_spread_template() -> Vec<LegTemplate> {
+    vec![
+        LegTemplate::new(OptionType::Call, Side::Short, 0),
+        LegTemplate::new(OptionType::Call, Side::Long, 1),
+    ]
+}
+
+/// The quantity an optimizer maximizes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Objective {
+    /// Risk-neutral expected P&L at expiry; see `Strategy::expected_pnl`.
+    ExpectedPnl,
+    /// Probability of profit at expiry; see `Strategy::probability_of_profit`.
+    ProbabilityOfProfit,
+    /// Net credit divided by the distance between the outer strikes.
+    CreditToWidth,
+}
+
+/// Limits a candidate must satisfy to be considered.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Constraints {
+    /// The largest acceptable expiry loss.
+    pub max_loss: Option<f64>,
+    /// The acceptable range of net delta, inclusive.
+    pub delta_band: Option<(f64, f64)>,
+    /// The smallest acceptable probability of profit.
+    pub min_probability: Option<f64>,
+    /// The largest acceptable margin requirement, measured as the worst loss under
+    /// `ShockGrid::standard()`; see `Portfolio::margin`.
+    pub max_margin: Option<f64>,
+}
+
+/// Errors returned when building an optimizer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OptimizerError {
+    /// The volatility is not a positive finite number.
+    InvalidVolatility(f64),
+}
+
+impl fmt::Display for OptimizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptimizerError::InvalidVolatility(sigma) => {
+                write!(f, "volatility must be positive, got {}", sigma)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OptimizerError {}
+
+/// A strategy found by the optimizer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    /// The legs, with strikes and expiry filled in.
+    pub legs: Vec<Leg>,
+    /// The strikes assigned to the slots, in increasing order.
+    pub strikes: Vec<f64>,
+    /// The common expiry of the legs.
+    pub expiry: f64,
+    /// The objective value.
+    pub score: f64,
+}
+
+impl Candidate {
+    /// Builds the candidate as a strategy valued with `model`.
+    pub fn strategy<'a, T: OptionPricingModel + ?Sized>(&self, model: &'a T) -> Strategy<'a, T> {
+        Strategy {
+            model,
+            legs: self.legs.clone(),
+            stock: Vec::new(),
+        }
+    }
+}
+
+/// Searches a grid of listed strikes and expiries for the best strategy of a given shape.
+///
+/// Every increasing assignment of strikes to the template's slots is tried at every
+/// expiry, candidates breaking the constraints are discarded, and the rest are ranked by
+/// the objective. Candidates whose objective cannot be computed, such as those expiring
+/// now when the objective needs the terminal distribution, are skipped. The search is
+/// exhaustive, so its cost grows with `C(strikes, slots) × expiries`.
+///
+/// # Example
+///
+/// ```
+/// use core::models::{BlackScholesModel, OptionParameters};
+/// use core::strategies::optimizer::{iron_condor_template, Objective, StrategyOptimizer};
+/// let params = OptionParameters {
+///     s: 100.0,
+///     k: 100.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let strikes: Vec<f64> = (80..=120).step_by(5).map(f64::from).collect();
+/// let model = BlackScholesModel;
+/// let template = iron_condor_template();
+/// let optimizer =
+///     StrategyOptimizer::new(&model, params, strikes, vec![0.25, 0.5], template).unwrap();
+/// let best = optimizer.optimize(Objective::ProbabilityOfProfit, 5);
+/// assert_eq!(best.len(), 5);
+/// ```
+pub struct StrategyOptimizer<'a, T: OptionPricingModel + ?Sized> {
+    /// The option pricing model used to value candidates.
+    pub model: &'a T,
+    /// The spot, rate and volatility; the strike and expiry are searched.
+    pub params: OptionParameters,
+    /// The listed strikes.
+    pub strikes: Vec<f64>,
+    /// The listed expiries in years.
+    pub expiries: Vec<f64>,
+    /// The shape of the strategy.
+    pub template: Vec<LegTemplate>,
+    /// The limits candidates must satisfy.
+    pub constraints: Constraints,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> StrategyOptimizer<'a, T> {
+    /// Creates a new `StrategyOptimizer` without constraints, or fails if `params.sigma`
+    /// is not positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to value candidates.
+    /// * `params` - The spot, rate and volatility.
+    /// * `strikes` - The listed strikes.
+    /// * `expiries` - The listed expiries in years.
+    /// * `template` - The shape of the strategy.
+    pub fn new(
+        model: &'a T,
+        params: OptionParameters,
+        strikes: Vec<f64>,
+        expiries: Vec<f64>,
+        template: Vec<LegTemplate>,
+    ) -> Result<Self, OptimizerError> {
+        if !(params.sigma.is_finite() && params.sigma > 0.0) {
+            return Err(OptimizerError::InvalidVolatility(params.sigma));
+        }
+        let mut strikes = strikes;
+        strikes.sort_by(|a, b| a.total_cmp(b));
+        strikes.dedup();
+        Ok(Self {
+            model,
+            params,
+            strikes,
+            expiries,
+            template,
+            constraints: Constraints::default(),
+        })
+    }
+
+    /// Sets the constraints.
+    pub fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Finds the best candidates for one of the built-in objectives.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - The quantity to maximize.
+    /// * `top` - The number of candidates to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `top` candidates, best first.
+    pub fn optimize(&self, objective: Objective, top: usize) -> Vec<Candidate> {
+        self.optimize_by(
+            |strategy, strikes| match objective {
+                Objective::ExpectedPnl => strategy.expected_pnl(None).unwrap_or(f64::NAN),
+                Objective::ProbabilityOfProfit => {
+                    strategy.probability_of_profit(None).unwrap_or(f64::NAN)
+                }
+                Objective::CreditToWidth => {
+                    let width = strikes[strikes.len() - 1] - strikes[0];
+                    if width > 0.0 {
+                        -strategy.price() / width
+                    } else {
+                        f64::NEG_INFINITY
+                    }
+                }
+            },
+            top,
+        )
+    }
+
+    /// Finds the best candidates for a user-supplied objective.
+    ///
+    /// # Arguments
+    ///
+    /// * `objective` - Scores a candidate strategy given its slot strikes; higher is better,
+    ///   and NaN skips the candidate.
+    /// * `top` - The number of candidates to return.
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `top` candidates, best first.
+    pub fn optimize_by<F>(&self, objective: F, top: usize) -> Vec<Candidate>
+    where
+        F: Fn(&Strategy<'a, T>, &[f64]) -> f64,
+    {
+        let slots = self.template.iter().map(|leg| leg.strike + 1).max();
+        let Some(slots) = slots else {
+            return Vec::new();
+        };
+
+        if slots > self.strikes.len() {
+            return Vec::new();
+        }
+
+        let mut candidates = Vec::new();
+        let mut indices: Vec<usize> = (0..slots).collect();
+        loop {
+            let strikes: Vec<f64> = indices.iter().map(|&i| self.strikes[i]).collect();
+            for &expiry in &self.expiries {
+                let legs = self.legs(&strikes, expiry);
+                let strategy = Strategy {
+                    model: self.model,
+                    legs,
+                    stock: Vec::new(),
+                };
+                if !self.admissible(&strategy) {
+                    continue;
+                }
+                let score = objective(&strategy, &strikes);
+                if score.is_nan() {
+                    continue;
+                }
+                candidates.push(Candidate {
+                    legs: strategy.legs,
+                    strikes: strikes.clone(),
+                    expiry,
+                    score,
+                });
+            }
+            if !next_combination(&mut indices, self.strikes.len()) {
+                break;
+            }
+        }
+
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates.truncate(top);
+        candidates
+    }
+
+    /// Fills the template with strikes and an expiry.
+    fn legs(&self, strikes: &[f64], expiry: f64) -> Vec<Leg> {
+        self.template
+            .iter()
+            .map(|leg| {
+                let params = OptionParameters {
+                    k: strikes[leg.strike],
+                    t: expiry,
+                    ..self.params.clone()
+                };
+                Leg::new(leg.option_type, leg.side, leg.quantity, params)
+            })
+            .collect()
+    }
+
+    /// Checks a candidate against the constraints.
+    fn admissible(&self, strategy: &Strategy<'a, T>) -> bool {
+        let constraints = &self.constraints;
+        if let Some(max_loss) = constraints.max_loss {
+            if strategy.max_loss() > max_loss {
+                return false;
+            }
+        }
+        if let Some((low, high)) = constraints.delta_band {
+            let delta = strategy.greeks().delta;
+            if delta < low || delta > high {
+                return false;
+            }
+        }
+        if let Some(min_probability) = constraints.min_probability {
+            match strategy.probability_of_profit(None) {
+                Ok(probability) if probability >= min_probability => {}
+                _ => return false,
+            }
+        }
+        if let Some(max_margin) = constraints.max_margin {
+            let mut book = Portfolio::new();
+            book.add_strategy(strategy);
+            if book.margin(self.model, &ShockGrid::standard()).total > max_margin {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Advances `indices` to the next increasing combination of `0..n`, returning `false`
+/// after the last one.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
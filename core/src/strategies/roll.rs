@@ -0,0 +1,82 @@
+use crate::models::{Greeks, OptionPricingModel};
+use crate::strategies::strategy::{Breakeven, Strategy};
+
+/// The effect of closing one position and opening another in its place.
+///
+/// Both positions are marked with the model today, so breakevens are measured against
+/// each position's own current value rather than the premium originally paid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollAnalysis {
+    /// The cost of the roll: positive for a net debit, negative for a net credit.
+    pub cost: f64,
+    /// The expiry breakevens of the current position.
+    pub breakevens_before: Vec<Breakeven>,
+    /// The expiry breakevens of the new position.
+    pub breakevens_after: Vec<Breakeven>,
+    /// The net Greeks of the current position.
+    pub greeks_before: Greeks,
+    /// The net Greeks of the new position.
+    pub greeks_after: Greeks,
+    /// The change in net Greeks, new less current.
+    pub greeks_change: Greeks,
+}
+
+impl RollAnalysis {
+    /// Returns `true` if the roll is done for a net credit.
+    pub fn is_credit(&self) -> bool {
+        self.cost < 0.0
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Analyzes rolling this position into `target`.
+    ///
+    /// The roll sells the current legs at their model value and buys the target legs, so
+    /// its cost is the difference in value. Stock legs held in both positions cancel out.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The position after the roll.
+    pub fn roll_to(&self, target: &Strategy<'_, T>) -> RollAnalysis {
+        let greeks_before = self.greeks();
+        let greeks_after = target.greeks();
+        RollAnalysis {
+            cost: target.price() - self.price(),
+            breakevens_before: self.breakevens(),
+            breakevens_after: target.breakevens(),
+            greeks_before,
+            greeks_after,
+            greeks_change: greeks_after - greeks_before,
+        }
+    }
+
+    /// Builds the position rolled to a new expiry and/or shifted strikes.
+    ///
+    /// Every option leg keeps its side, type and quantity; stock legs are carried over.
+    /// Entry prices are dropped since the rolled legs have not been traded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `expiry` - The new time to expiry in years, or `None` to keep each leg's own.
+    /// * `strike_shift` - The amount added to every strike.
+    pub fn rolled(&self, expiry: Option<f64>, strike_shift: f64) -> Strategy<'a, T> {
+        let legs = self
+            .legs
+            .iter()
+            .map(|leg| {
+                let mut leg = leg.clone();
+                leg.params.k += strike_shift;
+                if let Some(t) = expiry {
+                    leg.params.t = t;
+                }
+                leg.entry_price = None;
+                leg
+            })
+            .collect();
+        Strategy {
+            model: self.model,
+            legs,
+            stock: self.stock.clone(),
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::models::{Greeks, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, StockLeg, Strategy};
+use crate::strategies::OptionStrategy;
+use std::sync::Arc;
+
+/// A pricing model that can be shared between owners and threads.
+pub type SharedModel = Arc<dyn OptionPricingModel + Send + Sync>;
+
+/// A strategy that owns a shared handle to its model.
+///
+/// `Strategy` borrows its model, which ties it to the model's scope. `SharedStrategy`
+/// holds an `Arc` instead, so it can be stored in collections, returned from factories and
+/// sent to other threads. The analytics live on `Strategy`; `strategy()` borrows one for
+/// as long as they are needed.
+///
+/// # Example
+///
+/// ```
+/// use core::models::{BlackScholesModel, OptionParameters};
+/// use core::strategies::shared::SharedStrategy;
+/// use core::strategies::{Leg, Side};
+/// use std::sync::Arc;
+/// let params_95 = OptionParameters {
+///     s: 100.0,
+///     k: 95.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let params_105 = OptionParameters {
+///     s: 100.0,
+///     k: 105.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let spread = SharedStrategy::new(Arc::new(BlackScholesModel))
+///     .with_leg(Leg::call(Side::Long, params_95))
+///     .with_leg(Leg::call(Side::Short, params_105));
+/// let handle = std::thread::spawn(move || spread.strategy().breakevens());
+/// assert_eq!(handle.join().unwrap().len(), 1);
+/// ```
+#[derive(Clone)]
+pub struct SharedStrategy {
+    /// The option pricing model used to value every leg.
+    pub model: SharedModel,
+    /// The option legs.
+    pub legs: Vec<Leg>,
+    /// The stock legs.
+    pub stock: Vec<StockLeg>,
+}
+
+impl SharedStrategy {
+    /// Creates an empty strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to value every leg.
+    pub fn new(model: SharedModel) -> Self {
+        Self {
+            model,
+            legs: Vec::new(),
+            stock: Vec::new(),
+        }
+    }
+
+    /// Adds an option leg.
+    pub fn with_leg(mut self, leg: Leg) -> Self {
+        self.legs.push(leg);
+        self
+    }
+
+    /// Adds a stock leg; see `Strategy::with_stock`.
+    pub fn with_stock(mut self, side: Side, quantity: f64, spot: f64) -> Self {
+        self.stock.push(StockLeg {
+            side,
+            quantity,
+            spot,
+            entry_price: None,
+        });
+        self
+    }
+
+    /// Borrows the position as a `Strategy` for analysis.
+    pub fn strategy(&self) -> Strategy<'_, dyn OptionPricingModel + Send + Sync> {
+        Strategy {
+            model: self.model.as_ref(),
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        }
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Copies the legs into a `SharedStrategy` valued with `model`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The shared model, normally the same model this strategy borrows.
+    pub fn to_shared(&self, model: SharedModel) -> SharedStrategy {
+        SharedStrategy {
+            model,
+            legs: self.legs.clone(),
+            stock: self.stock.clone(),
+        }
+    }
+}
+
+impl OptionStrategy for SharedStrategy {
+    /// Calculates the net value of the strategy.
+    fn price(&self) -> f64 {
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a single leg of an option (either a call or a put).
@@ -44,6 +46,30 @@ impl<'a, T: OptionPricingModel> SingleLegOption<'a, T> {
             is_call,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: a single long call or put.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let leg = if self.is_call {
+            Leg::call(Side::Long, self.params.clone())
+        } else {
+            Leg::put(Side::Long, self.params.clone())
+        };
+        Strategy::new(self.model).with_leg(leg)
+    }
+
+    /// Checks the legs; see `Strategy::validate`.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()
+    }
+
+    /// Creates a new `SingleLegOption`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params: OptionParameters,
+        is_call: bool,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params, is_call), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for SingleLegOption<'a, T> {
@@ -58,8 +84,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for SingleLegOption<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::SingleLegOption;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::single_leg::SingleLegOption;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params_call = OptionParameters {
     ///     s: 100.0,
@@ -81,11 +109,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for SingleLegOption<'a, T> {
     /// let put_price = put_option.price();
     /// println!("Call Option Price: {}", call_price);
     /// println!("Put Option Price: {}", put_price);
+    /// ```
     fn price(&self) -> f64 {
-        if self.is_call {
-            self.model.call_price(&self.params)
-        } else {
-            self.model.put_price(&self.params)
-        }
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a straddle option strategy.
@@ -35,6 +37,23 @@ impl<'a, T: OptionPricingModel> Straddle<'a, T> {
     pub fn new(model: &'a T, params: OptionParameters) -> Self {
         Self { model, params }
     }
+
+    /// Builds the equivalent leg-based strategy: a long call and a long put at the same strike.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params.clone()))
+            .with_leg(Leg::put(Side::Long, self.params.clone()))
+    }
+
+    /// Checks the legs; see `Strategy::validate`.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        self.strategy().validate()
+    }
+
+    /// Creates a new `Straddle`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(model: &'a T, params: OptionParameters) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for Straddle<'a, T> {
@@ -48,8 +67,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Straddle<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::Straddle;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::straddle::Straddle;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params = OptionParameters {
     ///     s: 100.0,
@@ -61,9 +82,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Straddle<'a, T> {
     /// let straddle = Straddle::new(&model, params);
     /// let straddle_price = straddle.price();
     /// println!("Straddle Price: {}", straddle_price);
+    /// ```
     fn price(&self) -> f64 {
-        let call_price = self.model.call_price(&self.params);
-        let put_price = self.model.put_price(&self.params);
-        call_price + put_price
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
@@ -1,4 +1,7 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::math::roots::RootError;
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, same_expiry, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a strangle option strategy.
@@ -35,6 +38,58 @@ impl<'a, T: OptionPricingModel> Strangle<'a, T> {
             params_put,
         }
     }
+
+    /// Creates a `Strangle` whose call and put have the same absolute delta, e.g. a
+    /// 25-delta strangle for `0.25`.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model to be used.
+    /// * `params` - The spot, rate, volatility and expiry; the strikes are solved for.
+    /// * `delta` - The absolute delta of each leg.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strangle, or an error if no strike has that delta.
+    pub fn from_delta(
+        model: &'a T,
+        params: OptionParameters,
+        delta: f64,
+    ) -> Result<Self, RootError> {
+        let k_call = model.strike_for_delta(&params, OptionType::Call, delta)?;
+        let k_put = model.strike_for_delta(&params, OptionType::Put, delta)?;
+        Ok(Self::new(
+            model,
+            OptionParameters {
+                k: k_call,
+                ..params.clone()
+            },
+            OptionParameters { k: k_put, ..params },
+        ))
+    }
+
+    /// Builds the equivalent leg-based strategy: a long call and a long put at different strikes.
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        Strategy::new(self.model)
+            .with_leg(Leg::call(Side::Long, self.params_call.clone()))
+            .with_leg(Leg::put(Side::Long, self.params_put.clone()))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the legs expire at different
+    /// times or the put strike is not below the call strike.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        legs_and_strikes(&self.strategy(), &[self.params_put.k, self.params_call.k])?;
+        same_expiry(&[(0, &self.params_call), (1, &self.params_put)])
+    }
+
+    /// Creates a new `Strangle`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params_call: OptionParameters,
+        params_put: OptionParameters,
+    ) -> Result<Self, StrategyError> {
+        validated(Self::new(model, params_call, params_put), Self::validate)
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for Strangle<'a, T> {
@@ -48,8 +103,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Strangle<'a, T> {
     ///
     /// # Example
     ///
-    /// use crate::models::{BlackScholesModel, OptionParameters};
-    /// use crate::strategies::Strangle;
+    /// ```
+    /// use core::models::{BlackScholesModel, OptionParameters};
+    /// use core::strategies::strangle::Strangle;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params_call = OptionParameters {
     ///     s: 100.0,
@@ -68,9 +125,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for Strangle<'a, T> {
     /// let strangle = Strangle::new(&model, params_call, params_put);
     /// let strangle_price = strangle.price();
     /// println!("Strangle Price: {}", strangle_price);
+    /// ```
     fn price(&self) -> f64 {
-        let call_price = self.model.call_price(&self.params_call);
-        let put_price = self.model.put_price(&self.params_put);
-        call_price + put_price
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
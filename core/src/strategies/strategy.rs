@@ -0,0 +1,696 @@
+use crate::math::roots::brent;
+use crate::models::black_scholes::{standard_normal_cdf, standard_normal_pdf};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::validation::StrategyError;
+use crate::strategies::OptionStrategy;
+use serde::{Deserialize, Serialize};
+
+/// Whether a position is bought or sold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Long,
+    Short,
+}
+
+impl Side {
+    /// Returns `1.0` for long positions and `-1.0` for short ones.
+    pub fn sign(&self) -> f64 {
+        match self {
+            Side::Long => 1.0,
+            Side::Short => -1.0,
+        }
+    }
+}
+
+/// One option position within a strategy.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Leg {
+    /// Call or put.
+    pub option_type: OptionType,
+    /// Bought or sold.
+    pub side: Side,
+    /// Number of contracts (positive; the direction comes from `side`).
+    #[serde(default = "one_lot")]
+    pub quantity: f64,
+    /// The option's parameters, including its own strike and expiry.
+    pub params: OptionParameters,
+    /// The premium paid (long) or received (short) per contract when the leg was opened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry_price: Option<f64>,
+}
+
+impl Leg {
+    /// Creates a new `Leg`.
+    ///
+    /// # Arguments
+    ///
+    /// * `option_type` - Call or put.
+    /// * `side` - Long or short.
+    /// * `quantity` - The number of contracts.
+    /// * `params` - The option parameters.
+    pub fn new(
+        option_type: OptionType,
+        side: Side,
+        quantity: f64,
+        params: OptionParameters,
+    ) -> Self {
+        Self {
+            option_type,
+            side,
+            quantity,
+            params,
+            entry_price: None,
+        }
+    }
+
+    /// Records the premium per contract at which the leg was opened.
+    pub fn with_entry_price(mut self, entry_price: f64) -> Self {
+        self.entry_price = Some(entry_price);
+        self
+    }
+
+    /// Returns the signed premium paid at entry (negative when received), if recorded.
+    pub fn entry_value(&self) -> Option<f64> {
+        self.entry_price.map(|price| self.signed_quantity() * price)
+    }
+
+    /// Creates a one-lot call leg.
+    pub fn call(side: Side, params: OptionParameters) -> Self {
+        Self::new(OptionType::Call, side, 1.0, params)
+    }
+
+    /// Creates a one-lot put leg.
+    pub fn put(side: Side, params: OptionParameters) -> Self {
+        Self::new(OptionType::Put, side, 1.0, params)
+    }
+
+    /// Returns the quantity with the sign of the side.
+    pub fn signed_quantity(&self) -> f64 {
+        self.side.sign() * self.quantity
+    }
+
+    /// Calculates the signed value of the leg under `model`.
+    pub fn value<T: OptionPricingModel + ?Sized>(&self, model: &T) -> f64 {
+        self.signed_quantity() * model.option_price(&self.params, self.option_type)
+    }
+
+    /// Calculates the signed Greeks of the leg under `model`.
+    pub fn greeks<T: OptionPricingModel + ?Sized>(&self, model: &T) -> Greeks {
+        model
+            .option_greeks(&self.params, self.option_type)
+            .scale(self.signed_quantity())
+    }
+
+    /// Calculates the signed payoff of the leg at expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The underlying price at expiry.
+    pub fn payoff(&self, spot: f64) -> f64 {
+        let intrinsic = match self.option_type {
+            OptionType::Call => (spot - self.params.k).max(0.0),
+            OptionType::Put => (self.params.k - spot).max(0.0),
+        };
+        self.signed_quantity() * intrinsic
+    }
+}
+
+/// A position in the underlying within a strategy.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StockLeg {
+    /// Bought or sold.
+    pub side: Side,
+    /// Number of shares (positive; the direction comes from `side`).
+    pub quantity: f64,
+    /// The current price of the underlying.
+    pub spot: f64,
+    /// The price per share at which the position was opened.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry_price: Option<f64>,
+}
+
+impl StockLeg {
+    /// Returns the quantity with the sign of the side.
+    pub fn signed_quantity(&self) -> f64 {
+        self.side.sign() * self.quantity
+    }
+
+    /// Returns the signed cost at entry, if recorded.
+    pub fn entry_value(&self) -> Option<f64> {
+        self.entry_price.map(|price| self.signed_quantity() * price)
+    }
+}
+
+/// A strategy built from any combination of option legs and stock legs.
+///
+/// Values follow the holder's perspective: long legs add their value, short legs subtract
+/// it, so `price()` is the net debit to open the position (negative for a net credit).
+///
+/// # Example
+///
+/// ```
+/// use core::models::{BlackScholesModel, OptionParameters};
+/// use core::strategies::strategy::{Leg, Side, Strategy};
+/// let params_95 = OptionParameters {
+///     s: 100.0,
+///     k: 95.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let params_105 = OptionParameters {
+///     s: 100.0,
+///     k: 105.0,
+///     r: 0.05,
+///     sigma: 0.2,
+///     t: 0.5,
+/// };
+/// let model = BlackScholesModel;
+/// let spread = Strategy::new(&model)
+///     .with_leg(Leg::call(Side::Long, params_95))
+///     .with_leg(Leg::call(Side::Short, params_105));
+/// let (debit, greeks) = (spread.price(), spread.greeks());
+/// assert!(debit > 0.0 && greeks.delta > 0.0);
+/// ```
+pub struct Strategy<'a, T: OptionPricingModel + ?Sized> {
+    /// The option pricing model used to value every leg.
+    pub model: &'a T,
+    /// The option legs.
+    pub legs: Vec<Leg>,
+    /// The stock legs.
+    pub stock: Vec<StockLeg>,
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Creates an empty strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The option pricing model used to value every leg.
+    pub fn new(model: &'a T) -> Self {
+        Self {
+            model,
+            legs: Vec::new(),
+            stock: Vec::new(),
+        }
+    }
+
+    /// Adds an option leg.
+    pub fn with_leg(mut self, leg: Leg) -> Self {
+        self.legs.push(leg);
+        self
+    }
+
+    /// Adds a stock leg.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Long or short.
+    /// * `quantity` - The number of shares.
+    /// * `spot` - The current price of the underlying.
+    pub fn with_stock(mut self, side: Side, quantity: f64, spot: f64) -> Self {
+        self.stock.push(StockLeg {
+            side,
+            quantity,
+            spot,
+            entry_price: None,
+        });
+        self
+    }
+
+    /// Adds a stock leg opened at `entry_price` per share.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - Long or short.
+    /// * `quantity` - The number of shares.
+    /// * `spot` - The current price of the underlying.
+    /// * `entry_price` - The price per share paid or received at entry.
+    pub fn with_stock_entry(
+        mut self,
+        side: Side,
+        quantity: f64,
+        spot: f64,
+        entry_price: f64,
+    ) -> Self {
+        self.stock.push(StockLeg {
+            side,
+            quantity,
+            spot,
+            entry_price: Some(entry_price),
+        });
+        self
+    }
+
+    /// Calculates the net premium at entry: a debit when positive, a credit when negative.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` unless every leg has an entry price.
+    pub fn entry_cost(&self) -> Option<f64> {
+        let options: Option<f64> = self.legs.iter().map(Leg::entry_value).sum();
+        let stock: Option<f64> = self.stock.iter().map(StockLeg::entry_value).sum();
+        Some(options? + stock?)
+    }
+
+    /// Calculates the unrealized P&L: the mark-to-model value less the entry cost.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` unless every leg has an entry price.
+    pub fn unrealized_pnl(&self) -> Option<f64> {
+        self.entry_cost().map(|cost| self.price() - cost)
+    }
+
+    /// Calculates the net value of the strategy.
+    pub fn price(&self) -> f64 {
+        let options: f64 = self.legs.iter().map(|leg| leg.value(self.model)).sum();
+        let stock: f64 = self
+            .stock
+            .iter()
+            .map(|leg| leg.signed_quantity() * leg.spot)
+            .sum();
+        options + stock
+    }
+
+    /// Calculates the net Greeks of the strategy. Each share contributes a delta of one.
+    pub fn greeks(&self) -> Greeks {
+        let options: Greeks = self.legs.iter().map(|leg| leg.greeks(self.model)).sum();
+        let shares: f64 = self.stock.iter().map(StockLeg::signed_quantity).sum();
+        Greeks {
+            delta: options.delta + shares,
+            ..options
+        }
+    }
+
+    /// Calculates the net payoff at expiry, before premiums.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The underlying price at expiry.
+    pub fn payoff(&self, spot: f64) -> f64 {
+        let options: f64 = self.legs.iter().map(|leg| leg.payoff(spot)).sum();
+        let shares: f64 = self.stock.iter().map(StockLeg::signed_quantity).sum();
+        options + shares * spot
+    }
+
+    /// Revalues the strategy with the underlying at `spot` after `days` calendar days.
+    ///
+    /// Legs with time remaining are priced by the model at their shortened expiry; legs
+    /// that have expired are settled at intrinsic value. Rates and volatilities are held
+    /// constant.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot` - The underlying price.
+    /// * `days` - The number of calendar days from today.
+    pub fn value_at(&self, spot: f64, days: f64) -> f64 {
+        self.value_after(spot, days / 365.0)
+    }
+
+    /// Revalues the strategy after `elapsed` years; see `value_at`.
+    fn value_after(&self, spot: f64, elapsed: f64) -> f64 {
+        let options: f64 = self
+            .legs
+            .iter()
+            .map(|leg| {
+                let t = leg.params.t - elapsed;
+                if t <= 0.0 {
+                    return leg.payoff(spot);
+                }
+                let params = OptionParameters {
+                    s: spot,
+                    t,
+                    ..leg.params.clone()
+                };
+                leg.signed_quantity() * self.model.option_price(&params, leg.option_type)
+            })
+            .sum();
+        let shares: f64 = self.stock.iter().map(StockLeg::signed_quantity).sum();
+        options + shares * spot
+    }
+
+    /// Calculates the theoretical P&L on a grid of spot prices and elapsed days.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_grid` - The underlying prices.
+    /// * `days` - The calendar days from today, e.g. `[0.0, 15.0, 30.0]`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `PnlSurface` of model value less today's value.
+    pub fn pnl_surface(&self, spot_grid: &[f64], days: &[f64]) -> PnlSurface {
+        let premium = self.price();
+        let pnl = days
+            .iter()
+            .map(|&day| {
+                spot_grid
+                    .iter()
+                    .map(|&spot| self.value_at(spot, day) - premium)
+                    .collect()
+            })
+            .collect();
+        PnlSurface {
+            spots: spot_grid.to_vec(),
+            days: days.to_vec(),
+            pnl,
+        }
+    }
+
+    /// Returns zero and the strikes, sorted: the points where the expiry payoff can bend.
+    fn knots(&self) -> Vec<f64> {
+        let mut knots: Vec<f64> = self.legs.iter().map(|leg| leg.params.k).collect();
+        knots.push(0.0);
+        knots.sort_by(|a, b| a.total_cmp(b));
+        knots.dedup();
+        knots
+    }
+
+    /// Returns the expiry P&L at every knot and its slope above the highest one.
+    fn pnl_extremes(&self) -> (f64, f64, f64) {
+        let premium = self.price();
+        let pnl = |spot: f64| self.payoff(spot) - premium;
+        let knots = self.knots();
+        let last = *knots.last().unwrap_or(&0.0);
+        let values = knots.iter().map(|&spot| pnl(spot));
+        let low = values.clone().fold(f64::INFINITY, f64::min);
+        let high = values.fold(f64::NEG_INFINITY, f64::max);
+        (low, high, pnl(last + 1.0) - pnl(last))
+    }
+
+    /// Calculates the largest expiry profit, net of today's premium.
+    ///
+    /// # Returns
+    ///
+    /// Returns the maximum profit, or infinity if the profit grows without bound as the
+    /// underlying rises.
+    pub fn max_profit(&self) -> f64 {
+        let (_, high, slope) = self.pnl_extremes();
+        if slope > 1e-12 {
+            f64::INFINITY
+        } else {
+            high
+        }
+    }
+
+    /// Calculates the largest expiry loss, net of today's premium, as a positive number.
+    ///
+    /// # Returns
+    ///
+    /// Returns the maximum loss, or infinity if the loss grows without bound as the
+    /// underlying rises.
+    pub fn max_loss(&self) -> f64 {
+        let (low, _, slope) = self.pnl_extremes();
+        if slope < -1e-12 {
+            f64::INFINITY
+        } else {
+            -low
+        }
+    }
+
+    /// Finds the expiry breakeven prices, net of today's premium.
+    ///
+    /// The expiry P&L is piecewise linear between strikes, so every interval between
+    /// consecutive strikes (and the tails below the lowest and above the highest) is
+    /// searched with Brent's method. Prices where the P&L only touches zero are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the breakevens sorted by price; empty if the P&L never changes sign.
+    pub fn breakevens(&self) -> Vec<Breakeven> {
+        let premium = self.price();
+        let pnl = |spot: f64| self.payoff(spot) - premium;
+
+        let mut knots = self.knots();
+
+        // Beyond the highest strike the P&L is linear; extend far enough to cross zero.
+        let last = *knots.last().unwrap_or(&0.0);
+        let slope = pnl(last + 1.0) - pnl(last);
+        if slope != 0.0 {
+            knots.push(last + pnl(last).abs() / slope.abs() + 1.0);
+        }
+
+        let mut roots: Vec<f64> = Vec::new();
+        for window in knots.windows(2) {
+            let (a, b) = (window[0], window[1]);
+            let (fa, fb) = (sign(pnl(a)), sign(pnl(b)));
+            if fa == fb {
+                continue;
+            }
+            if let Ok(root) = brent(pnl, a, b, 1e-10, 100) {
+                if roots.last().is_none_or(|&prev| (root - prev).abs() > 1e-8) {
+                    roots.push(root);
+                }
+            }
+        }
+
+        roots
+            .into_iter()
+            .filter_map(|price| {
+                let h = 1e-6 * price.max(1.0);
+                let (below, above) = (sign(pnl(price - h)), sign(pnl(price + h)));
+                let direction = match (below, above) {
+                    (b, a) if a > b && a > 0 => BreakevenDirection::Lower,
+                    (b, a) if a < b && b > 0 => BreakevenDirection::Upper,
+                    _ => return None,
+                };
+                Some(Breakeven { price, direction })
+            })
+            .collect()
+    }
+
+    /// Returns the earliest expiry and the lognormal parameters of the log spot at that
+    /// time, or `None` if the strategy has no option legs.
+    ///
+    /// One distribution drives every leg, so the legs must be valid and share their spot
+    /// and volatility. Legs may expire at different times: the distribution runs to the
+    /// earliest expiry and later legs are revalued there.
+    fn terminal_distribution(
+        &self,
+        drift: Option<f64>,
+    ) -> Result<Option<(f64, f64, f64)>, StrategyError> {
+        self.validate()?;
+        let Some(first) = self.legs.first() else {
+            return Ok(None);
+        };
+        let sigma = first.params.sigma;
+        if let Some((leg, other)) = self
+            .legs
+            .iter()
+            .enumerate()
+            .find(|(_, leg)| (leg.params.sigma - sigma).abs() > 1e-12 * sigma)
+        {
+            return Err(StrategyError::VolatilityMismatch {
+                leg,
+                expected: sigma,
+                found: other.params.sigma,
+            });
+        }
+        let horizon = self
+            .legs
+            .iter()
+            .map(|leg| leg.params.t)
+            .fold(f64::INFINITY, f64::min);
+        let mu = drift.unwrap_or(first.params.r);
+        let mean = first.params.s.ln() + (mu - 0.5 * sigma * sigma) * horizon;
+        Ok(Some((horizon, mean, sigma * horizon.sqrt())))
+    }
+
+    /// Calculates the probability that the position shows a profit at the earliest expiry.
+    ///
+    /// The underlying follows a geometric Brownian motion from the legs' common spot with
+    /// their common volatility. Legs expiring later are revalued with the model, so calendars and
+    /// diagonals are handled. The P&L is scanned on a fine grid of terminal prices, each
+    /// sign change is refined with Brent's method, and the lognormal probabilities of the
+    /// profitable intervals are summed.
+    ///
+    /// # Arguments
+    ///
+    /// * `drift` - The real-world drift, or `None` for the risk-neutral drift `r`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the probability of profit, or zero for a strategy without option legs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a leg is invalid or the legs differ in spot or volatility.
+    pub fn probability_of_profit(&self, drift: Option<f64>) -> Result<f64, StrategyError> {
+        let Some((horizon, mean, sd)) = self.terminal_distribution(drift)? else {
+            return Ok(0.0);
+        };
+        let premium = self.price();
+        let pnl = |z: f64| self.value_after((mean + sd * z).exp(), horizon) - premium;
+
+        let step = 2.0 * TERMINAL_WIDTH / TERMINAL_GRID as f64;
+        let mut probability = 0.0;
+        let (mut start, mut profitable) = (-TERMINAL_WIDTH, pnl(-TERMINAL_WIDTH) > 0.0);
+        for i in 1..=TERMINAL_GRID {
+            let z = -TERMINAL_WIDTH + i as f64 * step;
+            let now = pnl(z) > 0.0;
+            if now != profitable {
+                let edge = brent(pnl, z - step, z, 1e-12, 100).unwrap_or(z);
+                if profitable {
+                    probability += standard_normal_cdf(edge) - standard_normal_cdf(start);
+                }
+                start = edge;
+                profitable = now;
+            }
+        }
+        if profitable {
+            probability += 1.0 - standard_normal_cdf(start);
+        }
+        Ok(probability)
+    }
+
+    /// Calculates the expected P&L at the earliest expiry under the lognormal distribution
+    /// of `probability_of_profit`, by Simpson's rule over the standardized log price.
+    ///
+    /// With `drift` of `None` the expectation is risk-neutral and undiscounted, so it
+    /// reflects only the carry on the premium.
+    ///
+    /// # Arguments
+    ///
+    /// * `drift` - The real-world drift, or `None` for the risk-neutral drift `r`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a leg is invalid or the legs differ in spot or volatility.
+    pub fn expected_pnl(&self, drift: Option<f64>) -> Result<f64, StrategyError> {
+        let Some((horizon, mean, sd)) = self.terminal_distribution(drift)? else {
+            return Ok(0.0);
+        };
+        let premium = self.price();
+        let step = 2.0 * TERMINAL_WIDTH / TERMINAL_GRID as f64;
+        let integral: f64 = (0..=TERMINAL_GRID)
+            .map(|i| {
+                let z = -TERMINAL_WIDTH + i as f64 * step;
+                let weight = if i == 0 || i == TERMINAL_GRID {
+                    1.0
+                } else if i % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+                let value = self.value_after((mean + sd * z).exp(), horizon) - premium;
+                weight * value * standard_normal_pdf(z)
+            })
+            .sum();
+        Ok(integral * step / 3.0)
+    }
+
+    /// Calculates the expiry payoff across a grid of underlying prices.
+    ///
+    /// Every leg is settled at intrinsic value, so for structures with several expiries
+    /// (calendars, diagonals) this is the payoff if all legs expired together.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_grid` - The underlying prices at expiry.
+    ///
+    /// # Returns
+    ///
+    /// Returns `(spot, payoff)` pairs in the order of `spot_grid`.
+    pub fn payoff_curve(&self, spot_grid: &[f64]) -> Vec<(f64, f64)> {
+        spot_grid
+            .iter()
+            .map(|&spot| (spot, self.payoff(spot)))
+            .collect()
+    }
+
+    /// Calculates the expiry P&L net of the premium paid (or received) today.
+    ///
+    /// # Arguments
+    ///
+    /// * `spot_grid` - The underlying prices at expiry.
+    ///
+    /// # Returns
+    ///
+    /// Returns `(spot, payoff - price)` pairs in the order of `spot_grid`.
+    pub fn pnl_curve(&self, spot_grid: &[f64]) -> Vec<(f64, f64)> {
+        let premium = self.price();
+        spot_grid
+            .iter()
+            .map(|&spot| (spot, self.payoff(spot) - premium))
+            .collect()
+    }
+}
+
+/// Number of (even) Simpson intervals used to integrate over the terminal distribution.
+const TERMINAL_GRID: usize = 2000;
+/// Half-width, in standard deviations, of the terminal log-price grid.
+const TERMINAL_WIDTH: f64 = 8.0;
+
+/// Which side of a breakeven price the position makes money on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BreakevenDirection {
+    /// The lower edge of a profitable region: P&L turns positive as the price rises through it.
+    Lower,
+    /// The upper edge of a profitable region: P&L turns negative as the price rises through it.
+    Upper,
+}
+
+/// An underlying price at which the expiry P&L is zero.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Breakeven {
+    /// The underlying price at expiry.
+    pub price: f64,
+    /// Whether profit lies above (`Lower`) or below (`Upper`) this price.
+    pub direction: BreakevenDirection,
+}
+
+/// Theoretical P&L of a strategy over a grid of spot prices and future dates.
+///
+/// `pnl[i][j]` is the P&L after `days[i]` calendar days with the underlying at `spots[j]`;
+/// each row is one "T+n" line of a risk graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PnlSurface {
+    /// The underlying prices (columns).
+    pub spots: Vec<f64>,
+    /// The calendar days elapsed from today (rows).
+    pub days: Vec<f64>,
+    /// The P&L relative to today's value, indexed `[day][spot]`.
+    pub pnl: Vec<Vec<f64>>,
+}
+
+/// Default quantity for legs deserialized without one.
+fn one_lot() -> f64 {
+    1.0
+}
+
+/// Returns -1, 0 or 1; unlike `f64::signum`, zero maps to zero.
+fn sign(x: f64) -> i8 {
+    if x > 0.0 {
+        1
+    } else if x < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Returns `points` evenly spaced prices from `low` to `high` inclusive, for use as a
+/// `spot_grid`.
+pub fn spot_grid(low: f64, high: f64, points: usize) -> Vec<f64> {
+    match points {
+        0 => Vec::new(),
+        1 => vec![low],
+        _ => {
+            let step = (high - low) / (points - 1) as f64;
+            (0..points).map(|i| low + i as f64 * step).collect()
+        }
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> OptionStrategy for Strategy<'a, T> {
+    /// Calculates the net value of the strategy.
+    fn price(&self) -> f64 {
+        Strategy::price(self)
+    }
+
+    fn greeks(&self) -> Greeks {
+        Strategy::greeks(self)
+    }
+}
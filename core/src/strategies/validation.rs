@@ -0,0 +1,266 @@
+use crate::models::{OptionParameters, OptionPricingModel};
+use crate::strategies::strategy::Strategy;
+use std::fmt;
+
+/// Errors returned when a strategy is constructed from inconsistent legs.
+///
+/// Leg indices count option legs first, followed by stock legs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StrategyError {
+    /// A parameter is not finite, or is not positive where it must be.
+    InvalidParameter {
+        leg: usize,
+        field: &'static str,
+        value: f64,
+    },
+    /// A quantity is not a positive finite number.
+    InvalidQuantity { leg: usize, quantity: f64 },
+    /// A leg is priced off a different underlying price than the first leg.
+    SpotMismatch {
+        leg: usize,
+        expected: f64,
+        found: f64,
+    },
+    /// A leg is priced with a different volatility than the first leg, where one
+    /// distribution of the underlying is needed.
+    VolatilityMismatch {
+        leg: usize,
+        expected: f64,
+        found: f64,
+    },
+    /// A leg expires at a different time than the structure requires (or, for a wing,
+    /// before the body).
+    ExpiryMismatch {
+        leg: usize,
+        expected: f64,
+        found: f64,
+    },
+    /// A leg has a different strike than the structure requires.
+    StrikeMismatch {
+        leg: usize,
+        expected: f64,
+        found: f64,
+    },
+    /// The strikes are not in the order the structure requires.
+    StrikesNotOrdered { strikes: Vec<f64> },
+    /// The near expiry does not fall before the far expiry.
+    ExpiriesNotOrdered { near: f64, far: f64 },
+}
+
+impl fmt::Display for StrategyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrategyError::InvalidParameter { leg, field, value } => {
+                write!(f, "leg {}: invalid {} {}", leg, field, value)
+            }
+            StrategyError::InvalidQuantity { leg, quantity } => {
+                write!(
+                    f,
+                    "leg {}: quantity must be positive, got {}",
+                    leg, quantity
+                )
+            }
+            StrategyError::SpotMismatch {
+                leg,
+                expected,
+                found,
+            } => write!(f, "leg {}: spot {} differs from {}", leg, found, expected),
+            StrategyError::VolatilityMismatch {
+                leg,
+                expected,
+                found,
+            } => write!(
+                f,
+                "leg {}: volatility {} differs from {}",
+                leg, found, expected
+            ),
+            StrategyError::ExpiryMismatch {
+                leg,
+                expected,
+                found,
+            } => write!(f, "leg {}: expiry {} differs from {}", leg, found, expected),
+            StrategyError::StrikeMismatch {
+                leg,
+                expected,
+                found,
+            } => write!(f, "leg {}: strike {} differs from {}", leg, found, expected),
+            StrategyError::StrikesNotOrdered { strikes } => {
+                write!(f, "strikes {:?} are not in the required order", strikes)
+            }
+            StrategyError::ExpiriesNotOrdered { near, far } => {
+                write!(
+                    f,
+                    "near expiry {} must fall before far expiry {}",
+                    near, far
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StrategyError {}
+
+/// Builds a strategy only if `validate` accepts it; the body of every `try_new`.
+pub(crate) fn validated<S>(
+    strategy: S,
+    validate: impl FnOnce(&S) -> Result<(), StrategyError>,
+) -> Result<S, StrategyError> {
+    validate(&strategy)?;
+    Ok(strategy)
+}
+
+/// Checks the legs of `strategy` (see `Strategy::validate`), then that `strikes` are
+/// strictly increasing.
+pub(crate) fn legs_and_strikes<T: OptionPricingModel + ?Sized>(
+    strategy: &Strategy<'_, T>,
+    strikes: &[f64],
+) -> Result<(), StrategyError> {
+    strategy.validate()?;
+    ascending(strikes)
+}
+
+/// Checks that a structural parameter, such as a wing width, is positive and finite.
+pub(crate) fn positive(leg: usize, field: &'static str, value: f64) -> Result<(), StrategyError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(())
+    } else {
+        Err(StrategyError::InvalidParameter { leg, field, value })
+    }
+}
+
+/// Checks that `strikes` are strictly increasing.
+pub(crate) fn ascending(strikes: &[f64]) -> Result<(), StrategyError> {
+    if strikes.windows(2).all(|pair| pair[0] < pair[1]) {
+        Ok(())
+    } else {
+        Err(StrategyError::StrikesNotOrdered {
+            strikes: strikes.to_vec(),
+        })
+    }
+}
+
+/// Checks that every leg expires with the first one. Each entry pairs a leg index with
+/// its parameters.
+pub(crate) fn same_expiry(legs: &[(usize, &OptionParameters)]) -> Result<(), StrategyError> {
+    let expected = legs[0].1.t;
+    match legs.iter().find(|(_, params)| params.t != expected) {
+        Some(&(leg, params)) => Err(StrategyError::ExpiryMismatch {
+            leg,
+            expected,
+            found: params.t,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that no wing expires before the body, for calendarized structures whose long
+/// wings may run further out than the short body.
+pub(crate) fn wings_not_before(
+    body: &OptionParameters,
+    wings: &[(usize, &OptionParameters)],
+) -> Result<(), StrategyError> {
+    match wings.iter().find(|(_, params)| params.t < body.t) {
+        Some(&(leg, params)) => Err(StrategyError::ExpiryMismatch {
+            leg,
+            expected: body.t,
+            found: params.t,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that the near leg expires strictly before the far leg.
+pub(crate) fn near_before_far(
+    near: &OptionParameters,
+    far: &OptionParameters,
+) -> Result<(), StrategyError> {
+    if near.t < far.t {
+        Ok(())
+    } else {
+        Err(StrategyError::ExpiriesNotOrdered {
+            near: near.t,
+            far: far.t,
+        })
+    }
+}
+
+/// Checks the parameters of one option leg.
+fn check_params(leg: usize, params: &OptionParameters) -> Result<(), StrategyError> {
+    let positive = [
+        ("spot", params.s),
+        ("strike", params.k),
+        ("volatility", params.sigma),
+        ("expiry", params.t),
+    ];
+    for (field, value) in positive {
+        self::positive(leg, field, value)?;
+    }
+    if !params.r.is_finite() {
+        return Err(StrategyError::InvalidParameter {
+            leg,
+            field: "rate",
+            value: params.r,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a quantity is positive and finite.
+fn check_quantity(leg: usize, quantity: f64) -> Result<(), StrategyError> {
+    if quantity.is_finite() && quantity > 0.0 {
+        Ok(())
+    } else {
+        Err(StrategyError::InvalidQuantity { leg, quantity })
+    }
+}
+
+/// Checks that a leg's spot matches the strategy's.
+fn check_spot(leg: usize, expected: f64, found: f64) -> Result<(), StrategyError> {
+    if (found - expected).abs() <= 1e-12 * expected.abs() {
+        Ok(())
+    } else {
+        Err(StrategyError::SpotMismatch {
+            leg,
+            expected,
+            found,
+        })
+    }
+}
+
+impl<'a, T: OptionPricingModel + ?Sized> Strategy<'a, T> {
+    /// Checks that every leg has valid parameters and a positive quantity, and that all
+    /// legs are priced off the same underlying price.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first problem found, if any.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        let spot = self
+            .legs
+            .first()
+            .map(|leg| leg.params.s)
+            .or_else(|| self.stock.first().map(|leg| leg.spot));
+        for (i, leg) in self.legs.iter().enumerate() {
+            check_params(i, &leg.params)?;
+            check_quantity(i, leg.quantity)?;
+            if let Some(spot) = spot {
+                check_spot(i, spot, leg.params.s)?;
+            }
+        }
+        for (j, leg) in self.stock.iter().enumerate() {
+            let i = self.legs.len() + j;
+            if !(leg.spot.is_finite() && leg.spot > 0.0) {
+                return Err(StrategyError::InvalidParameter {
+                    leg: i,
+                    field: "spot",
+                    value: leg.spot,
+                });
+            }
+            check_quantity(i, leg.quantity)?;
+            if let Some(spot) = spot {
+                check_spot(i, spot, leg.spot)?;
+            }
+        }
+        Ok(())
+    }
+}
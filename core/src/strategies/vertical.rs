@@ -1,4 +1,6 @@
-use crate::models::{OptionParameters, OptionPricingModel};
+use crate::models::{Greeks, OptionParameters, OptionPricingModel, OptionType};
+use crate::strategies::strategy::{Leg, Side, Strategy};
+use crate::strategies::validation::{legs_and_strikes, same_expiry, validated, StrategyError};
 use crate::strategies::OptionStrategy;
 
 /// Represents a vertical spread option strategy.
@@ -55,6 +57,53 @@ impl<'a, T: OptionPricingModel> VerticalSpread<'a, T> {
             is_bull,
         }
     }
+
+    /// Builds the equivalent leg-based strategy: a long and a short call (bull) or put (bear).
+    pub fn strategy(&self) -> Strategy<'a, T> {
+        let option_type = if self.is_bull {
+            OptionType::Call
+        } else {
+            OptionType::Put
+        };
+        Strategy::new(self.model)
+            .with_leg(Leg::new(
+                option_type,
+                Side::Long,
+                1.0,
+                self.params_long.clone(),
+            ))
+            .with_leg(Leg::new(
+                option_type,
+                Side::Short,
+                1.0,
+                self.params_short.clone(),
+            ))
+    }
+
+    /// Checks the legs (see `Strategy::validate`) and fails if the legs expire at different
+    /// times, or the long strike is not below (bull) or above (bear) the short strike.
+    pub fn validate(&self) -> Result<(), StrategyError> {
+        let strikes = if self.is_bull {
+            [self.params_long.k, self.params_short.k]
+        } else {
+            [self.params_short.k, self.params_long.k]
+        };
+        legs_and_strikes(&self.strategy(), &strikes)?;
+        same_expiry(&[(0, &self.params_long), (1, &self.params_short)])
+    }
+
+    /// Creates a new `VerticalSpread`, rejecting inconsistent legs; see `validate`.
+    pub fn try_new(
+        model: &'a T,
+        params_long: OptionParameters,
+        params_short: OptionParameters,
+        is_bull: bool,
+    ) -> Result<Self, StrategyError> {
+        validated(
+            Self::new(model, params_long, params_short, is_bull),
+            Self::validate,
+        )
+    }
 }
 
 impl<'a, T: OptionPricingModel> OptionStrategy for VerticalSpread<'a, T> {
@@ -69,8 +118,10 @@ impl<'a, T: OptionPricingModel> OptionStrategy for VerticalSpread<'a, T> {
     ///
     /// # Example
     ///
+    /// ```
     /// use core::models::{BlackScholesModel, OptionParameters};
-    /// use core::strategies::VerticalSpread;
+    /// use core::strategies::vertical::VerticalSpread;
+    /// use core::strategies::OptionStrategy;
     /// let model = BlackScholesModel;
     /// let params_long = OptionParameters {
     ///     s: 100.0,
@@ -89,17 +140,12 @@ impl<'a, T: OptionPricingModel> OptionStrategy for VerticalSpread<'a, T> {
     /// let vertical_spread = VerticalSpread::new(&model, params_long, params_short, true);
     /// let spread_price = vertical_spread.price();
     /// println!("Vertical Spread Price: {}", spread_price);
+    /// ```
     fn price(&self) -> f64 {
-        if self.is_bull {
-            // Bull spread using calls: long call and short call with different strike prices
-            let call_price_long = self.model.call_price(&self.params_long);
-            let call_price_short = self.model.call_price(&self.params_short);
-            call_price_long - call_price_short
-        } else {
-            // Bear spread using puts: long put and short put with different strike prices
-            let put_price_long = self.model.put_price(&self.params_long);
-            let put_price_short = self.model.put_price(&self.params_short);
-            put_price_long - put_price_short
-        }
+        self.strategy().price()
+    }
+
+    fn greeks(&self) -> Greeks {
+        self.strategy().greeks()
     }
 }
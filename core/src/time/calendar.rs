@@ -0,0 +1,286 @@
+use crate::time::DayCount;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeSet;
+
+/// How a date falling on a non-business day is moved onto a business day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RollConvention {
+    /// Leave the date as it is.
+    Unadjusted,
+    /// Move to the next business day.
+    Following,
+    /// Move to the next business day unless that crosses into the next month, in which case
+    /// move to the previous business day.
+    ModifiedFollowing,
+    /// Move to the previous business day (the usual rule for option expiries on holidays).
+    Preceding,
+    /// Move to the previous business day unless that crosses into the previous month, in
+    /// which case move to the next business day.
+    ModifiedPreceding,
+}
+
+/// Built-in holiday rule sets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HolidayRules {
+    /// Weekends only.
+    None,
+    /// Full-day closures of the US equity and derivatives exchanges.
+    UsExchange,
+}
+
+/// A trading calendar: weekends plus a set of holidays.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use core::time::calendar::{RollConvention, TradingCalendar};
+/// let nyse = TradingCalendar::nyse();
+/// let trade = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+/// // Good Friday: the exchange is closed, so the expiry moves to Thursday.
+/// let good_friday = NaiveDate::from_ymd_opt(2024, 3, 29).unwrap();
+/// let expiry = nyse.roll(good_friday, RollConvention::Preceding);
+/// assert_eq!(expiry, NaiveDate::from_ymd_opt(2024, 3, 28).unwrap());
+/// let days = nyse.business_days_between(trade, expiry);
+/// assert_eq!(days, 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TradingCalendar {
+    name: String,
+    rules: HolidayRules,
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl TradingCalendar {
+    /// Creates a calendar that only closes at weekends.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The calendar name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            rules: HolidayRules::None,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Creates the New York Stock Exchange calendar.
+    ///
+    /// Holidays are generated by rule for any year: New Year's Day, Martin Luther King Jr.
+    /// Day, Washington's Birthday, Good Friday, Memorial Day, Juneteenth (from 2022),
+    /// Independence Day, Labor Day, Thanksgiving and Christmas, observed on the nearest
+    /// weekday when they fall on a weekend. Ad hoc closures can be added with
+    /// `add_holiday`.
+    pub fn nyse() -> Self {
+        Self {
+            name: "NYSE".to_string(),
+            rules: HolidayRules::UsExchange,
+            holidays: BTreeSet::new(),
+        }
+    }
+
+    /// Creates the CME Group calendar, which shares the NYSE full-day closures.
+    pub fn cme() -> Self {
+        Self {
+            name: "CME".to_string(),
+            ..Self::nyse()
+        }
+    }
+
+    /// Adds the given holidays to the calendar.
+    ///
+    /// # Arguments
+    ///
+    /// * `holidays` - The extra non-business days.
+    pub fn with_holidays<I: IntoIterator<Item = NaiveDate>>(mut self, holidays: I) -> Self {
+        self.holidays.extend(holidays);
+        self
+    }
+
+    /// Adds a single holiday to the calendar.
+    pub fn add_holiday(&mut self, date: NaiveDate) {
+        self.holidays.insert(date);
+    }
+
+    /// Returns the calendar name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if `date` is a holiday (weekends are not counted as holidays).
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+            || match self.rules {
+                HolidayRules::None => false,
+                HolidayRules::UsExchange => us_exchange_holidays(date.year()).contains(&date),
+            }
+    }
+
+    /// Returns `true` if the exchange is open on `date`.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.is_holiday(date)
+    }
+
+    /// Counts the business days in `[start, end)`, negative if `end` is before `start`.
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+        start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| self.is_business_day(*day))
+            .count() as i64
+    }
+
+    /// Moves `date` by `n` business days (backwards if `n` is negative).
+    ///
+    /// The result is the `n`th business day after `date` (before it if `n` is negative),
+    /// whether or not `date` is itself a business day: Saturday plus one is Monday, and
+    /// Saturday minus one is Friday. Adding zero days leaves any date unchanged.
+    pub fn add_business_days(&self, date: NaiveDate, n: i64) -> NaiveDate {
+        let step = if n < 0 { -1 } else { 1 };
+        let mut day = date;
+        let mut remaining = n.abs();
+        while remaining > 0 {
+            day += Duration::days(step);
+            if self.is_business_day(day) {
+                remaining -= 1;
+            }
+        }
+        day
+    }
+
+    /// Adjusts `date` onto a business day according to `convention`.
+    pub fn roll(&self, date: NaiveDate, convention: RollConvention) -> NaiveDate {
+        let next = |mut day: NaiveDate| {
+            while !self.is_business_day(day) {
+                day += Duration::days(1);
+            }
+            day
+        };
+        let previous = |mut day: NaiveDate| {
+            while !self.is_business_day(day) {
+                day -= Duration::days(1);
+            }
+            day
+        };
+        match convention {
+            RollConvention::Unadjusted => date,
+            RollConvention::Following => next(date),
+            RollConvention::Preceding => previous(date),
+            RollConvention::ModifiedFollowing => {
+                let rolled = next(date);
+                if rolled.month() == date.month() {
+                    rolled
+                } else {
+                    previous(date)
+                }
+            }
+            RollConvention::ModifiedPreceding => {
+                let rolled = previous(date);
+                if rolled.month() == date.month() {
+                    rolled
+                } else {
+                    next(date)
+                }
+            }
+        }
+    }
+
+    /// Calculates the year fraction between two dates, counting business days on this
+    /// calendar for `DayCount::Bus252` and deferring to the convention otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `convention` - The day-count convention.
+    /// * `start` - The start date.
+    /// * `end` - The end date.
+    pub fn year_fraction(&self, convention: DayCount, start: NaiveDate, end: NaiveDate) -> f64 {
+        match convention {
+            DayCount::Bus252 => self.business_days_between(start, end) as f64 / 252.0,
+            _ => convention.year_fraction(start, end),
+        }
+    }
+
+    /// Calculates the time to maturity in trading years (`BUS/252` on this calendar).
+    ///
+    /// Pricing with this `t` makes one unit of daily theta correspond to one trading day
+    /// rather than one calendar day.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade` - The trade (valuation) date.
+    /// * `expiry` - The expiry date.
+    ///
+    /// # Returns
+    ///
+    /// Returns the trading-time year fraction, or `0.0` once expired.
+    pub fn trading_time_to_expiry(&self, trade: NaiveDate, expiry: NaiveDate) -> f64 {
+        self.year_fraction(DayCount::Bus252, trade, expiry).max(0.0)
+    }
+}
+
+/// Returns the `n`-th `weekday` of a month (1-based).
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+}
+
+/// Returns the last `weekday` of a month.
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    (1..=5)
+        .rev()
+        .find_map(|n| nth_weekday(year, month, weekday, n))
+}
+
+/// Moves a fixed-date holiday falling on a weekend to the nearest weekday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date - Duration::days(1),
+        Weekday::Sun => date + Duration::days(1),
+        _ => date,
+    }
+}
+
+/// Computes Easter Sunday with the anonymous Gregorian algorithm.
+fn easter(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+/// Full-day closures of the US exchanges for `year`.
+fn us_exchange_holidays(year: i32) -> Vec<NaiveDate> {
+    let fixed = |month, day| NaiveDate::from_ymd_opt(year, month, day).map(observed);
+    let mut holidays = vec![
+        // New Year's Day is not moved back into the previous year when it is a Saturday.
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .map(observed)
+            .filter(|d| d.year() == year),
+        nth_weekday(year, 1, Weekday::Mon, 3),
+        nth_weekday(year, 2, Weekday::Mon, 3),
+        easter(year).map(|d| d - Duration::days(2)),
+        last_weekday(year, 5, Weekday::Mon),
+        fixed(7, 4),
+        nth_weekday(year, 9, Weekday::Mon, 1),
+        nth_weekday(year, 11, Weekday::Thu, 4),
+        fixed(12, 25),
+    ];
+    if year >= 2022 {
+        holidays.push(fixed(6, 19));
+    }
+    holidays.into_iter().flatten().collect()
+}
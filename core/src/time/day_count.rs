@@ -0,0 +1,131 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::fmt;
+use std::str::FromStr;
+
+/// Day-count conventions for converting a pair of dates into a year fraction.
+///
+/// ref:<https://en.wikipedia.org/wiki/Day_count_convention>
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DayCount {
+    /// Actual days / 365 (the usual equity option convention).
+    Act365Fixed,
+    /// Actual days / 360 (money-market rates).
+    Act360,
+    /// 30/360 US bond basis: every month counts as 30 days.
+    Thirty360,
+    /// Business days / 252 (Brazilian-style; also the trading-day theta convention).
+    /// Only weekends are excluded here; use `TradingCalendar::year_fraction` to exclude
+    /// exchange holidays too.
+    Bus252,
+}
+
+impl DayCount {
+    /// Calculates the year fraction between two dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The start date (e.g. the trade date).
+    /// * `end` - The end date (e.g. the expiry date).
+    ///
+    /// # Returns
+    ///
+    /// Returns the year fraction, negative if `end` is before `start`.
+    pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> f64 {
+        if end < start {
+            return -self.year_fraction(end, start);
+        }
+        match self {
+            DayCount::Act365Fixed => (end - start).num_days() as f64 / 365.0,
+            DayCount::Act360 => (end - start).num_days() as f64 / 360.0,
+            DayCount::Thirty360 => {
+                let d1 = start.day().min(30);
+                let d2 = if d1 == 30 {
+                    end.day().min(30)
+                } else {
+                    end.day()
+                };
+                let days = 360 * (end.year() - start.year())
+                    + 30 * (end.month() as i32 - start.month() as i32)
+                    + (d2 as i32 - d1 as i32);
+                days as f64 / 360.0
+            }
+            DayCount::Bus252 => weekdays_between(start, end) as f64 / 252.0,
+        }
+    }
+
+    /// Returns the number of days in a year under this convention.
+    pub fn days_per_year(&self) -> f64 {
+        match self {
+            DayCount::Act365Fixed => 365.0,
+            DayCount::Act360 | DayCount::Thirty360 => 360.0,
+            DayCount::Bus252 => 252.0,
+        }
+    }
+}
+
+/// Counts the weekdays in `[start, end)`.
+pub(crate) fn weekdays_between(start: NaiveDate, end: NaiveDate) -> i64 {
+    let days = (end - start).num_days();
+    let full_weeks = days / 7;
+    let mut count = full_weeks * 5;
+    let mut day = start + chrono::Duration::days(full_weeks * 7);
+    while day < end {
+        if !matches!(day.weekday(), Weekday::Sat | Weekday::Sun) {
+            count += 1;
+        }
+        day = day.succ_opt().unwrap_or(end);
+    }
+    count
+}
+
+impl fmt::Display for DayCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DayCount::Act365Fixed => "ACT/365",
+            DayCount::Act360 => "ACT/360",
+            DayCount::Thirty360 => "30/360",
+            DayCount::Bus252 => "BUS/252",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for DayCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().replace(['_', ' '], "/").as_str() {
+            "ACT/365" | "ACT/365F" | "ACT/365/FIXED" => Ok(DayCount::Act365Fixed),
+            "ACT/360" => Ok(DayCount::Act360),
+            "30/360" => Ok(DayCount::Thirty360),
+            "BUS/252" => Ok(DayCount::Bus252),
+            _ => Err(format!("Unknown day-count convention: {}", s)),
+        }
+    }
+}
+
+/// Calculates the time to maturity `t` of an option from its trade and expiry dates.
+///
+/// # Arguments
+///
+/// * `trade` - The trade (valuation) date.
+/// * `expiry` - The expiry date.
+/// * `convention` - The day-count convention.
+///
+/// # Returns
+///
+/// Returns the year fraction to use as `OptionParameters::t`, or `0.0` once expired.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+/// use core::time::{time_to_expiry, DayCount};
+/// let trade = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap();
+/// let expiry = NaiveDate::from_ymd_opt(2024, 6, 21).unwrap();
+/// let t = time_to_expiry(trade, expiry, DayCount::Act365Fixed);
+/// assert_eq!(t, 171.0 / 365.0);
+/// ```
+pub fn time_to_expiry(trade: NaiveDate, expiry: NaiveDate, convention: DayCount) -> f64 {
+    convention.year_fraction(trade, expiry).max(0.0)
+}
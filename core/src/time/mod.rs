@@ -0,0 +1,5 @@
+pub mod calendar;
+pub mod day_count;
+
+pub use calendar::{RollConvention, TradingCalendar};
+pub use day_count::{time_to_expiry, DayCount};
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// One period of an underlying's price history.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// An estimator of historical volatility from price bars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Estimator {
+    /// Standard deviation of close-to-close log returns.
+    CloseToClose,
+    /// Parkinson (1980), from the high-low range.
+    Parkinson,
+    /// Garman-Klass (1980), from open, high, low and close.
+    GarmanKlass,
+    /// Yang-Zhang (2000), combining overnight, open-to-close and Rogers-Satchell
+    /// variances; robust to drift and opening jumps.
+    YangZhang,
+}
+
+impl Estimator {
+    pub const ALL: [Estimator; 4] = [
+        Estimator::CloseToClose,
+        Estimator::Parkinson,
+        Estimator::GarmanKlass,
+        Estimator::YangZhang,
+    ];
+
+    /// Whether the estimator also needs the close of the period before the window.
+    fn needs_previous_close(self) -> bool {
+        matches!(self, Estimator::CloseToClose | Estimator::YangZhang)
+    }
+
+    /// Estimates the annualized volatility over the last `window` periods of `bars`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bars` - The price history, oldest first.
+    /// * `window` - The number of periods to estimate over.
+    /// * `periods_per_year` - Periods in a year, e.g. `252.0` for daily bars.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` when the window is shorter than two periods or `bars` cannot fill
+    /// it; close-to-close and Yang-Zhang need one bar more than `window`.
+    pub fn estimate(self, bars: &[Bar], window: usize, periods_per_year: f64) -> Option<f64> {
+        let needed = window + usize::from(self.needs_previous_close());
+        if window < 2 || bars.len() < needed {
+            return None;
+        }
+        let bars = &bars[bars.len() - needed..];
+        let n = window as f64;
+        let variance = match self {
+            Estimator::CloseToClose => sample_variance(
+                bars.windows(2)
+                    .map(|pair| (pair[1].close / pair[0].close).ln()),
+            ),
+            Estimator::Parkinson => {
+                let sum: f64 = bars
+                    .iter()
+                    .map(|bar| (bar.high / bar.low).ln().powi(2))
+                    .sum();
+                sum / (4.0 * std::f64::consts::LN_2 * n)
+            }
+            Estimator::GarmanKlass => {
+                let sum: f64 = bars
+                    .iter()
+                    .map(|bar| {
+                        0.5 * (bar.high / bar.low).ln().powi(2)
+                            - (2.0 * std::f64::consts::LN_2 - 1.0)
+                                * (bar.close / bar.open).ln().powi(2)
+                    })
+                    .sum();
+                sum / n
+            }
+            Estimator::YangZhang => {
+                let overnight = sample_variance(
+                    bars.windows(2)
+                        .map(|pair| (pair[1].open / pair[0].close).ln()),
+                );
+                let open_close =
+                    sample_variance(bars[1..].iter().map(|bar| (bar.close / bar.open).ln()));
+                let rogers_satchell: f64 = bars[1..]
+                    .iter()
+                    .map(|bar| {
+                        (bar.high / bar.open).ln() * (bar.high / bar.close).ln()
+                            + (bar.low / bar.open).ln() * (bar.low / bar.close).ln()
+                    })
+                    .sum::<f64>()
+                    / n;
+                let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+                overnight + k * open_close + (1.0 - k) * rogers_satchell
+            }
+        };
+        Some((variance * periods_per_year).sqrt())
+    }
+}
+
+/// The unbiased variance of `values`.
+fn sample_variance(values: impl Iterator<Item = f64>) -> f64 {
+    let values: Vec<f64> = values.collect();
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+}
+
+impl fmt::Display for Estimator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Estimator::CloseToClose => "close-to-close",
+            Estimator::Parkinson => "parkinson",
+            Estimator::GarmanKlass => "garman-klass",
+            Estimator::YangZhang => "yang-zhang",
+        })
+    }
+}
+
+impl FromStr for Estimator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace(['_', ' '], "-").as_str() {
+            "close-to-close" | "close-close" | "cc" => Ok(Estimator::CloseToClose),
+            "parkinson" => Ok(Estimator::Parkinson),
+            "garman-klass" | "gk" => Ok(Estimator::GarmanKlass),
+            "yang-zhang" | "yz" => Ok(Estimator::YangZhang),
+            _ => Err(format!("Unknown volatility estimator: {}", s)),
+        }
+    }
+}
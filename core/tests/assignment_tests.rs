@@ -0,0 +1,59 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::assignment::{AssignmentReason, AssignmentRisk, Dividend};
+use core::strategies::{Leg, Side, Strategy};
+
+fn params(k: f64, t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t,
+    }
+}
+
+#[test]
+fn test_short_call_before_dividend() {
+    let model = BlackScholesModel;
+    let strategy = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Short, params(80.0, 0.1)))
+        .with_leg(Leg::call(Side::Long, params(70.0, 0.1)));
+    let dividend = Dividend {
+        amount: 1.0,
+        ex_date: 0.05,
+    };
+
+    let warnings = strategy.assignment_risk(Some(dividend));
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].leg, 0);
+    assert_eq!(warnings[0].risk, AssignmentRisk::High);
+    assert_eq!(warnings[0].reason, AssignmentReason::Dividend);
+    assert!(warnings[0].extrinsic < 1.0);
+
+    // A dividend after expiry does not matter; the leg is still deep in the money.
+    let late = Dividend {
+        amount: 1.0,
+        ex_date: 0.2,
+    };
+    let warnings = strategy.assignment_risk(Some(late));
+    assert_eq!(warnings[0].risk, AssignmentRisk::Elevated);
+    assert_eq!(warnings[0].reason, AssignmentReason::DeepInTheMoney);
+}
+
+#[test]
+fn test_short_put_carry() {
+    let model = BlackScholesModel;
+    let deep = Strategy::new(&model).with_leg(Leg::put(Side::Short, params(130.0, 1.0)));
+    let warnings = deep.assignment_risk(None);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].reason, AssignmentReason::Carry);
+    assert!(warnings[0].extrinsic < 0.0);
+
+    // Out-of-the-money short legs are never flagged.
+    let otm = Strategy::new(&model)
+        .with_leg(Leg::put(Side::Short, params(90.0, 1.0)))
+        .with_leg(Leg::call(Side::Short, params(110.0, 1.0)));
+    assert!(otm.assignment_risk(None).is_empty());
+}
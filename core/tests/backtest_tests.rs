@@ -0,0 +1,114 @@
+extern crate core;
+
+use chrono::{Duration, NaiveDate, Weekday};
+use core::backtest::{
+    on_weekday, BacktestConfig, BacktestError, Backtester, Bar, ExitReason, ExitRule,
+};
+use core::models::BlackScholesModel;
+use core::strategies::iron_condor::IronCondor;
+use core::strategies::{Leg, Side};
+
+/// Daily bars from Monday 2024-01-01 with the close given by `price(day)`.
+fn history(days: i64, price: impl Fn(i64) -> f64) -> Vec<Bar> {
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    (0..days)
+        .map(|day| Bar {
+            date: start + Duration::days(day),
+            close: price(day),
+            volatility: None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_bars_from_csv() {
+    let csv = "date,close,iv\n2024-01-02,100.5,0.21\n\n2024-01-03,101,\n";
+    let bars = Bar::from_csv(csv).unwrap();
+    assert_eq!(bars.len(), 2);
+    assert_eq!(bars[0].volatility, Some(0.21));
+    assert_eq!(bars[1].close, 101.0);
+    assert_eq!(bars[1].volatility, None);
+
+    assert!(matches!(
+        Bar::from_csv("2024-01-02,abc"),
+        Err(BacktestError::Parse { line: 1, .. })
+    ));
+    assert_eq!(
+        Bar::from_csv("2024-01-03,1\n2024-01-02,1"),
+        Err(BacktestError::Unordered { line: 2 })
+    );
+}
+
+#[test]
+fn test_weekly_condor_in_a_quiet_market() {
+    let model = BlackScholesModel;
+    let bars = history(120, |day| 100.0 + (day as f64 * 0.7).sin());
+    let backtester = Backtester::new(
+        &model,
+        BacktestConfig {
+            initial_capital: 1000.0,
+            ..BacktestConfig::default()
+        },
+    );
+    let exit = ExitRule {
+        profit_target: Some(0.5),
+        days_before_expiry: Some(7.0),
+        ..ExitRule::default()
+    };
+    let report = backtester.run(
+        &bars,
+        on_weekday(Weekday::Mon),
+        |model, params| {
+            IronCondor::from_deltas(model, params, 0.30, 0.15)
+                .ok()
+                .map(|condor| condor.strategy().legs)
+        },
+        &exit,
+    );
+
+    // One per Monday; the last bar is a Monday too but opens nothing.
+    assert_eq!(report.trades.len(), 17);
+    assert!(report.win_rate > 0.9);
+    assert!(report.total_pnl > 0.0);
+    for trade in &report.trades {
+        // Short condors are opened for a credit.
+        assert!(trade.entry_value < 0.0);
+        assert!(trade.reason != ExitReason::StopLoss);
+    }
+
+    assert_eq!(report.equity_curve.len(), bars.len());
+    let last = report.equity_curve.last().unwrap();
+    assert_eq!(last.open_trades, 0);
+    assert!((last.equity - 1000.0 - report.total_pnl).abs() < 1e-9);
+    assert!(report.max_drawdown >= 0.0);
+    // Short premium is short vega while a trade is on.
+    assert!(report.equity_curve[1].greeks.vega < 0.0);
+}
+
+#[test]
+fn test_expiry_and_stop_loss() {
+    let model = BlackScholesModel;
+    let config = BacktestConfig {
+        days_to_expiry: 10.0,
+        ..BacktestConfig::default()
+    };
+    let backtester = Backtester::new(&model, config);
+    let short_call = |_: &BlackScholesModel, params| Some(vec![Leg::call(Side::Short, params)]);
+    let first_day = |_: &Bar, open: usize| open == 0;
+
+    // A short ATM call held through a rally expires in the money.
+    let rally = history(30, |day| 100.0 + day as f64);
+    let report = backtester.run(&rally, first_day, short_call, &ExitRule::default());
+    let trade = &report.trades[0];
+    assert_eq!(trade.reason, ExitReason::Expiry);
+    assert_eq!(trade.exit_value, -(110.0 - 100.0));
+
+    let stop = ExitRule {
+        stop_loss: Some(1.0),
+        ..ExitRule::default()
+    };
+    let report = backtester.run(&rally, first_day, short_call, &stop);
+    assert_eq!(report.trades[0].reason, ExitReason::StopLoss);
+    assert!(report.trades[0].pnl <= report.trades[0].entry_value);
+    assert!(report.max_drawdown > 0.0);
+}
@@ -0,0 +1,82 @@
+extern crate core;
+
+use core::models::{
+    price_batch, BinomialTreeModel, BlackScholesModel, OptionPricingModel, OptionType,
+    PricingRequest,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn requests(n: usize) -> Vec<PricingRequest> {
+    (0..n)
+        .map(|i| PricingRequest {
+            id: Some(format!("trade-{}", i)),
+            option_type: if i % 2 == 0 {
+                OptionType::Call
+            } else {
+                OptionType::Put
+            },
+            s: 100.0,
+            k: 80.0 + i as f64,
+            r: 0.05,
+            sigma: 0.2,
+            t: 0.5,
+        })
+        .collect()
+}
+
+#[test]
+fn test_batch_matches_individual_pricing_in_order() {
+    let model = BlackScholesModel;
+    let requests = requests(40);
+    let priced = price_batch(&model, &requests, 4, |_| {});
+    assert_eq!(priced.len(), requests.len());
+    for (request, priced) in requests.iter().zip(&priced) {
+        assert_eq!(&priced.request, request);
+        let params = request.params();
+        assert_eq!(
+            priced.price,
+            model.option_price(&params, request.option_type)
+        );
+        assert_eq!(
+            priced.greeks,
+            model.option_greeks(&params, request.option_type)
+        );
+    }
+}
+
+#[test]
+fn test_batch_is_independent_of_thread_count() {
+    let model = BinomialTreeModel::default();
+    let requests = requests(12);
+    let serial = price_batch(&model, &requests, 1, |_| {});
+    for threads in [0, 3, 64] {
+        assert_eq!(price_batch(&model, &requests, threads, |_| {}), serial);
+    }
+}
+
+#[test]
+fn test_batch_reports_progress_for_every_request() {
+    let calls = AtomicUsize::new(0);
+    let last = AtomicUsize::new(0);
+    price_batch(&BlackScholesModel, &requests(25), 4, |done| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        last.fetch_max(done, Ordering::Relaxed);
+    });
+    assert_eq!(calls.load(Ordering::Relaxed), 25);
+    assert_eq!(last.load(Ordering::Relaxed), 25);
+}
+
+#[test]
+fn test_empty_batch() {
+    assert!(price_batch(&BlackScholesModel, &[], 8, |_| {}).is_empty());
+}
+
+#[test]
+fn test_request_parses_without_id() {
+    let request: PricingRequest = serde_json::from_str(
+        r#"{"option_type": "put", "s": 100, "k": 95, "r": 0.05, "sigma": 0.2, "t": 1}"#,
+    )
+    .unwrap();
+    assert_eq!(request.id, None);
+    assert_eq!(request.option_type, OptionType::Put);
+}
@@ -43,7 +43,7 @@ fn test_delta() {
     let model = BinomialTreeModel::default();
     let delta = model.delta(&params);
     println!("Delta: {}", delta);
-    assert!(delta >= -1.0 && delta <= 1.0);
+    assert!((-1.0..=1.0).contains(&delta));
 }
 
 #[test]
@@ -102,3 +102,18 @@ fn test_rho() {
     let rho = model.rho(&params);
     assert!(rho >= 0.0);
 }
+
+#[test]
+fn test_call_put_price() {
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    };
+    let model = BinomialTreeModel::default();
+    let (call, put) = model.call_put_price(&params);
+    assert_eq!(call, model.call_price(&params));
+    assert_eq!(put, model.put_price(&params));
+}
@@ -1,8 +1,8 @@
 extern crate core;
 
-use core::models::black_scholes::BlackScholesModel;
+use core::models::black_scholes::{BlackScholesModel, BsIntermediates};
 
-use core::models::{OptionParameters, OptionPricingModel};
+use core::models::{OptionParameters, OptionPricingModel, OptionType};
 
 #[test]
 fn test_black_scholes_call() {
@@ -116,3 +116,81 @@ fn test_rho() {
         "Rho should be approximately 0.5323"
     );
 }
+
+#[test]
+fn test_intermediates_match_model() {
+    let model = BlackScholesModel;
+    let params = OptionParameters {
+        s: 100.0,
+        k: 95.0,
+        r: 0.05,
+        sigma: 0.25,
+        t: 0.5,
+    };
+    let bs = BsIntermediates::new(&params);
+    assert!((bs.d2 - (bs.d1 - 0.25 * 0.5_f64.sqrt())).abs() < 1e-12);
+    assert_eq!(bs.call_price(), model.call_price(&params));
+    assert_eq!(bs.put_price(), model.put_price(&params));
+    assert_eq!(bs.gamma(), model.gamma(&params));
+    assert_eq!(
+        model.call_put_price(&params),
+        (bs.call_price(), bs.put_price())
+    );
+}
+
+#[test]
+fn test_strike_for_delta() {
+    let model = BlackScholesModel;
+    let params = OptionParameters {
+        s: 100.0,
+        k: 0.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    };
+    for target in [0.05, 0.16, 0.5, 0.84] {
+        let k = model
+            .strike_for_delta(&params, OptionType::Call, target)
+            .unwrap();
+        let at = OptionParameters {
+            k,
+            ..params.clone()
+        };
+        assert!((model.delta(&at) - target).abs() < 1e-8);
+
+        let k = model
+            .strike_for_delta(&params, OptionType::Put, target)
+            .unwrap();
+        let at = OptionParameters {
+            k,
+            ..params.clone()
+        };
+        assert!((model.put_delta(&at) + target).abs() < 1e-8);
+    }
+    assert!(model
+        .strike_for_delta(&params, OptionType::Call, 1.5)
+        .is_err());
+}
+
+#[test]
+fn test_implied_volatility_round_trip() {
+    let model = BlackScholesModel;
+    let params = OptionParameters {
+        s: 100.0,
+        k: 110.0,
+        r: 0.03,
+        sigma: 0.35,
+        t: 0.75,
+    };
+    for option_type in [OptionType::Call, OptionType::Put] {
+        let price = model.option_price(&params, option_type);
+        let implied = model
+            .implied_volatility(&params, option_type, price)
+            .unwrap();
+        assert!((implied - 0.35).abs() < 1e-9);
+    }
+    // A put quoted below its discounted intrinsic value has no implied volatility.
+    assert!(model
+        .implied_volatility(&params, OptionType::Put, 1.0)
+        .is_err());
+}
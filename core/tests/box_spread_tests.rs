@@ -0,0 +1,54 @@
+extern crate core;
+
+use core::models::{BinomialTreeModel, BlackScholesModel, OptionParameters};
+use core::strategies::box_spread::{implied_box_rate, BoxSpread};
+use core::strategies::OptionStrategy;
+
+fn params() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 90.0,
+        r: 0.05,
+        sigma: 0.25,
+        t: 2.0,
+    }
+}
+
+#[test]
+fn test_box_prices_as_discounted_width() {
+    let model = BlackScholesModel;
+    let box_spread = BoxSpread::new(&model, params(), 110.0);
+    assert_eq!(box_spread.face_value(), 20.0);
+    let expected = 20.0 * (-0.05_f64 * 2.0).exp();
+    assert!((box_spread.price() - expected).abs() < 1e-9);
+
+    // The position carries no market risk.
+    let greeks = box_spread.greeks();
+    assert!(greeks.delta.abs() < 1e-9);
+    assert!(greeks.gamma.abs() < 1e-9);
+    assert!(greeks.vega.abs() < 1e-9);
+    assert!(box_spread.strategy().payoff(50.0) == 20.0);
+    assert!(box_spread.strategy().payoff(150.0) == 20.0);
+}
+
+#[test]
+fn test_implied_financing_rate() {
+    let model = BlackScholesModel;
+    let box_spread = BoxSpread::new(&model, params(), 110.0);
+    let rate = box_spread.implied_rate(box_spread.price());
+    assert!((rate - 0.05).abs() < 1e-9);
+
+    // Paying more for the box means lending at a lower rate.
+    assert!(box_spread.implied_rate(19.0) < 0.05);
+    assert!((implied_box_rate(20.0, 90.0, 110.0, 1.0)).abs() < 1e-15);
+    assert!(implied_box_rate(10.0, 110.0, 90.0, 1.0).is_nan());
+}
+
+#[test]
+fn test_box_with_lattice_model() {
+    let model = BinomialTreeModel::default();
+    let box_spread = BoxSpread::new(&model, params(), 110.0);
+    let expected = 20.0 * (-0.05_f64 * 2.0).exp();
+    assert!((box_spread.price() - expected).abs() < 1e-6);
+    assert!(BoxSpread::try_new(&model, params(), 80.0).is_err());
+}
@@ -0,0 +1,43 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, Greeks, OptionParameters, OptionPricingModel, OptionType};
+use core::strategies::Leg;
+use core::strategies::{LegKind, Side, Strategy};
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+#[test]
+fn test_breakdown_sums_to_net() {
+    let model = BlackScholesModel;
+    let collar = Strategy::new(&model)
+        .with_leg(Leg::put(Side::Long, params(95.0)))
+        .with_leg(Leg::new(OptionType::Call, Side::Short, 2.0, params(110.0)))
+        .with_stock(Side::Long, 1.0, 100.0);
+
+    let lines = collar.breakdown();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].kind, LegKind::Put);
+    assert_eq!(lines[1].kind, LegKind::Call);
+    assert_eq!(lines[2].kind, LegKind::Stock);
+    assert_eq!(lines[2].strike, None);
+    assert_eq!(lines[2].greeks.delta, 1.0);
+
+    assert!((lines[1].unit_price - model.call_price(&params(110.0))).abs() < 1e-12);
+    assert!((lines[1].value + 2.0 * lines[1].unit_price).abs() < 1e-12);
+
+    let value: f64 = lines.iter().map(|line| line.value).sum();
+    let greeks: Greeks = lines.iter().map(|line| line.greeks).sum();
+    let contribution: f64 = lines.iter().map(|line| line.contribution).sum();
+    assert!((value - collar.price()).abs() < 1e-12);
+    assert!((greeks.delta - collar.greeks().delta).abs() < 1e-12);
+    assert!((greeks.vega - collar.greeks().vega).abs() < 1e-12);
+    assert!((contribution - 1.0).abs() < 1e-12);
+}
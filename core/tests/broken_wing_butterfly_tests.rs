@@ -0,0 +1,70 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel};
+use core::strategies::broken_wing_butterfly::BrokenWingButterfly;
+use core::strategies::butterfly::ButterflySpread;
+use core::strategies::{BreakevenDirection, OptionStrategy};
+
+fn body() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+#[test]
+fn test_symmetric_wings_match_butterfly() {
+    let model = BlackScholesModel;
+    let bwb = BrokenWingButterfly::new(&model, body(), 5.0, 5.0, true);
+    let lower = OptionParameters { k: 95.0, ..body() };
+    let butterfly = ButterflySpread::new(&model, lower, 100.0, 105.0);
+    assert!((bwb.price() - butterfly.price()).abs() < 1e-12);
+}
+
+#[test]
+fn test_broken_wing_call_analytics() {
+    let model = BlackScholesModel;
+    let bwb = BrokenWingButterfly::new(&model, body(), 10.0, 11.0, true);
+    assert_eq!(bwb.strikes(), (90.0, 100.0, 111.0));
+
+    let debit = bwb.price();
+    assert!(debit > 0.0);
+    let call = |k: f64| model.call_price(&OptionParameters { k, ..body() });
+    assert!((debit - (call(90.0) - 2.0 * call(100.0) + call(111.0))).abs() < 1e-12);
+
+    // The wider upper wing carries the risk: D + w_u - w_l above 111.
+    assert!((bwb.max_loss() - (debit + 1.0)).abs() < 1e-9);
+    assert!((bwb.max_profit() - (10.0 - debit)).abs() < 1e-9);
+
+    let breakevens = bwb.breakevens();
+    assert_eq!(breakevens.len(), 2);
+    assert_eq!(breakevens[0].direction, BreakevenDirection::Lower);
+    assert!((breakevens[0].price - (90.0 + debit)).abs() < 1e-8);
+    assert_eq!(breakevens[1].direction, BreakevenDirection::Upper);
+    assert!((breakevens[1].price - (110.0 - debit)).abs() < 1e-8);
+}
+
+#[test]
+fn test_broken_wing_put_for_credit() {
+    let model = BlackScholesModel;
+    let bwb = BrokenWingButterfly::new(&model, body(), 15.0, 5.0, false);
+    let credit = -bwb.price();
+    assert!(credit > 0.0);
+
+    // The put version risks the wide lower wing, less the credit, and has one breakeven.
+    assert!((bwb.max_loss() - (10.0 - credit)).abs() < 1e-9);
+    let breakevens = bwb.breakevens();
+    assert_eq!(breakevens.len(), 1);
+    assert_eq!(breakevens[0].direction, BreakevenDirection::Lower);
+}
+
+#[test]
+fn test_broken_wing_validation() {
+    let model = BlackScholesModel;
+    assert!(BrokenWingButterfly::try_new(&model, body(), 5.0, 10.0, true).is_ok());
+    assert!(BrokenWingButterfly::try_new(&model, body(), -5.0, 10.0, true).is_err());
+    assert!(BrokenWingButterfly::try_new(&model, body(), 100.0, 10.0, true).is_err());
+}
@@ -0,0 +1,139 @@
+extern crate core;
+
+use chrono::NaiveDate;
+use core::time::{DayCount, RollConvention, TradingCalendar};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_nyse_holidays_2024() {
+    let nyse = TradingCalendar::nyse();
+    let holidays = [
+        date(2024, 1, 1),
+        date(2024, 1, 15),
+        date(2024, 2, 19),
+        date(2024, 3, 29),
+        date(2024, 5, 27),
+        date(2024, 6, 19),
+        date(2024, 7, 4),
+        date(2024, 9, 2),
+        date(2024, 11, 28),
+        date(2024, 12, 25),
+    ];
+    for holiday in holidays {
+        assert!(nyse.is_holiday(holiday), "{} should be a holiday", holiday);
+        assert!(!nyse.is_business_day(holiday));
+    }
+    // 366 days, 104 weekend days and 10 holidays.
+    assert_eq!(
+        nyse.business_days_between(date(2024, 1, 1), date(2025, 1, 1)),
+        252
+    );
+}
+
+#[test]
+fn test_weekend_observance() {
+    let nyse = TradingCalendar::nyse();
+    // July 4th 2026 is a Saturday, observed on Friday the 3rd.
+    assert!(nyse.is_holiday(date(2026, 7, 3)));
+    // Christmas 2022 is a Sunday, observed on Monday the 26th.
+    assert!(nyse.is_holiday(date(2022, 12, 26)));
+    // New Year's Day 2022 is a Saturday and is not observed on Friday 31 December 2021.
+    assert!(nyse.is_business_day(date(2021, 12, 31)));
+    // Juneteenth only from 2022.
+    assert!(nyse.is_business_day(date(2021, 6, 18)));
+}
+
+#[test]
+fn test_custom_holidays() {
+    let calendar = TradingCalendar::new("TEST").with_holidays([date(2024, 3, 6)]);
+    assert_eq!(calendar.name(), "TEST");
+    assert!(!calendar.is_business_day(date(2024, 3, 6)));
+    assert!(calendar.is_business_day(date(2024, 3, 29)));
+
+    let mut cme = TradingCalendar::cme();
+    cme.add_holiday(date(2025, 1, 9));
+    assert!(!cme.is_business_day(date(2025, 1, 9)));
+    assert!(!cme.is_business_day(date(2025, 4, 18)));
+}
+
+#[test]
+fn test_business_day_arithmetic() {
+    let nyse = TradingCalendar::nyse();
+    // Thursday before Good Friday 2024, then the weekend.
+    assert_eq!(
+        nyse.add_business_days(date(2024, 3, 28), 1),
+        date(2024, 4, 1)
+    );
+    assert_eq!(
+        nyse.add_business_days(date(2024, 4, 1), -1),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        nyse.business_days_between(date(2024, 4, 1), date(2024, 3, 28)),
+        -1
+    );
+}
+
+#[test]
+fn test_add_business_days_from_non_business_days() {
+    let nyse = TradingCalendar::nyse();
+    // Saturday 2024-03-30, after Good Friday.
+    let saturday = date(2024, 3, 30);
+    assert_eq!(nyse.add_business_days(saturday, 1), date(2024, 4, 1));
+    assert_eq!(nyse.add_business_days(saturday, 2), date(2024, 4, 2));
+    assert_eq!(nyse.add_business_days(saturday, -1), date(2024, 3, 28));
+    assert_eq!(nyse.add_business_days(saturday, 0), saturday);
+    // Good Friday itself.
+    assert_eq!(
+        nyse.add_business_days(date(2024, 3, 29), 1),
+        date(2024, 4, 1)
+    );
+    assert_eq!(
+        nyse.add_business_days(date(2024, 3, 29), -1),
+        date(2024, 3, 28)
+    );
+}
+
+#[test]
+fn test_roll_conventions() {
+    let nyse = TradingCalendar::nyse();
+    // Good Friday 2024.
+    let good_friday = date(2024, 3, 29);
+    assert_eq!(
+        nyse.roll(good_friday, RollConvention::Following),
+        date(2024, 4, 1)
+    );
+    assert_eq!(
+        nyse.roll(good_friday, RollConvention::ModifiedFollowing),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        nyse.roll(good_friday, RollConvention::Preceding),
+        date(2024, 3, 28)
+    );
+    assert_eq!(
+        nyse.roll(good_friday, RollConvention::Unadjusted),
+        good_friday
+    );
+    // Saturday 1 June 2024 rolls forward under modified preceding.
+    assert_eq!(
+        nyse.roll(date(2024, 6, 1), RollConvention::ModifiedPreceding),
+        date(2024, 6, 3)
+    );
+}
+
+#[test]
+fn test_year_fraction_excludes_holidays() {
+    let nyse = TradingCalendar::nyse();
+    let (start, end) = (date(2024, 1, 1), date(2025, 1, 1));
+    assert_eq!(nyse.year_fraction(DayCount::Bus252, start, end), 1.0);
+    assert!(DayCount::Bus252.year_fraction(start, end) > 1.0);
+    assert_eq!(
+        nyse.year_fraction(DayCount::Act365Fixed, start, end),
+        DayCount::Act365Fixed.year_fraction(start, end)
+    );
+    assert_eq!(nyse.trading_time_to_expiry(end, start), 0.0);
+}
@@ -0,0 +1,156 @@
+extern crate core;
+
+use core::chain::{OptionChain, OptionQuote};
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+
+/// Volatility with a downward skew in strike and an upward term structure.
+fn smile(k: f64, t: f64) -> f64 {
+    0.2 + 0.05 * t - 0.002 * (k - 100.0)
+}
+
+fn chain() -> OptionChain {
+    let model = BlackScholesModel;
+    let mut chain = OptionChain::new(100.0, 0.03);
+    for &t in &[0.25, 0.5, 1.0] {
+        for i in 0..=12 {
+            let k = 70.0 + 5.0 * i as f64;
+            for option_type in [OptionType::Call, OptionType::Put] {
+                let params = OptionParameters {
+                    s: 100.0,
+                    k,
+                    r: 0.03,
+                    sigma: smile(k, t),
+                    t,
+                };
+                let price = model.option_price(&params, option_type);
+                chain = chain.with_quote(OptionQuote::new(
+                    option_type,
+                    k,
+                    t,
+                    price - 0.01,
+                    price + 0.01,
+                ));
+            }
+        }
+    }
+    chain
+}
+
+#[test]
+fn test_chain_implied_vols_recover_the_smile() {
+    let chain = chain();
+    assert_eq!(chain.expiries(), vec![0.25, 0.5, 1.0]);
+    assert_eq!(chain.strikes().len(), 13);
+
+    let implied = chain.implied_vols(&BlackScholesModel);
+    for quote in &implied {
+        let vol = quote.implied_vol.expect("implied vol");
+        assert!((vol - smile(quote.quote.strike, quote.quote.expiry)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_chain_analytics_skew_and_term_structure() {
+    let analytics = chain().analytics(&BlackScholesModel);
+    assert_eq!(analytics.expiries.len(), 3);
+
+    for expiry in &analytics.expiries {
+        let forward = 100.0 * (0.03 * expiry.expiry).exp();
+        let atm = expiry.atm_vol.unwrap();
+        assert!((atm - smile(forward, expiry.expiry)).abs() < 1e-9);
+        // Downward skew: the 25-delta put trades richer than the 25-delta call.
+        assert!(expiry.skew_25d.unwrap() > 0.0);
+        assert!(expiry.put_25d_vol.unwrap() > atm);
+        assert!(expiry.call_25d_vol.unwrap() < atm);
+    }
+
+    // ATM vol rises 0.05 per year of expiry, less the skew along the forward.
+    let slope = analytics.term_slope.unwrap();
+    assert!(slope > 0.04 && slope < 0.05, "slope {}", slope);
+}
+
+#[test]
+fn test_chain_skips_quotes_without_implied_vol() {
+    let chain = OptionChain::new(100.0, 0.0).with_quote(OptionQuote::new(
+        OptionType::Call,
+        80.0,
+        0.5,
+        1.0,
+        1.0,
+    ));
+    let analytics = chain.analytics(&BlackScholesModel);
+    assert_eq!(analytics.quotes[0].implied_vol, None);
+    assert_eq!(analytics.expiries[0].atm_vol, None);
+    assert_eq!(analytics.term_slope, None);
+}
+
+fn positioned() -> OptionChain {
+    let model = BlackScholesModel;
+    let mut chain = OptionChain::new(100.0, 0.0);
+    let book = [
+        (OptionType::Call, 95.0, 10.0, 100.0),
+        (OptionType::Call, 100.0, 40.0, 300.0),
+        (OptionType::Call, 105.0, 50.0, 900.0),
+        (OptionType::Put, 95.0, 60.0, 800.0),
+        (OptionType::Put, 100.0, 30.0, 200.0),
+        (OptionType::Put, 105.0, 0.0, 50.0),
+    ];
+    for (option_type, k, volume, open_interest) in book {
+        let params = OptionParameters {
+            s: 100.0,
+            k,
+            r: 0.0,
+            sigma: 0.2,
+            t: 0.25,
+        };
+        let price = model.option_price(&params, option_type);
+        chain = chain.with_quote(
+            OptionQuote::new(option_type, k, 0.25, price, price)
+                .with_activity(volume, open_interest),
+        );
+    }
+    chain
+}
+
+#[test]
+fn test_chain_put_call_ratio_and_max_pain() {
+    let chain = positioned();
+    let ratio = chain.put_call_ratio();
+    assert!((ratio.volume.unwrap() - 90.0 / 100.0).abs() < 1e-12);
+    assert!((ratio.open_interest.unwrap() - 1050.0 / 1300.0).abs() < 1e-12);
+
+    // Payout to holders at 95: 1000 + 500; at 100: 500 + 250; at 105: 1000 + 1500.
+    assert_eq!(chain.max_pain(0.25), Some(100.0));
+    assert_eq!(chain.max_pain(1.0), None);
+
+    let empty = OptionChain::new(100.0, 0.0);
+    assert_eq!(empty.put_call_ratio().volume, None);
+}
+
+#[test]
+fn test_chain_gamma_exposure_by_strike() {
+    let model = BlackScholesModel;
+    let chain = positioned();
+    let profile = chain.gamma_exposure(&model, 100.0);
+    assert_eq!(
+        profile.iter().map(|e| e.strike).collect::<Vec<_>>(),
+        vec![95.0, 100.0, 105.0]
+    );
+
+    let gamma = model.gamma(&OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.0,
+        sigma: 0.2,
+        t: 0.25,
+    });
+    let atm = profile[1];
+    assert!((atm.call - gamma * 300.0 * 100.0 * 100.0).abs() < 1e-4);
+    assert!((atm.put + gamma * 200.0 * 100.0 * 100.0).abs() < 1e-4);
+    assert!((atm.net - (atm.call + atm.put)).abs() < 1e-12);
+    assert!(profile[0].net < 0.0);
+    assert!(profile[2].net > 0.0);
+
+    let total: f64 = profile.iter().map(|e| e.net).sum();
+    assert!((chain.total_gamma_exposure(&model, 100.0) - total).abs() < 1e-9);
+}
@@ -0,0 +1,79 @@
+extern crate core;
+
+use core::models::{BinomialTreeModel, BlackScholesModel, OptionParameters, OptionPricingModel};
+use core::strategies::{Leg, ModelSpec, Side, Strategy};
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+#[test]
+fn test_compare_models_against_reference() {
+    let bs = BlackScholesModel;
+    let tree = BinomialTreeModel {
+        steps: 500,
+        ..BinomialTreeModel::default()
+    };
+    let spread = Strategy::new(&bs)
+        .with_leg(Leg::call(Side::Long, params(95.0)))
+        .with_leg(Leg::call(Side::Short, params(105.0)));
+    let models: [(&str, &dyn OptionPricingModel); 2] =
+        [("black_scholes", &bs), ("binomial_tree", &tree)];
+
+    let comparison = spread.compare_models(&models);
+    assert_eq!(comparison.valuations.len(), 2);
+    let reference = &comparison.valuations[0];
+    assert_eq!(reference.model, "black_scholes");
+    assert_eq!(reference.price_difference, 0.0);
+    assert_eq!(reference.leg_differences, vec![0.0, 0.0]);
+    assert!((reference.price - spread.price()).abs() < 1e-12);
+
+    let other = &comparison.valuations[1];
+    let leg_sum: f64 = other.leg_differences.iter().sum();
+    assert!((leg_sum - other.price_difference).abs() < 1e-12);
+    assert!(other.price_difference.abs() < 0.05);
+    assert!(
+        (other.greeks_difference.delta - (other.greeks.delta - reference.greeks.delta)).abs()
+            < 1e-12
+    );
+    assert_eq!(
+        comparison.max_price_difference(),
+        other.price_difference.abs()
+    );
+
+    let json = comparison.to_json().unwrap();
+    assert!(json.contains("\"binomial_tree\""));
+    assert!(json.contains("price_difference"));
+}
+
+#[test]
+fn test_compare_definition_specs() {
+    let model = BlackScholesModel;
+    let definition = Strategy::new(&model)
+        .with_leg(Leg::put(Side::Long, params(100.0)))
+        .with_stock(Side::Long, 1.0, 100.0)
+        .definition(ModelSpec::BlackScholes);
+    let comparison = definition.compare_models(&[
+        ModelSpec::BlackScholes,
+        ModelSpec::MonteCarlo {
+            simulations: 20_000,
+            seed: Some(7),
+        },
+    ]);
+    let names: Vec<&str> = comparison
+        .valuations
+        .iter()
+        .map(|v| v.model.as_str())
+        .collect();
+    assert_eq!(names, ["black_scholes", "monte_carlo"]);
+    // The stock leg is valued identically, so only the put differs.
+    let mc = &comparison.valuations[1];
+    assert!((mc.price_difference - mc.leg_differences[0]).abs() < 1e-9);
+    assert!(definition.compare_models(&[]).valuations.is_empty());
+}
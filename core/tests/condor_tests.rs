@@ -0,0 +1,164 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel};
+use core::strategies::condor::Condor;
+use core::strategies::iron_butterfly::IronButterfly;
+use core::strategies::iron_condor::IronCondor;
+use core::strategies::{OptionStrategy, StrategyError};
+
+fn params(k: f64, t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t,
+    }
+}
+
+#[test]
+fn test_condor_from_strikes_matches_per_leg() {
+    let model = BlackScholesModel;
+    let classic = Condor::from_strikes(&model, params(90.0, 0.5), 95.0, 105.0, 110.0);
+    let per_leg = Condor::new(
+        &model,
+        params(90.0, 0.5),
+        params(95.0, 0.5),
+        params(105.0, 0.5),
+        params(110.0, 0.5),
+    );
+    assert_eq!(classic.price(), per_leg.price());
+    assert!(classic.validate().is_ok());
+}
+
+#[test]
+fn test_condor_payoff_and_neutral_delta() {
+    let model = BlackScholesModel;
+    let condor = Condor::from_strikes(&model, params(90.0, 0.5), 95.0, 105.0, 110.0);
+    let strategy = condor.strategy();
+    let debit = condor.price();
+    assert!(debit > 0.0);
+
+    // Flat at zero outside the wings and 5 between the short strikes.
+    assert_eq!(strategy.payoff(80.0), 0.0);
+    assert_eq!(strategy.payoff(100.0), 5.0);
+    assert_eq!(strategy.payoff(120.0), 0.0);
+    assert!((strategy.max_profit() - (5.0 - debit)).abs() < 1e-12);
+    assert!((strategy.max_loss() - debit).abs() < 1e-12);
+
+    // Centred on the spot, the long wings offset the short body.
+    let greeks = condor.greeks();
+    assert!(greeks.delta.abs() < 0.05);
+    assert!(greeks.gamma < 0.0);
+}
+
+#[test]
+fn test_calendarized_condor() {
+    let model = BlackScholesModel;
+    let condor = Condor::try_new(
+        &model,
+        params(90.0, 1.0),
+        params(95.0, 0.5),
+        params(105.0, 0.5),
+        params(110.0, 1.0),
+    );
+    assert!(condor.is_ok());
+
+    let split_body = Condor::try_new(
+        &model,
+        params(90.0, 1.0),
+        params(95.0, 0.5),
+        params(105.0, 1.0),
+        params(110.0, 1.0),
+    );
+    assert!(matches!(
+        split_body,
+        Err(StrategyError::ExpiryMismatch { leg: 2, .. })
+    ));
+}
+
+#[test]
+fn test_calendarized_iron_condor() {
+    let model = BlackScholesModel;
+    let same = IronCondor::new(
+        &model,
+        params(80.0, 0.25),
+        params(90.0, 0.25),
+        params(110.0, 0.25),
+        params(120.0, 0.25),
+    );
+    let calendarized = IronCondor::try_new(
+        &model,
+        params(80.0, 0.5),
+        params(90.0, 0.25),
+        params(110.0, 0.25),
+        params(120.0, 0.5),
+    )
+    .unwrap();
+
+    // Longer-dated wings cost more, so the credit shrinks.
+    assert!(calendarized.price() < same.price());
+    let wing = |k: f64, t: f64| model.put_price(&params(k, t));
+    let difference = wing(80.0, 0.5) - wing(80.0, 0.25);
+    let call_wing = |k: f64, t: f64| model.call_price(&params(k, t));
+    let difference = difference + call_wing(120.0, 0.5) - call_wing(120.0, 0.25);
+    assert!((same.price() - calendarized.price() - difference).abs() < 1e-12);
+
+    let split_body = IronCondor::try_new(
+        &model,
+        params(80.0, 0.5),
+        params(90.0, 0.25),
+        params(110.0, 0.5),
+        params(120.0, 0.5),
+    );
+    assert!(matches!(
+        split_body,
+        Err(StrategyError::ExpiryMismatch { leg: 2, .. })
+    ));
+}
+
+#[test]
+fn test_iron_butterfly_per_leg() {
+    let model = BlackScholesModel;
+    let classic = IronButterfly::from_body(
+        &model,
+        params(95.0, 0.5),
+        params(100.0, 0.5),
+        params(105.0, 0.5),
+    );
+    assert!(classic.validate().is_ok());
+    assert!(classic.price() > 0.0);
+
+    let calendarized = IronButterfly::try_new(
+        &model,
+        params(95.0, 1.0),
+        params(100.0, 0.5),
+        params(100.0, 0.5),
+        params(105.0, 1.0),
+    );
+    assert!(calendarized.is_ok());
+
+    let broken_body = IronButterfly::try_new(
+        &model,
+        params(95.0, 0.5),
+        params(100.0, 0.5),
+        params(101.0, 0.5),
+        params(105.0, 0.5),
+    );
+    assert!(matches!(
+        broken_body,
+        Err(StrategyError::StrikeMismatch { leg: 2, .. })
+    ));
+
+    let early_wing = IronButterfly::try_new(
+        &model,
+        params(95.0, 0.25),
+        params(100.0, 0.5),
+        params(100.0, 0.5),
+        params(105.0, 0.5),
+    );
+    assert!(matches!(
+        early_wing,
+        Err(StrategyError::ExpiryMismatch { leg: 0, .. })
+    ));
+}
@@ -0,0 +1,72 @@
+extern crate core;
+
+use chrono::NaiveDate;
+use core::time::{time_to_expiry, DayCount};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn test_actual_conventions() {
+    let (start, end) = (date(2024, 1, 1), date(2024, 7, 1));
+    assert_eq!(
+        DayCount::Act365Fixed.year_fraction(start, end),
+        182.0 / 365.0
+    );
+    assert_eq!(DayCount::Act360.year_fraction(start, end), 182.0 / 360.0);
+}
+
+#[test]
+fn test_thirty_360() {
+    assert_eq!(
+        DayCount::Thirty360.year_fraction(date(2024, 1, 31), date(2024, 7, 31)),
+        0.5
+    );
+    // End-of-February is not adjusted under the US bond basis.
+    assert_eq!(
+        DayCount::Thirty360.year_fraction(date(2024, 1, 15), date(2024, 2, 29)),
+        44.0 / 360.0
+    );
+}
+
+#[test]
+fn test_bus_252_skips_weekends() {
+    // Monday to the following Monday: five business days.
+    assert_eq!(
+        DayCount::Bus252.year_fraction(date(2024, 9, 2), date(2024, 9, 9)),
+        5.0 / 252.0
+    );
+    // Friday to Monday: one business day.
+    assert_eq!(
+        DayCount::Bus252.year_fraction(date(2024, 9, 6), date(2024, 9, 9)),
+        1.0 / 252.0
+    );
+    assert_eq!(
+        DayCount::Bus252.year_fraction(date(2024, 1, 1), date(2025, 1, 1)),
+        262.0 / 252.0
+    );
+}
+
+#[test]
+fn test_time_to_expiry() {
+    let trade = date(2024, 3, 15);
+    assert_eq!(
+        time_to_expiry(trade, date(2025, 3, 15), DayCount::Act365Fixed),
+        1.0
+    );
+    assert_eq!(
+        time_to_expiry(trade, date(2024, 3, 1), DayCount::Act365Fixed),
+        0.0
+    );
+    assert!(DayCount::Act360.year_fraction(trade, date(2024, 3, 1)) < 0.0);
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("act/365".parse(), Ok(DayCount::Act365Fixed));
+    assert_eq!("ACT_360".parse(), Ok(DayCount::Act360));
+    assert_eq!("30/360".parse(), Ok(DayCount::Thirty360));
+    assert_eq!(DayCount::Bus252.to_string().parse(), Ok(DayCount::Bus252));
+    assert!("ACT/ACT".parse::<DayCount>().is_err());
+}
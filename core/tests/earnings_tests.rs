@@ -0,0 +1,93 @@
+extern crate core;
+
+use core::models::earnings::EventCalibrationError;
+use core::models::{BlackScholesModel, EarningsModel, OptionParameters, OptionPricingModel};
+
+fn params(t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.03,
+        sigma: 0.25,
+        t,
+    }
+}
+
+#[test]
+fn test_pre_event_expiry_is_black_scholes() {
+    let model = EarningsModel::new(0.1, 0.08);
+    let p = params(0.05);
+    assert_eq!(model.call_price(&p), BlackScholesModel.call_price(&p));
+    assert_eq!(model.implied_vol(0.25, 0.05), 0.25);
+}
+
+#[test]
+fn test_post_event_expiry_adds_event_variance() {
+    let model = EarningsModel::new(0.1, 0.08);
+    let p = params(0.2);
+    let effective = (0.25_f64 * 0.25 + 0.08 * 0.08 / 0.2).sqrt();
+    assert!((model.implied_vol(0.25, 0.2) - effective).abs() < 1e-15);
+    let bs = BlackScholesModel.call_price(&OptionParameters {
+        sigma: effective,
+        ..p.clone()
+    });
+    assert!((model.call_price(&p) - bs).abs() < 1e-12);
+    assert!(model.call_price(&p) > BlackScholesModel.call_price(&p));
+    assert!((model.expected_absolute_move() - 0.08 * 0.797_884_56).abs() < 1e-8);
+}
+
+#[test]
+fn test_calibrate_recovers_event_move() {
+    let model = EarningsModel::new(0.05, 0.07);
+    let (t1, t2) = (0.1, 0.3);
+    let first = (t1, model.implied_vol(0.3, t1));
+    let second = (t2, model.implied_vol(0.3, t2));
+    let (diffusive, calibrated) = EarningsModel::calibrate(0.05, first, second).unwrap();
+    assert!((diffusive - 0.3).abs() < 1e-12);
+    assert!((calibrated.event_move - 0.07).abs() < 1e-12);
+
+    // A pre-event expiry gives the diffusive vol directly.
+    let (diffusive, calibrated) = EarningsModel::calibrate(0.05, (0.02, 0.3), second).unwrap();
+    assert!((diffusive - 0.3).abs() < 1e-12);
+    assert!((calibrated.event_move - 0.07).abs() < 1e-12);
+}
+
+#[test]
+fn test_calibrate_errors() {
+    assert_eq!(
+        EarningsModel::calibrate(0.05, (0.3, 0.4), (0.1, 0.3)).err(),
+        Some(EventCalibrationError::ExpiriesNotOrdered)
+    );
+    assert_eq!(
+        EarningsModel::calibrate(0.5, (0.1, 0.4), (0.3, 0.3)).err(),
+        Some(EventCalibrationError::NoExpiryAfterEvent)
+    );
+    // Vol rising steeply after the event implies a negative event variance.
+    assert_eq!(
+        EarningsModel::calibrate(0.05, (0.1, 0.2), (0.3, 0.4)).err(),
+        Some(EventCalibrationError::NegativeVariance)
+    );
+}
+
+#[test]
+fn test_greeks() {
+    let model = EarningsModel::new(0.1, 0.08);
+    let p = params(0.2);
+    let h = 1e-3;
+    let numeric_vega = (model.call_price(&OptionParameters {
+        sigma: 0.25 + h,
+        ..p.clone()
+    }) - model.call_price(&OptionParameters {
+        sigma: 0.25 - h,
+        ..p.clone()
+    })) / (2.0 * h);
+    assert!((model.vega(&p) - numeric_vega).abs() < 1e-3);
+    // Before the event the event variance does not decay, so theta is smaller than for a
+    // flat vol at the same effective level.
+    let flat = OptionParameters {
+        sigma: model.implied_vol(0.25, 0.2),
+        ..p.clone()
+    };
+    assert!(model.theta(&p) < 0.0);
+    assert!(model.theta(&p).abs() < BlackScholesModel.theta(&flat).abs());
+}
@@ -0,0 +1,61 @@
+extern crate core;
+
+use core::models::{
+    BlackScholesModel, EmployeeStockOptionModel, OptionParameters, OptionPricingModel,
+};
+
+fn grant() -> OptionParameters {
+    OptionParameters {
+        s: 50.0,
+        k: 50.0,
+        r: 0.05,
+        sigma: 0.3,
+        t: 10.0,
+    }
+}
+
+#[test]
+fn test_plain_option_matches_black_scholes() {
+    // No vesting, no exits and no early exercise: an American call without dividends,
+    // which is worth the European price.
+    let model = EmployeeStockOptionModel::default();
+    let expected = BlackScholesModel.call_price(&grant());
+    assert!((model.value(&grant()) - expected).abs() < 0.05);
+    assert!((model.expected_life(&grant()) - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_exits_and_early_exercise_reduce_value() {
+    let plain = EmployeeStockOptionModel::default().value(&grant());
+    let with_exits = EmployeeStockOptionModel::new(0.0, 0.05, f64::INFINITY).value(&grant());
+    let with_multiple = EmployeeStockOptionModel::new(0.0, 0.0, 2.0).value(&grant());
+    let hull_white = EmployeeStockOptionModel::new(3.0, 0.05, 2.0);
+    let value = hull_white.value(&grant());
+
+    assert!(with_exits < plain);
+    assert!(with_multiple < plain);
+    assert!(value > 0.0 && value < with_exits.min(with_multiple));
+
+    let life = hull_white.expected_life(&grant());
+    assert!(life > 3.0 && life < 10.0);
+}
+
+#[test]
+fn test_vesting_forfeits_on_exit() {
+    let vested = EmployeeStockOptionModel::new(0.0, 0.1, f64::INFINITY).value(&grant());
+    let unvested = EmployeeStockOptionModel::new(5.0, 0.1, f64::INFINITY).value(&grant());
+    assert!(unvested < vested);
+
+    // Fully vesting at expiry with exits: only stayers are paid.
+    let cliff = EmployeeStockOptionModel::new(10.0, 0.1, f64::INFINITY).value(&grant());
+    let stay = (-0.1_f64 * 10.0).exp();
+    let expected = stay * BlackScholesModel.call_price(&grant());
+    assert!((cliff - expected).abs() < 0.05);
+}
+
+#[test]
+fn test_dividends_lower_value() {
+    let model = EmployeeStockOptionModel::new(3.0, 0.05, 2.0);
+    let with_dividends = EmployeeStockOptionModel::new(3.0, 0.05, 2.0).with_dividend_yield(0.02);
+    assert!(with_dividends.value(&grant()) < model.value(&grant()));
+}
@@ -0,0 +1,132 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::factory::{
+    create_definition, create_strategy, create_strategy_by_name, create_strategy_from_json,
+};
+use core::strategies::iron_condor::IronCondor;
+use core::strategies::{FactoryError, ModelSpec, OptionStrategy, StrategyError, StrategyKind};
+use serde_json::{json, Map, Value};
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+fn object(value: Value) -> Map<String, Value> {
+    value.as_object().unwrap().clone()
+}
+
+#[test]
+fn test_kind_names_round_trip() {
+    for kind in StrategyKind::ALL {
+        assert_eq!(kind.name().parse::<StrategyKind>(), Ok(kind));
+        assert_eq!(
+            serde_json::to_string(&kind).unwrap(),
+            format!("\"{}\"", kind)
+        );
+    }
+    assert_eq!(
+        "strangle_swap".parse::<StrategyKind>(),
+        Err(FactoryError::UnknownKind("strangle_swap".to_string()))
+    );
+}
+
+#[test]
+fn test_every_kind_builds_from_its_parameters() {
+    let model = BlackScholesModel;
+    let strikes = [90.0, 95.0, 105.0, 110.0];
+    for kind in StrategyKind::ALL {
+        let mut map = object(json!({"s": 100.0, "r": 0.05, "sigma": 0.2, "t": 0.5}));
+        for (i, name) in kind.parameters().iter().enumerate() {
+            let value = match *name {
+                "k" => 100.0,
+                "t2" => 1.0,
+                _ => strikes[i],
+            };
+            map.insert(name.to_string(), json!(value));
+        }
+        let strategy = create_strategy(&model, kind, &map).unwrap();
+        assert!(strategy.price().is_finite(), "{}", kind);
+    }
+}
+
+#[test]
+fn test_factory_matches_direct_construction() {
+    let model = BlackScholesModel;
+    let json = r#"{"kind": "iron_condor", "s": 100, "r": 0.05, "sigma": 0.2, "t": 0.5,
+                   "k1": 85, "k2": 95, "k3": 105, "k4": 115}"#;
+    let built = create_strategy_from_json(&model, json).unwrap();
+    let direct = IronCondor::new(
+        &model,
+        params(85.0),
+        params(95.0),
+        params(105.0),
+        params(115.0),
+    );
+    assert_eq!(built.price(), direct.price());
+    assert_eq!(built.greeks(), direct.greeks());
+}
+
+#[test]
+fn test_definition_legs_match_built_strategy() {
+    let model = BlackScholesModel;
+    let map = object(json!({"s": 100, "r": 0.05, "sigma": 0.2, "t": 0.5,
+                            "k1": 85, "k2": 95, "k3": 105, "k4": 115}));
+    let definition = create_definition(StrategyKind::IronCondor, &map).unwrap();
+    assert_eq!(definition.legs.len(), 4);
+    assert_eq!(definition.model, ModelSpec::BlackScholes);
+
+    let built = create_strategy(&model, StrategyKind::IronCondor, &map).unwrap();
+    let legs = definition.strategy(&model);
+    // The legs value the position, so the credit the condor quotes is a negative value.
+    assert!((legs.price() + built.price()).abs() < 1e-12);
+    assert_eq!(legs.greeks(), built.greeks());
+    assert_eq!(legs.breakevens().len(), 2);
+    assert!(legs.max_loss() > 0.0 && legs.max_loss() < 10.0);
+
+    let mut map = object(json!({"s": 100, "r": 0.05, "sigma": 0.2, "t": 0.5}));
+    assert_eq!(
+        create_definition(StrategyKind::Straddle, &map).err(),
+        Some(FactoryError::MissingParameter("k"))
+    );
+    map.insert("k".to_string(), json!(100));
+    let straddle = create_definition(StrategyKind::Straddle, &map).unwrap();
+    assert_eq!(straddle.legs.len(), 2);
+}
+
+#[test]
+fn test_factory_errors() {
+    let model = BlackScholesModel;
+    let base = json!({"s": 100.0, "r": 0.05, "sigma": 0.2, "t": 0.5});
+
+    let missing = create_strategy_by_name(&model, "straddle", &object(base.clone()));
+    assert_eq!(missing.err(), Some(FactoryError::MissingParameter("k")));
+
+    let mut map = object(base.clone());
+    map.insert("k".to_string(), json!(100.0));
+    map.insert("is_call".to_string(), json!("yes"));
+    let wrong = create_strategy(&model, StrategyKind::SingleLeg, &map);
+    assert_eq!(wrong.err(), Some(FactoryError::WrongType("is_call")));
+
+    let mut map = object(base);
+    map.insert("k1".to_string(), json!(110.0));
+    map.insert("k2".to_string(), json!(90.0));
+    let unordered = create_strategy(&model, StrategyKind::Strangle, &map);
+    assert_eq!(
+        unordered.err(),
+        Some(FactoryError::Strategy(StrategyError::StrikesNotOrdered {
+            strikes: vec![110.0, 90.0]
+        }))
+    );
+
+    assert!(matches!(
+        create_strategy_from_json(&model, "[1, 2]"),
+        Err(FactoryError::Json(_))
+    ));
+}
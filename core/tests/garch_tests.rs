@@ -1,6 +1,6 @@
 extern crate core;
 
-use core::models::{GarchModel, OptionParameters, OptionPricingModel};
+use core::models::{BinomialTreeModel, GarchModel, OptionParameters, OptionPricingModel};
 
 #[test]
 fn test_call_price() {
@@ -44,7 +44,7 @@ fn test_delta() {
     let model = GarchModel::default();
     let delta = model.delta(&params);
     println!("Delta: {}", delta);
-    assert!(delta >= -1.0 && delta <= 1.0);
+    assert!((-1.0..=1.0).contains(&delta));
 }
 
 #[test]
@@ -106,3 +106,53 @@ fn test_rho() {
     println!("Rho: {}", rho);
     assert!(rho >= 0.0);
 }
+
+#[test]
+fn test_call_put_price() {
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    };
+    let model = GarchModel::default();
+    let (call, put) = model.call_put_price(&params);
+    assert_eq!(call, model.call_price(&params));
+    assert_eq!(put, model.put_price(&params));
+}
+
+#[test]
+fn test_constant_variance_matches_binomial_tree() {
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    };
+    // Long-run variance equal to the seed: the variance term structure is flat.
+    let daily_variance = 0.04 / 252.0;
+    let model = GarchModel::new(100, daily_variance * 0.1, 0.1, 0.8, 1e-5);
+    let tree = BinomialTreeModel::new(100, 1e-5);
+
+    assert!((model.call_price(&params) - tree.call_price(&params)).abs() < 1e-8);
+    assert!((model.put_price(&params) - tree.put_price(&params)).abs() < 1e-8);
+}
+
+#[test]
+fn test_innovations_drive_conditional_variance() {
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.25,
+    };
+    let calm = GarchModel::default().with_innovations(vec![0.001; 20], 252.0);
+    let stressed = GarchModel::default().with_innovations(vec![0.05; 20], 252.0);
+
+    assert!(stressed.conditional_variance(0.2) > calm.conditional_variance(0.2));
+    assert!(stressed.call_price(&params) > calm.call_price(&params));
+    assert!(stressed.put_price(&params) > calm.put_price(&params));
+}
@@ -0,0 +1,48 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::guts::Guts;
+use core::strategies::OptionStrategy;
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    }
+}
+
+#[test]
+fn test_guts_price_and_payoff() {
+    let model = BlackScholesModel;
+    let guts = Guts::new(&model, params(95.0), params(105.0));
+    let strangle = guts.equivalent_strangle();
+    let carry = 10.0 * (-0.05_f64).exp();
+    assert!((guts.price() - strangle.price() - carry).abs() < 1e-9);
+
+    // The guts always pays at least the strike difference.
+    let strategy = guts.strategy();
+    assert_eq!(strategy.payoff(100.0), 10.0);
+    assert_eq!(strategy.payoff(80.0), 25.0);
+    assert_eq!(strategy.payoff(120.0), 25.0);
+}
+
+#[test]
+fn test_guts_extrinsic_comparison() {
+    let model = BlackScholesModel;
+    let guts = Guts::new(&model, params(95.0), params(105.0));
+    let comparison = guts.extrinsic_comparison();
+    assert!(comparison.guts_extrinsic > 0.0);
+    assert!(comparison.strangle_extrinsic > 0.0);
+    let expected = 10.0 * ((-0.05_f64).exp() - 1.0);
+    assert!((comparison.difference - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_guts_validation() {
+    let model = BlackScholesModel;
+    assert!(Guts::try_new(&model, params(95.0), params(105.0)).is_ok());
+    assert!(Guts::try_new(&model, params(105.0), params(95.0)).is_err());
+}
@@ -0,0 +1,80 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use core::strategies::{Leg, Side, Strategy};
+
+fn params(k: f64, t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t,
+    }
+}
+
+#[test]
+fn test_delta_hedge_with_multipliers() {
+    let model = BlackScholesModel;
+    let strategy = Strategy::new(&model)
+        .with_leg(Leg::new(
+            OptionType::Call,
+            Side::Long,
+            3.0,
+            params(100.0, 0.5),
+        ))
+        .with_stock(Side::Short, 50.0, 100.0);
+    let delta = model.option_delta(&params(100.0, 0.5), OptionType::Call);
+
+    let hedge = strategy.hedge(100.0, 1.0);
+    assert!((hedge.underlying - (50.0 - 300.0 * delta)).abs() < 1e-9);
+    assert_eq!(hedge.option, None);
+    assert_eq!(hedge.residual.delta, 0.0);
+    assert!(hedge.residual.gamma > 0.0);
+
+    // Hedging with a future on 50 shares needs a fiftieth as many contracts.
+    let futures = strategy.hedge(100.0, 50.0);
+    assert!((futures.underlying * 50.0 - hedge.underlying).abs() < 1e-9);
+}
+
+#[test]
+fn test_gamma_hedge() {
+    let model = BlackScholesModel;
+    let short_straddle = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Short, params(100.0, 0.25)))
+        .with_leg(Leg::put(Side::Short, params(100.0, 0.25)));
+
+    let hedge = short_straddle
+        .hedge_gamma(OptionType::Call, params(105.0, 0.5), 100.0, 1.0)
+        .unwrap();
+    let option = hedge.option.clone().unwrap();
+    assert_eq!(option.side, Side::Long);
+    assert_eq!(hedge.residual.gamma, 0.0);
+
+    // Applying the trades leaves no Delta or Gamma.
+    let mut hedged = Strategy {
+        model: &model,
+        legs: short_straddle.legs.clone(),
+        stock: Vec::new(),
+    }
+    .with_leg(option);
+    let shares = hedge.underlying / 100.0;
+    let side = if shares < 0.0 {
+        Side::Short
+    } else {
+        Side::Long
+    };
+    hedged = hedged.with_stock(side, shares.abs(), 100.0);
+    let greeks = hedged.greeks();
+    assert!(greeks.delta.abs() < 1e-9);
+    assert!(greeks.gamma.abs() < 1e-12);
+
+    // A deep in-the-money call at zero volatility has no Gamma to hedge with.
+    let expired = OptionParameters {
+        sigma: 0.0,
+        ..params(50.0, 0.25)
+    };
+    assert!(short_straddle
+        .hedge_gamma(OptionType::Call, expired, 100.0, 1.0)
+        .is_none());
+}
@@ -0,0 +1,74 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::implied_move::{expected_move, ImpliedMoveError};
+use core::strategies::straddle::Straddle;
+use core::strategies::strangle::Strangle;
+use core::strategies::{Leg, OptionStrategy, Side, Strategy, StrategyError};
+
+fn params(k: f64, sigma: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma,
+        t: 0.25,
+    }
+}
+
+#[test]
+fn test_forward_straddle_prices_the_expected_move() {
+    let model = BlackScholesModel;
+    let forward = 100.0 * (0.05_f64 * 0.25).exp();
+    let straddle = Straddle::new(&model, params(forward, 0.3));
+    let price = straddle.price();
+
+    let implied = straddle.implied_move(price).unwrap();
+    assert!((implied.volatility - 0.3).abs() < 1e-9);
+    // At the forward strike the straddle's undiscounted value is the expected move.
+    assert!((implied.absolute - price * (0.05_f64 * 0.25).exp()).abs() < 1e-9);
+    assert!((implied.percent - implied.absolute).abs() < 1e-12);
+    assert!((expected_move(&params(100.0, 0.3), 0.3) - implied.absolute).abs() < 1e-9);
+
+    let repriced = straddle.price_for_move(implied.absolute).unwrap();
+    assert!((repriced - price).abs() < 1e-9);
+}
+
+#[test]
+fn test_strangle_implied_move() {
+    let model = BlackScholesModel;
+    let strangle = Strangle::new(&model, params(110.0, 0.2), params(90.0, 0.2));
+    let richer = Strangle::new(&model, params(110.0, 0.4), params(90.0, 0.4)).price();
+
+    let implied = strangle.implied_move(richer).unwrap();
+    assert!((implied.volatility - 0.4).abs() < 1e-8);
+    assert!(implied.absolute > strangle.implied_move(strangle.price()).unwrap().absolute);
+    assert!((strangle.price_for_move(implied.absolute).unwrap() - richer).abs() < 1e-8);
+
+    // A price below the strangle's value at any volatility cannot be implied.
+    assert!(strangle.implied_move(-1.0).is_err());
+}
+
+#[test]
+fn test_implied_move_rejects_empty_and_mismatched_legs() {
+    let model = BlackScholesModel;
+    let empty = Strategy::new(&model);
+    assert_eq!(empty.implied_move(5.0), Err(ImpliedMoveError::NoOptionLegs));
+    assert_eq!(
+        empty.price_for_move(5.0),
+        Err(ImpliedMoveError::NoOptionLegs)
+    );
+
+    let mut later = params(100.0, 0.2);
+    later.t = 0.5;
+    let split = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(100.0, 0.2)))
+        .with_leg(Leg::put(Side::Long, later));
+    assert!(matches!(
+        split.implied_move(5.0),
+        Err(ImpliedMoveError::Strategy(StrategyError::ExpiryMismatch {
+            leg: 1,
+            ..
+        }))
+    ));
+}
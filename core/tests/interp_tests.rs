@@ -0,0 +1,115 @@
+extern crate core;
+
+use core::math::interp::{Curve, Extrapolation, InterpError, Kind, Surface};
+
+#[test]
+fn test_linear_curve() {
+    let curve = Curve::new(
+        vec![0.0, 1.0, 3.0],
+        vec![1.0, 3.0, 4.0],
+        Kind::Linear,
+        Extrapolation::Flat,
+    )
+    .unwrap();
+    assert_eq!(curve.value(0.5), 2.0);
+    assert_eq!(curve.value(2.0), 3.5);
+    assert_eq!(curve.value(3.0), 4.0);
+    assert_eq!(curve.value(-1.0), 1.0);
+    assert_eq!(curve.value(10.0), 4.0);
+}
+
+#[test]
+fn test_linear_extrapolation() {
+    let curve = Curve::new(
+        vec![0.0, 1.0, 3.0],
+        vec![1.0, 3.0, 4.0],
+        Kind::Linear,
+        Extrapolation::Linear,
+    )
+    .unwrap();
+    assert_eq!(curve.value(-1.0), -1.0);
+    assert_eq!(curve.value(5.0), 5.0);
+}
+
+#[test]
+fn test_natural_spline_reproduces_lines_and_smooth_functions() {
+    let line = Curve::new(
+        vec![0.0, 1.0, 2.5, 4.0],
+        vec![1.0, 3.0, 6.0, 9.0],
+        Kind::NaturalCubic,
+        Extrapolation::Linear,
+    )
+    .unwrap();
+    assert!((line.value(1.7) - 4.4).abs() < 1e-12);
+    assert!((line.value(5.0) - 11.0).abs() < 1e-12);
+
+    let xs: Vec<f64> = (0..=20).map(|i| i as f64 * 0.25).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    let spline = Curve::new(xs, ys, Kind::NaturalCubic, Extrapolation::Flat).unwrap();
+    for x in [0.4, 1.3, 2.2, 3.9] {
+        assert!((spline.value(x) - f64::sin(x)).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_monotone_cubic_does_not_overshoot() {
+    let xs = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys = vec![0.0, 0.0, 1.0, 1.0, 1.0];
+    let monotone = Curve::new(
+        xs.clone(),
+        ys.clone(),
+        Kind::MonotoneCubic,
+        Extrapolation::Flat,
+    )
+    .unwrap();
+    let spline = Curve::new(xs, ys, Kind::NaturalCubic, Extrapolation::Flat).unwrap();
+
+    let grid: Vec<f64> = (0..=400).map(|i| i as f64 * 0.01).collect();
+    let mut previous = monotone.value(0.0);
+    for &x in &grid {
+        let v = monotone.value(x);
+        assert!((0.0..=1.0).contains(&v));
+        assert!(v >= previous - 1e-15);
+        previous = v;
+    }
+    assert!(grid.iter().any(|&x| spline.value(x) > 1.0));
+}
+
+#[test]
+fn test_surface() {
+    let xs = vec![0.25, 0.5, 1.0];
+    let ys = vec![90.0, 100.0, 110.0];
+    // Bilinear function: exact under bilinear interpolation.
+    let f = |x: f64, y: f64| 0.2 + 0.1 * x - 0.001 * y + 0.002 * x * y;
+    let values = xs
+        .iter()
+        .map(|&x| ys.iter().map(|&y| f(x, y)).collect())
+        .collect();
+    let surface = Surface::new(xs, ys, values, Kind::Linear, Extrapolation::Flat).unwrap();
+
+    assert!((surface.value(0.75, 95.0) - f(0.75, 95.0)).abs() < 1e-12);
+    assert!((surface.value(0.3, 107.0) - f(0.3, 107.0)).abs() < 1e-12);
+    assert!((surface.value(2.0, 120.0) - f(1.0, 110.0)).abs() < 1e-12);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Curve::new(vec![0.0], vec![1.0], Kind::Linear, Extrapolation::Flat).unwrap_err(),
+        InterpError::TooFewPoints
+    );
+    assert_eq!(
+        Curve::new(vec![0.0, 1.0], vec![1.0], Kind::Linear, Extrapolation::Flat).unwrap_err(),
+        InterpError::LengthMismatch { xs: 2, ys: 1 }
+    );
+    assert_eq!(
+        Curve::new(
+            vec![0.0, 1.0, 1.0],
+            vec![1.0, 2.0, 3.0],
+            Kind::NaturalCubic,
+            Extrapolation::Flat
+        )
+        .unwrap_err(),
+        InterpError::NotIncreasing { index: 2 }
+    );
+}
@@ -0,0 +1,63 @@
+extern crate core;
+
+use core::models::inverse::{inverse_payoff, usd_to_coin};
+use core::models::{BlackScholesModel, InverseOptionModel, OptionParameters, OptionPricingModel};
+
+fn params(s: f64) -> OptionParameters {
+    OptionParameters {
+        s,
+        k: 60_000.0,
+        r: 0.0,
+        sigma: 0.6,
+        t: 0.25,
+    }
+}
+
+#[test]
+fn test_coin_premium() {
+    let model = InverseOptionModel::new(BlackScholesModel);
+    let p = params(62_000.0);
+    let usd = BlackScholesModel.call_price(&p);
+    assert!((model.call_price(&p) - usd / 62_000.0).abs() < 1e-15);
+    let (call, put) = model.call_put_price(&p);
+    assert_eq!(call, model.call_price(&p));
+    assert_eq!(put, model.put_price(&p));
+    assert_eq!(usd_to_coin(31_000.0, 62_000.0), 0.5);
+}
+
+#[test]
+fn test_inverse_payoff() {
+    assert!((inverse_payoff(80_000.0, 60_000.0, true) - 0.25).abs() < 1e-15);
+    assert_eq!(inverse_payoff(50_000.0, 60_000.0, true), 0.0);
+    assert!((inverse_payoff(50_000.0, 60_000.0, false) - 0.2).abs() < 1e-15);
+}
+
+#[test]
+fn test_greeks_are_derivatives_of_coin_premium() {
+    let model = InverseOptionModel::new(BlackScholesModel);
+    let s = 62_000.0;
+    let h = 1.0;
+    let up = model.call_price(&params(s + h));
+    let mid = model.call_price(&params(s));
+    let down = model.call_price(&params(s - h));
+
+    let delta = model.delta(&params(s));
+    assert!((delta - (up - down) / (2.0 * h)).abs() < 1e-10);
+    let gamma = model.gamma(&params(s));
+    assert!((gamma - (up - 2.0 * mid + down) / (h * h)).abs() < 1e-11);
+    assert!((model.vega(&params(s)) - BlackScholesModel.vega(&params(s)) / s).abs() < 1e-12);
+}
+
+#[test]
+fn test_premium_adjusted_delta() {
+    let model = InverseOptionModel::new(BlackScholesModel);
+    let p = params(62_000.0);
+    let call_delta = BlackScholesModel.delta(&p);
+    let adjusted = model.premium_adjusted_delta(&p, true);
+    assert!((adjusted - (call_delta - model.call_price(&p))).abs() < 1e-15);
+    assert!((model.delta(&p) * p.s - adjusted).abs() < 1e-12);
+
+    let put = model.premium_adjusted_delta(&p, false);
+    assert!((put - (call_delta - 1.0 - model.put_price(&p))).abs() < 1e-15);
+    assert!(put < -0.4);
+}
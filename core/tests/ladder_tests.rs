@@ -0,0 +1,62 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel};
+use core::strategies::christmas_tree::ChristmasTree;
+use core::strategies::ladder::Ladder;
+use core::strategies::OptionStrategy;
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+#[test]
+fn test_call_christmas_tree() {
+    let model = BlackScholesModel;
+    let tree = ChristmasTree::new(&model, params(95.0), 105.0, 110.0, true);
+    let call = |k: f64| model.call_price(&params(k));
+    let expected = call(95.0) - 3.0 * call(105.0) + 2.0 * call(110.0);
+    assert!((tree.price() - expected).abs() < 1e-12);
+
+    let strategy = tree.strategy();
+    assert_eq!(strategy.payoff(90.0), 0.0);
+    assert_eq!(strategy.payoff(105.0), 10.0);
+    // Beyond k3 the position is flat: 1 - 3 + 2 calls.
+    assert_eq!(strategy.payoff(110.0), 0.0);
+    assert_eq!(strategy.payoff(130.0), 0.0);
+    assert!(strategy.max_loss().is_finite());
+}
+
+#[test]
+fn test_put_christmas_tree() {
+    let model = BlackScholesModel;
+    let tree = ChristmasTree::new(&model, params(90.0), 95.0, 105.0, false);
+    let strategy = tree.strategy();
+    assert_eq!(strategy.payoff(110.0), 0.0);
+    assert_eq!(strategy.payoff(95.0), 10.0);
+    assert_eq!(strategy.payoff(80.0), 0.0);
+    assert!(ChristmasTree::try_new(&model, params(105.0), 95.0, 90.0, false).is_err());
+}
+
+#[test]
+fn test_ladders() {
+    let model = BlackScholesModel;
+    let ladder = Ladder::new(&model, params(95.0), 105.0, 110.0, true);
+    let call = |k: f64| model.call_price(&params(k));
+    assert!((ladder.price() - (call(95.0) - call(105.0) - call(110.0))).abs() < 1e-12);
+    let strategy = ladder.strategy();
+    assert_eq!(strategy.payoff(105.0), 10.0);
+    assert_eq!(strategy.payoff(120.0), 0.0);
+    assert_eq!(strategy.max_loss(), f64::INFINITY);
+    assert!(ladder.greeks().vega < 0.0);
+
+    let puts = Ladder::new(&model, params(90.0), 95.0, 105.0, false).strategy();
+    assert_eq!(puts.payoff(95.0), 10.0);
+    assert_eq!(puts.payoff(80.0), 0.0);
+    assert!(puts.max_loss().is_finite());
+}
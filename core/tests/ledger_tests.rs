@@ -0,0 +1,124 @@
+extern crate core;
+
+use chrono::NaiveDate;
+use core::models::{BlackScholesModel, OptionParameters, OptionType};
+use core::portfolio::{Contract, Event, Ledger, LedgerError, Position};
+use core::strategies::Side;
+
+fn params(k: f64, t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t,
+    }
+}
+
+fn stock() -> Contract {
+    Contract::Stock { underlying: None }
+}
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+}
+
+#[test]
+fn test_fifo_realized_pnl() {
+    let mut ledger = Ledger::new();
+    ledger
+        .open(Position::stock(Side::Long, 100.0, 100.0, 100.0))
+        .unwrap();
+    ledger
+        .open(Position::stock(Side::Long, 50.0, 105.0, 105.0))
+        .unwrap();
+
+    // The first 120 shares come from the 100 @ 100 lot, then 20 @ 105.
+    let realized = ledger.close(stock(), Side::Long, 120.0, 110.0).unwrap();
+    assert_eq!(realized, 100.0 * 10.0 + 20.0 * 5.0);
+    assert_eq!(ledger.lots().len(), 1);
+    assert_eq!(ledger.lots()[0].quantity, 30.0);
+    assert_eq!(ledger.lots()[0].entry_price, 105.0);
+
+    let err = ledger.close(stock(), Side::Long, 31.0, 110.0).unwrap_err();
+    assert_eq!(
+        err,
+        LedgerError::InsufficientQuantity {
+            requested: 31.0,
+            available: 30.0
+        }
+    );
+    assert_eq!(ledger.events().len(), 3);
+    assert_eq!(ledger.realized_pnl(), 1100.0);
+
+    // Short lots realize the entry price less the cover price.
+    ledger
+        .open(Position::stock(Side::Short, 10.0, 110.0, 110.0))
+        .unwrap();
+    assert_eq!(
+        ledger.close(stock(), Side::Short, 10.0, 100.0).unwrap(),
+        100.0
+    );
+}
+
+#[test]
+fn test_roll_and_replay() {
+    let model = BlackScholesModel;
+    let mut ledger = Ledger::new();
+    let near = Position::option(OptionType::Call, Side::Short, 2.0, params(105.0, 0.1), 1.5)
+        .with_expiry(date(2, 16));
+    ledger.open(near.clone()).unwrap();
+
+    let far = Position::option(OptionType::Call, Side::Short, 2.0, params(105.0, 0.35), 3.0)
+        .with_expiry(date(5, 17));
+    let contract = Contract::of(&near).unwrap();
+    let realized = ledger.roll(contract, Side::Short, 2.0, 0.5, far).unwrap();
+    assert_eq!(realized, 2.0);
+    assert_eq!(ledger.lots().len(), 1);
+    assert_eq!(ledger.lots()[0].entry_price, 3.0);
+
+    let unrealized = ledger.unrealized_pnl(&model);
+    let marked = ledger.portfolio().unrealized_pnl(&model);
+    assert!((unrealized - marked).abs() < 1e-12);
+
+    let json = serde_json::to_string(ledger.events()).unwrap();
+    let events: Vec<Event> = serde_json::from_str(&json).unwrap();
+    let replayed = Ledger::from_events(events).unwrap();
+    assert_eq!(replayed, ledger);
+}
+
+#[test]
+fn test_close_matches_the_expiry_date_as_time_passes() {
+    let mut ledger = Ledger::new();
+    let opened = Position::option(OptionType::Put, Side::Long, 3.0, params(95.0, 0.5), 2.0)
+        .with_underlying("SPY")
+        .with_expiry(date(6, 21));
+    ledger.open(opened).unwrap();
+
+    // A month later the same contract is quoted with less time left and a new spot.
+    let today = Position::option(
+        OptionType::Put,
+        Side::Long,
+        3.0,
+        OptionParameters {
+            s: 92.0,
+            ..params(95.0, 0.4)
+        },
+        3.5,
+    )
+    .with_underlying("SPY")
+    .with_expiry(date(6, 21));
+    let contract = Contract::of(&today).unwrap();
+
+    // The same strike and expiry on another underlying is a different contract.
+    let other = Contract::of(&today.clone().with_underlying("QQQ")).unwrap();
+    assert!(ledger.close(other, Side::Long, 1.0, 3.5).is_err());
+
+    let realized = ledger.close(contract, Side::Long, 3.0, 3.5).unwrap();
+    assert_eq!(realized, 3.0 * 1.5);
+    assert!(ledger.lots().is_empty());
+
+    // Option lots need an expiry date to be matched at all.
+    let undated = Position::option(OptionType::Put, Side::Long, 1.0, params(95.0, 0.5), 2.0);
+    assert_eq!(ledger.open(undated), Err(LedgerError::MissingExpiry));
+}
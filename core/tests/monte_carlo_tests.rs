@@ -1,14 +1,14 @@
 extern crate core;
 
+use core::math::random::SeededSource;
+use core::models::black_scholes::BlackScholesModel;
 use core::models::monte_carlo::MonteCarloModel;
 use core::models::{OptionParameters, OptionPricingModel};
+use rand::rngs::StdRng;
 
 #[test]
 fn test_call_price() {
-    let model = MonteCarloModel {
-        simulations: 100000,
-        epsilon: 0.01,
-    };
+    let model = MonteCarloModel::new(100000, 0.01);
     let params = OptionParameters {
         s: 100.0,
         k: 100.0,
@@ -22,10 +22,7 @@ fn test_call_price() {
 
 #[test]
 fn test_put_price() {
-    let model = MonteCarloModel {
-        simulations: 100000,
-        epsilon: 0.01,
-    };
+    let model = MonteCarloModel::new(100000, 0.01);
     let params = OptionParameters {
         s: 100.0,
         k: 100.0,
@@ -37,3 +34,33 @@ fn test_put_price() {
     assert!((put_price - 5.57).abs() < 1.0);
 }
 
+#[test]
+fn test_seeded_prices_are_reproducible() {
+    let model = MonteCarloModel::new(20000, 0.01).with_rng(SeededSource::<StdRng>::new(7));
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    };
+    let first = model.call_price(&params);
+    assert_eq!(first, model.call_price(&params));
+
+    let other = MonteCarloModel::new(20000, 0.01).with_rng(SeededSource::<StdRng>::new(8));
+    assert_ne!(first, other.call_price(&params));
+}
+
+#[test]
+fn test_seeded_delta_uses_common_random_numbers() {
+    let model = MonteCarloModel::new(20000, 0.5).with_rng(SeededSource::<StdRng>::new(11));
+    let params = OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    };
+    let delta = model.delta(&params);
+    assert!((delta - BlackScholesModel.delta(&params)).abs() < 0.02);
+}
@@ -0,0 +1,92 @@
+extern crate core;
+
+use core::math::optimize::{numerical_gradient, Lbfgsb, NelderMead, OptimizeError};
+
+fn rosenbrock(x: &[f64]) -> f64 {
+    (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2)
+}
+
+fn rosenbrock_gradient(x: &[f64]) -> Vec<f64> {
+    vec![
+        -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+        200.0 * (x[1] - x[0] * x[0]),
+    ]
+}
+
+#[test]
+fn test_nelder_mead_rosenbrock() {
+    let min = NelderMead::default()
+        .minimize(rosenbrock, &[-1.2, 1.0], None)
+        .unwrap();
+    assert!(min.converged);
+    assert!((min.x[0] - 1.0).abs() < 1e-3);
+    assert!((min.x[1] - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_lbfgsb_rosenbrock() {
+    let min = Lbfgsb::default()
+        .minimize(rosenbrock, rosenbrock_gradient, &[-1.2, 1.0], None)
+        .unwrap();
+    assert!(min.converged);
+    assert!((min.x[0] - 1.0).abs() < 1e-6);
+    assert!((min.x[1] - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn test_box_constraints_are_active() {
+    // Unconstrained minimum at (3, -2), outside the box.
+    let f = |x: &[f64]| (x[0] - 3.0).powi(2) + (x[1] + 2.0).powi(2);
+    let bounds = [(0.0, 1.0), (-1.0, 1.0)];
+
+    let nm = NelderMead::default()
+        .minimize(f, &[0.5, 0.5], Some(&bounds))
+        .unwrap();
+    assert!((nm.x[0] - 1.0).abs() < 1e-4 && (nm.x[1] + 1.0).abs() < 1e-4);
+
+    let lb = Lbfgsb::default()
+        .minimize(
+            f,
+            |x| numerical_gradient(f, x, 1e-6),
+            &[0.5, 0.5],
+            Some(&bounds),
+        )
+        .unwrap();
+    assert!(lb.converged);
+    assert_eq!(lb.x, vec![1.0, -1.0]);
+}
+
+#[test]
+fn test_deterministic() {
+    let a = NelderMead::default()
+        .minimize(rosenbrock, &[0.0, 0.0], None)
+        .unwrap();
+    let b = NelderMead::default()
+        .minimize(rosenbrock, &[0.0, 0.0], None)
+        .unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        NelderMead::default().minimize(rosenbrock, &[], None),
+        Err(OptimizeError::Empty)
+    );
+    assert_eq!(
+        Lbfgsb::default().minimize(
+            rosenbrock,
+            rosenbrock_gradient,
+            &[0.0, 0.0],
+            Some(&[(0.0, 1.0)])
+        ),
+        Err(OptimizeError::DimensionMismatch {
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        NelderMead::default().minimize(rosenbrock, &[0.0, 0.0], Some(&[(0.0, 1.0), (2.0, 1.0)])),
+        Err(OptimizeError::InvalidBounds { index: 1 })
+    );
+}
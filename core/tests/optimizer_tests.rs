@@ -0,0 +1,171 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::portfolio::{Portfolio, ShockGrid};
+use core::strategies::optimizer::{
+    iron_condor_template, put_credit_spread_template, Candidate, Constraints, Objective,
+    OptimizerError, StrategyOptimizer,
+};
+
+fn params() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.25,
+    }
+}
+
+fn strikes() -> Vec<f64> {
+    (0..9).map(|i| 80.0 + 5.0 * i as f64).collect()
+}
+
+#[test]
+fn test_enumerates_all_candidates() {
+    let model = BlackScholesModel;
+    let optimizer = StrategyOptimizer::new(
+        &model,
+        params(),
+        strikes(),
+        vec![0.25, 0.5],
+        put_credit_spread_template(),
+    )
+    .unwrap();
+    // C(9, 2) strike pairs at two expiries.
+    let all = optimizer.optimize(Objective::CreditToWidth, usize::MAX);
+    assert_eq!(all.len(), 72);
+    assert!(all.windows(2).all(|pair| pair[0].score >= pair[1].score));
+    for candidate in &all {
+        assert!(candidate.strikes[0] < candidate.strikes[1]);
+    }
+}
+
+#[test]
+fn test_probability_objective_with_constraints() {
+    let model = BlackScholesModel;
+    let constraints = Constraints {
+        max_loss: Some(8.0),
+        delta_band: Some((-0.1, 0.1)),
+        min_probability: None,
+        max_margin: None,
+    };
+    let optimizer = StrategyOptimizer::new(
+        &model,
+        params(),
+        strikes(),
+        vec![0.25],
+        iron_condor_template(),
+    )
+    .unwrap()
+    .with_constraints(constraints);
+
+    let best = optimizer.optimize(Objective::ProbabilityOfProfit, 3);
+    assert_eq!(best.len(), 3);
+    for candidate in &best {
+        let strategy = candidate.strategy(&model);
+        assert!(strategy.max_loss() <= 8.0);
+        assert!(strategy.greeks().delta.abs() <= 0.1);
+        assert!((strategy.probability_of_profit(None).unwrap() - candidate.score).abs() < 1e-12);
+    }
+    assert!(best[0].score >= best[2].score);
+}
+
+#[test]
+fn test_custom_objective() {
+    let model = BlackScholesModel;
+    let optimizer = StrategyOptimizer::new(
+        &model,
+        params(),
+        strikes(),
+        vec![0.25],
+        put_credit_spread_template(),
+    )
+    .unwrap();
+    // The largest credit comes from selling the highest put and buying the lowest.
+    let best = optimizer.optimize_by(|strategy, _| -strategy.price(), 1);
+    assert_eq!(best[0].strikes, vec![80.0, 120.0]);
+    assert!(-best[0].strategy(&model).price() > 0.0);
+    assert!(best[0].strategy(&model).greeks().delta > 0.0);
+}
+
+#[test]
+fn test_margin_constraint_prunes_wide_spreads() {
+    let model = BlackScholesModel;
+    let unconstrained = StrategyOptimizer::new(
+        &model,
+        params(),
+        strikes(),
+        vec![0.25],
+        put_credit_spread_template(),
+    )
+    .unwrap();
+    let all = unconstrained.optimize(Objective::CreditToWidth, usize::MAX);
+
+    let margin = |candidate: &Candidate| {
+        let mut book = Portfolio::new();
+        book.add_strategy(&candidate.strategy(&model));
+        book.margin(&model, &ShockGrid::standard()).total
+    };
+    let constraints = Constraints {
+        max_margin: Some(3.0),
+        ..Constraints::default()
+    };
+    let kept = unconstrained
+        .with_constraints(constraints)
+        .optimize(Objective::CreditToWidth, usize::MAX);
+    assert!(!kept.is_empty() && kept.len() < all.len());
+    assert!(kept.iter().all(|candidate| margin(candidate) <= 3.0));
+    let pruned = all.len() - kept.len();
+    assert_eq!(
+        pruned,
+        all.iter()
+            .filter(|candidate| margin(candidate) > 3.0)
+            .count()
+    );
+}
+
+#[test]
+fn test_expiring_candidates_are_skipped() {
+    let model = BlackScholesModel;
+    let optimizer = StrategyOptimizer::new(
+        &model,
+        params(),
+        strikes(),
+        vec![0.0, 0.5],
+        iron_condor_template(),
+    )
+    .unwrap();
+    for objective in [Objective::ProbabilityOfProfit, Objective::ExpectedPnl] {
+        let best = optimizer.optimize(objective, 3);
+        assert_eq!(best.len(), 3);
+        assert!(best.iter().all(|candidate| candidate.expiry == 0.5));
+    }
+
+    let constrained = optimizer.with_constraints(Constraints {
+        min_probability: Some(0.0),
+        ..Constraints::default()
+    });
+    let all = constrained.optimize(Objective::CreditToWidth, usize::MAX);
+    assert_eq!(all.len(), 126);
+    assert!(all.iter().all(|candidate| candidate.expiry == 0.5));
+}
+
+#[test]
+fn test_rejects_non_positive_volatility() {
+    let model = BlackScholesModel;
+    for sigma in [0.0, -0.2, f64::NAN] {
+        let params = OptionParameters { sigma, ..params() };
+        let optimizer = StrategyOptimizer::new(
+            &model,
+            params,
+            strikes(),
+            vec![0.25],
+            iron_condor_template(),
+        );
+        assert!(matches!(
+            optimizer.err(),
+            Some(OptimizerError::InvalidVolatility(_))
+        ));
+    }
+}
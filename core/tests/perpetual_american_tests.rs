@@ -0,0 +1,61 @@
+extern crate core;
+
+use core::models::{OptionParameters, OptionPricingModel, PerpetualAmericanModel};
+
+fn params(s: f64) -> OptionParameters {
+    OptionParameters {
+        s,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    }
+}
+
+#[test]
+fn test_put_without_dividends() {
+    // h2 = -2r/σ² = -2.5, so the boundary is 100 · 2.5 / 3.5 and the price K/3.5 · 1.4^-2.5.
+    let model = PerpetualAmericanModel::default();
+    let expected = 100.0 / 3.5 * 1.4_f64.powf(-2.5);
+    assert!((model.put_price(&params(100.0)) - expected).abs() < 1e-10);
+    assert!((model.put_boundary(&params(100.0)) - 100.0 * 2.5 / 3.5).abs() < 1e-10);
+    assert_eq!(model.put_price(&params(60.0)), 40.0);
+}
+
+#[test]
+fn test_call_without_dividends_is_never_exercised() {
+    let model = PerpetualAmericanModel::default();
+    assert_eq!(model.call_boundary(&params(100.0)), f64::INFINITY);
+    assert!((model.call_price(&params(100.0)) - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_value_matching_and_smooth_pasting() {
+    let model = PerpetualAmericanModel::new(0.03);
+    let boundary = model.call_boundary(&params(100.0));
+    assert!(boundary > 100.0);
+
+    let below = params(boundary * (1.0 - 1e-9));
+    assert!((model.call_price(&below) - (boundary - 100.0)).abs() < 1e-6);
+    assert!((model.delta(&below) - 1.0).abs() < 1e-6);
+    assert_eq!(model.gamma(&params(boundary * 1.1)), 0.0);
+
+    let put_boundary = model.put_boundary(&params(100.0));
+    let above = params(put_boundary * (1.0 + 1e-9));
+    assert!((model.put_price(&above) - (100.0 - put_boundary)).abs() < 1e-6);
+}
+
+#[test]
+fn test_greeks() {
+    let model = PerpetualAmericanModel::new(0.03);
+    let p = params(100.0);
+    let h = 1e-4;
+    let numeric_delta =
+        (model.call_price(&params(100.0 + h)) - model.call_price(&params(100.0 - h))) / (2.0 * h);
+    assert!((model.delta(&p) - numeric_delta).abs() < 1e-6);
+    assert!(model.gamma(&p) > 0.0);
+    assert!(model.vega(&p) > 0.0);
+    assert_eq!(model.theta(&p), 0.0);
+    assert!(model.put_price(&p) > 0.0);
+    assert!(model.call_price(&p) > model.call_price(&params(90.0)));
+}
@@ -0,0 +1,253 @@
+extern crate core;
+
+use core::models::{BinomialTreeModel, BlackScholesModel, OptionParameters, OptionType};
+use core::portfolio::{Instrument, MarketChange, Portfolio, Position, ShockGrid};
+use core::strategies::{Leg, Side, Strategy};
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+fn params_at(k: f64, t: f64) -> OptionParameters {
+    OptionParameters { t, ..params(k) }
+}
+
+#[test]
+fn test_portfolio_add_remove_and_aggregate() {
+    let model = BlackScholesModel;
+    let mut book = Portfolio::new();
+    let call = book.add(Position::option(
+        OptionType::Call,
+        Side::Short,
+        2.0,
+        params(110.0),
+        3.0,
+    ));
+    let stock = book.add(Position::stock(Side::Long, 100.0, 100.0, 95.0));
+    assert_eq!(book.len(), 2);
+
+    let greeks = book.greeks(&model);
+    let call_delta = book.get(call).unwrap().greeks(&model).delta;
+    assert!((greeks.delta - (100.0 + call_delta)).abs() < 1e-12);
+    assert!(call_delta < 0.0);
+    assert!(greeks.gamma < 0.0);
+
+    assert_eq!(book.entry_cost(), 9500.0 - 6.0);
+    let expected = book.value(&model) - book.entry_cost();
+    assert!((book.unrealized_pnl(&model) - expected).abs() < 1e-9);
+
+    let removed = book.remove(stock).unwrap();
+    assert_eq!(removed.instrument, Instrument::Stock { spot: 100.0 });
+    assert!(book.remove(stock).is_none());
+    let ids: Vec<usize> = book.positions().map(|(id, _)| id).collect();
+    assert_eq!(ids, vec![call]);
+
+    // New ids are never reused.
+    assert_eq!(book.add(removed), 2);
+}
+
+#[test]
+fn test_portfolio_from_strategy_under_any_model() {
+    let model = BlackScholesModel;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(95.0)).with_entry_price(9.0))
+        .with_leg(Leg::call(Side::Short, params(105.0)))
+        .with_stock(Side::Short, 0.5, 100.0);
+    let mut book = Portfolio::new();
+    assert_eq!(book.add_strategy(&spread), vec![0, 1, 2]);
+
+    assert!((book.value(&model) - spread.price()).abs() < 1e-12);
+    assert!((book.greeks(&model).delta - spread.greeks().delta).abs() < 1e-12);
+    assert_eq!(book.get(0).unwrap().entry_price, 9.0);
+
+    let tree = BinomialTreeModel::default();
+    assert!((book.value(&tree) - book.value(&model)).abs() < 0.05);
+
+    let json = serde_json::to_string(&book).unwrap();
+    let restored: Portfolio = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, book);
+}
+
+#[test]
+fn test_greeks_ladder() {
+    let model = BlackScholesModel;
+    let mut book = Portfolio::new();
+    book.add(Position::option(
+        OptionType::Put,
+        Side::Long,
+        1.0,
+        params_at(90.0, 0.1),
+        0.5,
+    ));
+    book.add(Position::option(
+        OptionType::Call,
+        Side::Short,
+        1.0,
+        params_at(100.0, 0.1),
+        3.0,
+    ));
+    book.add(Position::option(
+        OptionType::Call,
+        Side::Long,
+        2.0,
+        params_at(100.0, 1.0),
+        10.0,
+    ));
+    book.add(Position::stock(Side::Long, 10.0, 100.0, 100.0));
+
+    let ladder = book.greeks_ladder(&model, &[0.25, 0.5], &[0.95, 1.05]);
+    assert_eq!(ladder.by_expiry.len(), 3);
+    assert_eq!(ladder.by_expiry[0].positions, 2);
+    assert_eq!(ladder.by_expiry[1].positions, 0);
+    assert_eq!(ladder.by_expiry[2].positions, 1);
+    assert_eq!(ladder.by_expiry[2].lower, 0.5);
+    assert_eq!(ladder.by_expiry[2].upper, None);
+    assert_eq!(ladder.by_moneyness[0].positions, 1);
+    assert_eq!(ladder.by_moneyness[1].positions, 2);
+    assert_eq!(ladder.by_moneyness[1].upper, Some(1.05));
+
+    // The short-dated ATM call carries the gamma; the long-dated calls carry the vega.
+    assert!(ladder.grid[0][1].gamma < 0.0);
+    assert!(ladder.grid[2][1].vega > 0.0);
+    assert_eq!(ladder.stock.delta, 10.0);
+
+    let total = book.greeks(&model);
+    assert!((ladder.total.delta - total.delta).abs() < 1e-12);
+    assert!((ladder.total.vega - total.vega).abs() < 1e-12);
+    let moneyness_vega: f64 = ladder.by_moneyness.iter().map(|b| b.greeks.vega).sum();
+    assert!((moneyness_vega - total.vega).abs() < 1e-12);
+
+    let json = serde_json::to_string(&ladder).unwrap();
+    assert!(json.contains("by_expiry"));
+}
+
+#[test]
+fn test_pnl_explain() {
+    let model = BlackScholesModel;
+    let mut book = Portfolio::new();
+    book.add(Position::option(
+        OptionType::Call,
+        Side::Long,
+        10.0,
+        params(100.0),
+        6.9,
+    ));
+    book.add(Position::option(
+        OptionType::Put,
+        Side::Short,
+        5.0,
+        params(95.0),
+        2.7,
+    ));
+    book.add(Position::stock(Side::Short, 3.0, 100.0, 100.0));
+
+    let change = MarketChange {
+        spot: 101.0,
+        volatility: 0.005,
+        rate: 0.001,
+        days: 1.0,
+    };
+    let explain = book.explain_pnl(&model, &change);
+    let terms = explain.delta + explain.gamma + explain.vega + explain.theta + explain.rho;
+    assert!((terms + explain.residual - explain.total).abs() < 1e-9);
+
+    // For a small move the Greeks explain nearly everything.
+    assert!(explain.residual.abs() < 0.02 * explain.total.abs());
+    assert!(explain.delta > 0.0);
+    assert!(explain.theta < 0.0);
+
+    // Stock P&L is pure delta.
+    let stock = book.get(2).unwrap().explain_pnl(&model, &change);
+    assert_eq!(stock.total, -3.0);
+    assert_eq!(stock.delta, -3.0);
+    assert_eq!(stock.residual, 0.0);
+
+    // Nothing moves: no P&L to explain.
+    let flat = MarketChange {
+        spot: 100.0,
+        ..MarketChange::default()
+    };
+    assert!(book.explain_pnl(&model, &flat).total.abs() < 1e-12);
+}
+
+#[test]
+fn test_scenario_margin() {
+    let model = BlackScholesModel;
+    let mut book = Portfolio::new();
+    // A short put on one underlying and a covered call on another.
+    book.add(
+        Position::option(OptionType::Put, Side::Short, 1.0, params(95.0), 3.0)
+            .with_underlying("AAA"),
+    );
+    book.add(Position::stock(Side::Long, 1.0, 100.0, 100.0).with_underlying("BBB"));
+    book.add(
+        Position::option(OptionType::Call, Side::Short, 1.0, params(105.0), 4.0)
+            .with_underlying("BBB"),
+    );
+
+    let report = book.margin(&model, &ShockGrid::standard());
+    assert_eq!(report.underlyings.len(), 2);
+    let short_put = &report.underlyings[0];
+    assert_eq!(short_put.underlying.as_deref(), Some("AAA"));
+    assert_eq!(short_put.scenarios.len(), 33);
+    assert_eq!(short_put.worst.spot_shock, -0.15);
+    assert_eq!(short_put.worst.volatility_shock, 0.05);
+    assert_eq!(short_put.margin, -short_put.worst.pnl);
+
+    // The covered call loses most when the stock falls, cushioned by the call premium.
+    let covered = &report.underlyings[1];
+    assert_eq!(covered.worst.spot_shock, -0.15);
+    assert!(covered.margin < 15.0);
+    assert_eq!(report.total, short_put.margin + covered.margin);
+
+    // A long option can only lose its premium.
+    let mut long = Portfolio::new();
+    long.add(Position::option(
+        OptionType::Call,
+        Side::Long,
+        1.0,
+        params(100.0),
+        7.0,
+    ));
+    let premium = long.value(&model);
+    assert!(long.margin(&model, &ShockGrid::standard()).total <= premium);
+}
+
+#[test]
+fn test_value_at_risk() {
+    let model = BlackScholesModel;
+    // 100 shares at 20% volatility over a year lose 100 * (1 - e^(-2.326 * 0.2)) at 99%.
+    let mut stock = Portfolio::new();
+    stock.add(Position::stock(Side::Long, 100.0, 100.0, 100.0));
+    let var = stock.value_at_risk(&model, 0.2, 0.99, 1.0);
+    assert!(var.spot_shock < 0.0);
+    assert!((var.var - 100.0 * 100.0 * -(-2.326348 * 0.2_f64).exp_m1()).abs() < 1e-2);
+
+    // Shorting the stock makes the rally the loss.
+    let mut short = Portfolio::new();
+    short.add(Position::stock(Side::Short, 100.0, 100.0, 100.0));
+    let var = short.value_at_risk(&model, 0.2, 0.99, 1.0);
+    assert!(var.spot_shock > 0.0);
+
+    // Covering the shares with a short call reduces the risk, and a longer horizon raises it.
+    let covered = stock.value_at_risk(&model, 0.2, 0.99, 1.0 / 252.0).var;
+    stock.add(Position::option(
+        OptionType::Call,
+        Side::Short,
+        100.0,
+        params(100.0),
+        7.0,
+    ));
+    assert!(stock.value_at_risk(&model, 0.2, 0.99, 1.0 / 252.0).var < covered);
+    assert!(stock.value_at_risk(&model, 0.2, 0.99, 10.0 / 252.0).var > covered / 2.0);
+    assert_eq!(
+        Portfolio::new().value_at_risk(&model, 0.2, 0.99, 1.0).var,
+        0.0
+    );
+}
@@ -0,0 +1,124 @@
+extern crate core;
+
+use core::math::random::{
+    cholesky, nearest_correlation, validate_correlation, CorrelatedNormal, CorrelationError,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_cholesky_reconstructs_matrix() {
+    let matrix = vec![
+        vec![4.0, 2.0, 0.4],
+        vec![2.0, 5.0, 1.0],
+        vec![0.4, 1.0, 3.0],
+    ];
+    let lower = cholesky(&matrix).unwrap();
+    for i in 0..3 {
+        for j in 0..3 {
+            let product: f64 = (0..3).map(|k| lower[i][k] * lower[j][k]).sum();
+            assert!((product - matrix[i][j]).abs() < 1e-12);
+        }
+        assert!(lower[i][i + 1..].iter().all(|&v| v == 0.0));
+    }
+}
+
+#[test]
+fn test_cholesky_rejects_indefinite() {
+    let matrix = vec![vec![1.0, 2.0], vec![2.0, 1.0]];
+    assert_eq!(
+        cholesky(&matrix),
+        Err(CorrelationError::NotPositiveDefinite { index: 1 })
+    );
+}
+
+#[test]
+fn test_validate_correlation() {
+    assert_eq!(validate_correlation(&[]), Err(CorrelationError::Empty));
+    assert_eq!(
+        validate_correlation(&[vec![1.0, 0.5], vec![0.4, 1.0]]),
+        Err(CorrelationError::NotSymmetric { row: 0, column: 1 })
+    );
+    assert_eq!(
+        validate_correlation(&[vec![1.0, 1.5], vec![1.5, 1.0]]),
+        Err(CorrelationError::InvalidEntry { row: 0, column: 1 })
+    );
+    assert_eq!(
+        validate_correlation(&[vec![2.0, 0.5], vec![0.5, 1.0]]),
+        Err(CorrelationError::NonUnitDiagonal { index: 0 })
+    );
+    assert_eq!(
+        validate_correlation(&[vec![1.0, 0.5], vec![0.5]]),
+        Err(CorrelationError::NotSquare { row: 1 })
+    );
+}
+
+#[test]
+fn test_nearest_correlation_repairs_matrix() {
+    // Pairwise correlations that cannot hold simultaneously.
+    let matrix = vec![
+        vec![1.0, 0.9, 0.7],
+        vec![0.9, 1.0, -0.4],
+        vec![0.7, -0.4, 1.0],
+    ];
+    assert!(cholesky(&matrix).is_err());
+
+    let repaired = nearest_correlation(&matrix, 1e-12, 1000);
+    assert!(validate_correlation(&repaired).is_ok());
+    assert!(cholesky(&repaired).is_ok());
+    let distance: f64 = (0..3)
+        .flat_map(|i| (0..3).map(move |j| (i, j)))
+        .map(|(i, j)| (repaired[i][j] - matrix[i][j]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+    assert!(distance < 0.5);
+
+    let sampler = CorrelatedNormal::with_repair(matrix.clone()).unwrap();
+    assert!(sampler.repaired().is_some());
+    assert!(CorrelatedNormal::new(matrix).is_err());
+}
+
+#[test]
+fn test_sampler_matches_target_correlation() {
+    let rho = 0.6;
+    let sampler = CorrelatedNormal::new(vec![vec![1.0, rho], vec![rho, 1.0]]).unwrap();
+    assert_eq!(sampler.dimension(), 2);
+    assert!(sampler.repaired().is_none());
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let n = 50_000;
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    let mut out = [0.0; 2];
+    for _ in 0..n {
+        sampler.sample(&mut rng, &mut out);
+        sxy += out[0] * out[1];
+        sxx += out[0] * out[0];
+        syy += out[1] * out[1];
+    }
+    let estimate = sxy / (sxx * syy).sqrt();
+    assert!((estimate - rho).abs() < 0.02);
+    assert!((sxx / n as f64 - 1.0).abs() < 0.03);
+}
+
+#[test]
+fn test_correlate_is_deterministic() {
+    let sampler = CorrelatedNormal::new(vec![vec![1.0, 0.8], vec![0.8, 1.0]]).unwrap();
+    let mut out = [0.0; 2];
+    sampler.correlate(&[1.0, 1.0], &mut out);
+    assert_eq!(out[0], 1.0);
+    assert!((out[1] - (0.8 + 0.6)).abs() < 1e-12);
+}
+
+#[test]
+fn test_seeded_source_streams() {
+    use core::math::random::{RngSource, SeededSource};
+    use rand::Rng;
+
+    let source = SeededSource::<StdRng>::new(3);
+    assert_eq!(source.seed(), 3);
+    let a: u64 = source.stream(0).gen();
+    let b: u64 = source.stream(0).gen();
+    let c: u64 = source.stream(1).gen();
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
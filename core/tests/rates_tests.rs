@@ -0,0 +1,103 @@
+extern crate core;
+
+use core::rates::{bachelier, black, CapFloor, Caplet, Swaption, VolatilityType, YieldCurve};
+
+#[test]
+fn test_yield_curve() {
+    let curve = YieldCurve::new(vec![1.0, 2.0], vec![0.04, 0.05]).unwrap();
+    assert!((curve.discount_factor(1.0) - (-0.04_f64).exp()).abs() < 1e-12);
+    assert!((curve.zero_rate(2.0) - 0.05).abs() < 1e-12);
+    // Linear in r·t: the midpoint has r·t = 0.07.
+    assert!((curve.discount_factor(1.5) - (-0.07_f64).exp()).abs() < 1e-12);
+    assert!((curve.forward_rate(1.0, 2.0) - (0.06_f64.exp() - 1.0)).abs() < 1e-12);
+    assert!(YieldCurve::new(vec![1.0], vec![0.04, 0.05]).is_err());
+
+    let flat = YieldCurve::flat(0.03);
+    assert!((flat.zero_rate(7.0) - 0.03).abs() < 1e-12);
+    assert!((flat.zero_rate(0.0) - 0.03).abs() < 1e-6);
+}
+
+#[test]
+fn test_black_and_bachelier() {
+    // At the money Black-76 is F (2N(σ√T / 2) - 1).
+    let atm = black(0.05, 0.05, 0.2, 1.0, true);
+    assert!((atm - 0.05 * 0.079_655_7).abs() < 1e-7);
+    assert!((black(0.05, 0.05, 0.2, 1.0, false) - atm).abs() < 1e-12);
+    // At the money Bachelier is σ√T / √(2π).
+    let normal = bachelier(0.01, 0.01, 0.005, 4.0, true);
+    assert!((normal - 0.01 / (2.0 * std::f64::consts::PI).sqrt()).abs() < 1e-9);
+    // Bachelier handles negative rates; parity holds for both models.
+    for price in [black, bachelier] {
+        let (f, k) = (0.03, 0.025);
+        let parity = price(f, k, 0.25, 2.0, true) - price(f, k, 0.25, 2.0, false);
+        assert!((parity - (f - k)).abs() < 1e-9);
+    }
+    assert!(bachelier(-0.002, 0.0, 0.006, 1.0, true) > 0.0);
+    assert!((black(0.05, 0.04, 0.2, 0.0, true) - 0.01).abs() < 1e-15);
+}
+
+#[test]
+fn test_cap_floor_parity() {
+    let curve = YieldCurve::new(vec![1.0, 3.0, 5.0], vec![0.03, 0.035, 0.04]).unwrap();
+    let cap = CapFloor::cap(0.035, 1_000_000.0, 5.0, 4.0);
+    let floor = CapFloor::floor(0.035, 1_000_000.0, 5.0, 4.0);
+    assert_eq!(cap.caplets().len(), 19);
+
+    let swap: f64 = cap
+        .caplets()
+        .iter()
+        .map(|c| {
+            c.notional * c.accrual() * curve.discount_factor(c.end) * (c.forward(&curve) - c.strike)
+        })
+        .sum();
+    for vol_type in [VolatilityType::Lognormal, VolatilityType::Normal] {
+        let vol = match vol_type {
+            VolatilityType::Lognormal => 0.2,
+            VolatilityType::Normal => 0.007,
+        };
+        let difference = cap.price(&curve, vol, vol_type) - floor.price(&curve, vol, vol_type);
+        assert!((difference - swap).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_caplet_matches_formula() {
+    let curve = YieldCurve::flat(0.04);
+    let caplet = Caplet {
+        start: 1.0,
+        end: 1.25,
+        strike: 0.04,
+        notional: 100.0,
+    };
+    let forward = caplet.forward(&curve);
+    let expected =
+        100.0 * 0.25 * curve.discount_factor(1.25) * black(forward, 0.04, 0.3, 1.0, true);
+    let price = caplet.caplet_price(&curve, 0.3, VolatilityType::Lognormal);
+    assert!((price - expected).abs() < 1e-12);
+    assert!(caplet.floorlet_price(&curve, 0.3, VolatilityType::Lognormal) > 0.0);
+}
+
+#[test]
+fn test_swaption_parity() {
+    let curve = YieldCurve::new(vec![1.0, 5.0, 10.0], vec![0.03, 0.037, 0.04]).unwrap();
+    let payer = Swaption {
+        expiry: 2.0,
+        tenor: 5.0,
+        frequency: 2.0,
+        strike: 0.04,
+        notional: 10_000_000.0,
+        payer: true,
+    };
+    let receiver = Swaption {
+        payer: false,
+        ..payer.clone()
+    };
+    let annuity = payer.annuity(&curve);
+    let forward = payer.forward_swap_rate(&curve);
+    assert!(annuity > 4.0 && annuity < 5.0);
+    assert!(forward > 0.03 && forward < 0.05);
+
+    let difference = payer.price(&curve, 0.2, VolatilityType::Lognormal)
+        - receiver.price(&curve, 0.2, VolatilityType::Lognormal);
+    assert!((difference - 10_000_000.0 * annuity * (forward - 0.04)).abs() < 1e-4);
+}
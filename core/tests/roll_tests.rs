@@ -0,0 +1,60 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::{Leg, Side, Strategy};
+
+fn params(k: f64, t: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t,
+    }
+}
+
+#[test]
+fn test_roll_short_put_out_in_time() {
+    let model = BlackScholesModel;
+    let current = Strategy::new(&model)
+        .with_leg(Leg::put(Side::Short, params(95.0, 0.1)).with_entry_price(2.0));
+    let target = current.rolled(Some(0.35), 0.0);
+    assert_eq!(target.legs[0].params.t, 0.35);
+    assert_eq!(target.legs[0].entry_price, None);
+
+    // Selling more time on the same strike collects a credit.
+    let roll = current.roll_to(&target);
+    assert!(roll.is_credit());
+    assert!((roll.cost - (target.price() - current.price())).abs() < 1e-12);
+
+    // The extra premium pushes the breakeven further below the strike.
+    assert_eq!(roll.breakevens_before.len(), 1);
+    assert_eq!(roll.breakevens_after.len(), 1);
+    assert!(roll.breakevens_after[0].price < roll.breakevens_before[0].price);
+
+    let change = roll.greeks_change;
+    assert!((change.delta - (roll.greeks_after.delta - roll.greeks_before.delta)).abs() < 1e-12);
+    assert!(change.vega < 0.0);
+}
+
+#[test]
+fn test_roll_call_spread_up() {
+    let model = BlackScholesModel;
+    let current = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(100.0, 0.25)))
+        .with_leg(Leg::call(Side::Short, params(110.0, 0.25)))
+        .with_stock(Side::Long, 1.0, 100.0);
+    let target = current.rolled(None, 5.0);
+    assert_eq!(target.legs[0].params.k, 105.0);
+    assert_eq!(target.legs[1].params.k, 115.0);
+    assert_eq!(target.stock, current.stock);
+
+    // Moving a debit call spread further out of the money cheapens it; the stock cancels.
+    let roll = current.roll_to(&target);
+    let options = |s: &Strategy<BlackScholesModel>| -> f64 {
+        s.legs.iter().map(|leg| leg.value(&model)).sum()
+    };
+    assert!((roll.cost - (options(&target) - options(&current))).abs() < 1e-9);
+    assert!(roll.is_credit());
+    assert!(roll.greeks_change.delta < 0.0);
+}
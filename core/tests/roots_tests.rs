@@ -0,0 +1,72 @@
+extern crate core;
+
+use core::math::roots::{bisection, brent, newton_raphson, RootError};
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel};
+
+#[test]
+fn test_bisection() {
+    let root = bisection(|x| x * x - 2.0, 0.0, 2.0, 1e-10, 200).unwrap();
+    assert!((root - 2.0_f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn test_newton_raphson() {
+    let root = newton_raphson(
+        |x| x.powi(3) - x - 2.0,
+        |x| 3.0 * x * x - 1.0,
+        1.5,
+        1e-12,
+        50,
+    )
+    .unwrap();
+    assert!((root.powi(3) - root - 2.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_brent() {
+    let root = brent(|x| x.cos() - x, 0.0, 1.0, 1e-14, 100).unwrap();
+    assert!((root - 0.739_085_133_215_160_6).abs() < 1e-12);
+}
+
+#[test]
+fn test_brent_recovers_black_scholes_volatility() {
+    let model = BlackScholesModel;
+    let params = OptionParameters {
+        s: 100.0,
+        k: 110.0,
+        r: 0.05,
+        sigma: 0.3,
+        t: 0.75,
+    };
+    let target = model.call_price(&params);
+    let sigma = brent(
+        |sigma| {
+            model.call_price(&OptionParameters {
+                sigma,
+                ..params.clone()
+            }) - target
+        },
+        0.01,
+        2.0,
+        1e-12,
+        100,
+    )
+    .unwrap();
+    assert!((sigma - 0.3).abs() < 1e-8);
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        brent(|x| x * x + 1.0, -1.0, 1.0, 1e-12, 100),
+        Err(RootError::NotBracketed { a: -1.0, b: 1.0 })
+    );
+    assert!(matches!(
+        bisection(|x| x - 0.3, 0.0, 1.0, 1e-15, 3),
+        Err(RootError::MaxIterations { .. })
+    ));
+    assert_eq!(
+        newton_raphson(|x| x * x - 1.0, |x| 2.0 * x, 0.0, 1e-12, 10),
+        Err(RootError::ZeroDerivative { x: 0.0 })
+    );
+}
@@ -0,0 +1,65 @@
+extern crate core;
+
+use core::models::{BinomialTreeModel, BlackScholesModel, OptionParameters, OptionPricingModel};
+use core::sanity::{self, Check};
+
+/// A model that prices puts as if they were calls, breaking parity.
+struct BrokenModel;
+
+impl OptionPricingModel for BrokenModel {
+    fn call_price(&self, params: &OptionParameters) -> f64 {
+        BlackScholesModel.call_price(params)
+    }
+    fn put_price(&self, params: &OptionParameters) -> f64 {
+        BlackScholesModel.call_price(params)
+    }
+    fn delta(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+    fn gamma(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+    fn vega(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+    fn theta(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+    fn rho(&self, _params: &OptionParameters) -> f64 {
+        0.0
+    }
+}
+
+fn params() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    }
+}
+
+fn strikes() -> Vec<f64> {
+    (70..=130).step_by(5).map(f64::from).collect()
+}
+
+#[test]
+fn test_black_scholes_is_clean() {
+    let report = sanity::validate(&BlackScholesModel, &params(), &strikes(), 1e-6);
+    assert!(report.is_clean(), "{:?}", report.violations);
+}
+
+#[test]
+fn test_binomial_tree_is_clean() {
+    let report = sanity::validate(&BinomialTreeModel::default(), &params(), &strikes(), 1e-6);
+    assert!(report.is_clean(), "{:?}", report.violations);
+}
+
+#[test]
+fn test_broken_model_reports_violations() {
+    let report = sanity::validate(&BrokenModel, &params(), &strikes(), 1e-6);
+    assert!(!report.is_clean());
+    assert_eq!(report.of(Check::PutCallParity).count(), strikes().len());
+    assert!(report.of(Check::PutMonotonicity).count() > 0);
+}
@@ -0,0 +1,125 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use core::portfolio::{Portfolio, Position, ScenarioMatrix, ScenarioMeasure, ShockGrid};
+use core::strategies::Side;
+
+fn params() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+#[test]
+fn test_symmetric_grid() {
+    let grid = ShockGrid::symmetric(0.2, 2, 0.1, 1);
+    assert_eq!(grid.spot_shocks, vec![-0.2, -0.1, 0.0, 0.1, 0.2]);
+    assert_eq!(grid.volatility_shocks, vec![-0.1, 0.0, 0.1]);
+
+    let flat = ShockGrid::symmetric(0.2, 0, 0.1, 0);
+    assert_eq!(flat.spot_shocks, vec![0.0]);
+    assert_eq!(flat.volatility_shocks, vec![0.0]);
+}
+
+#[test]
+fn test_price_matrix_matches_shocked_prices() {
+    let model = BlackScholesModel;
+    let grid = ShockGrid::symmetric(0.1, 1, 0.05, 1);
+    let matrix = ScenarioMatrix::new(
+        &model,
+        &params(),
+        OptionType::Call,
+        &grid,
+        ScenarioMeasure::Price,
+    );
+    assert_eq!(matrix.values.len(), 3);
+    assert!(matrix.values.iter().all(|row| row.len() == 3));
+
+    // The centre cell is today's price.
+    let today = model.option_price(&params(), OptionType::Call);
+    assert!((matrix.values[1][1] - today).abs() < 1e-12);
+
+    let up = OptionParameters {
+        s: 110.0,
+        sigma: 0.25,
+        ..params()
+    };
+    let expected = model.option_price(&up, OptionType::Call);
+    assert!((matrix.values[2][2] - expected).abs() < 1e-9);
+
+    // Calls gain with spot and volatility.
+    for i in 0..3 {
+        for j in 1..3 {
+            assert!(matrix.values[i][j] > matrix.values[i][j - 1]);
+            assert!(matrix.values[j][i] > matrix.values[j - 1][i]);
+        }
+    }
+}
+
+#[test]
+fn test_greek_matrix_and_range() {
+    let model = BlackScholesModel;
+    let grid = ShockGrid::standard();
+    let matrix = ScenarioMatrix::new(
+        &model,
+        &params(),
+        OptionType::Put,
+        &grid,
+        ScenarioMeasure::Delta,
+    );
+    let (min, max) = matrix.range().unwrap();
+    assert!(min >= -1.0 && max <= 0.0);
+    assert!(min < max);
+    let centre = matrix.values[5][1];
+    assert!((centre - model.option_greeks(&params(), OptionType::Put).delta).abs() < 1e-12);
+}
+
+#[test]
+fn test_volatility_shock_is_floored() {
+    let model = BlackScholesModel;
+    let grid = ShockGrid {
+        spot_shocks: vec![0.0],
+        volatility_shocks: vec![-1.0],
+    };
+    let matrix = ScenarioMatrix::new(
+        &model,
+        &params(),
+        OptionType::Call,
+        &grid,
+        ScenarioMeasure::Price,
+    );
+    assert!(matrix.values[0][0].is_finite());
+}
+
+#[test]
+fn test_shocked_portfolio_value() {
+    let model = BlackScholesModel;
+    let mut book = Portfolio::new();
+    book.add(Position::stock(Side::Long, 100.0, 100.0, 100.0));
+    book.add(Position::option(
+        OptionType::Put,
+        Side::Long,
+        100.0,
+        params(),
+        5.0,
+    ));
+
+    // No shock leaves the value unchanged.
+    assert!((book.shocked_value(&model, 0.0, 0.0) - book.value(&model)).abs() < 1e-9);
+
+    // The stock moves with the spot shock alone; the put is repriced under both.
+    let put = model.option_price(
+        &OptionParameters {
+            s: 90.0,
+            sigma: 0.25,
+            ..params()
+        },
+        OptionType::Put,
+    );
+    let crash = book.shocked_value(&model, -0.1, 0.05);
+    assert!((crash - (100.0 * 90.0 + 100.0 * put)).abs() < 1e-9);
+}
@@ -0,0 +1,52 @@
+extern crate core;
+
+use core::models::{BlackScholesModel, OptionParameters};
+use core::strategies::{Leg, ModelSpec, OptionStrategy, SharedStrategy, Side, Strategy};
+use std::sync::Arc;
+use std::thread;
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+fn bull_spread(low: f64, high: f64) -> SharedStrategy {
+    SharedStrategy::new(Arc::new(BlackScholesModel))
+        .with_leg(Leg::call(Side::Long, params(low)))
+        .with_leg(Leg::call(Side::Short, params(high)))
+}
+
+#[test]
+fn test_shared_strategy_matches_borrowed() {
+    let model = BlackScholesModel;
+    let borrowed = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(95.0)))
+        .with_leg(Leg::call(Side::Short, params(105.0)))
+        .with_stock(Side::Short, 0.5, 100.0);
+    let shared = borrowed.to_shared(Arc::new(BlackScholesModel));
+    assert_eq!(shared.price(), borrowed.price());
+    assert_eq!(shared.greeks(), borrowed.greeks());
+    assert_eq!(shared.strategy().breakevens(), borrowed.breakevens());
+
+    let definition = borrowed.definition(ModelSpec::BlackScholes);
+    assert_eq!(definition.shared().price(), borrowed.price());
+}
+
+#[test]
+fn test_shared_strategies_in_collections_and_threads() {
+    let book: Vec<SharedStrategy> = vec![bull_spread(90.0, 100.0), bull_spread(100.0, 110.0)];
+    let expected: Vec<f64> = book.iter().map(SharedStrategy::price).collect();
+
+    let handles: Vec<_> = book
+        .into_iter()
+        .map(|strategy| thread::spawn(move || strategy.price()))
+        .collect();
+    let prices: Vec<f64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(prices, expected);
+    assert!(prices[0] > prices[1]);
+}
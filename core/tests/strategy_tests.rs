@@ -0,0 +1,463 @@
+extern crate core;
+
+use core::models::black_scholes::standard_normal_cdf;
+use core::models::{
+    BinomialTreeModel, BlackScholesModel, OptionParameters, OptionPricingModel, OptionType,
+};
+use core::strategies::covered_call::CoveredCall;
+use core::strategies::iron_condor::IronCondor;
+use core::strategies::straddle::Straddle;
+use core::strategies::strangle::Strangle;
+use core::strategies::{
+    spot_grid, BreakevenDirection, Leg, ModelSpec, OptionStrategy, Side, Strategy,
+    StrategyDefinition, StrategyError,
+};
+
+fn params(k: f64) -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k,
+        r: 0.05,
+        sigma: 0.2,
+        t: 1.0,
+    }
+}
+
+#[test]
+fn test_vertical_spread_price_and_greeks() {
+    let model = BlackScholesModel;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(95.0)))
+        .with_leg(Leg::call(Side::Short, params(105.0)));
+
+    let expected = model.call_price(&params(95.0)) - model.call_price(&params(105.0));
+    assert!((spread.price() - expected).abs() < 1e-12);
+
+    let greeks = spread.greeks();
+    let delta = model.delta(&params(95.0)) - model.delta(&params(105.0));
+    assert!((greeks.delta - delta).abs() < 1e-12);
+    assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+}
+
+#[test]
+fn test_quantities_and_short_puts() {
+    let model = BlackScholesModel;
+    let leg = Leg::new(OptionType::Put, Side::Short, 3.0, params(100.0));
+    assert_eq!(leg.signed_quantity(), -3.0);
+
+    let strategy = Strategy::new(&model).with_leg(leg);
+    assert!((strategy.price() + 3.0 * model.put_price(&params(100.0))).abs() < 1e-12);
+
+    // A short put is long delta and short gamma and vega.
+    let greeks = strategy.greeks();
+    assert!(greeks.delta > 0.0);
+    assert!(greeks.gamma < 0.0);
+    assert!(greeks.vega < 0.0);
+    assert!((greeks.delta + 3.0 * (model.delta(&params(100.0)) - 1.0)).abs() < 1e-12);
+}
+
+#[test]
+fn test_stock_legs() {
+    let model = BlackScholesModel;
+    let covered = Strategy::new(&model)
+        .with_stock(Side::Long, 1.0, 100.0)
+        .with_leg(Leg::call(Side::Short, params(110.0)));
+
+    assert!((covered.price() - (100.0 - model.call_price(&params(110.0)))).abs() < 1e-12);
+    assert!((covered.greeks().delta - (1.0 - model.delta(&params(110.0)))).abs() < 1e-12);
+    assert_eq!(covered.payoff(90.0), 90.0);
+    assert_eq!(covered.payoff(120.0), 110.0);
+}
+
+#[test]
+fn test_payoff_at_expiry() {
+    let model = BlackScholesModel;
+    let butterfly = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(90.0)))
+        .with_leg(Leg::new(OptionType::Call, Side::Short, 2.0, params(100.0)))
+        .with_leg(Leg::call(Side::Long, params(110.0)));
+    assert_eq!(butterfly.payoff(80.0), 0.0);
+    assert_eq!(butterfly.payoff(100.0), 10.0);
+    assert_eq!(butterfly.payoff(105.0), 5.0);
+    assert_eq!(butterfly.payoff(120.0), 0.0);
+}
+
+#[test]
+fn test_put_greeks_match_parity_for_other_models() {
+    let model = BinomialTreeModel::default();
+    let strategy = Strategy::new(&model).with_leg(Leg::put(Side::Long, params(100.0)));
+    let h = 1e-2;
+    let up = model.put_price(&OptionParameters {
+        s: 100.0 + h,
+        ..params(100.0)
+    });
+    let down = model.put_price(&OptionParameters {
+        s: 100.0 - h,
+        ..params(100.0)
+    });
+    assert!((strategy.greeks().delta - (up - down) / (2.0 * h)).abs() < 0.02);
+}
+
+#[test]
+fn test_fixed_strategies_delegate_to_legs() {
+    let model = BlackScholesModel;
+    let condor = IronCondor::new(
+        &model,
+        params(80.0),
+        params(90.0),
+        params(110.0),
+        params(120.0),
+    );
+    let strategy = condor.strategy();
+    assert_eq!(strategy.legs.len(), 4);
+    // The iron condor is quoted as a credit; the strategy reports the holder's value.
+    assert!((condor.price() + strategy.price()).abs() < 1e-12);
+    assert!(condor.price() > 0.0);
+}
+
+#[test]
+fn test_strategy_greeks_through_trait() {
+    let model = BlackScholesModel;
+    let straddle = Straddle::new(&model, params(100.0));
+    let greeks = OptionStrategy::greeks(&straddle);
+    let delta = model.delta(&params(100.0)) + model.put_delta(&params(100.0));
+    assert!((greeks.delta - delta).abs() < 1e-12);
+    assert!((greeks.gamma - 2.0 * model.gamma(&params(100.0))).abs() < 1e-12);
+    assert!(greeks.theta < 0.0);
+
+    // The iron condor is sold, so it is short gamma and vega and collects theta.
+    let condor = IronCondor::new(
+        &model,
+        params(80.0),
+        params(90.0),
+        params(110.0),
+        params(120.0),
+    );
+    let greeks = OptionStrategy::greeks(&condor);
+    assert!(greeks.gamma < 0.0);
+    assert!(greeks.vega < 0.0);
+    assert!(greeks.theta > 0.0);
+}
+
+#[test]
+fn test_payoff_and_pnl_curves() {
+    let model = BlackScholesModel;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(95.0)))
+        .with_leg(Leg::call(Side::Short, params(105.0)));
+
+    let grid = spot_grid(80.0, 120.0, 5);
+    assert_eq!(grid, vec![80.0, 90.0, 100.0, 110.0, 120.0]);
+
+    let payoff = spread.payoff_curve(&grid);
+    let values: Vec<f64> = payoff.iter().map(|&(_, v)| v).collect();
+    assert_eq!(values, vec![0.0, 0.0, 5.0, 10.0, 10.0]);
+
+    let debit = spread.price();
+    for ((spot, pnl), (_, value)) in spread.pnl_curve(&grid).into_iter().zip(payoff) {
+        assert!(grid.contains(&spot));
+        assert!((pnl - (value - debit)).abs() < 1e-12);
+    }
+
+    // A credit structure profits by the premium when all options expire worthless.
+    let condor = IronCondor::new(
+        &model,
+        params(80.0),
+        params(90.0),
+        params(110.0),
+        params(120.0),
+    );
+    let pnl = condor.strategy().pnl_curve(&[100.0]);
+    assert!((pnl[0].1 - condor.price()).abs() < 1e-12);
+}
+
+#[test]
+fn test_pnl_surface() {
+    let model = BlackScholesModel;
+    let mut short = params(100.0);
+    short.t = 30.0 / 365.0;
+    let mut long = params(100.0);
+    long.t = 60.0 / 365.0;
+    let calendar = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Short, short))
+        .with_leg(Leg::call(Side::Long, long.clone()));
+
+    let grid = spot_grid(90.0, 110.0, 3);
+    let surface = calendar.pnl_surface(&grid, &[0.0, 30.0]);
+    assert_eq!(surface.pnl.len(), 2);
+    assert_eq!(surface.pnl[0].len(), 3);
+
+    // Today at today's spot the P&L is zero.
+    assert!(surface.pnl[0][1].abs() < 1e-12);
+
+    // At the short expiry the long call still carries time value, unlike the expiry payoff.
+    let remaining = OptionParameters {
+        t: 30.0 / 365.0,
+        ..long
+    };
+    let expected = model.call_price(&remaining) - calendar.price();
+    assert!((surface.pnl[1][1] - expected).abs() < 1e-9);
+    assert!(surface.pnl[1][1] > calendar.payoff(100.0) - calendar.price());
+}
+
+#[test]
+fn test_breakevens() {
+    let model = BlackScholesModel;
+
+    let straddle = Straddle::new(&model, params(100.0)).strategy();
+    let premium = straddle.price();
+    let breakevens = straddle.breakevens();
+    assert_eq!(breakevens.len(), 2);
+    assert!((breakevens[0].price - (100.0 - premium)).abs() < 1e-8);
+    assert_eq!(breakevens[0].direction, BreakevenDirection::Upper);
+    assert!((breakevens[1].price - (100.0 + premium)).abs() < 1e-8);
+    assert_eq!(breakevens[1].direction, BreakevenDirection::Lower);
+
+    let condor = IronCondor::new(
+        &model,
+        params(80.0),
+        params(90.0),
+        params(110.0),
+        params(120.0),
+    );
+    let credit = condor.price();
+    let breakevens = condor.strategy().breakevens();
+    assert_eq!(breakevens.len(), 2);
+    assert!((breakevens[0].price - (90.0 - credit)).abs() < 1e-8);
+    assert_eq!(breakevens[0].direction, BreakevenDirection::Lower);
+    assert!((breakevens[1].price - (110.0 + credit)).abs() < 1e-8);
+    assert_eq!(breakevens[1].direction, BreakevenDirection::Upper);
+
+    let covered = Strategy::new(&model)
+        .with_stock(Side::Long, 1.0, 100.0)
+        .with_leg(Leg::call(Side::Short, params(110.0)));
+    let breakevens = covered.breakevens();
+    assert_eq!(breakevens.len(), 1);
+    let expected = 100.0 - model.call_price(&params(110.0));
+    assert!((breakevens[0].price - expected).abs() < 1e-8);
+    assert_eq!(breakevens[0].direction, BreakevenDirection::Lower);
+}
+
+#[test]
+fn test_probability_of_profit_and_expected_pnl() {
+    let model = BlackScholesModel;
+    let p = params(100.0);
+
+    // A long call profits when S_T exceeds K plus the premium.
+    let call = Strategy::new(&model).with_leg(Leg::call(Side::Long, p.clone()));
+    let breakeven = 100.0 + call.price();
+    let sd = p.sigma * p.t.sqrt();
+    let d = ((p.s / breakeven).ln() + (p.r - 0.5 * p.sigma * p.sigma) * p.t) / sd;
+    let expected = standard_normal_cdf(d);
+    assert!((call.probability_of_profit(None).unwrap() - expected).abs() < 1e-6);
+
+    // Risk-neutral expected P&L is the undiscounted carry on the premium.
+    let carry = call.price() * ((p.r * p.t).exp() - 1.0);
+    assert!((call.expected_pnl(None).unwrap() - carry).abs() < 1e-3);
+
+    // A higher drift helps a long call.
+    assert!(call.expected_pnl(Some(0.15)).unwrap() > call.expected_pnl(None).unwrap());
+    assert!(
+        call.probability_of_profit(Some(0.15)).unwrap() > call.probability_of_profit(None).unwrap()
+    );
+
+    // A short straddle and a long straddle split the outcomes.
+    let long = Straddle::new(&model, p.clone()).strategy();
+    let short = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Short, p.clone()))
+        .with_leg(Leg::put(Side::Short, p));
+    let total =
+        long.probability_of_profit(None).unwrap() + short.probability_of_profit(None).unwrap();
+    assert!((total - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_probability_of_profit_rejects_mismatched_legs() {
+    let model = BlackScholesModel;
+    let mut skewed = params(110.0);
+    skewed.sigma = 0.25;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(100.0)))
+        .with_leg(Leg::call(Side::Short, skewed));
+    assert_eq!(
+        spread.probability_of_profit(None),
+        Err(StrategyError::VolatilityMismatch {
+            leg: 1,
+            expected: 0.2,
+            found: 0.25
+        })
+    );
+
+    let mut moved = params(110.0);
+    moved.s = 101.0;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(100.0)))
+        .with_leg(Leg::call(Side::Short, moved));
+    assert!(matches!(
+        spread.expected_pnl(None),
+        Err(StrategyError::SpotMismatch { leg: 1, .. })
+    ));
+
+    // Different expiries share one distribution; the later leg is revalued.
+    let mut far = params(100.0);
+    far.t = 2.0;
+    let calendar = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Short, params(100.0)))
+        .with_leg(Leg::call(Side::Long, far));
+    assert!(calendar.probability_of_profit(None).is_ok());
+}
+
+#[test]
+fn test_entry_prices_and_unrealized_pnl() {
+    let model = BlackScholesModel;
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::new(OptionType::Call, Side::Long, 2.0, params(95.0)).with_entry_price(10.0))
+        .with_leg(Leg::call(Side::Short, params(105.0)));
+    assert_eq!(spread.entry_cost(), None);
+    assert_eq!(spread.unrealized_pnl(), None);
+
+    let spread = Strategy::new(&model)
+        .with_leg(Leg::new(OptionType::Call, Side::Long, 2.0, params(95.0)).with_entry_price(10.0))
+        .with_leg(Leg::call(Side::Short, params(105.0)).with_entry_price(4.0));
+    assert_eq!(spread.entry_cost(), Some(16.0));
+    let value = 2.0 * model.call_price(&params(95.0)) - model.call_price(&params(105.0));
+    assert!((spread.unrealized_pnl().unwrap() - (value - 16.0)).abs() < 1e-12);
+
+    // A covered call written for a credit against stock bought at 90.
+    let covered = Strategy::new(&model)
+        .with_stock_entry(Side::Long, 100.0, 100.0, 90.0)
+        .with_leg(
+            Leg::new(OptionType::Call, Side::Short, 100.0, params(110.0)).with_entry_price(5.0),
+        );
+    assert_eq!(covered.entry_cost(), Some(8500.0));
+    let value = 100.0 * (100.0 - model.call_price(&params(110.0)));
+    assert!((covered.unrealized_pnl().unwrap() - (value - 8500.0)).abs() < 1e-9);
+}
+
+#[test]
+fn test_json_round_trip() {
+    let model = BlackScholesModel;
+    let covered = Strategy::new(&model)
+        .with_stock_entry(Side::Long, 100.0, 100.0, 90.0)
+        .with_leg(
+            Leg::new(OptionType::Call, Side::Short, 100.0, params(110.0)).with_entry_price(5.0),
+        );
+
+    let json = covered.to_json(ModelSpec::BlackScholes).unwrap();
+    let restored = Strategy::from_json(&json, &model).unwrap();
+    assert_eq!(restored.legs, covered.legs);
+    assert_eq!(restored.stock, covered.stock);
+    assert_eq!(restored.price(), covered.price());
+}
+
+#[test]
+fn test_json_definition_with_model_choice() {
+    let json = r#"{
+        "model": {"type": "binomial_tree", "steps": 200},
+        "legs": [
+            {"option_type": "call", "side": "long", "params": {"s": 100.0, "k": 95.0, "r": 0.05, "sigma": 0.2, "t": 1.0}},
+            {"option_type": "call", "side": "short", "quantity": 2.0, "params": {"s": 100.0, "k": 105.0, "r": 0.05, "sigma": 0.2, "t": 1.0}}
+        ]
+    }"#;
+    let definition = StrategyDefinition::from_json(json).unwrap();
+    assert_eq!(definition.model, ModelSpec::BinomialTree { steps: 200 });
+    assert_eq!(definition.legs[0].quantity, 1.0);
+    assert_eq!(definition.legs[1].signed_quantity(), -2.0);
+    assert!(definition.stock.is_empty());
+
+    let model = definition.model.build();
+    let strategy = definition.strategy(model.as_ref());
+    let tree = BinomialTreeModel {
+        steps: 200,
+        ..BinomialTreeModel::default()
+    };
+    let expected = tree.call_price(&params(95.0)) - 2.0 * tree.call_price(&params(105.0));
+    assert!((strategy.price() - expected).abs() < 1e-12);
+
+    let again = StrategyDefinition::from_json(&definition.to_json().unwrap()).unwrap();
+    assert_eq!(again, definition);
+
+    assert!(StrategyDefinition::from_json(r#"{"legs": [{"side": "long"}]}"#).is_err());
+}
+
+#[test]
+fn test_validation() {
+    let model = BlackScholesModel;
+
+    let inverted = IronCondor::try_new(
+        &model,
+        params(120.0),
+        params(110.0),
+        params(90.0),
+        params(80.0),
+    );
+    assert_eq!(
+        inverted.err(),
+        Some(StrategyError::StrikesNotOrdered {
+            strikes: vec![120.0, 110.0, 90.0, 80.0]
+        })
+    );
+    assert!(IronCondor::try_new(
+        &model,
+        params(80.0),
+        params(90.0),
+        params(110.0),
+        params(120.0)
+    )
+    .is_ok());
+
+    let mut earlier = params(120.0);
+    earlier.t = 0.5;
+    let mismatch = IronCondor::try_new(&model, params(80.0), params(90.0), params(110.0), earlier);
+    assert!(matches!(
+        mismatch,
+        Err(StrategyError::ExpiryMismatch { leg: 3, .. })
+    ));
+
+    let zero_quantity =
+        Strategy::new(&model).with_leg(Leg::new(OptionType::Call, Side::Long, 0.0, params(100.0)));
+    assert!(matches!(
+        zero_quantity.validate(),
+        Err(StrategyError::InvalidQuantity { leg: 0, .. })
+    ));
+
+    let mut other_spot = params(105.0);
+    other_spot.s = 101.0;
+    let spots = Strategy::new(&model)
+        .with_leg(Leg::call(Side::Long, params(95.0)))
+        .with_leg(Leg::call(Side::Short, other_spot));
+    assert!(matches!(
+        spots.validate(),
+        Err(StrategyError::SpotMismatch { leg: 1, .. })
+    ));
+
+    let mut expired = params(100.0);
+    expired.t = 0.0;
+    let err = Strategy::new(&model)
+        .with_leg(Leg::put(Side::Long, expired))
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "leg 0: invalid expiry 0");
+}
+
+#[test]
+fn test_delta_targeted_construction() {
+    let model = BlackScholesModel;
+    let base = params(0.0);
+
+    let condor = IronCondor::from_deltas(&model, base.clone(), 0.16, 0.05).unwrap();
+    assert!(condor.validate().is_ok());
+    assert!((model.put_delta(&condor.params2) + 0.16).abs() < 1e-8);
+    assert!((model.delta(&condor.params3) - 0.16).abs() < 1e-8);
+    assert!((model.put_delta(&condor.params1) + 0.05).abs() < 1e-8);
+    assert!((model.delta(&condor.params4) - 0.05).abs() < 1e-8);
+    // Symmetric deltas leave the position roughly delta-neutral.
+    assert!(condor.greeks().delta.abs() < 0.01);
+
+    let covered = CoveredCall::from_delta(&model, base.clone(), 0.30).unwrap();
+    assert!((model.delta(&covered.params) - 0.30).abs() < 1e-8);
+    assert!((covered.greeks().delta - 0.70).abs() < 1e-8);
+
+    let strangle = Strangle::from_delta(&model, base, 0.25).unwrap();
+    assert!(strangle.params_put.k < 100.0 && strangle.params_call.k > 100.0);
+}
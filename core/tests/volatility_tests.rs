@@ -0,0 +1,95 @@
+extern crate core;
+
+use core::volatility::{Bar, Estimator};
+
+/// Bars that open at the previous close and close where they open, ranging `up` above
+/// and `down` below it.
+fn flat_bars(n: usize, up: f64, down: f64) -> Vec<Bar> {
+    (0..n)
+        .map(|_| Bar {
+            open: 100.0,
+            high: 100.0 * up,
+            low: 100.0 * down,
+            close: 100.0,
+        })
+        .collect()
+}
+
+#[test]
+fn test_close_to_close() {
+    // Steady growth has no return variance.
+    let steady: Vec<Bar> = (0..30)
+        .map(|i| {
+            let close = 100.0 * 1.01f64.powi(i);
+            Bar {
+                open: close,
+                high: close,
+                low: close,
+                close,
+            }
+        })
+        .collect();
+    let vol = Estimator::CloseToClose
+        .estimate(&steady, 20, 252.0)
+        .unwrap();
+    assert!(vol.abs() < 1e-9);
+
+    // Alternating ±r returns have a sample variance of r² n / (n - 1).
+    let r: f64 = 0.01;
+    let zigzag: Vec<Bar> = (0..21)
+        .map(|i| {
+            let close = 100.0 * if i % 2 == 0 { 1.0 } else { r.exp() };
+            Bar {
+                open: close,
+                high: close,
+                low: close,
+                close,
+            }
+        })
+        .collect();
+    let vol = Estimator::CloseToClose.estimate(&zigzag, 20, 1.0).unwrap();
+    assert!((vol.powi(2) - r * r * 20.0 / 19.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_range_estimators() {
+    let bars = flat_bars(10, 1.02, 0.98);
+    let range = (1.02f64 / 0.98).ln();
+
+    let parkinson = Estimator::Parkinson.estimate(&bars, 10, 1.0).unwrap();
+    assert!((parkinson.powi(2) - range * range / (4.0 * 2f64.ln())).abs() < 1e-12);
+
+    // With no open-to-close move Garman-Klass reduces to half the squared range.
+    let garman_klass = Estimator::GarmanKlass.estimate(&bars, 10, 1.0).unwrap();
+    assert!((garman_klass.powi(2) - 0.5 * range * range).abs() < 1e-12);
+}
+
+#[test]
+fn test_yang_zhang_without_jumps() {
+    // No overnight or open-to-close variance leaves the Rogers-Satchell term.
+    let bars = flat_bars(21, 1.02, 0.98);
+    let n = 20.0;
+    let k = 0.34 / (1.34 + (n + 1.0) / (n - 1.0));
+    let rogers_satchell = 1.02f64.ln().powi(2) + 0.98f64.ln().powi(2);
+    let vol = Estimator::YangZhang.estimate(&bars, 20, 1.0).unwrap();
+    assert!((vol.powi(2) - (1.0 - k) * rogers_satchell).abs() < 1e-12);
+}
+
+#[test]
+fn test_short_history() {
+    let bars = flat_bars(20, 1.01, 0.99);
+    assert!(Estimator::Parkinson.estimate(&bars, 20, 252.0).is_some());
+    // Close-to-close and Yang-Zhang need the close before the window.
+    assert!(Estimator::CloseToClose.estimate(&bars, 20, 252.0).is_none());
+    assert!(Estimator::YangZhang.estimate(&bars, 20, 252.0).is_none());
+    assert!(Estimator::GarmanKlass.estimate(&bars, 1, 252.0).is_none());
+}
+
+#[test]
+fn test_parse_estimator() {
+    for estimator in Estimator::ALL {
+        assert_eq!(estimator.to_string().parse::<Estimator>(), Ok(estimator));
+    }
+    assert_eq!("YZ".parse::<Estimator>(), Ok(Estimator::YangZhang));
+    assert!("ewma".parse::<Estimator>().is_err());
+}
@@ -0,0 +1,196 @@
+use crate::rule::Decision;
+use serde_json::json;
+use thiserror::Error;
+use zen_expression::{evaluate_expression, IsolateError};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Row {row}, column {column}: {message} in `{cell}`")]
+pub struct CellError {
+    /// The 1-based rule number.
+    pub row: usize,
+    pub column: String,
+    pub cell: String,
+    pub message: String,
+}
+
+const COMPARISONS: [&str; 6] = [">=", "<=", "!=", "==", ">", "<"];
+
+/// Expands a decision-table input cell into an explicit expression over `$`.
+///
+/// Accepted forms:
+///
+/// * empty or `-` - matches anything and stays empty.
+/// * intervals `[100..200)` or `(0..1]` - bounds may be any expression.
+/// * comparisons `>= 0.3`, `< 5`, `== 'AAPL'`, `!= 0`.
+/// * sets `in ['AAPL', 'MSFT']`, `not in [1, 2]` or a bare list `'AAPL', 'MSFT'`.
+/// * a single literal, compared for equality.
+///
+/// Anything else, such as a cell already written in terms of `$`, is kept as is. The
+/// result is checked to parse, so malformed cells fail here rather than in the engine.
+pub fn expand_cell(cell: &str) -> Result<String, String> {
+    let cell = cell.trim();
+    let expanded = if cell.is_empty() || cell == "-" {
+        return Ok(String::new());
+    } else if let Some(interval) = expand_interval(cell)? {
+        interval
+    } else if let Some(op) = COMPARISONS.iter().find(|op| cell.starts_with(*op)) {
+        let operand = cell[op.len()..].trim();
+        if operand.is_empty() || COMPARISONS.iter().any(|op| operand.starts_with(op)) {
+            return Err(format!("missing operand after `{}`", op));
+        }
+        format!("$ {} {}", op, operand)
+    } else if let Some(list) = cell.strip_prefix("not in ") {
+        format!("not ($ in {})", set(list)?)
+    } else if let Some(list) = cell.strip_prefix("in ") {
+        format!("$ in {}", set(list)?)
+    } else if !cell.contains('$') && split_top_level(cell).len() > 1 {
+        format!("$ in [{}]", cell)
+    } else if is_literal(cell) {
+        format!("$ == {}", cell)
+    } else {
+        cell.to_string()
+    };
+    check(&expanded)?;
+    Ok(expanded)
+}
+
+/// Expands every input cell of a table decision in place, reporting each malformed cell
+/// with its position.
+pub fn expand_table(decision: &mut Decision) -> Result<(), Vec<CellError>> {
+    let mut errors = Vec::new();
+    for (index, rule) in decision.rules.iter_mut().enumerate() {
+        for column in &decision.inputs {
+            let Some(cell) = rule.get_mut(column) else {
+                continue;
+            };
+            match expand_cell(cell) {
+                Ok(expanded) => *cell = expanded,
+                Err(message) => errors.push(CellError {
+                    row: index + 1,
+                    column: column.clone(),
+                    cell: cell.clone(),
+                    message,
+                }),
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Expands a cell of the form `[lower..upper]`, where either bracket may instead be a
+/// parenthesis to exclude its bound. Anything else, including a `..` inside a string or
+/// a call, is not an interval.
+fn expand_interval(cell: &str) -> Result<Option<String>, String> {
+    let (Some(open), Some(close)) = (cell.chars().next(), cell.chars().last()) else {
+        return Ok(None);
+    };
+    let lower_op = match open {
+        '[' => ">=",
+        '(' => ">",
+        _ => return Ok(None),
+    };
+    let upper_op = match close {
+        ']' => "<=",
+        ')' => "<",
+        _ => return Ok(None),
+    };
+    let inner = &cell[1..cell.len() - 1];
+    let Some(split) = range_operator(inner) else {
+        return Ok(None);
+    };
+    let (lower, upper) = (inner[..split].trim(), inner[split + 2..].trim());
+    if lower.is_empty() || upper.is_empty() {
+        return Err("interval needs both bounds".to_string());
+    }
+    if let (Ok(lo), Ok(hi)) = (lower.parse::<f64>(), upper.parse::<f64>()) {
+        if lo > hi {
+            return Err("interval lower bound exceeds upper bound".to_string());
+        }
+    }
+    Ok(Some(format!(
+        "$ {} {} and $ {} {}",
+        lower_op, lower, upper_op, upper
+    )))
+}
+
+/// Returns the position of the only `..` outside brackets and string literals, if there
+/// is exactly one.
+fn range_operator(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let (mut depth, mut quote, mut found) = (0, None, None);
+    let mut i = 0;
+    while i < bytes.len() {
+        match (quote, bytes[i]) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, c @ (b'\'' | b'"')) => quote = Some(c),
+            (None, b'(' | b'[' | b'{') => depth += 1,
+            (None, b')' | b']' | b'}') => depth -= 1,
+            (None, b'.') if depth == 0 && bytes.get(i + 1) == Some(&b'.') => {
+                if found.is_some() || bytes.get(i + 2) == Some(&b'.') {
+                    return None;
+                }
+                found = Some(i);
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    found
+}
+
+fn set(list: &str) -> Result<String, String> {
+    let list = list.trim();
+    let inner = list
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+        .ok_or("set must be a bracketed list")?;
+    if inner.trim().is_empty() {
+        return Err("set is empty".to_string());
+    }
+    Ok(list.to_string())
+}
+
+/// Splits at commas outside brackets and string literals.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut quote, mut start) = (0, None, 0);
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn is_literal(cell: &str) -> bool {
+    let quoted = |q: char| cell.len() >= 2 && cell.starts_with(q) && cell.ends_with(q);
+    quoted('\'') || quoted('"') || cell.parse::<f64>().is_ok() || cell == "true" || cell == "false"
+}
+
+/// Fails if `expression` does not lex, parse and compile.
+fn check(expression: &str) -> Result<(), String> {
+    match evaluate_expression(expression, &json!({ "$": null })) {
+        Err(
+            error @ (IsolateError::LexerError { .. }
+            | IsolateError::ParserError { .. }
+            | IsolateError::CompilerError { .. }),
+        ) => Err(error.to_string()),
+        _ => Ok(()),
+    }
+}
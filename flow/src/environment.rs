@@ -0,0 +1,126 @@
+use crate::functions::Functions;
+use crate::EvalError;
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// An expression environment layered over the JSON context of each evaluation.
+///
+/// Constants and lookup tables are visible as variables, so a table `limits` is read as
+/// `limits.delta`; `lookup('limits', key)` reads one by a computed key and returns
+/// `null` when the key is absent. Fields of the evaluation context take precedence over
+/// constants and tables of the same name.
+///
+/// `default(value, fallback)` from [`Functions::coalescing`] is always registered.
+pub struct Environment {
+    constants: Map<String, Value>,
+    tables: BTreeMap<String, Map<String, Value>>,
+    functions: Functions,
+    missing_as_null: bool,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self {
+            constants: Map::new(),
+            tables: BTreeMap::new(),
+            functions: Functions::coalescing(),
+            missing_as_null: false,
+        }
+    }
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from the pricing functions of [`Functions::pricing`].
+    pub fn pricing() -> Self {
+        let mut environment = Self::default();
+        environment.functions.extend(Functions::pricing());
+        environment
+    }
+
+    /// Makes expressions that fail at runtime, such as arithmetic on a field the input
+    /// lacks, evaluate to null instead of returning an error, so sparse inputs degrade
+    /// to null results. Syntax errors and failing functions are still reported.
+    pub fn with_missing_as_null(mut self) -> Self {
+        self.missing_as_null = true;
+        self
+    }
+
+    pub fn with_constant<V: Into<Value>>(mut self, name: &str, value: V) -> Self {
+        self.constants.insert(name.to_string(), value.into());
+        self
+    }
+
+    pub fn with_table<K, V, I>(mut self, name: &str, rows: I) -> Self
+    where
+        K: Into<String>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let table = rows
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.into()))
+            .collect();
+        self.tables.insert(name.to_string(), table);
+        self.register_lookup();
+        self
+    }
+
+    pub fn with_function<F>(mut self, name: &str, function: F) -> Self
+    where
+        F: Fn(&[Value]) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.functions.register(name, function);
+        self
+    }
+
+    pub fn constant(&self, name: &str) -> Option<&Value> {
+        self.constants.get(name)
+    }
+
+    pub fn table(&self, name: &str) -> Option<&Map<String, Value>> {
+        self.tables.get(name)
+    }
+
+    /// Returns `data` merged over the constants and tables.
+    pub fn context(&self, data: &Value) -> Value {
+        let mut context = self.constants.clone();
+        for (name, table) in &self.tables {
+            context.insert(name.clone(), Value::Object(table.clone()));
+        }
+        if let Value::Object(fields) = data {
+            context.extend(fields.clone());
+        }
+        Value::Object(context)
+    }
+
+    pub fn eval(&self, expr: &str, data: &Value) -> Result<Value, EvalError> {
+        match self.functions.eval(expr, &self.context(data)) {
+            Err(EvalError::Runtime(_)) if self.missing_as_null => Ok(Value::Null),
+            result => result,
+        }
+    }
+
+    /// Re-registers `lookup` over a snapshot of the current tables.
+    fn register_lookup(&mut self) {
+        let tables = self.tables.clone();
+        self.functions.register("lookup", move |args| {
+            let name = args
+                .first()
+                .and_then(Value::as_str)
+                .ok_or("lookup: argument 1 must be a table name")?;
+            let table = tables
+                .get(name)
+                .ok_or_else(|| format!("lookup: unknown table {}", name))?;
+            let key = match args.get(1) {
+                Some(Value::String(key)) => key.clone(),
+                Some(key) => key.to_string(),
+                None => return Err("lookup: missing key".to_string()),
+            };
+            Ok(table.get(&key).cloned().unwrap_or(Value::Null))
+        });
+    }
+}
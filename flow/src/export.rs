@@ -0,0 +1,168 @@
+use zen_engine::model::{DecisionContent, DecisionEdge, DecisionNodeKind};
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// The Graphviz shape and fill colour for each node kind.
+fn dot_style(kind: &DecisionNodeKind) -> (&'static str, &'static str) {
+    match kind {
+        DecisionNodeKind::InputNode | DecisionNodeKind::OutputNode => ("circle", "#d5e8d4"),
+        DecisionNodeKind::DecisionTableNode { .. } => ("box", "#dae8fc"),
+        DecisionNodeKind::ExpressionNode { .. } => ("box", "#fff2cc"),
+        DecisionNodeKind::FunctionNode { .. } => ("component", "#e1d5e7"),
+        DecisionNodeKind::SwitchNode { .. } => ("diamond", "#ffe6cc"),
+        DecisionNodeKind::DecisionNode { .. } => ("folder", "#f5f5f5"),
+        DecisionNodeKind::CustomNode { .. } => ("hexagon", "#f8cecc"),
+    }
+}
+
+/// Wraps a Mermaid label in the brackets that give each node kind its shape.
+fn mermaid_shape(kind: &DecisionNodeKind, label: &str) -> String {
+    match kind {
+        DecisionNodeKind::InputNode | DecisionNodeKind::OutputNode => format!("((\"{}\"))", label),
+        DecisionNodeKind::DecisionTableNode { .. } => format!("[\"{}\"]", label),
+        DecisionNodeKind::ExpressionNode { .. } => format!("(\"{}\")", label),
+        DecisionNodeKind::FunctionNode { .. } => format!("[[\"{}\"]]", label),
+        DecisionNodeKind::SwitchNode { .. } => format!("{{\"{}\"}}", label),
+        DecisionNodeKind::DecisionNode { .. } => format!("[/\"{}\"/]", label),
+        DecisionNodeKind::CustomNode { .. } => format!("{{{{\"{}\"}}}}", label),
+    }
+}
+
+fn mermaid_class(kind: &DecisionNodeKind) -> &'static str {
+    match kind {
+        DecisionNodeKind::InputNode | DecisionNodeKind::OutputNode => "io",
+        DecisionNodeKind::DecisionTableNode { .. } => "table",
+        DecisionNodeKind::ExpressionNode { .. } => "expression",
+        DecisionNodeKind::FunctionNode { .. } => "function",
+        DecisionNodeKind::SwitchNode { .. } => "switch",
+        DecisionNodeKind::DecisionNode { .. } => "decision",
+        DecisionNodeKind::CustomNode { .. } => "custom",
+    }
+}
+
+/// Returns the label of an edge: the condition of the switch statement that selects it,
+/// or `else` for an empty condition.
+fn edge_label(content: &DecisionContent, edge: &DecisionEdge) -> Option<String> {
+    let handle = edge.source_handle.as_deref().filter(|h| !h.is_empty())?;
+    content
+        .nodes
+        .iter()
+        .filter(|node| node.id == edge.source_id)
+        .find_map(|node| match &node.kind {
+            DecisionNodeKind::SwitchNode { content } => content
+                .statements
+                .iter()
+                .find(|statement| statement.id == handle),
+            _ => None,
+        })
+        .map(|statement| match statement.condition.trim() {
+            "" => "else".to_string(),
+            condition => condition.to_string(),
+        })
+}
+
+/// Returns the edges once each, in order, since a connection may be declared on both
+/// of its ends.
+pub(crate) fn unique_edges(content: &DecisionContent) -> Vec<&DecisionEdge> {
+    let mut seen = HashSet::new();
+    content
+        .edges
+        .iter()
+        .filter(|edge| {
+            seen.insert((
+                edge.source_id.as_str(),
+                edge.target_id.as_str(),
+                edge.source_handle.as_deref(),
+            ))
+        })
+        .collect()
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+/// Renders a decision graph as Graphviz DOT, shaped and coloured by node kind, with
+/// switch branches labelled by their conditions.
+pub fn to_dot(content: &DecisionContent) -> String {
+    let mut out = String::from("digraph decision {\n    rankdir=LR;\n    node [style=filled];\n");
+    for node in &content.nodes {
+        let (shape, fill) = dot_style(&node.kind);
+        let _ = writeln!(
+            out,
+            "    \"{}\" [label=\"{}\", shape={}, fillcolor=\"{}\"];",
+            escape_dot(&node.id),
+            escape_dot(&node.name),
+            shape,
+            fill
+        );
+    }
+    for edge in unique_edges(content) {
+        let _ = write!(
+            out,
+            "    \"{}\" -> \"{}\"",
+            escape_dot(&edge.source_id),
+            escape_dot(&edge.target_id)
+        );
+        if let Some(label) = edge_label(content, edge) {
+            let _ = write!(out, " [label=\"{}\"]", escape_dot(&label));
+        }
+        out.push_str(";\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Renders a decision graph as a Mermaid flowchart, shaped and styled by node kind, with
+/// switch branches labelled by their conditions.
+pub fn to_mermaid(content: &DecisionContent) -> String {
+    // Node ids may hold characters Mermaid rejects, so nodes are numbered instead.
+    let index = |id: &str| content.nodes.iter().position(|node| node.id == id);
+    let mut out = String::from("flowchart LR\n");
+    for (i, node) in content.nodes.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    n{}{}:::{}",
+            i,
+            mermaid_shape(&node.kind, &escape_mermaid(&node.name)),
+            mermaid_class(&node.kind)
+        );
+    }
+    for edge in unique_edges(content) {
+        let (Some(source), Some(target)) = (index(&edge.source_id), index(&edge.target_id)) else {
+            continue;
+        };
+        match edge_label(content, edge) {
+            Some(label) => {
+                let _ = writeln!(
+                    out,
+                    "    n{} -->|\"{}\"| n{}",
+                    source,
+                    escape_mermaid(&label),
+                    target
+                );
+            }
+            None => {
+                let _ = writeln!(out, "    n{} --> n{}", source, target);
+            }
+        }
+    }
+    let mut styled = HashSet::new();
+    for node in &content.nodes {
+        let class = mermaid_class(&node.kind);
+        if styled.insert(class) {
+            let _ = writeln!(
+                out,
+                "    classDef {} fill:{}",
+                class,
+                dot_style(&node.kind).1
+            );
+        }
+    }
+    out
+}
@@ -0,0 +1,434 @@
+use crate::{try_eval, EvalError};
+use cqf_core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use cqf_core::strategies::factory::create_strategy_by_name;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// The custom node kind of the nodes the graph builder inserts to evaluate function calls.
+pub const CALLS_KIND: &str = "calls";
+
+/// The input field under which a calls node passes its results to the node it feeds.
+const BINDINGS: &str = "__calls";
+
+pub type Function = Box<dyn Fn(&[Value]) -> Result<Value, String> + Send + Sync>;
+
+/// Rust functions callable from expressions.
+///
+/// The expression language has a fixed set of built-ins, so calls to registered
+/// functions are resolved before evaluation: each call's arguments are evaluated, the
+/// function runs, and the call is replaced by a variable bound to its result.
+#[derive(Default)]
+pub struct Functions {
+    functions: HashMap<String, Function>,
+}
+
+impl Functions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the pricing functions backed by the Black-Scholes model:
+    ///
+    /// * `bs_call(s, k, r, sigma, t)` and `bs_put(s, k, r, sigma, t)` - option prices.
+    /// * `delta(s, k, r, sigma, t, type)` - the Delta of a `'call'` or `'put'`.
+    /// * `implied_vol(price, s, k, r, t, type)` - the volatility implied by `price`.
+    /// * `strategy_price(kind, params)` - the price of a named strategy, with `params`
+    ///   an object of factory parameters such as `{s: 100, k1: 95, k2: 105, ...}`.
+    pub fn pricing() -> Self {
+        let mut functions = Self::new();
+        functions.register("bs_call", |args| {
+            let params = parameters("bs_call", args)?;
+            Ok(BlackScholesModel
+                .option_price(&params, OptionType::Call)
+                .into())
+        });
+        functions.register("bs_put", |args| {
+            let params = parameters("bs_put", args)?;
+            Ok(BlackScholesModel
+                .option_price(&params, OptionType::Put)
+                .into())
+        });
+        functions.register("delta", |args| {
+            let params = parameters("delta", args)?;
+            let option_type = option_type("delta", args, 5)?;
+            Ok(BlackScholesModel.option_delta(&params, option_type).into())
+        });
+        functions.register("implied_vol", |args| {
+            let price = number("implied_vol", args, 0)?;
+            let params = OptionParameters {
+                s: number("implied_vol", args, 1)?,
+                k: number("implied_vol", args, 2)?,
+                r: number("implied_vol", args, 3)?,
+                sigma: 0.0,
+                t: number("implied_vol", args, 4)?,
+            };
+            let option_type = option_type("implied_vol", args, 5)?;
+            BlackScholesModel
+                .implied_volatility(&params, option_type, price)
+                .map(Value::from)
+                .map_err(|error| format!("implied_vol: {}", error))
+        });
+        functions.register("strategy_price", |args| {
+            let kind = args
+                .first()
+                .and_then(Value::as_str)
+                .ok_or("strategy_price: argument 1 must be a strategy name")?;
+            let params = args
+                .get(1)
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_else(Map::new);
+            create_strategy_by_name(&BlackScholesModel, kind, &params)
+                .map(|strategy| strategy.price().into())
+                .map_err(|error| format!("strategy_price: {}", error))
+        });
+        functions
+    }
+
+    /// Returns math functions missing from the expression language, each checking its
+    /// arity: `sqrt(x)`, `ln(x)`, `exp(x)`, and `min`/`max` over numbers or one array.
+    pub fn math() -> Self {
+        let mut functions = Self::new();
+        functions.register("sqrt", |args| unary("sqrt", args, f64::sqrt));
+        functions.register("ln", |args| unary("ln", args, f64::ln));
+        functions.register("exp", |args| unary("exp", args, f64::exp));
+        functions.register("min", |args| extreme("min", args, f64::min));
+        functions.register("max", |args| extreme("max", args, f64::max));
+        functions
+    }
+
+    /// Returns `default(value, fallback, ...)`, the first argument that is not null.
+    ///
+    /// Missing fields read as null, so `default(order.qty, 0)` substitutes `0` on inputs
+    /// without a quantity. Flows built by the graph builder get the same function through
+    /// [`expand_defaults`].
+    pub fn coalescing() -> Self {
+        let mut functions = Self::new();
+        functions.register("default", |args| {
+            if args.len() < 2 {
+                return Err(format!(
+                    "default: expects at least 2 argument(s), got {}",
+                    args.len()
+                ));
+            }
+            Ok(args
+                .iter()
+                .find(|arg| !arg.is_null())
+                .cloned()
+                .unwrap_or(Value::Null))
+        });
+        functions
+    }
+
+    /// Adds every function of `other`, replacing any of the same name.
+    pub fn extend(&mut self, other: Functions) {
+        self.functions.extend(other.functions);
+    }
+
+    pub fn register<F>(&mut self, name: &str, function: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, String> + Send + Sync + 'static,
+    {
+        self.functions.insert(name.to_string(), Box::new(function));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Evaluates `expr` against `data`, resolving calls to registered functions first.
+    pub fn eval(&self, expr: &str, data: &Value) -> Result<Value, EvalError> {
+        let mut expr = expr.to_string();
+        let mut context = match data {
+            Value::Object(map) => map.clone(),
+            _ => Map::new(),
+        };
+        let mut bindings = 0;
+        while let Some(call) = self.find_call(&expr) {
+            let args = split_args(&expr[call.args.clone()])
+                .into_iter()
+                .map(|arg| self.eval(arg, data))
+                .collect::<Result<Vec<_>, _>>()?;
+            let result =
+                self.functions[&call.name](&args).map_err(|message| EvalError::Function {
+                    name: call.name.clone(),
+                    message,
+                })?;
+            let binding = format!("__fn{}", bindings);
+            bindings += 1;
+            context.insert(binding.clone(), result);
+            expr.replace_range(call.span, &binding);
+        }
+        try_eval(&expr, &Value::Object(context))
+    }
+
+    /// Replaces each call to a registered function in `expr` with a reference to the field
+    /// its result will be bound to, recording the call under that field's key in `calls`.
+    pub(crate) fn bind_calls(&self, expr: &str, calls: &mut HashMap<String, String>) -> String {
+        let mut expr = expr.to_string();
+        let mut start = 0;
+        while let Some(call) = self.find_call(&expr[start..]) {
+            let span = start + call.span.start..start + call.span.end;
+            let key = format!("f{}", calls.len());
+            calls.insert(key.clone(), expand_defaults(&expr[span.clone()]));
+            let binding = format!("{}.{}", BINDINGS, key);
+            start = span.start + binding.len();
+            expr.replace_range(span, &binding);
+        }
+        expr
+    }
+
+    /// Finds the leftmost call to a registered function outside string literals.
+    fn find_call(&self, expr: &str) -> Option<Call> {
+        let bytes = expr.as_bytes();
+        let mut quote = None;
+        let mut i = 0;
+        while i < bytes.len() {
+            let c = bytes[i];
+            if let Some(q) = quote {
+                if c == q {
+                    quote = None;
+                }
+                i += 1;
+                continue;
+            }
+            if matches!(c, b'\'' | b'"' | b'`') {
+                quote = Some(c);
+                i += 1;
+                continue;
+            }
+            let starts_word = i == 0
+                || !(bytes[i - 1].is_ascii_alphanumeric()
+                    || matches!(bytes[i - 1], b'_' | b'.' | b'$'));
+            if starts_word && (c.is_ascii_alphabetic() || c == b'_') {
+                let end = expr[i..]
+                    .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+                    .map_or(expr.len(), |n| i + n);
+                let name = &expr[i..end];
+                let open = expr[end..]
+                    .find(|ch: char| !ch.is_whitespace())
+                    .map(|n| end + n);
+                if let Some(open) = open.filter(|&o| bytes[o] == b'(' && self.contains(name)) {
+                    let close = matching_paren(expr, open)?;
+                    return Some(Call {
+                        name: name.to_string(),
+                        args: open + 1..close,
+                        span: i..close + 1,
+                    });
+                }
+                i = end;
+                continue;
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+/// The function sets built graphs call, indexed by the `functions` field of their calls
+/// nodes. Set 0 is [`Functions::pricing`], the graph builder's default; the others are
+/// added by [`crate::graph::DecisionGraphBuilder::with_functions`]. Sets are never
+/// removed, so a graph keeps working after its builder is dropped.
+fn function_sets() -> &'static RwLock<Vec<Arc<Functions>>> {
+    static SETS: OnceLock<RwLock<Vec<Arc<Functions>>>> = OnceLock::new();
+    SETS.get_or_init(|| RwLock::new(vec![Arc::new(Functions::pricing())]))
+}
+
+/// Adds a function set graphs can call, returning its index.
+pub(crate) fn register_graph_functions(functions: Functions) -> usize {
+    let mut sets = function_sets()
+        .write()
+        .expect("function sets are never poisoned");
+    sets.push(Arc::new(functions));
+    sets.len() - 1
+}
+
+/// Returns the function set at `index`, if this process registered one.
+pub(crate) fn graph_functions(index: usize) -> Option<Arc<Functions>> {
+    let sets = function_sets()
+        .read()
+        .expect("function sets are never poisoned");
+    sets.get(index).cloned()
+}
+
+/// Runs a calls node: every call in its `calls` config is evaluated against the node's
+/// input with the function set its `functions` config names, and the input is passed on
+/// with the results bound under their keys.
+pub(crate) fn evaluate_calls(config: &Value, input: &Value) -> Result<Value, EvalError> {
+    let index = config["functions"].as_u64().unwrap_or_default() as usize;
+    let functions = graph_functions(index).ok_or_else(|| EvalError::Function {
+        name: CALLS_KIND.to_string(),
+        message: format!("no function set {} is registered in this process", index),
+    })?;
+    let mut output = match input {
+        Value::Object(map) => map.clone(),
+        _ => Map::new(),
+    };
+    output.remove("$nodes");
+    let mut results = Map::new();
+    if let Some(calls) = config["calls"].as_object() {
+        for (key, call) in calls {
+            let call = call.as_str().unwrap_or_default();
+            results.insert(key.clone(), functions.eval(call, input)?);
+        }
+    }
+    output.insert(BINDINGS.to_string(), Value::Object(results));
+    Ok(Value::Object(output))
+}
+
+/// Rewrites calls to `default(value, fallback, ...)` into the engine's `??` operator,
+/// e.g. `default(qty, 0) * price` becomes `(qty ?? 0) * price`, so the function works in
+/// decision tables and expression nodes too. Calls with fewer than two arguments are
+/// kept as they are.
+pub fn expand_defaults(expr: &str) -> String {
+    let mut finder = Functions::new();
+    finder.register("default", |_| Ok(Value::Null));
+    let mut expr = expr.to_string();
+    let mut start = 0;
+    while let Some(call) = finder.find_call(&expr[start..]) {
+        let args = split_args(&expr[start..][call.args.clone()]);
+        if args.len() < 2 {
+            start += call.span.end;
+            continue;
+        }
+        let args: Vec<String> = args
+            .into_iter()
+            .map(|arg| {
+                let arg = expand_defaults(arg.trim());
+                let atom = arg
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '$' | '\''));
+                if atom {
+                    arg
+                } else {
+                    format!("({})", arg)
+                }
+            })
+            .collect();
+        let rewritten = format!("({})", args.join(" ?? "));
+        let span = start + call.span.start..start + call.span.end;
+        start = span.start + rewritten.len();
+        expr.replace_range(span, &rewritten);
+    }
+    expr
+}
+
+struct Call {
+    name: String,
+    args: std::ops::Range<usize>,
+    span: std::ops::Range<usize>,
+}
+
+/// Returns the index of the bracket closing the one at `open`.
+fn matching_paren(expr: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    for (i, c) in expr.char_indices().skip_while(|&(i, _)| i < open) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits an argument list at its top-level commas.
+fn split_args(args: &str) -> Vec<&str> {
+    if args.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start..]);
+    parts
+}
+
+fn unary(name: &str, args: &[Value], function: fn(f64) -> f64) -> Result<Value, String> {
+    arity(name, args, 1)?;
+    let value = function(number(name, args, 0)?);
+    if value.is_finite() {
+        Ok(value.into())
+    } else {
+        Err(format!("{}: result is not finite", name))
+    }
+}
+
+/// Folds numbers with `pick`; a single array argument is folded element-wise.
+fn extreme(name: &str, args: &[Value], pick: fn(f64, f64) -> f64) -> Result<Value, String> {
+    let values = match args {
+        [Value::Array(items)] => items.as_slice(),
+        _ => args,
+    };
+    (0..values.len())
+        .map(|i| number(name, values, i))
+        .reduce(|a, b| Ok(pick(a?, b?)))
+        .unwrap_or_else(|| Err(format!("{}: expects at least one number", name)))
+        .map(Value::from)
+}
+
+fn arity(name: &str, args: &[Value], expected: usize) -> Result<(), String> {
+    if args.len() == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}: expects {} argument(s), got {}",
+            name,
+            expected,
+            args.len()
+        ))
+    }
+}
+
+fn number(name: &str, args: &[Value], index: usize) -> Result<f64, String> {
+    args.get(index)
+        .and_then(Value::as_f64)
+        .ok_or_else(|| format!("{}: argument {} must be a number", name, index + 1))
+}
+
+fn option_type(name: &str, args: &[Value], index: usize) -> Result<OptionType, String> {
+    match args.get(index).and_then(Value::as_str) {
+        Some("call") => Ok(OptionType::Call),
+        Some("put") => Ok(OptionType::Put),
+        _ => Err(format!(
+            "{}: argument {} must be 'call' or 'put'",
+            name,
+            index + 1
+        )),
+    }
+}
+
+fn parameters(name: &str, args: &[Value]) -> Result<OptionParameters, String> {
+    Ok(OptionParameters {
+        s: number(name, args, 0)?,
+        k: number(name, args, 1)?,
+        r: number(name, args, 2)?,
+        sigma: number(name, args, 3)?,
+        t: number(name, args, 4)?,
+    })
+}
@@ -1,11 +1,21 @@
 use zen_engine::model::{
-    DecisionContent, DecisionEdge, DecisionNode, DecisionNodeKind, DecisionTableContent,
-    DecisionTableHitPolicy, DecisionTableInputField, DecisionTableOutputField, Expression,
-    ExpressionNodeContent, FunctionNodeContent,
+    CustomNodeContent, DecisionContent, DecisionEdge, DecisionNode, DecisionNodeKind,
+    DecisionTableContent, DecisionTableHitPolicy, DecisionTableInputField,
+    DecisionTableOutputField, Expression, ExpressionNodeContent, FunctionContent,
+    FunctionNodeContent, SwitchNodeContent, SwitchStatement, SwitchStatementHitPolicy,
 };
 
-use crate::rule::Decision;
-use std::collections::HashMap;
+use crate::cells::{expand_table, CellError};
+use crate::functions::{
+    expand_defaults, graph_functions, register_graph_functions, Functions, CALLS_KIND,
+};
+use crate::iteration::LOOP_KIND;
+use crate::rule::{Decision, DecisionReader};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
 
 trait NodeBuilder {
     fn build(&self, decision: Decision) -> DecisionNode;
@@ -19,6 +29,7 @@ impl NodeBuilder for DecisionTableNodeBuilder {
             rules,
             inputs,
             outputs,
+            hit_policy,
             ..
         } = decision;
         let input_fields = make_fields(inputs, |f| DecisionTableInputField {
@@ -31,8 +42,12 @@ impl NodeBuilder for DecisionTableNodeBuilder {
             name: f.clone(),
             field: f.clone(),
         });
+        let hit_policy = match hit_policy.as_str() {
+            "collect" => DecisionTableHitPolicy::Collect,
+            _ => DecisionTableHitPolicy::First,
+        };
         let content = DecisionTableContent {
-            hit_policy: DecisionTableHitPolicy::First,
+            hit_policy,
             rules,
             inputs: input_fields,
             outputs: output_fields,
@@ -54,11 +69,14 @@ impl NodeBuilder for ExpressionNodeBuilder {
             inputs,
             ..
         } = decision;
-        let key = inputs.first().unwrap().to_string();
+        let key = inputs
+            .first()
+            .unwrap_or_else(|| panic!("{}", GraphError::MissingExpressionKey(id.clone())))
+            .to_string();
         let expression = Expression {
             id: key.clone(),
             key,
-            value: expression.to_string(),
+            value: expand_defaults(&expression),
         };
         let content = ExpressionNodeContent {
             expressions: vec![expression],
@@ -71,27 +89,133 @@ impl NodeBuilder for ExpressionNodeBuilder {
     }
 }
 
+/// Sources that export their handler, as the GoRules editor writes them, run as
+/// modules; anything else runs as a classic script.
 pub struct FunctionNodeBuilder;
 impl NodeBuilder for FunctionNodeBuilder {
     fn build(&self, decision: Decision) -> DecisionNode {
         let Decision { id, function, .. } = decision;
+        let content = if function.contains("export ") {
+            FunctionNodeContent::Version2(FunctionContent { source: function })
+        } else {
+            FunctionNodeContent::Version1(function)
+        };
+        DecisionNode {
+            id: id.clone(),
+            name: id,
+            kind: DecisionNodeKind::FunctionNode { content },
+        }
+    }
+}
+
+/// Branches to one of the decision's targets. `conditions` pairs with `targets`; an
+/// empty condition always matches, so a trailing one acts as the default branch.
+pub struct SwitchNodeBuilder;
+impl NodeBuilder for SwitchNodeBuilder {
+    fn build(&self, decision: Decision) -> DecisionNode {
+        let statements = decision
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(i, target)| SwitchStatement {
+                id: statement_id(&decision.id, target),
+                condition: decision
+                    .conditions
+                    .get(i)
+                    .map(|condition| expand_defaults(condition))
+                    .unwrap_or_default(),
+            })
+            .collect();
+        let hit_policy = match decision.hit_policy.as_str() {
+            "collect" => SwitchStatementHitPolicy::Collect,
+            _ => SwitchStatementHitPolicy::First,
+        };
+        DecisionNode {
+            id: decision.id.clone(),
+            name: decision.id,
+            kind: DecisionNodeKind::SwitchNode {
+                content: SwitchNodeContent {
+                    hit_policy,
+                    statements,
+                },
+            },
+        }
+    }
+}
+
+/// Maps the array at the field named by `inputs[0]` through the flow file named by
+/// `rules`, collecting the results under `outputs[0]` (`results` by default). Loop nodes
+/// run on [`crate::iteration::engine`].
+pub struct LoopNodeBuilder {
+    /// The function set the body's calls nodes use.
+    functions: usize,
+}
+impl NodeBuilder for LoopNodeBuilder {
+    fn build(&self, decision: Decision) -> DecisionNode {
+        let Decision {
+            id,
+            inputs,
+            outputs,
+            body,
+            ..
+        } = decision;
+        let body = DecisionGraphBuilder::with_function_set(self.functions).build(body);
+        let config = json!({
+            "items": inputs.first().cloned().unwrap_or_default(),
+            "key": outputs.first().map_or("results", String::as_str),
+            "flow": body,
+        });
+        DecisionNode {
+            id: id.clone(),
+            name: id,
+            kind: DecisionNodeKind::CustomNode {
+                content: CustomNodeContent {
+                    kind: LOOP_KIND.to_string(),
+                    config,
+                },
+            },
+        }
+    }
+}
+
+/// Evaluates the function calls held in the decision's only rule, keyed by the field
+/// each result is bound to; inserted by the graph builder, see
+/// [`DecisionGraphBuilder::build`]. Calls nodes run on [`crate::iteration::engine`].
+pub struct CallsNodeBuilder {
+    /// The function set the calls are evaluated with.
+    functions: usize,
+}
+impl NodeBuilder for CallsNodeBuilder {
+    fn build(&self, decision: Decision) -> DecisionNode {
+        let Decision { id, rules, .. } = decision;
+        let config = json!({
+            "calls": rules.into_iter().next().unwrap_or_default(),
+            "functions": self.functions,
+        });
         DecisionNode {
             id: id.clone(),
             name: id,
-            kind: DecisionNodeKind::FunctionNode {
-                content: FunctionNodeContent::Version1(function),
+            kind: DecisionNodeKind::CustomNode {
+                content: CustomNodeContent {
+                    kind: CALLS_KIND.to_string(),
+                    config,
+                },
             },
         }
     }
 }
 
+fn statement_id(switch: &str, target: &str) -> String {
+    format!("{}:{}", switch, target)
+}
+
 // Node factory
 pub struct NodeFactory {
     builders: HashMap<String, Box<dyn NodeBuilder>>,
 }
 
 impl NodeFactory {
-    fn new() -> Self {
+    fn new(functions: usize) -> Self {
         let mut builders = HashMap::new();
         builders.insert(
             "table".to_string(),
@@ -105,15 +229,31 @@ impl NodeFactory {
             "function".to_string(),
             Box::new(FunctionNodeBuilder) as Box<dyn NodeBuilder>,
         );
+        builders.insert(
+            "switch".to_string(),
+            Box::new(SwitchNodeBuilder) as Box<dyn NodeBuilder>,
+        );
+        builders.insert(
+            "loop".to_string(),
+            Box::new(LoopNodeBuilder { functions }) as Box<dyn NodeBuilder>,
+        );
+        builders.insert(
+            CALLS_KIND.to_string(),
+            Box::new(CallsNodeBuilder { functions }) as Box<dyn NodeBuilder>,
+        );
         Self { builders }
     }
 
     fn create_node(&self, decision: Decision) -> DecisionNode {
         self.builders
             .get(&decision.kind)
-            .expect(&format!("Unsupported decision kind: {}", decision.kind))
+            .unwrap_or_else(|| panic!("Unsupported decision kind: {}", decision.kind))
             .build(decision)
     }
+
+    fn supports(&self, kind: &str) -> bool {
+        self.builders.contains_key(kind)
+    }
 }
 
 fn make_fields<T, F>(fields: Vec<String>, field_creator: F) -> Vec<T>
@@ -127,6 +267,19 @@ pub struct EdgeBuilder;
 
 impl EdgeBuilder {
     fn build_edges(flow: &[Decision]) -> Vec<DecisionEdge> {
+        let switches: Vec<&str> = flow
+            .iter()
+            .filter(|d| d.kind == "switch")
+            .map(|d| d.id.as_str())
+            .collect();
+        // Edges leaving a switch carry the id of the statement that selects them.
+        let handle = |source: &str, target: &str| {
+            if switches.contains(&source) {
+                Some(statement_id(source, target))
+            } else {
+                Some("".into())
+            }
+        };
         flow.iter()
             .flat_map(|d| {
                 d.sources
@@ -135,13 +288,13 @@ impl EdgeBuilder {
                         id: "".into(),
                         source_id: source.clone(),
                         target_id: d.id.clone(),
-                        source_handle: Some("".into()),
+                        source_handle: handle(source, &d.id),
                     })
                     .chain(d.targets.iter().map(|target| DecisionEdge {
                         id: "".into(),
                         source_id: d.id.clone(),
                         target_id: target.clone(),
-                        source_handle: Some("".into()),
+                        source_handle: handle(&d.id, target),
                     }))
             })
             .collect()
@@ -150,16 +303,70 @@ impl EdgeBuilder {
 
 pub struct DecisionGraphBuilder {
     node_factory: NodeFactory,
+    /// The function set decisions can call.
+    functions: usize,
+}
+
+impl Default for DecisionGraphBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DecisionGraphBuilder {
     pub fn new() -> Self {
+        Self::with_function_set(0)
+    }
+
+    /// Lets decisions call `functions` instead of the pricing functions, e.g.
+    /// `Functions::math()` or a set with domain functions added by
+    /// [`Functions::register`]; extend it with [`Functions::pricing`] to keep those too.
+    ///
+    /// The set is registered for the life of the process, so graphs built by this
+    /// builder can run after it is dropped; create the builder once rather than per build.
+    pub fn with_functions(functions: Functions) -> Self {
+        Self::with_function_set(register_graph_functions(functions))
+    }
+
+    fn with_function_set(functions: usize) -> Self {
         Self {
-            node_factory: NodeFactory::new(),
+            node_factory: NodeFactory::new(functions),
+            functions,
         }
     }
 
+    /// The functions decisions can call.
+    fn functions(&self) -> Arc<Functions> {
+        graph_functions(self.functions).expect("builders only name registered function sets")
+    }
+
+    /// Builds the graph, inlining sub-flows first. Calls to `default(value, fallback)` in
+    /// table cells, expressions and switch conditions are rewritten by
+    /// [`expand_defaults`], and table input cells are expanded by [`expand_table`].
+    ///
+    /// Calls to the pricing functions (see [`Functions::pricing`]), such as
+    /// `bs_call(s, k, r, sigma, t)`, or to those given to
+    /// [`DecisionGraphBuilder::with_functions`], are evaluated by a calls node inserted before the
+    /// decision making them, against the same input; the decision reads the results
+    /// instead. Graphs with calls nodes must run on [`crate::iteration::engine`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on unsupported decision kinds, on expressions without an input field to
+    /// write to, on malformed table cells, and on sub-flows that cannot be loaded; use
+    /// [`DecisionGraphBuilder::try_build`] to get these as errors.
     pub fn build(&self, flow: Vec<Decision>) -> DecisionContent {
+        let flow = inline_sub_flows(flow).unwrap_or_else(|error| panic!("{}", error));
+        let mut flow = insert_calls(flow, &self.functions());
+        if let Some(error) = expand_tables(&mut flow).into_iter().next() {
+            panic!("{}", error);
+        }
+        self.assemble(flow)
+    }
+
+    /// Turns a flow whose sub-flows, calls and table cells are already resolved into nodes
+    /// and edges.
+    fn assemble(&self, flow: Vec<Decision>) -> DecisionContent {
         let mut nodes = vec![DecisionNode {
             id: "request".to_string(),
             name: "request".to_string(),
@@ -181,6 +388,358 @@ impl DecisionGraphBuilder {
 
         DecisionContent { nodes, edges }
     }
+
+    /// Builds the graph and validates it, reporting every problem instead of panicking on
+    /// unsupported decision kinds, expressions without an input field or malformed table
+    /// cells.
+    pub fn try_build(&self, flow: Vec<Decision>) -> Result<DecisionContent, Vec<GraphError>> {
+        let flow = inline_sub_flows(flow).map_err(|error| vec![error])?;
+        let invalid: Vec<GraphError> = flow.iter().filter_map(|d| self.check(d)).collect();
+        if !invalid.is_empty() {
+            return Err(invalid);
+        }
+        for decision in flow.iter().filter(|d| d.kind == "loop") {
+            self.try_build(decision.body.clone()).map_err(|errors| {
+                let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+                vec![GraphError::SubFlow {
+                    node: decision.id.clone(),
+                    message: messages.join("; "),
+                }]
+            })?;
+        }
+        let mut flow = insert_calls(flow, &self.functions());
+        let invalid = expand_tables(&mut flow);
+        if !invalid.is_empty() {
+            return Err(invalid);
+        }
+        let content = self.assemble(flow);
+        validate(&content)?;
+        Ok(content)
+    }
+
+    /// Returns the problem that would stop `decision` from becoming a node, if any.
+    fn check(&self, decision: &Decision) -> Option<GraphError> {
+        if !self.node_factory.supports(&decision.kind) {
+            Some(GraphError::UnknownKind {
+                node: decision.id.clone(),
+                kind: decision.kind.clone(),
+            })
+        } else if decision.kind == "expression" && decision.inputs.is_empty() {
+            Some(GraphError::MissingExpressionKey(decision.id.clone()))
+        } else {
+            None
+        }
+    }
+}
+
+/// Replaces every `flow` decision with the decisions of the flow file named by its
+/// `rules`, recursively, and loads the body of every `loop` decision the same way.
+///
+/// Inlined decisions are renamed `{sub-flow id}/{decision id}`. Inside the sub-flow,
+/// `request` stands for the sub-flow decision's sources and `response` for its targets;
+/// outside it, a reference to the sub-flow decision is redirected to the sub-flow's
+/// entry decisions (fed by `request`) when it is a target, and to its exit decisions
+/// (feeding `response`) when it is a source.
+fn inline_sub_flows(flow: Vec<Decision>) -> Result<Vec<Decision>, GraphError> {
+    inline(flow, &mut Vec::new())
+}
+
+fn inline(flow: Vec<Decision>, stack: &mut Vec<PathBuf>) -> Result<Vec<Decision>, GraphError> {
+    let mut inlined = Vec::new();
+    let mut entries = HashMap::new();
+    let mut exits = HashMap::new();
+    for mut decision in flow {
+        if decision.kind == "loop" && decision.body.is_empty() {
+            decision.body = load_sub_flow(&decision, stack)?;
+        }
+        if decision.kind != "flow" {
+            inlined.push(decision);
+            continue;
+        }
+        let sub_flow = load_sub_flow(&decision, stack)?;
+
+        let prefix = format!("{}/", decision.id);
+        let rename = |ids: &[String], request: &[String], response: &[String]| -> Vec<String> {
+            ids.iter()
+                .flat_map(|id| match id.as_str() {
+                    "request" => request.to_vec(),
+                    "response" => response.to_vec(),
+                    _ => vec![format!("{}{}", prefix, id)],
+                })
+                .collect()
+        };
+        let (mut sub_entries, mut sub_exits) = (Vec::new(), Vec::new());
+        for mut inner in sub_flow {
+            let id = format!("{}{}", prefix, inner.id);
+            if inner.sources.iter().any(|s| s == "request") {
+                sub_entries.push(id.clone());
+            }
+            if inner.targets.iter().any(|t| t == "response") {
+                sub_exits.push(id.clone());
+            }
+            inner.sources = rename(&inner.sources, &decision.sources, &[]);
+            inner.targets = rename(&inner.targets, &[], &decision.targets);
+            inner.id = id;
+            inlined.push(inner);
+        }
+        entries.insert(decision.id.clone(), sub_entries);
+        exits.insert(decision.id, sub_exits);
+    }
+    if entries.is_empty() {
+        return Ok(inlined);
+    }
+    for decision in &mut inlined {
+        decision.sources = redirect(&decision.sources, &exits);
+        let conditions = std::mem::take(&mut decision.conditions);
+        let mut targets = Vec::new();
+        for (i, target) in decision.targets.iter().enumerate() {
+            for target in redirect(std::slice::from_ref(target), &entries) {
+                if let Some(condition) = conditions.get(i) {
+                    decision.conditions.push(condition.clone());
+                }
+                targets.push(target);
+            }
+        }
+        decision.targets = targets;
+    }
+    Ok(inlined)
+}
+
+/// Reads the flow file named by a decision's `rules`, with its own sub-flows inlined.
+///
+/// A relative path is resolved against the directory of the flow file that names it, or
+/// the working directory at the top level; so are the rule and function files of the
+/// sub-flow's decisions.
+fn load_sub_flow(
+    decision: &Decision,
+    stack: &mut Vec<PathBuf>,
+) -> Result<Vec<Decision>, GraphError> {
+    let error = |message: String| GraphError::SubFlow {
+        node: decision.id.clone(),
+        message,
+    };
+    let including = stack.last().and_then(|path| path.parent());
+    let path = std::fs::canonicalize(resolve(including, &decision.expression))
+        .map_err(|e| error(format!("{}: {}", decision.expression, e)))?;
+    if stack.contains(&path) {
+        return Err(error(format!("{} includes itself", decision.expression)));
+    }
+    let refs = DecisionReader::read_flow_refs(&path).map_err(|e| error(e.to_string()))?;
+    let dir = path.parent();
+    let sub_flow = refs
+        .into_iter()
+        .map(|mut dec_ref| {
+            if matches!(dec_ref.kind.as_str(), "table" | "function") {
+                dec_ref.rules = resolve(dir, &dec_ref.rules).to_string_lossy().into_owned();
+            }
+            Decision::from(dec_ref)
+        })
+        .collect();
+    stack.push(path);
+    let sub_flow = inline(sub_flow, stack);
+    stack.pop();
+    sub_flow
+}
+
+/// Joins a relative `path` onto `dir`, if any.
+fn resolve(dir: Option<&Path>, path: &str) -> PathBuf {
+    match dir {
+        Some(dir) if !path.is_empty() => dir.join(path),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Moves the calls to `functions` of each table, expression and switch decision into a
+/// calls decision `{id}:calls` placed between the decision and its sources.
+fn insert_calls(flow: Vec<Decision>, functions: &Functions) -> Vec<Decision> {
+    let mut moved = HashMap::new();
+    let mut inserted = Vec::new();
+    let mut flow: Vec<Decision> = flow
+        .into_iter()
+        .map(|mut decision| {
+            let mut calls = HashMap::new();
+            let mut bind = |expr: &mut String| *expr = functions.bind_calls(expr, &mut calls);
+            match decision.kind.as_str() {
+                "table" => decision
+                    .rules
+                    .iter_mut()
+                    .flat_map(|rule| rule.values_mut())
+                    .for_each(&mut bind),
+                "expression" => bind(&mut decision.expression),
+                "switch" => decision.conditions.iter_mut().for_each(&mut bind),
+                _ => {}
+            }
+            if !calls.is_empty() {
+                let id = format!("{}:{}", decision.id, CALLS_KIND);
+                moved.insert(decision.id.clone(), vec![id.clone()]);
+                inserted.push(Decision {
+                    id: id.clone(),
+                    kind: CALLS_KIND.to_string(),
+                    rules: vec![calls],
+                    expression: String::new(),
+                    function: String::new(),
+                    inputs: Vec::new(),
+                    outputs: Vec::new(),
+                    sources: std::mem::replace(&mut decision.sources, vec![id]),
+                    targets: Vec::new(),
+                    conditions: Vec::new(),
+                    hit_policy: String::new(),
+                    version: None,
+                    body: Vec::new(),
+                });
+            }
+            decision
+        })
+        .collect();
+    for decision in &mut flow {
+        decision.targets = redirect(&decision.targets, &moved);
+    }
+    flow.extend(inserted);
+    flow
+}
+
+/// Rewrites `default` calls in the cells of every table decision, then expands its input
+/// cells, returning an error for each malformed cell.
+fn expand_tables(flow: &mut [Decision]) -> Vec<GraphError> {
+    let mut errors = Vec::new();
+    for decision in flow.iter_mut().filter(|d| d.kind == "table") {
+        for cell in decision.rules.iter_mut().flat_map(|rule| rule.values_mut()) {
+            *cell = expand_defaults(cell);
+        }
+        if let Err(cells) = expand_table(decision) {
+            errors.extend(cells.into_iter().map(|error| GraphError::Cell {
+                node: decision.id.clone(),
+                error,
+            }));
+        }
+    }
+    errors
+}
+
+fn redirect(ids: &[String], to: &HashMap<String, Vec<String>>) -> Vec<String> {
+    ids.iter()
+        .flat_map(|id| to.get(id).cloned().unwrap_or_else(|| vec![id.clone()]))
+        .collect()
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    #[error("Sub-flow {node} could not be inlined: {message}")]
+    SubFlow { node: String, message: String },
+    #[error("Unsupported decision kind {kind} for node {node}")]
+    UnknownKind { node: String, kind: String },
+    #[error("Expression node {0} names no input field to write its result to")]
+    MissingExpressionKey(String),
+    #[error("Table {node}: {error}")]
+    Cell { node: String, error: CellError },
+    #[error("Duplicate node id: {0}")]
+    DuplicateNode(String),
+    #[error("Edge {source_id} -> {target_id} refers to missing node {missing}")]
+    DanglingEdge {
+        source_id: String,
+        target_id: String,
+        missing: String,
+    },
+    #[error("Cycle through nodes: {}", .0.join(" -> "))]
+    Cycle(Vec<String>),
+    #[error("Node {0} is unreachable from request")]
+    Unreachable(String),
+    #[error("Node {0} has no path to response")]
+    NoPathToResponse(String),
+}
+
+/// Checks that a graph can run: node ids are unique, every edge joins existing nodes,
+/// there are no cycles, and every node lies on a path from `request` to `response`.
+pub fn validate(content: &DecisionContent) -> Result<(), Vec<GraphError>> {
+    let mut errors = Vec::new();
+    let mut ids = HashSet::new();
+    for node in &content.nodes {
+        if !ids.insert(node.id.as_str()) {
+            errors.push(GraphError::DuplicateNode(node.id.clone()));
+        }
+    }
+
+    let mut forward: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut backward: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in &content.edges {
+        let (source, target) = (edge.source_id.as_str(), edge.target_id.as_str());
+        let missing = [source, target].into_iter().find(|id| !ids.contains(id));
+        if let Some(missing) = missing {
+            errors.push(GraphError::DanglingEdge {
+                source_id: source.to_string(),
+                target_id: target.to_string(),
+                missing: missing.to_string(),
+            });
+            continue;
+        }
+        forward.entry(source).or_default().push(target);
+        backward.entry(target).or_default().push(source);
+    }
+
+    errors.extend(find_cycles(&content.nodes, &forward));
+
+    let from_request = reachable("request", &forward);
+    let to_response = reachable("response", &backward);
+    for node in &content.nodes {
+        let id = node.id.as_str();
+        if !from_request.contains(id) {
+            errors.push(GraphError::Unreachable(id.to_string()));
+        } else if !to_response.contains(id) {
+            errors.push(GraphError::NoPathToResponse(id.to_string()));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn reachable<'a>(start: &'a str, adjacency: &HashMap<&'a str, Vec<&'a str>>) -> HashSet<&'a str> {
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+    while let Some(id) = stack.pop() {
+        if seen.insert(id) {
+            stack.extend(adjacency.get(id).into_iter().flatten().copied());
+        }
+    }
+    seen
+}
+
+/// Returns one error per back edge found by a depth-first search, naming the cycle it
+/// closes.
+fn find_cycles(nodes: &[DecisionNode], forward: &HashMap<&str, Vec<&str>>) -> Vec<GraphError> {
+    fn visit<'a>(
+        id: &'a str,
+        forward: &HashMap<&'a str, Vec<&'a str>>,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+        cycles: &mut Vec<GraphError>,
+    ) {
+        if done.contains(id) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|&p| p == id) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|p| p.to_string()).collect();
+            cycle.push(id.to_string());
+            cycles.push(GraphError::Cycle(cycle));
+            return;
+        }
+        path.push(id);
+        for &next in forward.get(id).into_iter().flatten() {
+            visit(next, forward, path, done, cycles);
+        }
+        path.pop();
+        done.insert(id);
+    }
+
+    let mut cycles = Vec::new();
+    let mut done = HashSet::new();
+    for node in nodes {
+        visit(&node.id, forward, &mut Vec::new(), &mut done, &mut cycles);
+    }
+    cycles
 }
 
 pub async fn build(flow: Vec<Decision>) -> DecisionContent {
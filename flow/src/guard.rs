@@ -0,0 +1,259 @@
+use crate::iteration;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+use zen_engine::model::{DecisionContent, DecisionNodeKind};
+use zen_engine::{DecisionGraphResponse, EvaluationOptions};
+
+/// Execution limits for a single node. Unset limits are not enforced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeLimits {
+    pub timeout: Option<Duration>,
+    /// The largest output, in bytes of compact JSON.
+    pub max_output_bytes: Option<usize>,
+}
+
+impl NodeLimits {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn max_output_bytes(mut self, bytes: usize) -> Self {
+        self.max_output_bytes = Some(bytes);
+        self
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum LimitViolation {
+    #[error("Node {node} ran for {elapsed:?}, over its {limit:?} timeout")]
+    Timeout {
+        node: String,
+        limit: Duration,
+        elapsed: Duration,
+    },
+    #[error("Node {node} produced {size} bytes, over its {limit} byte limit")]
+    OutputSize {
+        node: String,
+        limit: usize,
+        size: usize,
+    },
+}
+
+/// What one node did during an evaluation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeTrace {
+    pub id: String,
+    pub name: String,
+    /// The node's run time as reported by the engine, if it reported one.
+    pub duration: Option<Duration>,
+    pub output_bytes: usize,
+    pub violations: Vec<LimitViolation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardedResponse {
+    pub result: Value,
+    /// One entry per executed node, in id order.
+    pub trace: Vec<NodeTrace>,
+}
+
+#[derive(Error, Debug)]
+pub enum GuardError {
+    #[error("Evaluation error: {0}")]
+    Evaluation(String),
+    #[error("Evaluation ran past its {0:?} deadline")]
+    Deadline(Duration),
+    #[error("Limits exceeded: {}", .violations.iter().map(|v| v.to_string()).collect::<Vec<_>>().join("; "))]
+    Limits {
+        violations: Vec<LimitViolation>,
+        trace: Vec<NodeTrace>,
+    },
+}
+
+/// Evaluates a flow while holding its function and expression nodes to execution
+/// limits.
+///
+/// When any node has a timeout, the evaluation runs on a worker thread and is abandoned
+/// once it has taken longer than the timeouts of all nodes together, failing with
+/// [`GuardError::Deadline`]; nodes without a timeout share that budget. An abandoned
+/// evaluation finishes in the background, as the engine cannot cancel a node midway,
+/// though it does interrupt function nodes that run for longer than a few seconds.
+///
+/// Evaluations that meet the deadline are then checked against the engine's per-node
+/// trace, so a single node over its own timeout or output limit is still reported.
+pub struct GuardedFlow {
+    content: Arc<DecisionContent>,
+    defaults: NodeLimits,
+    overrides: HashMap<String, NodeLimits>,
+}
+
+impl GuardedFlow {
+    pub fn new(content: DecisionContent) -> Self {
+        Self {
+            content: Arc::new(content),
+            defaults: NodeLimits::default(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets the limits of every function and expression node without an override.
+    pub fn with_limits(mut self, limits: NodeLimits) -> Self {
+        self.defaults = limits;
+        self
+    }
+
+    /// Sets the limits of one node, replacing the defaults for it.
+    pub fn with_node_limits(mut self, node: &str, limits: NodeLimits) -> Self {
+        self.overrides.insert(node.to_string(), limits);
+        self
+    }
+
+    /// Returns the limits that apply to a node, if any do.
+    pub fn limits(&self, node: &str) -> Option<NodeLimits> {
+        if let Some(limits) = self.overrides.get(node) {
+            return Some(*limits);
+        }
+        let kind = &self.content.nodes.iter().find(|n| n.id == node)?.kind;
+        match kind {
+            DecisionNodeKind::FunctionNode { .. } | DecisionNodeKind::ExpressionNode { .. } => {
+                Some(self.defaults)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the total of the node timeouts, or `None` when no node has one.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.content
+            .nodes
+            .iter()
+            .filter_map(|node| self.limits(&node.id)?.timeout)
+            .reduce(|total, timeout| total + timeout)
+    }
+
+    pub async fn evaluate(&self, input: &Value) -> Result<GuardedResponse, GuardError> {
+        let content = Arc::clone(&self.content);
+        let response = match self.deadline() {
+            Some(deadline) => evaluate_with_deadline(content, input.clone(), deadline).await?,
+            None => evaluate_traced(content, input).await?,
+        };
+
+        let mut trace: Vec<NodeTrace> = response
+            .trace
+            .unwrap_or_default()
+            .into_values()
+            .map(|node| {
+                let duration = node.performance.as_deref().and_then(parse_duration);
+                let output_bytes = serde_json::to_vec(&node.output).map_or(0, |b| b.len());
+                let violations = self
+                    .limits(&node.id)
+                    .map(|limits| check(&node.id, &limits, duration, output_bytes))
+                    .unwrap_or_default();
+                NodeTrace {
+                    id: node.id,
+                    name: node.name,
+                    duration,
+                    output_bytes,
+                    violations,
+                }
+            })
+            .collect();
+        trace.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let violations: Vec<LimitViolation> = trace
+            .iter()
+            .flat_map(|node| node.violations.iter().cloned())
+            .collect();
+        if violations.is_empty() {
+            Ok(GuardedResponse {
+                result: response.result,
+                trace,
+            })
+        } else {
+            Err(GuardError::Limits { violations, trace })
+        }
+    }
+}
+
+async fn evaluate_traced(
+    content: Arc<DecisionContent>,
+    input: &Value,
+) -> Result<DecisionGraphResponse, GuardError> {
+    let options = EvaluationOptions {
+        trace: Some(true),
+        max_depth: None,
+    };
+    iteration::engine()
+        .create_decision(content)
+        .evaluate_with_opts(input, options)
+        .await
+        .map_err(|error| GuardError::Evaluation(error.to_string()))
+}
+
+/// Evaluates on a thread of its own, as engine futures are not `Send`, and stops waiting
+/// once `deadline` has passed.
+async fn evaluate_with_deadline(
+    content: Arc<DecisionContent>,
+    input: Value,
+    deadline: Duration,
+) -> Result<DecisionGraphResponse, GuardError> {
+    let (sender, receiver) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        let result = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| GuardError::Evaluation(error.to_string()))
+            .and_then(|runtime| runtime.block_on(evaluate_traced(content, &input)));
+        let _ = sender.send(result);
+    });
+    match tokio::time::timeout(deadline, receiver).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(_)) => Err(GuardError::Evaluation("evaluation panicked".to_string())),
+        Err(_) => Err(GuardError::Deadline(deadline)),
+    }
+}
+
+fn check(
+    node: &str,
+    limits: &NodeLimits,
+    elapsed: Option<Duration>,
+    size: usize,
+) -> Vec<LimitViolation> {
+    let mut violations = Vec::new();
+    if let (Some(limit), Some(elapsed)) = (limits.timeout, elapsed) {
+        if elapsed > limit {
+            violations.push(LimitViolation::Timeout {
+                node: node.to_string(),
+                limit,
+                elapsed,
+            });
+        }
+    }
+    if let Some(limit) = limits.max_output_bytes.filter(|limit| size > *limit) {
+        violations.push(LimitViolation::OutputSize {
+            node: node.to_string(),
+            limit,
+            size,
+        });
+    }
+    violations
+}
+
+/// Parses the engine's `Debug` rendering of a duration, such as `1.5ms` or `820ns`.
+pub(crate) fn parse_duration(text: &str) -> Option<Duration> {
+    let split = text.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+    let (number, unit) = text.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let seconds = match unit {
+        "s" => number,
+        "ms" => number / 1e3,
+        "µs" | "us" => number / 1e6,
+        "ns" => number / 1e9,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(seconds))
+}
@@ -0,0 +1,122 @@
+use crate::functions::{evaluate_calls, CALLS_KIND};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use zen_engine::handler::custom_node_adapter::{CustomNodeAdapter, CustomNodeRequest};
+use zen_engine::handler::node::{NodeResponse, NodeResult};
+use zen_engine::loader::NoopLoader;
+use zen_engine::model::{DecisionContent, DecisionEdge, DecisionNode, DecisionNodeKind};
+use zen_engine::DecisionEngine;
+
+/// The custom node kind of loop nodes.
+pub const LOOP_KIND: &str = "loop";
+
+/// An engine that runs loop nodes and the calls nodes the graph builder inserts. Flows without them evaluate exactly as on
+/// `DecisionEngine::default()`.
+pub fn engine() -> DecisionEngine<NoopLoader, LoopAdapter> {
+    DecisionEngine::default().with_adapter(Arc::new(LoopAdapter))
+}
+
+/// Runs loop nodes: the array at the node's `items` path is mapped through its body flow,
+/// one evaluation per element, and the results are returned in order under its `key`.
+///
+/// Object elements are the body's input as they are; any other element is passed as
+/// `{"item": element}`. A missing or null array maps to an empty one.
+///
+/// Calls nodes, which evaluate the registered functions a node calls, are run here too.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoopAdapter;
+
+impl CustomNodeAdapter for LoopAdapter {
+    async fn handle(&self, request: CustomNodeRequest<'_>) -> NodeResult {
+        if request.node.kind == CALLS_KIND {
+            let output = evaluate_calls(request.node.config, request.input)?;
+            return Ok(NodeResponse {
+                output,
+                trace_data: None,
+            });
+        }
+        if request.node.kind != LOOP_KIND {
+            anyhow::bail!("Unsupported custom node kind {}", request.node.kind);
+        }
+        let config = request.node.config;
+        let path = config["items"].as_str().unwrap_or_default();
+        let key = config["key"].as_str().unwrap_or_default().to_string();
+        let body: DecisionContent = serde_json::from_value(config["flow"].clone())?;
+
+        let items = match lookup(request.input, path) {
+            Some(Value::Array(items)) => items.clone(),
+            None | Some(Value::Null) => Vec::new(),
+            Some(other) => anyhow::bail!("{} is not an array but {}", path, other),
+        };
+        let inputs = items
+            .into_iter()
+            .map(|item| match item {
+                Value::Object(_) => item,
+                item => json!({ "item": item }),
+            })
+            .collect();
+        let results = evaluate_detached(body, inputs)?;
+        Ok(NodeResponse {
+            output: json!({ key: results }),
+            trace_data: None,
+        })
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| value.get(segment))
+}
+
+/// Evaluates a flow once per input. Engine futures are not `Send`, as adapter futures
+/// must be, so the evaluations run on their own thread and runtime.
+pub(crate) fn evaluate_detached(
+    content: DecisionContent,
+    inputs: Vec<Value>,
+) -> anyhow::Result<Vec<Value>> {
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async {
+            let decision = engine().create_decision(Arc::new(content));
+            let mut results = Vec::with_capacity(inputs.len());
+            for input in &inputs {
+                let response = decision
+                    .evaluate(input)
+                    .await
+                    .map_err(|error| anyhow::anyhow!("{}", error))?;
+                results.push(response.result);
+            }
+            Ok(results)
+        })
+    })
+    .join()
+    .map_err(|_| anyhow::anyhow!("detached evaluation panicked"))?
+}
+
+/// Returns `request -> node -> response`.
+pub(crate) fn single_node(node: DecisionNode) -> DecisionContent {
+    let edge = |source: &str, target: &str| DecisionEdge {
+        id: "".into(),
+        source_id: source.to_string(),
+        target_id: target.to_string(),
+        source_handle: Some("".into()),
+    };
+    let edges = vec![edge("request", &node.id), edge(&node.id, "response")];
+    let nodes = vec![
+        DecisionNode {
+            id: "request".to_string(),
+            name: "request".to_string(),
+            kind: DecisionNodeKind::InputNode,
+        },
+        node,
+        DecisionNode {
+            id: "response".to_string(),
+            name: "response".to_string(),
+            kind: DecisionNodeKind::OutputNode,
+        },
+    ];
+    DecisionContent { nodes, edges }
+}
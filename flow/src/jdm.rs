@@ -0,0 +1,266 @@
+use crate::export::unique_edges;
+use crate::rule::Decision;
+use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use thiserror::Error;
+use zen_engine::model::{
+    DecisionContent, DecisionNodeKind, DecisionTableHitPolicy, FunctionNodeContent,
+    SwitchStatementHitPolicy,
+};
+
+#[derive(Error, Debug)]
+pub enum JdmError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Node {node}: {reason}")]
+    Unsupported { node: String, reason: String },
+    #[error("Edge {source_id} -> {target_id} refers to a missing node")]
+    DanglingEdge {
+        source_id: String,
+        target_id: String,
+    },
+}
+
+/// Horizontal and vertical spacing of exported nodes in the editor.
+const COLUMN_WIDTH: i64 = 300;
+const ROW_HEIGHT: i64 = 120;
+
+/// Renders a decision graph as a JDM document that the GoRules editor can open.
+///
+/// Nodes are laid out left to right by their distance from `request`, and edges are
+/// given ids and deduplicated.
+pub fn to_jdm(content: &DecisionContent) -> Value {
+    let depths = depths(content);
+    let mut rows: HashMap<usize, i64> = HashMap::new();
+    let nodes: Vec<Value> = content
+        .nodes
+        .iter()
+        .map(|node| {
+            let depth = depths.get(node.id.as_str()).copied().unwrap_or(0);
+            let row = rows.entry(depth).or_default();
+            let mut value = serde_json::to_value(node).unwrap_or(Value::Null);
+            value["position"] = json!({"x": depth as i64 * COLUMN_WIDTH, "y": *row * ROW_HEIGHT});
+            *row += 1;
+            value
+        })
+        .collect();
+    let edges: Vec<Value> = unique_edges(content)
+        .into_iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let mut value = json!({
+                "id": format!("edge-{}", i + 1),
+                "type": "edge",
+                "sourceId": edge.source_id,
+                "targetId": edge.target_id,
+            });
+            if let Some(handle) = edge.source_handle.as_deref().filter(|h| !h.is_empty()) {
+                value["sourceHandle"] = json!(handle);
+            }
+            value
+        })
+        .collect();
+    json!({"contentType": "application/vnd.gorules.decision", "nodes": nodes, "edges": edges})
+}
+
+pub fn write_jdm<P: AsRef<Path>>(content: &DecisionContent, path: P) -> Result<(), JdmError> {
+    let data = serde_json::to_string_pretty(&to_jdm(content))?;
+    std::fs::write(path, data)?;
+    Ok(())
+}
+
+/// Reads a JDM file, such as one saved by the GoRules editor.
+pub fn read_jdm<P: AsRef<Path>>(path: P) -> Result<DecisionContent, JdmError> {
+    let data = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+/// Converts a JDM graph into decisions that [`DecisionGraphBuilder`] rebuilds into an
+/// equivalent graph.
+///
+/// The input and output nodes become `request` and `response`, other nodes keep their
+/// ids. Table columns are keyed by their fields, so every table input needs a field.
+/// Expression nodes must hold a single expression, and nested decision and custom nodes
+/// are not supported.
+///
+/// [`DecisionGraphBuilder`]: crate::graph::DecisionGraphBuilder
+pub fn from_jdm(content: &DecisionContent) -> Result<Vec<Decision>, JdmError> {
+    let mut ids = HashMap::new();
+    let mut decisions = Vec::new();
+    for node in &content.nodes {
+        let unsupported = |reason: &str| JdmError::Unsupported {
+            node: node.id.clone(),
+            reason: reason.to_string(),
+        };
+        let mut decision = Decision {
+            id: node.id.clone(),
+            kind: String::new(),
+            rules: Vec::new(),
+            expression: String::new(),
+            function: String::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            sources: Vec::new(),
+            targets: Vec::new(),
+            conditions: Vec::new(),
+            hit_policy: "first".to_string(),
+            version: None,
+            body: Vec::new(),
+        };
+        match &node.kind {
+            DecisionNodeKind::InputNode => {
+                ids.insert(node.id.as_str(), "request".to_string());
+                continue;
+            }
+            DecisionNodeKind::OutputNode => {
+                ids.insert(node.id.as_str(), "response".to_string());
+                continue;
+            }
+            DecisionNodeKind::DecisionTableNode { content } => {
+                let mut columns = HashMap::new();
+                for input in &content.inputs {
+                    let field = input
+                        .field
+                        .clone()
+                        .ok_or_else(|| unsupported("table inputs need a field"))?;
+                    columns.insert(input.id.as_str(), field.clone());
+                    decision.inputs.push(field);
+                }
+                for output in &content.outputs {
+                    columns.insert(output.id.as_str(), output.field.clone());
+                    decision.outputs.push(output.field.clone());
+                }
+                decision.rules = content
+                    .rules
+                    .iter()
+                    .map(|rule| {
+                        rule.iter()
+                            .map(|(key, cell)| {
+                                let key = columns.get(key.as_str()).unwrap_or(key);
+                                (key.clone(), cell.clone())
+                            })
+                            .collect()
+                    })
+                    .collect();
+                decision.kind = "table".to_string();
+                decision.hit_policy = match content.hit_policy {
+                    DecisionTableHitPolicy::First => "first",
+                    DecisionTableHitPolicy::Collect => "collect",
+                }
+                .to_string();
+            }
+            DecisionNodeKind::ExpressionNode { content } => {
+                let [expression] = content.expressions.as_slice() else {
+                    return Err(unsupported("expression nodes must hold one expression"));
+                };
+                decision.kind = "expression".to_string();
+                decision.expression = expression.value.clone();
+                decision.inputs = vec![expression.key.clone()];
+            }
+            DecisionNodeKind::FunctionNode { content } => {
+                decision.kind = "function".to_string();
+                decision.function = match content {
+                    FunctionNodeContent::Version1(source) => source.clone(),
+                    FunctionNodeContent::Version2(content) => content.source.clone(),
+                };
+            }
+            DecisionNodeKind::SwitchNode { content } => {
+                decision.kind = "switch".to_string();
+                decision.hit_policy = match content.hit_policy {
+                    SwitchStatementHitPolicy::First => "first",
+                    SwitchStatementHitPolicy::Collect => "collect",
+                }
+                .to_string();
+            }
+            DecisionNodeKind::DecisionNode { .. } => {
+                return Err(unsupported("nested decision nodes are not supported"))
+            }
+            DecisionNodeKind::CustomNode { .. } => {
+                return Err(unsupported("custom nodes are not supported"))
+            }
+        }
+        ids.insert(node.id.as_str(), node.id.clone());
+        decisions.push(decision);
+    }
+
+    let index: HashMap<String, usize> = decisions
+        .iter()
+        .enumerate()
+        .map(|(i, d)| (d.id.clone(), i))
+        .collect();
+    // Switch edges are declared on the switch, paired with their statement's condition,
+    // and every other edge on its target, or on its source when that is `response`.
+    for edge in &content.edges {
+        let dangling = || JdmError::DanglingEdge {
+            source_id: edge.source_id.clone(),
+            target_id: edge.target_id.clone(),
+        };
+        let source = ids.get(edge.source_id.as_str()).ok_or_else(dangling)?;
+        let target = ids.get(edge.target_id.as_str()).ok_or_else(dangling)?;
+        let switch = index
+            .get(source)
+            .copied()
+            .filter(|&i| decisions[i].kind == "switch");
+        if let Some(i) = switch {
+            let node = content.nodes.iter().find(|n| n.id == edge.source_id);
+            let condition = match node.map(|n| &n.kind) {
+                Some(DecisionNodeKind::SwitchNode { content }) => content
+                    .statements
+                    .iter()
+                    .find(|s| Some(s.id.as_str()) == edge.source_handle.as_deref())
+                    .map(|s| s.condition.clone())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+            decisions[i].targets.push(target.clone());
+            decisions[i].conditions.push(condition);
+        } else if let Some(&i) = index.get(target) {
+            decisions[i].sources.push(source.clone());
+        } else if let Some(&i) = index.get(source) {
+            decisions[i].targets.push(target.clone());
+        }
+    }
+    Ok(decisions)
+}
+
+/// Returns each node's longest distance from a node without incoming edges. Nodes on
+/// a cycle are left out.
+fn depths(content: &DecisionContent) -> HashMap<&str, usize> {
+    let mut incoming: HashMap<&str, usize> = HashMap::new();
+    let mut forward: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in unique_edges(content) {
+        *incoming.entry(edge.target_id.as_str()).or_default() += 1;
+        forward
+            .entry(edge.source_id.as_str())
+            .or_default()
+            .push(edge.target_id.as_str());
+    }
+    let mut depths = HashMap::new();
+    let mut queue: VecDeque<&str> = content
+        .nodes
+        .iter()
+        .map(|node| node.id.as_str())
+        .filter(|id| !incoming.contains_key(id))
+        .collect();
+    for id in &queue {
+        depths.insert(*id, 0);
+    }
+    while let Some(id) = queue.pop_front() {
+        let depth = depths[id];
+        for &next in forward.get(id).into_iter().flatten() {
+            let entry = depths.entry(next).or_insert(0);
+            *entry = (*entry).max(depth + 1);
+            let remaining = incoming
+                .get_mut(next)
+                .expect("edge target has incoming count");
+            *remaining -= 1;
+            if *remaining == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+    depths
+}
@@ -1,16 +1,60 @@
+pub mod cells;
+pub mod environment;
+pub mod export;
+pub mod functions;
 pub mod graph;
+pub mod guard;
+pub mod iteration;
+pub mod jdm;
+pub mod limits;
+pub mod memo;
+pub mod metrics;
+pub mod parallel;
+pub mod registry;
+pub mod reload;
+#[cfg(feature = "remote")]
+pub mod remote;
 pub mod rule;
+pub mod schema;
+pub mod selection;
+pub mod simulate;
 
-use zen_expression::{evaluate_expression, Isolate};
 use serde_json::Value;
+use thiserror::Error;
+use zen_expression::{evaluate_expression, Isolate, IsolateError};
 
-pub fn eval(expr: &str, data: &Value) -> Value {
-    match evaluate_expression(expr, data) {
-        Ok(result) => result,
-        Err(error) => Value::String(error.to_string()),
+#[derive(Error, Debug)]
+pub enum EvalError {
+    /// The expression could not be tokenized, parsed or compiled.
+    #[error(transparent)]
+    Syntax(IsolateError),
+    /// The expression is well formed but failed against the context.
+    #[error(transparent)]
+    Runtime(IsolateError),
+    /// A registered function rejected its arguments.
+    #[error("Function {name} failed: {message}")]
+    Function { name: String, message: String },
+}
+
+impl From<IsolateError> for EvalError {
+    fn from(error: IsolateError) -> Self {
+        match error {
+            IsolateError::LexerError { .. }
+            | IsolateError::ParserError { .. }
+            | IsolateError::CompilerError { .. } => EvalError::Syntax(error),
+            _ => EvalError::Runtime(error),
+        }
     }
 }
 
+pub fn try_eval(expr: &str, data: &Value) -> Result<Value, EvalError> {
+    Ok(evaluate_expression(expr, data)?)
+}
+
+pub fn eval(expr: &str, data: &Value) -> Value {
+    try_eval(expr, data).unwrap_or_else(|error| Value::String(error.to_string()))
+}
+
 pub struct ExpressionEvaluator<'a> {
     isolate: Isolate<'a>,
 }
@@ -22,10 +66,12 @@ impl<'a> ExpressionEvaluator<'a> {
         }
     }
 
+    pub fn try_eval(&mut self, expr: &'a str) -> Result<Value, EvalError> {
+        Ok(self.isolate.run_standard(expr)?)
+    }
+
     pub fn eval(&mut self, expr: &'a str) -> Value {
-        match self.isolate.run_standard(expr) {
-            Ok(result) => result,
-            Err(error) => Value::String(error.to_string()),
-        }
+        self.try_eval(expr)
+            .unwrap_or_else(|error| Value::String(error.to_string()))
     }
 }
@@ -0,0 +1,223 @@
+use crate::rule::{ReaderError, Rule, RulesReader};
+use cqf_core::models::OptionPricingModel;
+use cqf_core::portfolio::{Portfolio, ShockGrid};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+use zen_expression::{evaluate_expression, evaluate_unary_expression};
+
+/// The columns of a limit table that describe the alert rather than test the snapshot.
+const OUTPUT_COLUMNS: [&str; 3] = ["limit", "severity", "message"];
+
+/// The confidence level of the snapshot's value at risk.
+pub const VAR_CONFIDENCE: f64 = 0.99;
+
+/// The holding period of the snapshot's value at risk: one trading day, in years.
+pub const VAR_HORIZON: f64 = 1.0 / 252.0;
+
+/// The risk measures of a portfolio that limit rules are tested against.
+///
+/// Rule columns name these fields; `var` is `null` for a portfolio without options, and
+/// rows testing a `null` field never fire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    pub value: f64,
+    pub pnl: f64,
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega: f64,
+    pub theta: f64,
+    pub rho: f64,
+    /// The worst loss over the standard spot and volatility shock grid.
+    pub stress_loss: f64,
+    /// The one-day 99% value at risk (see `Portfolio::value_at_risk`), with the
+    /// underlying's volatility taken from the first option; or a figure supplied by an
+    /// external risk system through `with_var`.
+    pub var: Option<f64>,
+    pub positions: usize,
+}
+
+impl PortfolioSnapshot {
+    pub fn new<T: OptionPricingModel + ?Sized>(portfolio: &Portfolio, model: &T) -> Self {
+        let greeks = portfolio.greeks(model);
+        let var = portfolio.option_volatility().map(|volatility| {
+            portfolio
+                .value_at_risk(model, volatility, VAR_CONFIDENCE, VAR_HORIZON)
+                .var
+        });
+        Self {
+            value: portfolio.value(model),
+            pnl: portfolio.unrealized_pnl(model),
+            delta: greeks.delta,
+            gamma: greeks.gamma,
+            vega: greeks.vega,
+            theta: greeks.theta,
+            rho: greeks.rho,
+            stress_loss: portfolio.margin(model, &ShockGrid::standard()).total,
+            var,
+            positions: portfolio.len(),
+        }
+    }
+
+    pub fn with_var(mut self, var: f64) -> Self {
+        self.var = Some(var);
+        self
+    }
+
+    pub fn to_value(&self) -> Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Breach,
+}
+
+impl std::str::FromStr for Severity {
+    type Err = LimitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Severity::Info),
+            "warning" => Ok(Severity::Warning),
+            "breach" => Ok(Severity::Breach),
+            _ => Err(LimitError::InvalidSeverity(s.to_string())),
+        }
+    }
+}
+
+/// An alert raised by a limit rule that matched the snapshot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    pub limit: String,
+    pub severity: Severity,
+    pub message: String,
+    /// The snapshot fields the rule tested, with their values.
+    pub observed: Map<String, Value>,
+}
+
+#[derive(Error, Debug)]
+pub enum LimitError {
+    #[error("Reader error: {0}")]
+    Reader(#[from] ReaderError),
+    #[error("Rule {rule}: unknown snapshot field: {field}")]
+    UnknownField { rule: usize, field: String },
+    #[error("Rule {rule}: cannot evaluate {column}: {message}")]
+    Expression {
+        rule: usize,
+        column: String,
+        message: String,
+    },
+    #[error("Invalid severity: {0}")]
+    InvalidSeverity(String),
+}
+
+/// A limit table evaluated with the collect hit policy: every row whose tests all pass
+/// raises an alert.
+///
+/// Each row follows the decision-table layout of [`RulesReader`]: snapshot columns hold
+/// unary tests such as `> 500` or `abs($) > 500` (an empty cell always passes), and the
+/// `limit`, `severity` and `message` columns hold expressions evaluated against the
+/// snapshot, e.g. `'Delta ' + string(delta)`.
+pub struct LimitEngine {
+    rules: Vec<Rule>,
+}
+
+impl LimitEngine {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, LimitError> {
+        Ok(Self::new(RulesReader::read_rules(path)?))
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Returns the alerts raised by `snapshot`, most severe first.
+    pub fn check(&self, snapshot: &PortfolioSnapshot) -> Result<Vec<Alert>, LimitError> {
+        let context = snapshot.to_value();
+        let mut alerts = Vec::new();
+        for (index, rule) in self.rules.iter().enumerate() {
+            if let Some(alert) = Self::check_rule(index, rule, &context)? {
+                alerts.push(alert);
+            }
+        }
+        alerts.sort_by_key(|alert| std::cmp::Reverse(alert.severity));
+        Ok(alerts)
+    }
+
+    fn check_rule(index: usize, rule: &Rule, context: &Value) -> Result<Option<Alert>, LimitError> {
+        let mut observed = Map::new();
+        let mut columns: Vec<_> = rule
+            .iter()
+            .filter(|(column, _)| !OUTPUT_COLUMNS.contains(&column.as_str()))
+            .collect();
+        columns.sort();
+
+        for (column, test) in columns {
+            let value = context
+                .get(column)
+                .ok_or_else(|| LimitError::UnknownField {
+                    rule: index,
+                    field: column.clone(),
+                })?;
+            if test.trim().is_empty() {
+                continue;
+            }
+            if value.is_null() {
+                return Ok(None);
+            }
+            let mut unary = context.clone();
+            unary["$"] = value.clone();
+            let passed = evaluate_unary_expression(test, &unary).map_err(|error| {
+                LimitError::Expression {
+                    rule: index,
+                    column: column.clone(),
+                    message: error.to_string(),
+                }
+            })?;
+            if !passed {
+                return Ok(None);
+            }
+            observed.insert(column.clone(), value.clone());
+        }
+
+        let output = |column: &str| -> Result<String, LimitError> {
+            let Some(expression) = rule.get(column).filter(|e| !e.trim().is_empty()) else {
+                return Ok(String::new());
+            };
+            match evaluate_expression(expression, context) {
+                Ok(Value::String(text)) => Ok(text),
+                Ok(other) => Ok(other.to_string()),
+                Err(error) => Err(LimitError::Expression {
+                    rule: index,
+                    column: column.to_string(),
+                    message: error.to_string(),
+                }),
+            }
+        };
+
+        let limit = output("limit")?;
+        let severity = match output("severity")?.as_str() {
+            "" => Severity::Breach,
+            text => text.parse()?,
+        };
+        Ok(Some(Alert {
+            limit: if limit.is_empty() {
+                format!("rule {}", index)
+            } else {
+                limit
+            },
+            severity,
+            message: output("message")?,
+            observed,
+        }))
+    }
+}
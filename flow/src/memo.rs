@@ -0,0 +1,223 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+use zen_engine::handler::custom_node_adapter::{CustomNodeAdapter, CustomNodeRequest};
+use zen_engine::handler::node::{NodeResponse, NodeResult};
+use zen_engine::model::{CustomNodeContent, DecisionContent, DecisionNode, DecisionNodeKind};
+use zen_engine::{Decision, DecisionEngine, EvaluationError};
+
+use crate::iteration::{self, single_node, LoopAdapter};
+
+const MEMO_KIND: &str = "memo";
+
+/// Node outputs keyed by node id and the node's input, with hit and miss counters.
+///
+/// A cache may be shared between flows, in which case node ids must identify the same
+/// logic in each of them.
+#[derive(Debug, Default)]
+pub struct NodeCache {
+    entries: Mutex<HashMap<(String, String), Value>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl NodeCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every entry and resets the counters.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    fn get(&self, key: &(String, String)) -> Option<Value> {
+        let value = self.entries.lock().unwrap().get(key).cloned();
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    fn insert(&self, key: (String, String), value: Value) {
+        self.entries.lock().unwrap().insert(key, value);
+    }
+}
+
+/// Runs memoized nodes: a cached output is returned as is, otherwise the original node
+/// is evaluated on its own by the adapter's [`NodeWorker`] and its output stored.
+struct MemoAdapter {
+    cache: Arc<NodeCache>,
+    worker: NodeWorker,
+}
+
+impl CustomNodeAdapter for MemoAdapter {
+    async fn handle(&self, request: CustomNodeRequest<'_>) -> NodeResult {
+        if request.node.kind != MEMO_KIND {
+            return LoopAdapter.handle(request).await;
+        }
+        let mut input = request.input.clone();
+        if let Some(fields) = input.as_object_mut() {
+            fields.remove("$nodes");
+        }
+        let key = (request.node.id.to_string(), input.to_string());
+        if let Some(output) = self.cache.get(&key) {
+            return Ok(NodeResponse {
+                output,
+                trace_data: None,
+            });
+        }
+
+        let node: DecisionNode = serde_json::from_value(request.node.config.clone())?;
+        let output = self.worker.evaluate(node, input).await?;
+        self.cache.insert(key, output.clone());
+        Ok(NodeResponse {
+            output,
+            trace_data: None,
+        })
+    }
+}
+
+type Job = (DecisionNode, Value, oneshot::Sender<anyhow::Result<Value>>);
+
+/// A thread with its own runtime that evaluates nodes on their own, one at a time.
+///
+/// Engine futures are not `Send`, as adapter futures must be, so nodes cannot be
+/// evaluated on the caller's runtime. One worker serves every miss of a flow, keeping
+/// the flow built around each node, and stops once the flow is dropped.
+struct NodeWorker {
+    jobs: mpsc::UnboundedSender<Job>,
+}
+
+impl NodeWorker {
+    fn spawn() -> Self {
+        let (jobs, mut queue) = mpsc::unbounded_channel::<Job>();
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start the memo worker runtime");
+            runtime.block_on(async {
+                let engine = iteration::engine();
+                let mut decisions: HashMap<String, Decision<_, _>> = HashMap::new();
+                while let Some((node, input, reply)) = queue.recv().await {
+                    let decision = decisions
+                        .entry(node.id.clone())
+                        .or_insert_with(|| engine.create_decision(Arc::new(single_node(node))));
+                    let result = decision
+                        .evaluate(&input)
+                        .await
+                        .map(|response| response.result)
+                        .map_err(|error| anyhow::anyhow!("{}", error));
+                    let _ = reply.send(result);
+                }
+            });
+        });
+        Self { jobs }
+    }
+
+    async fn evaluate(&self, node: DecisionNode, input: Value) -> anyhow::Result<Value> {
+        let (reply, result) = oneshot::channel();
+        self.jobs
+            .send((node, input, reply))
+            .map_err(|_| anyhow::anyhow!("memo worker stopped"))?;
+        result
+            .await
+            .map_err(|_| anyhow::anyhow!("memo worker stopped"))?
+    }
+}
+
+/// A flow whose table, expression and function nodes are memoized, so repeated
+/// evaluations that give a node the same input skip recomputing it.
+///
+/// Switch and loop nodes are left alone since they route or delegate rather than
+/// compute. A memoized node sees the data of its incoming edges but not `$nodes`. Misses
+/// are evaluated one at a time on a worker thread owned by the flow.
+pub struct MemoizedFlow {
+    content: Arc<DecisionContent>,
+    engine: DecisionEngine<zen_engine::loader::NoopLoader, MemoAdapter>,
+    cache: Arc<NodeCache>,
+}
+
+impl MemoizedFlow {
+    pub fn new(content: DecisionContent) -> Self {
+        Self::with_cache(content, Arc::new(NodeCache::new()))
+    }
+
+    pub fn with_cache(mut content: DecisionContent, cache: Arc<NodeCache>) -> Self {
+        for node in &mut content.nodes {
+            let memoized = matches!(
+                node.kind,
+                DecisionNodeKind::DecisionTableNode { .. }
+                    | DecisionNodeKind::ExpressionNode { .. }
+                    | DecisionNodeKind::FunctionNode { .. }
+            );
+            if memoized {
+                let config = serde_json::to_value(&*node).unwrap_or(Value::Null);
+                node.kind = DecisionNodeKind::CustomNode {
+                    content: CustomNodeContent {
+                        kind: MEMO_KIND.to_string(),
+                        config,
+                    },
+                };
+            }
+        }
+        let engine = DecisionEngine::default().with_adapter(Arc::new(MemoAdapter {
+            cache: Arc::clone(&cache),
+            worker: NodeWorker::spawn(),
+        }));
+        Self {
+            content: Arc::new(content),
+            engine,
+            cache,
+        }
+    }
+
+    pub fn cache(&self) -> &NodeCache {
+        &self.cache
+    }
+
+    pub async fn evaluate(&self, input: &Value) -> Result<Value, Box<EvaluationError>> {
+        let response = self
+            .engine
+            .create_decision(Arc::clone(&self.content))
+            .evaluate(input)
+            .await?;
+        Ok(response.result)
+    }
+
+    /// Evaluates each input in turn, sharing cached node outputs across the batch.
+    pub async fn evaluate_batch(
+        &self,
+        inputs: &[Value],
+    ) -> Vec<Result<Value, Box<EvaluationError>>> {
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            results.push(self.evaluate(input).await);
+        }
+        results
+    }
+}
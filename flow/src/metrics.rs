@@ -0,0 +1,211 @@
+use crate::guard::parse_duration;
+use crate::iteration;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use zen_engine::model::DecisionContent;
+use zen_engine::{EvaluationError, EvaluationOptions};
+
+/// Receives execution events from [`InstrumentedFlow`]. Every method defaults to doing
+/// nothing, so implementations only override what they record.
+pub trait Metrics: Send + Sync {
+    /// A node finished, taking `duration`.
+    fn node_evaluated(&self, _node: &str, _duration: Duration) {}
+
+    /// Rule `rule` (0-based) of a decision-table node matched.
+    fn rule_hit(&self, _node: &str, _rule: usize) {}
+
+    /// The evaluation failed, in `node` when the engine names one.
+    fn error(&self, _node: Option<&str>, _message: &str) {}
+
+    /// The whole evaluation finished, successfully or not.
+    fn evaluation_finished(&self, _duration: Duration, _success: bool) {}
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn node_evaluated(&self, node: &str, duration: Duration) {
+        (**self).node_evaluated(node, duration)
+    }
+
+    fn rule_hit(&self, node: &str, rule: usize) {
+        (**self).rule_hit(node, rule)
+    }
+
+    fn error(&self, node: Option<&str>, message: &str) {
+        (**self).error(node, message)
+    }
+
+    fn evaluation_finished(&self, duration: Duration, success: bool) {
+        (**self).evaluation_finished(duration, success)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeStats {
+    pub count: u64,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub errors: u64,
+    /// Matches per rule index, for decision-table nodes.
+    pub rule_hits: BTreeMap<usize, u64>,
+}
+
+impl NodeStats {
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.count as u32
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetricsSnapshot {
+    pub evaluations: u64,
+    pub failures: u64,
+    pub total: Duration,
+    pub nodes: BTreeMap<String, NodeStats>,
+}
+
+/// Aggregates events into per-node counts and timings.
+#[derive(Debug, Default)]
+pub struct MetricsCollector {
+    state: Mutex<MetricsSnapshot>,
+}
+
+impl MetricsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        self.state.lock().unwrap().clone()
+    }
+
+    pub fn reset(&self) {
+        *self.state.lock().unwrap() = MetricsSnapshot::default();
+    }
+}
+
+impl Metrics for MetricsCollector {
+    fn node_evaluated(&self, node: &str, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let stats = state.nodes.entry(node.to_string()).or_default();
+        stats.min = if stats.count == 0 {
+            duration
+        } else {
+            stats.min.min(duration)
+        };
+        stats.max = stats.max.max(duration);
+        stats.total += duration;
+        stats.count += 1;
+    }
+
+    fn rule_hit(&self, node: &str, rule: usize) {
+        let mut state = self.state.lock().unwrap();
+        let stats = state.nodes.entry(node.to_string()).or_default();
+        *stats.rule_hits.entry(rule).or_default() += 1;
+    }
+
+    fn error(&self, node: Option<&str>, _message: &str) {
+        if let Some(node) = node {
+            let mut state = self.state.lock().unwrap();
+            state.nodes.entry(node.to_string()).or_default().errors += 1;
+        }
+    }
+
+    fn evaluation_finished(&self, duration: Duration, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.evaluations += 1;
+        state.total += duration;
+        if !success {
+            state.failures += 1;
+        }
+    }
+}
+
+/// A flow that reports node timings, table rule hits and errors to a [`Metrics`] sink
+/// on every evaluation. Node events are read from the engine's trace.
+pub struct InstrumentedFlow<M: Metrics = NoopMetrics> {
+    content: Arc<DecisionContent>,
+    metrics: M,
+}
+
+impl InstrumentedFlow<NoopMetrics> {
+    pub fn new(content: DecisionContent) -> Self {
+        Self::with_metrics(content, NoopMetrics)
+    }
+}
+
+impl<M: Metrics> InstrumentedFlow<M> {
+    pub fn with_metrics(content: DecisionContent, metrics: M) -> Self {
+        Self {
+            content: Arc::new(content),
+            metrics,
+        }
+    }
+
+    pub fn metrics(&self) -> &M {
+        &self.metrics
+    }
+
+    pub async fn evaluate(&self, input: &Value) -> Result<Value, Box<EvaluationError>> {
+        let start = Instant::now();
+        let options = EvaluationOptions {
+            trace: Some(true),
+            max_depth: None,
+        };
+        let result = iteration::engine()
+            .create_decision(Arc::clone(&self.content))
+            .evaluate_with_opts(input, options)
+            .await;
+        match result {
+            Ok(response) => {
+                let mut trace: Vec<_> = response.trace.unwrap_or_default().into_values().collect();
+                trace.sort_by(|a, b| a.id.cmp(&b.id));
+                for node in trace {
+                    if let Some(duration) = node.performance.as_deref().and_then(parse_duration) {
+                        self.metrics.node_evaluated(&node.id, duration);
+                    }
+                    for rule in matched_rules(node.trace_data.as_ref()) {
+                        self.metrics.rule_hit(&node.id, rule);
+                    }
+                }
+                self.metrics.evaluation_finished(start.elapsed(), true);
+                Ok(response.result)
+            }
+            Err(error) => {
+                let node = match error.as_ref() {
+                    EvaluationError::NodeError(error) => Some(error.node_id.as_str()),
+                    _ => None,
+                };
+                let message = match error.as_ref() {
+                    EvaluationError::NodeError(error) => error.source.to_string(),
+                    error => error.to_string(),
+                };
+                self.metrics.error(node, &message);
+                self.metrics.evaluation_finished(start.elapsed(), false);
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Reads the indices of matched rules from a decision table's trace data: one object
+/// for a first-hit table, an array of them for a collect table.
+fn matched_rules(trace_data: Option<&Value>) -> Vec<usize> {
+    let index = |row: &Value| row.get("index").and_then(Value::as_u64);
+    match trace_data {
+        Some(Value::Array(rows)) => rows.iter().filter_map(index).map(|i| i as usize).collect(),
+        Some(row) => index(row).map(|i| i as usize).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
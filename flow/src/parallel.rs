@@ -0,0 +1,272 @@
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Semaphore;
+use zen_engine::model::{
+    DecisionContent, DecisionEdge, DecisionNode, DecisionNodeKind, SwitchNodeContent,
+    SwitchStatementHitPolicy,
+};
+use zen_engine::EvaluationError;
+use zen_expression::Isolate;
+
+use crate::iteration::{self, single_node};
+
+#[derive(Error, Debug)]
+pub enum ParallelError {
+    #[error("Node {node} failed: {source}")]
+    Evaluation {
+        node: String,
+        source: Box<EvaluationError>,
+    },
+    #[error("Node {0} panicked")]
+    Panicked(String),
+}
+
+/// Groups the nodes of a graph into topological levels: every node lies one level past
+/// the furthest of its sources, so the nodes of a level depend only on earlier levels and
+/// a node fed by several others joins them. Nodes keep their order in `content` within a
+/// level; nodes on a cycle are left out, as [`crate::graph::validate`] rejects them.
+pub fn levels(content: &DecisionContent) -> Vec<Vec<String>> {
+    let mut remaining: Vec<&str> = content.nodes.iter().map(|node| node.id.as_str()).collect();
+    let mut placed: HashSet<&str> = HashSet::new();
+    let mut levels = Vec::new();
+    loop {
+        let level: Vec<&str> = remaining
+            .iter()
+            .copied()
+            .filter(|id| {
+                content
+                    .edges
+                    .iter()
+                    .filter(|edge| edge.target_id == *id)
+                    .all(|edge| placed.contains(edge.source_id.as_str()))
+            })
+            .collect();
+        if level.is_empty() {
+            return levels;
+        }
+        remaining.retain(|id| !level.contains(id));
+        placed.extend(&level);
+        levels.push(level.into_iter().map(String::from).collect());
+    }
+}
+
+/// A flow evaluated level by level (see [`levels`]), with the nodes of a level evaluated
+/// concurrently, at most `max_parallelism` at a time.
+///
+/// Each node receives the outputs of its sources merged as the engine merges them, and
+/// the output node's merged input is the result, so a direct edge from the input node
+/// passes the input through. Switch nodes are decided in place and only the edges of
+/// their matching statements carry data on; a node none of whose edges carry data is
+/// skipped, like its dead branch in the engine. Nodes see the data of their incoming
+/// edges but not `$nodes`.
+///
+/// Engine futures are not `Send`, so when a level has more than one node each runs on a
+/// blocking thread, with one runtime kept per thread; a level with a single node is
+/// evaluated in place.
+pub struct ParallelFlow {
+    content: Arc<DecisionContent>,
+    levels: Vec<Vec<String>>,
+    max_parallelism: usize,
+}
+
+impl ParallelFlow {
+    /// Uses up to as many threads as the machine has cores.
+    pub fn new(content: DecisionContent) -> Self {
+        let max_parallelism = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Self {
+            levels: levels(&content),
+            content: Arc::new(content),
+            max_parallelism,
+        }
+    }
+
+    /// Caps the number of nodes evaluated at once; `1` evaluates them one by one.
+    pub fn with_max_parallelism(mut self, max_parallelism: usize) -> Self {
+        self.max_parallelism = max_parallelism.max(1);
+        self
+    }
+
+    pub fn max_parallelism(&self) -> usize {
+        self.max_parallelism
+    }
+
+    pub fn levels(&self) -> &[Vec<String>] {
+        &self.levels
+    }
+
+    pub async fn evaluate(&self, input: &Value) -> Result<Value, ParallelError> {
+        let mut outputs: HashMap<&str, Value> = HashMap::new();
+        let mut selected: HashMap<&str, Vec<String>> = HashMap::new();
+        let mut result = None;
+        for level in &self.levels {
+            let mut jobs = Vec::new();
+            for id in level {
+                let node = self.node(id);
+                if let DecisionNodeKind::InputNode = node.kind {
+                    outputs.insert(id, input.clone());
+                    continue;
+                }
+                let incoming: Vec<&DecisionEdge> = self
+                    .content
+                    .edges
+                    .iter()
+                    .filter(|edge| edge.target_id == *id && carries(edge, &outputs, &selected))
+                    .collect();
+                if incoming.is_empty() {
+                    continue;
+                }
+                let node_input = incoming.iter().fold(json!({}), |mut merged, edge| {
+                    merge(&mut merged, &outputs[edge.source_id.as_str()]);
+                    merged
+                });
+                match &node.kind {
+                    DecisionNodeKind::OutputNode => {
+                        result = Some(node_input);
+                    }
+                    DecisionNodeKind::SwitchNode { content } => {
+                        selected.insert(id, matching(content, &node_input));
+                        outputs.insert(id, node_input);
+                    }
+                    _ => jobs.push((id.as_str(), node_input)),
+                }
+            }
+            for (id, output) in self.run(jobs).await? {
+                outputs.insert(id, output);
+            }
+        }
+        Ok(result.unwrap_or_else(|| json!({})))
+    }
+
+    fn node(&self, id: &str) -> &DecisionNode {
+        self.content
+            .nodes
+            .iter()
+            .find(|node| node.id == id)
+            .expect("levels only hold nodes of the graph")
+    }
+
+    /// Evaluates the nodes of one level, each on its own input.
+    async fn run<'a>(
+        &self,
+        jobs: Vec<(&'a str, Value)>,
+    ) -> Result<Vec<(&'a str, Value)>, ParallelError> {
+        if let [(id, input)] = jobs.as_slice() {
+            let flow = Arc::new(single_node(self.node(id).clone()));
+            let output =
+                evaluate_node(flow, input)
+                    .await
+                    .map_err(|source| ParallelError::Evaluation {
+                        node: id.to_string(),
+                        source,
+                    })?;
+            return Ok(vec![(id, output)]);
+        }
+
+        let permits = Arc::new(Semaphore::new(self.max_parallelism));
+        let mut handles = Vec::with_capacity(jobs.len());
+        for (id, input) in jobs {
+            let permit = Arc::clone(&permits)
+                .acquire_owned()
+                .await
+                .expect("semaphore is never closed");
+            let flow = Arc::new(single_node(self.node(id).clone()));
+            let handle = tokio::task::spawn_blocking(move || {
+                let _permit = permit;
+                RUNTIME.with(|runtime| runtime.block_on(evaluate_node(flow, &input)))
+            });
+            handles.push((id, handle));
+        }
+
+        let mut outputs = Vec::with_capacity(handles.len());
+        for (id, handle) in handles {
+            let output = handle
+                .await
+                .map_err(|_| ParallelError::Panicked(id.to_string()))?
+                .map_err(|source| ParallelError::Evaluation {
+                    node: id.to_string(),
+                    source,
+                })?;
+            outputs.push((id, output));
+        }
+        Ok(outputs)
+    }
+}
+
+thread_local! {
+    static RUNTIME: tokio::runtime::Runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build node runtime");
+}
+
+async fn evaluate_node(
+    flow: Arc<DecisionContent>,
+    input: &Value,
+) -> Result<Value, Box<EvaluationError>> {
+    iteration::engine()
+        .create_decision(flow)
+        .evaluate(input)
+        .await
+        .map(|response| response.result)
+}
+
+/// Whether an edge passes data on: its source has run and, for a switch, selected it.
+fn carries(
+    edge: &DecisionEdge,
+    outputs: &HashMap<&str, Value>,
+    selected: &HashMap<&str, Vec<String>>,
+) -> bool {
+    if !outputs.contains_key(edge.source_id.as_str()) {
+        return false;
+    }
+    match selected.get(edge.source_id.as_str()) {
+        Some(statements) => edge
+            .source_handle
+            .as_ref()
+            .is_some_and(|handle| statements.contains(handle)),
+        None => true,
+    }
+}
+
+/// Returns the ids of the statements a switch selects for `input`, evaluating conditions
+/// as the engine does: against the input, with `$` bound to it too, and counting only a
+/// `true` result.
+fn matching(content: &SwitchNodeContent, input: &Value) -> Vec<String> {
+    let mut context = input.clone();
+    merge(&mut context, &json!({ "$": input }));
+    let mut isolate = Isolate::with_environment(&context);
+    let mut matches = content.statements.iter().filter(|statement| {
+        statement.condition.is_empty()
+            || isolate
+                .run_standard(&statement.condition)
+                .is_ok_and(|value| value.as_bool() == Some(true))
+    });
+    let matches: Vec<_> = match content.hit_policy {
+        SwitchStatementHitPolicy::First => matches.next().into_iter().collect(),
+        SwitchStatementHitPolicy::Collect => matches.collect(),
+    };
+    matches
+        .into_iter()
+        .map(|statement| statement.id.clone())
+        .collect()
+}
+
+/// Merges node data as the engine does: objects field by field, with null fields
+/// removing their key, and arrays concatenated.
+fn merge(target: &mut Value, source: &Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                if value.is_null() {
+                    target.remove(key);
+                } else {
+                    merge(target.entry(key).or_insert(Value::Null), value);
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(source)) => target.extend(source.iter().cloned()),
+        (target, source) => *target = source.clone(),
+    }
+}
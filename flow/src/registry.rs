@@ -0,0 +1,208 @@
+use crate::graph::{DecisionGraphBuilder, GraphError};
+use crate::iteration;
+use crate::rule::{Decision, DecisionReader, ReaderError};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+use zen_engine::model::DecisionContent;
+
+/// A `major.minor.patch` flow version; missing components read as zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether a flow at this version can stand in for one at `required`: the same major
+    /// version, and no older.
+    pub fn is_compatible_with(&self, required: &Version) -> bool {
+        self.major == required.major && self >= required
+    }
+}
+
+impl FromStr for Version {
+    type Err = RegistryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RegistryError::InvalidVersion(s.to_string());
+        let parts: Vec<&str> = s.trim().trim_start_matches('v').split('.').collect();
+        if parts.len() > 3 {
+            return Err(invalid());
+        }
+        let mut numbers = [0u64; 3];
+        for (number, part) in numbers.iter_mut().zip(&parts) {
+            *number = part.parse().map_err(|_| invalid())?;
+        }
+        Ok(Self::new(numbers[0], numbers[1], numbers[2]))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    #[error("Reader error: {0}")]
+    Reader(#[from] ReaderError),
+    #[error("Invalid graph: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
+    Graph(Vec<GraphError>),
+    #[error("Invalid version: {0}")]
+    InvalidVersion(String),
+    #[error("Flow has no version")]
+    MissingVersion,
+    #[error("Flow mixes versions: {}", .0.join(", "))]
+    MixedVersions(Vec<String>),
+    #[error("Unknown flow: {0}")]
+    UnknownFlow(String),
+    #[error("Flow {name} has no version {version}")]
+    UnknownVersion { name: String, version: String },
+    #[error("Flow {name} version {version} is already registered")]
+    DuplicateVersion { name: String, version: Version },
+    #[error("Evaluation error: {0}")]
+    Evaluation(String),
+}
+
+/// Returns the single version declared by a flow's decisions.
+///
+/// Decisions without a version inherit the one declared by the others, but two
+/// different versions in one flow are rejected.
+pub fn flow_version(flow: &[Decision]) -> Result<Version, RegistryError> {
+    let mut declared = flow
+        .iter()
+        .filter_map(|d| d.version.as_deref())
+        .map(str::parse)
+        .collect::<Result<Vec<Version>, _>>()?;
+    declared.sort();
+    declared.dedup();
+    match declared.as_slice() {
+        [] => Err(RegistryError::MissingVersion),
+        [version] => Ok(*version),
+        many => Err(RegistryError::MixedVersions(
+            many.iter().map(Version::to_string).collect(),
+        )),
+    }
+}
+
+/// Keeps every registered version of each flow resident, so decisions can be
+/// reproduced against the rules that were live when they were made.
+#[derive(Default)]
+pub struct FlowRegistry {
+    flows: HashMap<String, BTreeMap<Version, Arc<DecisionContent>>>,
+}
+
+impl FlowRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates and registers a flow under the version its decisions declare.
+    /// Registered versions are immutable.
+    pub fn register(&mut self, name: &str, flow: Vec<Decision>) -> Result<Version, RegistryError> {
+        let version = flow_version(&flow)?;
+        let versions = self.flows.entry(name.to_string()).or_default();
+        if versions.contains_key(&version) {
+            return Err(RegistryError::DuplicateVersion {
+                name: name.to_string(),
+                version,
+            });
+        }
+        let content = DecisionGraphBuilder::new()
+            .try_build(flow)
+            .map_err(RegistryError::Graph)?;
+        versions.insert(version, Arc::new(content));
+        Ok(version)
+    }
+
+    pub async fn load<P: AsRef<Path>>(
+        &mut self,
+        name: &str,
+        path: P,
+    ) -> Result<Version, RegistryError> {
+        let flow = DecisionReader::read_flow(path).await?;
+        self.register(name, flow)
+    }
+
+    /// Returns the registered versions of a flow, oldest first.
+    pub fn versions(&self, name: &str) -> Vec<Version> {
+        self.flows
+            .get(name)
+            .map(|versions| versions.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn get(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> Result<Arc<DecisionContent>, RegistryError> {
+        self.flows
+            .get(name)
+            .ok_or_else(|| RegistryError::UnknownFlow(name.to_string()))?
+            .get(version)
+            .cloned()
+            .ok_or_else(|| RegistryError::UnknownVersion {
+                name: name.to_string(),
+                version: version.to_string(),
+            })
+    }
+
+    pub fn latest(&self, name: &str) -> Result<(Version, Arc<DecisionContent>), RegistryError> {
+        self.flows
+            .get(name)
+            .and_then(|versions| versions.iter().next_back())
+            .map(|(version, content)| (*version, Arc::clone(content)))
+            .ok_or_else(|| RegistryError::UnknownFlow(name.to_string()))
+    }
+
+    /// Returns the newest version compatible with `required`.
+    pub fn latest_compatible(
+        &self,
+        name: &str,
+        required: &Version,
+    ) -> Result<(Version, Arc<DecisionContent>), RegistryError> {
+        self.flows
+            .get(name)
+            .ok_or_else(|| RegistryError::UnknownFlow(name.to_string()))?
+            .iter()
+            .rev()
+            .find(|(version, _)| version.is_compatible_with(required))
+            .map(|(version, content)| (*version, Arc::clone(content)))
+            .ok_or_else(|| RegistryError::UnknownVersion {
+                name: name.to_string(),
+                version: format!("compatible with {}", required),
+            })
+    }
+
+    /// Evaluates `input` against exactly the given version of a flow.
+    pub async fn evaluate(
+        &self,
+        name: &str,
+        version: &Version,
+        input: &Value,
+    ) -> Result<Value, RegistryError> {
+        let content = self.get(name, version)?;
+        let response = iteration::engine()
+            .create_decision(content)
+            .evaluate(input)
+            .await
+            .map_err(|error| RegistryError::Evaluation(error.to_string()))?;
+        Ok(response.result)
+    }
+}
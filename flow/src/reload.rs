@@ -0,0 +1,194 @@
+use crate::graph::{DecisionGraphBuilder, GraphError};
+use crate::rule::{Decision, DecisionReader, ReaderError, RulesReader};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use thiserror::Error;
+use zen_engine::model::DecisionContent;
+
+#[derive(Error, Debug)]
+pub enum ReloadError {
+    #[error("Reader error: {0}")]
+    Reader(#[from] ReaderError),
+    #[error("Invalid graph: {}", .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "))]
+    Graph(Vec<GraphError>),
+    #[error("Watch error: {0}")]
+    Watch(#[from] notify::Error),
+}
+
+/// A flow file and the rule and function files its decisions refer to.
+struct Source {
+    flow: PathBuf,
+}
+
+impl Source {
+    /// Reads and validates the flow, failing on unreadable rule files rather than
+    /// treating them as empty tables. Returns the graph and every file it was built from.
+    fn load(&self) -> Result<(DecisionContent, HashSet<PathBuf>), ReloadError> {
+        let refs = DecisionReader::read_flow_refs(&self.flow)?;
+        let mut files = HashSet::from([absolute(&self.flow)]);
+        for decision in &refs {
+            match decision.kind.as_str() {
+                "table" => {
+                    RulesReader::read_rules(&decision.rules)?;
+                }
+                "function" => {
+                    std::fs::read_to_string(&decision.rules).map_err(ReaderError::from)?;
+                }
+                "flow" | "loop" => {
+                    DecisionReader::read_flow_refs(&decision.rules)?;
+                }
+                _ => continue,
+            }
+            files.insert(absolute(Path::new(&decision.rules)));
+        }
+        let flow: Vec<Decision> = refs.into_iter().map(Decision::from).collect();
+        let content = DecisionGraphBuilder::new()
+            .try_build(flow)
+            .map_err(ReloadError::Graph)?;
+        Ok((content, files))
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Watches the directories holding `files` rather than the files themselves, so changes
+/// made by replacing a file, as many editors save, are seen too.
+fn watch_directories(
+    watcher: &mut RecommendedWatcher,
+    files: &HashSet<PathBuf>,
+) -> Result<(), notify::Error> {
+    let directories: HashSet<PathBuf> = files
+        .iter()
+        .filter_map(|file| file.parent().map(Path::to_path_buf))
+        .collect();
+    for directory in directories {
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+    }
+    Ok(())
+}
+
+struct State {
+    source: Source,
+    graph: RwLock<Arc<DecisionContent>>,
+    files: RwLock<HashSet<PathBuf>>,
+    generation: AtomicU64,
+}
+
+impl State {
+    fn reload(&self) -> Result<(), ReloadError> {
+        let (content, files) = self.source.load()?;
+        *self.graph.write().unwrap() = Arc::new(content);
+        *self.files.write().unwrap() = files;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn affects(&self, event: &Event) -> bool {
+        let files = self.files.read().unwrap();
+        event
+            .paths
+            .iter()
+            .any(|path| files.contains(&absolute(path)) || files.contains(path))
+    }
+}
+
+/// Keeps a compiled decision graph in step with its files.
+///
+/// The directories holding the flow and its rule files are watched; when one of those
+/// files changes the flow is re-read, validated and swapped in atomically, so readers of
+/// [`HotFlow::current`] always see a complete graph. A reload that fails leaves the
+/// previous graph in place and is reported to the error callback.
+pub struct HotFlow {
+    state: Arc<State>,
+    _watcher: RecommendedWatcher,
+}
+
+impl HotFlow {
+    pub fn watch<P, F>(path: P, on_error: F) -> Result<Self, ReloadError>
+    where
+        P: AsRef<Path>,
+        F: Fn(ReloadError) + Send + 'static,
+    {
+        let source = Source {
+            flow: path.as_ref().to_path_buf(),
+        };
+        let (content, files) = source.load()?;
+        let state = Arc::new(State {
+            source,
+            graph: RwLock::new(Arc::new(content)),
+            files: RwLock::new(files),
+            generation: AtomicU64::new(0),
+        });
+
+        let handler_state = Arc::clone(&state);
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let result = match event {
+                Ok(event) if event.kind.is_access() || !handler_state.affects(&event) => Ok(()),
+                Ok(_) => handler_state.reload(),
+                Err(error) => Err(ReloadError::Watch(error)),
+            };
+            if let Err(error) = result {
+                on_error(error);
+            }
+        })?;
+
+        watch_directories(&mut watcher, &state.files.read().unwrap())?;
+
+        Ok(Self {
+            state,
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the graph built from the last successful load.
+    pub fn current(&self) -> Arc<DecisionContent> {
+        Arc::clone(&self.state.graph.read().unwrap())
+    }
+
+    /// Returns how many times the graph has been reloaded since watching began.
+    pub fn generation(&self) -> u64 {
+        self.state.generation.load(Ordering::SeqCst)
+    }
+
+    /// Reloads immediately, regardless of file events.
+    pub fn reload(&self) -> Result<(), ReloadError> {
+        self.state.reload()
+    }
+}
+
+/// Reports changes to a fixed set of files, the way [`HotFlow`] notices changes to a
+/// flow's files, for callers that reload something other than a flow.
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Calls `on_change` with the path of each changed file, or with the watch error.
+    pub fn watch<F>(files: &[PathBuf], on_change: F) -> Result<Self, ReloadError>
+    where
+        F: Fn(Result<PathBuf, ReloadError>) + Send + 'static,
+    {
+        let files: HashSet<PathBuf> = files.iter().map(|file| absolute(file)).collect();
+        let handler_files = files.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+                Ok(event) if event.kind.is_access() => {}
+                Ok(event) => {
+                    let changed = event.paths.into_iter().find(|path| {
+                        handler_files.contains(&absolute(path)) || handler_files.contains(path)
+                    });
+                    if let Some(path) = changed {
+                        on_change(Ok(path));
+                    }
+                }
+                Err(error) => on_change(Err(ReloadError::Watch(error))),
+            })?;
+        watch_directories(&mut watcher, &files)?;
+        Ok(Self { _watcher: watcher })
+    }
+}
@@ -0,0 +1,117 @@
+use crate::rule::{Decision, DecisionReader, DecisionRef, ReaderError, Rule, RulesReader};
+use reqwest::header::{ETAG, IF_NONE_MATCH};
+use reqwest::{Client, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+struct Cached {
+    etag: String,
+    body: String,
+}
+
+/// Fetches flow and rule documents over HTTP(S).
+///
+/// Responses carrying an `ETag` are cached, and later fetches of the same URL send
+/// `If-None-Match` so an unchanged document costs a `304 Not Modified` rather than a
+/// download.
+pub struct RemoteLoader {
+    client: Client,
+    cache: Mutex<HashMap<String, Cached>>,
+}
+
+impl RemoteLoader {
+    pub fn new(timeout: Duration) -> Result<Self, ReaderError> {
+        Ok(Self {
+            client: Client::builder().timeout(timeout).build()?,
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub async fn fetch(&self, url: &str) -> Result<String, ReaderError> {
+        let etag = self
+            .cache
+            .lock()
+            .unwrap()
+            .get(url)
+            .map(|cached| cached.etag.clone());
+        let mut request = self.client.get(url);
+        if let Some(etag) = &etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = request.send().await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = self.cache.lock().unwrap().get(url) {
+                return Ok(cached.body.clone());
+            }
+        }
+        let response = response.error_for_status()?;
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().await?;
+        if let Some(etag) = etag {
+            self.cache.lock().unwrap().insert(
+                url.to_string(),
+                Cached {
+                    etag,
+                    body: body.clone(),
+                },
+            );
+        }
+        Ok(body)
+    }
+
+    /// Fetches a JSON, CSV or YAML rule table, chosen by the URL's file extension.
+    pub async fn read_rules(&self, url: &str) -> Result<Vec<Rule>, ReaderError> {
+        let body = self.fetch(url).await?;
+        RulesReader::parse_rules(&body, extension(url).unwrap_or("json"))
+    }
+
+    /// Fetches a JSON or YAML flow. Table decisions whose rules are themselves URLs are
+    /// fetched too; other rule paths are read from the local file system as usual.
+    pub async fn read_flow(&self, url: &str) -> Result<Vec<Decision>, ReaderError> {
+        let body = self.fetch(url).await?;
+        let refs: Vec<DecisionRef> = match extension(url) {
+            Some("yaml" | "yml") => serde_yaml::from_str(&body)?,
+            _ => serde_json::from_str(&body)?,
+        };
+        let mut flow = Vec::with_capacity(refs.len());
+        for decision_ref in refs {
+            let remote = decision_ref.kind == "table" && is_url(&decision_ref.rules);
+            let rules = if remote {
+                Some(self.read_rules(&decision_ref.rules).await?)
+            } else {
+                None
+            };
+            let mut decision = Decision::from(decision_ref);
+            if let Some(rules) = rules {
+                decision.rules = rules;
+            }
+            flow.push(decision);
+        }
+        Ok(flow)
+    }
+}
+
+impl DecisionReader {
+    pub async fn read_flow_remote(
+        loader: &RemoteLoader,
+        url: &str,
+    ) -> Result<Vec<Decision>, ReaderError> {
+        loader.read_flow(url).await
+    }
+}
+
+fn is_url(path: &str) -> bool {
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
+/// Returns the file extension of a URL's path, ignoring any query or fragment.
+fn extension(url: &str) -> Option<&str> {
+    let path = url.split(['?', '#']).next()?;
+    let name = path.rsplit('/').next()?;
+    name.rsplit_once('.').map(|(_, extension)| extension)
+}
@@ -1,3 +1,4 @@
+use calamine::{open_workbook, Data, Reader, Xlsx};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -16,6 +17,9 @@ pub struct DecisionRef {
     pub outputs: Option<Vec<String>>,
     pub sources: Vec<String>,
     pub targets: Vec<String>,
+    pub conditions: Option<Vec<String>>,
+    pub hit_policy: Option<String>,
+    pub version: Option<String>,
 }
 
 pub type Rule = HashMap<String, String>;
@@ -31,6 +35,11 @@ pub struct Decision {
     pub outputs: Vec<String>,
     pub sources: Vec<String>,
     pub targets: Vec<String>,
+    pub conditions: Vec<String>,
+    pub hit_policy: String,
+    pub version: Option<String>,
+    /// The per-element flow of a `loop` decision, loaded when the graph is built.
+    pub body: Vec<Decision>,
 }
 
 #[derive(Error, Debug)]
@@ -41,6 +50,17 @@ pub enum ReaderError {
     Json(#[from] serde_json::Error),
     #[error("CSV error: {0}")]
     Csv(#[from] csv::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("XLSX error: {0}")]
+    Xlsx(#[from] calamine::XlsxError),
+    #[error("Worksheet not found: {0}")]
+    MissingSheet(String),
+    #[error("Invalid cell range: {0}")]
+    InvalidRange(String),
+    #[cfg(feature = "remote")]
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
     #[error("Unknown file extension: {0}")]
     UnknownExtension(String),
     #[error("Invalid or missing variable: {0}")]
@@ -57,9 +77,26 @@ impl From<DecisionRef> for Decision {
             outputs,
             sources,
             targets,
+            conditions,
+            hit_policy,
+            version,
         } = dec_ref;
 
-        let rules_table = RulesReader::read_rules(&rules).unwrap_or_default();
+        let (rules_table, inputs, outputs) = if rules.ends_with(".xlsx") {
+            let table =
+                RulesReader::read_table_xlsx(&rules, &XlsxOptions::default()).unwrap_or_default();
+            (
+                table.rules,
+                inputs.or(Some(table.inputs)),
+                outputs.or(Some(table.outputs)),
+            )
+        } else {
+            (
+                RulesReader::read_rules(&rules).unwrap_or_default(),
+                inputs,
+                outputs,
+            )
+        };
         let function_content = if kind == "function" {
             std::fs::read_to_string(&rules).unwrap_or_default()
         } else {
@@ -76,10 +113,67 @@ impl From<DecisionRef> for Decision {
             outputs: outputs.unwrap_or_default(),
             sources,
             targets,
+            conditions: conditions.unwrap_or_default(),
+            hit_policy: hit_policy.unwrap_or_else(|| "first".to_string()),
+            version,
+            body: Vec::new(),
         }
     }
 }
 
+/// Selects the cells of a workbook that hold a rule table.
+#[derive(Debug, Clone, Default)]
+pub struct XlsxOptions {
+    /// The worksheet name; the first worksheet when `None`.
+    pub sheet: Option<String>,
+    /// An `A1:C10` style range whose first row is the header; the used range when `None`.
+    pub range: Option<String>,
+}
+
+impl XlsxOptions {
+    pub fn sheet(mut self, sheet: &str) -> Self {
+        self.sheet = Some(sheet.to_string());
+        self
+    }
+
+    pub fn range(mut self, range: &str) -> Self {
+        self.range = Some(range.to_string());
+        self
+    }
+}
+
+/// A rule table with its columns split into decision-table inputs and outputs.
+#[derive(Debug, Clone, Default)]
+pub struct RuleTable {
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    pub rules: Vec<Rule>,
+}
+
+/// Parses an `A1` style cell reference into a zero-based `(row, column)`.
+fn parse_cell(cell: &str) -> Option<(u32, u32)> {
+    let split = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let column = letters
+        .to_ascii_uppercase()
+        .bytes()
+        .try_fold(0u32, |acc, b| {
+            acc.checked_mul(26)?.checked_add((b - b'A' + 1) as u32)
+        })?;
+    let row: u32 = digits.parse().ok()?;
+    Some((row.checked_sub(1)?, column - 1))
+}
+
+fn parse_range(range: &str) -> Option<((u32, u32), (u32, u32))> {
+    let (start, end) = range.split_once(':')?;
+    let start = parse_cell(start.trim())?;
+    let end = parse_cell(end.trim())?;
+    (start.0 <= end.0 && start.1 <= end.1).then_some((start, end))
+}
+
 pub struct RulesReader;
 
 impl RulesReader {
@@ -93,6 +187,22 @@ impl RulesReader {
         match extension {
             "json" => Self::read_rules_json(&path),
             "csv" => Self::read_rules_csv(&path),
+            "yaml" | "yml" => Self::read_rules_yaml(&path),
+            "xlsx" => Ok(Self::read_table_xlsx(&path, &XlsxOptions::default())?.rules),
+            _ => Err(ReaderError::UnknownExtension(extension.to_string())),
+        }
+    }
+
+    /// Parses a JSON, CSV or YAML rule table held in memory, by file extension.
+    pub fn parse_rules(data: &str, extension: &str) -> Result<Vec<Rule>, ReaderError> {
+        match extension {
+            "json" => Ok(serde_json::from_str(data)?),
+            "csv" => {
+                let mut rdr = csv::Reader::from_reader(data.as_bytes());
+                let rules: Result<Vec<Rule>, csv::Error> = rdr.deserialize().collect();
+                Ok(rules?)
+            }
+            "yaml" | "yml" => Ok(serde_yaml::from_str(data)?),
             _ => Err(ReaderError::UnknownExtension(extension.to_string())),
         }
     }
@@ -111,17 +221,106 @@ impl RulesReader {
         let rules: Result<Vec<Rule>, csv::Error> = rdr.deserialize().collect();
         Ok(rules?)
     }
+
+    /// Reads a rule table from an Excel workbook.
+    ///
+    /// The first row of the selected range names the columns. A header may be marked
+    /// `input:delta` or `output:action`; the marker is stripped from the rule key and
+    /// unmarked columns are inputs. Fully empty rows are skipped.
+    pub fn read_table_xlsx<P: AsRef<Path>>(
+        path: P,
+        options: &XlsxOptions,
+    ) -> Result<RuleTable, ReaderError> {
+        let mut workbook: Xlsx<_> = open_workbook(path)?;
+        let sheet = match &options.sheet {
+            Some(sheet) => sheet.clone(),
+            None => workbook
+                .sheet_names()
+                .first()
+                .cloned()
+                .ok_or_else(|| ReaderError::MissingSheet(String::new()))?,
+        };
+        if !workbook.sheet_names().contains(&sheet) {
+            return Err(ReaderError::MissingSheet(sheet));
+        }
+        let mut range = workbook.worksheet_range(&sheet)?;
+        if let Some(selection) = &options.range {
+            let (start, end) = parse_range(selection)
+                .ok_or_else(|| ReaderError::InvalidRange(selection.clone()))?;
+            range = range.range(start, end);
+        }
+
+        let mut rows = range.rows();
+        let mut table = RuleTable::default();
+        let Some(header) = rows.next() else {
+            return Ok(table);
+        };
+        let columns: Vec<String> = header
+            .iter()
+            .map(|cell| {
+                let name = cell.to_string();
+                let name = name.trim();
+                let (marker, key) = name.split_once(':').unwrap_or(("input", name));
+                let key = key.trim().to_string();
+                if marker.trim().eq_ignore_ascii_case("output") {
+                    table.outputs.push(key.clone());
+                } else {
+                    table.inputs.push(key.clone());
+                }
+                key
+            })
+            .collect();
+
+        for row in rows {
+            if row.iter().all(|cell| matches!(cell, Data::Empty)) {
+                continue;
+            }
+            let rule: Rule = columns
+                .iter()
+                .zip(row)
+                .filter(|(column, _)| !column.is_empty())
+                .map(|(column, cell)| (column.clone(), cell.to_string()))
+                .collect();
+            table.rules.push(rule);
+        }
+        table.inputs.retain(|column| !column.is_empty());
+        Ok(table)
+    }
+
+    fn read_rules_yaml(path: &Path) -> Result<Vec<Rule>, ReaderError> {
+        let file = File::open(path)?;
+        let rules: Vec<Rule> = serde_yaml::from_reader(file)?;
+        Ok(rules)
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(std::ffi::OsStr::to_str),
+        Some("yaml" | "yml")
+    )
 }
 
 pub struct DecisionReader;
 
 impl DecisionReader {
     pub async fn read_flow<P: AsRef<Path>>(path: P) -> Result<Vec<Decision>, ReaderError> {
+        let decision_refs = Self::read_flow_refs(path)?;
+        Ok(decision_refs.into_iter().map(Decision::from).collect())
+    }
+
+    /// Reads a flow definition without loading the rule files its decisions refer to.
+    pub fn read_flow_refs<P: AsRef<Path>>(path: P) -> Result<Vec<DecisionRef>, ReaderError> {
+        let path = path.as_ref();
         let mut file = File::open(path)?;
         let mut data = String::new();
         file.read_to_string(&mut data)?;
-        let decision_refs: Vec<DecisionRef> = serde_json::from_str(&data)?;
-        Ok(decision_refs.into_iter().map(Decision::from).collect())
+        let decision_refs: Vec<DecisionRef> = if is_yaml(path) {
+            serde_yaml::from_str(&data)?
+        } else {
+            serde_json::from_str(&data)?
+        };
+        Ok(decision_refs)
     }
 
     pub async fn read_input<P: AsRef<Path>>(path: P) -> Result<Value, ReaderError> {
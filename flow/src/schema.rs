@@ -0,0 +1,250 @@
+use crate::iteration;
+use crate::rule::ReaderError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+use zen_engine::model::DecisionContent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaType {
+    Null,
+    Boolean,
+    Number,
+    Integer,
+    String,
+    Array,
+    Object,
+}
+
+impl SchemaType {
+    fn matches(&self, value: &Value) -> bool {
+        match self {
+            SchemaType::Null => value.is_null(),
+            SchemaType::Boolean => value.is_boolean(),
+            SchemaType::Number => value.is_number(),
+            SchemaType::Integer => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+            SchemaType::String => value.is_string(),
+            SchemaType::Array => value.is_array(),
+            SchemaType::Object => value.is_object(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = serde_json::to_value(self).unwrap_or(Value::Null);
+        write!(f, "{}", name.as_str().unwrap_or_default())
+    }
+}
+
+/// A JSON-schema-like description of a value.
+///
+/// Supports the `type`, `properties`, `required`, `additionalProperties`, `items`,
+/// `enum`, `minimum` and `maximum` keywords; every keyword is optional, so `{}` accepts
+/// anything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Schema {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<SchemaType>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, Schema>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub required: Vec<String>,
+    /// Whether an object may carry fields not listed in `properties`; allowed when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_properties: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<Schema>>,
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub allowed: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+}
+
+/// A value that does not satisfy its schema.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{}: {message}", if .path.is_empty() { "<root>" } else { .path.as_str() })]
+pub struct SchemaError {
+    /// The dotted path of the offending value, e.g. `order.legs.0.strike`; empty for
+    /// the value itself.
+    pub path: String,
+    pub message: String,
+}
+
+impl Schema {
+    /// Checks `value` against the schema, reporting every violation rather than the first.
+    pub fn validate(&self, value: &Value) -> Result<(), Vec<SchemaError>> {
+        let mut errors = Vec::new();
+        self.check("", value, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn check(&self, path: &str, value: &Value, errors: &mut Vec<SchemaError>) {
+        let mut fail = |message: String| {
+            errors.push(SchemaError {
+                path: path.to_string(),
+                message,
+            })
+        };
+        if let Some(kind) = self.kind {
+            if !kind.matches(value) {
+                fail(format!("expected {}, found {}", kind, type_name(value)));
+                return;
+            }
+        }
+        if let Some(allowed) = &self.allowed {
+            if !allowed.contains(value) {
+                let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+                fail(format!("{} is not one of {}", value, allowed.join(", ")));
+            }
+        }
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = self.minimum.filter(|minimum| number < *minimum) {
+                fail(format!("{} is below the minimum {}", number, minimum));
+            }
+            if let Some(maximum) = self.maximum.filter(|maximum| number > *maximum) {
+                fail(format!("{} is above the maximum {}", number, maximum));
+            }
+        }
+
+        let child = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", path, key)
+            }
+        };
+        match value {
+            Value::Object(fields) => {
+                for name in &self.required {
+                    if !fields.contains_key(name) {
+                        errors.push(SchemaError {
+                            path: child(name),
+                            message: "required field is missing".to_string(),
+                        });
+                    }
+                }
+                for (name, field) in fields {
+                    match self.properties.get(name) {
+                        Some(schema) => schema.check(&child(name), field, errors),
+                        None if self.additional_properties == Some(false) => {
+                            errors.push(SchemaError {
+                                path: child(name),
+                                message: "unexpected field".to_string(),
+                            })
+                        }
+                        None => {}
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(schema) = &self.items {
+                    for (i, item) in items.iter().enumerate() {
+                        schema.check(&child(&i.to_string()), item, errors);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// The input and output schemas a flow promises to honour; a missing side is unchecked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Contract {
+    pub input: Option<Schema>,
+    pub output: Option<Schema>,
+}
+
+/// Reads a contract from a JSON or YAML file.
+pub fn read_contract<P: AsRef<Path>>(path: P) -> Result<Contract, ReaderError> {
+    let path = path.as_ref();
+    let mut data = String::new();
+    File::open(path)?.read_to_string(&mut data)?;
+    match path.extension().and_then(std::ffi::OsStr::to_str) {
+        Some("yaml" | "yml") => Ok(serde_yaml::from_str(&data)?),
+        _ => Ok(serde_json::from_str(&data)?),
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("Invalid input: {}", join(.0))]
+    Input(Vec<SchemaError>),
+    #[error("Invalid output: {}", join(.0))]
+    Output(Vec<SchemaError>),
+    #[error("Evaluation error: {0}")]
+    Evaluation(String),
+}
+
+fn join(errors: &[SchemaError]) -> String {
+    errors
+        .iter()
+        .map(SchemaError::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A flow guarded by its contract: inputs are validated before evaluation and results
+/// after it, so a malformed request fails at the boundary instead of producing a
+/// wrong decision.
+pub struct ContractFlow {
+    content: Arc<DecisionContent>,
+    contract: Contract,
+}
+
+impl ContractFlow {
+    pub fn new(content: DecisionContent, contract: Contract) -> Self {
+        Self {
+            content: Arc::new(content),
+            contract,
+        }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn evaluate(&self, input: &Value) -> Result<Value, ContractError> {
+        if let Some(schema) = &self.contract.input {
+            schema.validate(input).map_err(ContractError::Input)?;
+        }
+        let response = iteration::engine()
+            .create_decision(Arc::clone(&self.content))
+            .evaluate(input)
+            .await
+            .map_err(|error| ContractError::Evaluation(error.to_string()))?;
+        if let Some(schema) = &self.contract.output {
+            schema
+                .validate(&response.result)
+                .map_err(ContractError::Output)?;
+        }
+        Ok(response.result)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::iteration;
+use cqf_core::models::{Greeks, OptionPricingModel};
+use cqf_core::strategies::factory::{create_strategy, FactoryError, StrategyKind};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::sync::Arc;
+use thiserror::Error;
+use zen_engine::model::DecisionContent;
+
+#[derive(Error, Debug)]
+pub enum SelectionError {
+    #[error("Evaluation error: {0}")]
+    Evaluation(String),
+    #[error("Decision field {field} is invalid: {message}")]
+    InvalidField { field: String, message: String },
+    #[error("Strategy error: {0}")]
+    Factory(#[from] FactoryError),
+}
+
+/// A strategy chosen by a flow and priced by a model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PricedStrategy {
+    pub kind: StrategyKind,
+    /// The parameters the strategy was built from.
+    pub params: Map<String, Value>,
+    pub price: f64,
+    pub greeks: Greeks,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Selection {
+    /// The flow's full output.
+    pub decision: Value,
+    /// The priced strategy, or `None` when the flow chose none.
+    pub strategy: Option<PricedStrategy>,
+}
+
+/// Lets rules choose the trade and a model price it.
+///
+/// The flow names a strategy under `strategy` (e.g. `'iron_condor'`) and its parameters
+/// under `params`; a null or missing strategy means no trade. Parameters the flow leaves
+/// out are taken from the input's top-level fields, so market data such as `s`, `r`,
+/// `sigma` and `t` need only be passed in once.
+pub struct StrategySelector<M: OptionPricingModel> {
+    content: Arc<DecisionContent>,
+    model: M,
+    strategy_key: String,
+    params_key: String,
+}
+
+impl<M: OptionPricingModel> StrategySelector<M> {
+    pub fn new(content: DecisionContent, model: M) -> Self {
+        Self {
+            content: Arc::new(content),
+            model,
+            strategy_key: "strategy".to_string(),
+            params_key: "params".to_string(),
+        }
+    }
+
+    /// Reads the strategy and its parameters from other output fields.
+    pub fn with_keys(mut self, strategy: &str, params: &str) -> Self {
+        self.strategy_key = strategy.to_string();
+        self.params_key = params.to_string();
+        self
+    }
+
+    pub async fn select(&self, input: &Value) -> Result<Selection, SelectionError> {
+        let decision = iteration::engine()
+            .create_decision(Arc::clone(&self.content))
+            .evaluate(input)
+            .await
+            .map_err(|error| SelectionError::Evaluation(error.to_string()))?
+            .result;
+        let strategy = match decision.get(&self.strategy_key) {
+            None | Some(Value::Null) => None,
+            Some(Value::String(name)) => Some(self.price(name.parse()?, input, &decision)?),
+            Some(other) => {
+                return Err(SelectionError::InvalidField {
+                    field: self.strategy_key.clone(),
+                    message: format!("expected a strategy name, found {}", other),
+                })
+            }
+        };
+        Ok(Selection { decision, strategy })
+    }
+
+    fn price(
+        &self,
+        kind: StrategyKind,
+        input: &Value,
+        decision: &Value,
+    ) -> Result<PricedStrategy, SelectionError> {
+        let mut params = input.as_object().cloned().unwrap_or_default();
+        match decision.get(&self.params_key) {
+            None | Some(Value::Null) => {}
+            Some(Value::Object(chosen)) => params.extend(chosen.clone()),
+            Some(other) => {
+                return Err(SelectionError::InvalidField {
+                    field: self.params_key.clone(),
+                    message: format!("expected an object, found {}", other),
+                })
+            }
+        }
+        let strategy = create_strategy(&self.model, kind, &params)?;
+        Ok(PricedStrategy {
+            kind,
+            price: strategy.price(),
+            greeks: strategy.greeks(),
+            params,
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::iteration;
+use crate::rule::ReaderError;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+use zen_engine::model::DecisionContent;
+
+/// Numbers closer than this compare equal.
+const TOLERANCE: f64 = 1e-9;
+
+/// An example input and the output fields it should produce.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Case {
+    pub name: String,
+    pub input: Value,
+    pub expected: Value,
+}
+
+/// An expected field that the flow got wrong. `actual` is `None` when the field is
+/// missing from the output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Mismatch {
+    /// The dotted path of the field, e.g. `order.side` or `legs.0.strike`.
+    pub path: String,
+    pub expected: Value,
+    pub actual: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CaseReport {
+    pub name: String,
+    pub passed: bool,
+    /// The flow's output, or `null` if evaluation failed.
+    pub actual: Value,
+    pub mismatches: Vec<Mismatch>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulationReport {
+    pub cases: Vec<CaseReport>,
+}
+
+impl SimulationReport {
+    pub fn passed(&self) -> usize {
+        self.cases.iter().filter(|case| case.passed).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.cases.len() - self.passed()
+    }
+
+    pub fn all_passed(&self) -> bool {
+        self.cases.iter().all(|case| case.passed)
+    }
+}
+
+/// Reads test cases from a JSON or YAML file.
+pub fn read_cases<P: AsRef<Path>>(path: P) -> Result<Vec<Case>, ReaderError> {
+    let path = path.as_ref();
+    let mut data = String::new();
+    File::open(path)?.read_to_string(&mut data)?;
+    match path.extension().and_then(std::ffi::OsStr::to_str) {
+        Some("yaml" | "yml") => Ok(serde_yaml::from_str(&data)?),
+        _ => Ok(serde_json::from_str(&data)?),
+    }
+}
+
+/// Runs each case through the flow and compares the output with its expectation.
+///
+/// Expectations are partial: every field they name must be present with an equal value,
+/// recursing into objects and arrays, while fields they leave out are ignored.
+pub async fn simulate(content: &DecisionContent, cases: &[Case]) -> SimulationReport {
+    let decision = iteration::engine().create_decision(Arc::new(content.clone()));
+    let mut reports = Vec::with_capacity(cases.len());
+    for case in cases {
+        let report = match decision.evaluate(&case.input).await {
+            Ok(response) => {
+                let mut mismatches = Vec::new();
+                compare("", &case.expected, Some(&response.result), &mut mismatches);
+                CaseReport {
+                    name: case.name.clone(),
+                    passed: mismatches.is_empty(),
+                    actual: response.result,
+                    mismatches,
+                    error: None,
+                }
+            }
+            Err(error) => CaseReport {
+                name: case.name.clone(),
+                passed: false,
+                actual: Value::Null,
+                mismatches: Vec::new(),
+                error: Some(error.to_string()),
+            },
+        };
+        reports.push(report);
+    }
+    SimulationReport { cases: reports }
+}
+
+fn compare(path: &str, expected: &Value, actual: Option<&Value>, out: &mut Vec<Mismatch>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match (expected, actual) {
+        (Value::Object(fields), Some(Value::Object(actual))) => {
+            for (key, value) in fields {
+                compare(&child(key), value, actual.get(key), out);
+            }
+        }
+        (Value::Array(items), Some(Value::Array(actual))) if items.len() == actual.len() => {
+            for (i, (value, actual)) in items.iter().zip(actual).enumerate() {
+                compare(&child(&i.to_string()), value, Some(actual), out);
+            }
+        }
+        (Value::Number(a), Some(Value::Number(b)))
+            if (a.as_f64().unwrap_or(f64::NAN) - b.as_f64().unwrap_or(f64::NAN)).abs()
+                < TOLERANCE => {}
+        (expected, Some(actual)) if expected == actual => {}
+        (expected, actual) => out.push(Mismatch {
+            path: path.to_string(),
+            expected: expected.clone(),
+            actual: actual.cloned(),
+        }),
+    }
+}
@@ -0,0 +1,106 @@
+extern crate flow;
+use flow::cells::{expand_cell, expand_table};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+use zen_expression::evaluate_unary_expression;
+
+fn matches(cell: &str, value: serde_json::Value) -> bool {
+    let expanded = expand_cell(cell).unwrap();
+    evaluate_unary_expression(&expanded, &json!({ "$": value })).unwrap()
+}
+
+#[test]
+fn test_expand_cell_forms() {
+    assert_eq!(expand_cell("  ").unwrap(), "");
+    assert_eq!(expand_cell("-").unwrap(), "");
+    assert_eq!(expand_cell("[100..200)").unwrap(), "$ >= 100 and $ < 200");
+    assert_eq!(expand_cell("(0..1]").unwrap(), "$ > 0 and $ <= 1");
+    assert_eq!(expand_cell("[a.b..c)").unwrap(), "$ >= a.b and $ < c");
+    assert_eq!(
+        expand_cell("(lo..min([hi, 2]))").unwrap(),
+        "$ > lo and $ < min([hi, 2])"
+    );
+    assert_eq!(expand_cell(">= 0.3").unwrap(), "$ >= 0.3");
+    assert_eq!(
+        expand_cell("in ['AAPL','MSFT']").unwrap(),
+        "$ in ['AAPL','MSFT']"
+    );
+    assert_eq!(expand_cell("not in [1, 2]").unwrap(), "not ($ in [1, 2])");
+    assert_eq!(
+        expand_cell("'AAPL', 'MSFT'").unwrap(),
+        "$ in ['AAPL', 'MSFT']"
+    );
+    assert_eq!(expand_cell("'a..b'").unwrap(), "$ == 'a..b'");
+    // Only a bracketed cell with one top-level `..` is an interval.
+    assert_eq!(
+        expand_cell("(len('a..b') > 1)").unwrap(),
+        "(len('a..b') > 1)"
+    );
+    assert_eq!(expand_cell("[x, 'a..b']").unwrap(), "[x, 'a..b']");
+    assert_eq!(expand_cell("-5").unwrap(), "$ == -5");
+    assert_eq!(expand_cell("abs($) > 2").unwrap(), "abs($) > 2");
+}
+
+#[test]
+fn test_expanded_cells_evaluate() {
+    assert!(matches("[100..200)", json!(100)));
+    assert!(!matches("[100..200)", json!(200)));
+    assert!(matches("(0..1]", json!(1)));
+    assert!(matches(">= 0.3", json!(0.3)));
+    assert!(matches("in ['AAPL','MSFT']", json!("MSFT")));
+    assert!(matches("not in ['AAPL']", json!("MSFT")));
+    assert!(!matches("'AAPL', 'MSFT'", json!("GOOG")));
+    assert!(matches("'AAPL'", json!("AAPL")));
+}
+
+#[test]
+fn test_expand_cell_rejects_malformed_cells() {
+    for cell in [
+        "[1..2", "1..2)", "1..5", "[..2]", "[5..1]", ">=", "> >= 1", "in []", "in 'A'", "=> 5",
+    ] {
+        assert!(expand_cell(cell).is_err(), "{}", cell);
+    }
+}
+
+#[test]
+fn test_expand_table_reports_positions() {
+    let value = json!({
+        "id": "limits",
+        "kind": "table",
+        "rules": "",
+        "inputs": ["delta", "symbol"],
+        "outputs": ["action"],
+        "sources": ["request"],
+        "targets": ["response"]
+    });
+    let mut decision = Decision::from(serde_json::from_value::<DecisionRef>(value).unwrap());
+    let row = |cells: &[(&str, &str)]| {
+        cells
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    };
+    decision.rules = vec![
+        row(&[
+            ("delta", "[0..0.5)"),
+            ("symbol", "in ['SPX']"),
+            ("action", "'hold'"),
+        ]),
+        row(&[
+            ("delta", "[0.5..1"),
+            ("symbol", "'NDX'"),
+            ("action", "[1..2"),
+        ]),
+    ];
+    let errors = expand_table(&mut decision.clone()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].row, 2);
+    assert_eq!(errors[0].column, "delta");
+    assert!(errors[0].to_string().starts_with("Row 2, column delta:"));
+
+    decision.rules.truncate(1);
+    expand_table(&mut decision).unwrap();
+    assert_eq!(decision.rules[0]["delta"], "$ >= 0 and $ < 0.5");
+    assert_eq!(decision.rules[0]["symbol"], "$ in ['SPX']");
+    assert_eq!(decision.rules[0]["action"], "'hold'");
+}
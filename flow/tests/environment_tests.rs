@@ -0,0 +1,91 @@
+extern crate flow;
+use flow::environment::Environment;
+use flow::EvalError;
+use serde_json::json;
+
+fn environment() -> Environment {
+    Environment::pricing()
+        .with_constant("multiplier", 100)
+        .with_constant("desk", "vol")
+        .with_table("max_delta", [("SPX", 500.0), ("NDX", 250.0)])
+        .with_function("clamp", |args| {
+            let x = args[0].as_f64().unwrap_or_default();
+            let bound = args[1].as_f64().unwrap_or_default();
+            Ok(json!(x.max(-bound).min(bound)))
+        })
+}
+
+#[test]
+fn test_environment_constants_and_tables() {
+    let env = environment();
+    assert_eq!(env.constant("multiplier"), Some(&json!(100)));
+    assert_eq!(env.table("max_delta").unwrap()["NDX"], json!(250.0));
+
+    let data = json!({"delta": 3, "symbol": "NDX"});
+    assert_eq!(env.eval("delta * multiplier", &data).unwrap(), json!(300));
+    assert_eq!(env.eval("max_delta.SPX", &data).unwrap(), json!(500));
+    assert_eq!(
+        env.eval("delta * multiplier > lookup('max_delta', symbol)", &data)
+            .unwrap(),
+        json!(true)
+    );
+    assert_eq!(
+        env.eval("lookup('max_delta', 'RUT')", &data).unwrap(),
+        json!(null)
+    );
+
+    // Evaluation data shadows constants.
+    assert_eq!(
+        env.eval("desk", &json!({"desk": "rates"})).unwrap(),
+        json!("rates")
+    );
+    assert_eq!(env.context(&json!(null))["desk"], json!("vol"));
+}
+
+#[test]
+fn test_environment_functions() {
+    let env = environment();
+    assert_eq!(
+        env.eval("clamp(delta, 2)", &json!({"delta": -7})).unwrap(),
+        json!(-2)
+    );
+    let price = env
+        .eval("bs_call(100, 100, 0.05, 0.2, 1) * multiplier", &json!({}))
+        .unwrap();
+    assert!((price.as_f64().unwrap() - 1045.058).abs() < 1e-2);
+
+    assert!(matches!(
+        env.eval("lookup('missing', 'x')", &json!({})),
+        Err(EvalError::Function { name, .. }) if name == "lookup"
+    ));
+    assert!(Environment::new()
+        .eval("lookup('t', 1)", &json!({}))
+        .is_err());
+}
+
+#[test]
+fn test_environment_missing_fields() {
+    let data = json!({"qty": 4});
+    assert_eq!(
+        Environment::new()
+            .eval("default(qty, 1) * default(price, 10)", &data)
+            .unwrap(),
+        json!(40)
+    );
+    assert_eq!(
+        environment().eval("default(size, 'small')", &data).unwrap(),
+        json!("small")
+    );
+
+    assert!(matches!(
+        Environment::new().eval("qty * price", &data),
+        Err(EvalError::Runtime(_))
+    ));
+    let lenient = Environment::new().with_missing_as_null();
+    assert_eq!(lenient.eval("qty * price", &data).unwrap(), json!(null));
+    assert_eq!(lenient.eval("qty * 2", &data).unwrap(), json!(8));
+    assert!(matches!(
+        lenient.eval("qty *", &data),
+        Err(EvalError::Syntax(_))
+    ));
+}
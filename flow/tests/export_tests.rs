@@ -0,0 +1,67 @@
+extern crate flow;
+use flow::export::{to_dot, to_mermaid};
+use flow::graph::DecisionGraphBuilder;
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+use zen_engine::model::DecisionContent;
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["hedge", "hold"],
+            "conditions": ["side == \"long\"", ""]
+        },
+        {
+            "id": "hedge",
+            "kind": "expression",
+            "rules": "'hedge'",
+            "inputs": ["action"],
+            "sources": ["route"],
+            "targets": ["response"]
+        },
+        {
+            "id": "hold",
+            "kind": "table",
+            "rules": "",
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+#[test]
+fn test_export_dot() {
+    let dot = to_dot(&content());
+    assert!(dot.starts_with("digraph decision {"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("\"request\" [label=\"request\", shape=circle"));
+    assert!(dot.contains("\"route\" [label=\"route\", shape=diamond"));
+    assert!(dot.contains("\"hold\" [label=\"hold\", shape=box, fillcolor=\"#dae8fc\"]"));
+    assert!(dot.contains("\"request\" -> \"route\";"));
+    assert!(dot.contains("\"route\" -> \"hedge\" [label=\"side == \\\"long\\\"\"];"));
+    assert!(dot.contains("\"route\" -> \"hold\" [label=\"else\"];"));
+    // route -> hedge is declared on both ends but drawn once.
+    assert_eq!(dot.matches("\"route\" -> \"hedge\"").count(), 1);
+}
+
+#[test]
+fn test_export_mermaid() {
+    let mermaid = to_mermaid(&content());
+    let lines: Vec<&str> = mermaid.lines().collect();
+    assert_eq!(lines[0], "flowchart LR");
+    assert_eq!(lines[1], "    n0((\"request\")):::io");
+    assert_eq!(lines[2], "    n1{\"route\"}:::switch");
+    assert_eq!(lines[3], "    n2(\"hedge\"):::expression");
+    assert_eq!(lines[4], "    n3[\"hold\"]:::table");
+    assert!(lines.contains(&"    n0 --> n1"));
+    assert!(lines.contains(&"    n1 -->|\"side == #quot;long#quot;\"| n2"));
+    assert!(lines.contains(&"    n1 -->|\"else\"| n3"));
+    assert!(lines.contains(&"    classDef switch fill:#ffe6cc"));
+    assert!(!mermaid.contains("classDef function"));
+}
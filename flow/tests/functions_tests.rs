@@ -0,0 +1,174 @@
+extern crate flow;
+use cqf_core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use flow::functions::{expand_defaults, Functions};
+use flow::EvalError;
+use serde_json::{json, Value};
+
+fn params() -> OptionParameters {
+    OptionParameters {
+        s: 100.0,
+        k: 105.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    }
+}
+
+fn number(value: Value) -> f64 {
+    value.as_f64().unwrap()
+}
+
+#[test]
+fn test_pricing_functions() {
+    let functions = Functions::pricing();
+    let context = json!({"spot": 100, "strike": 105, "vol": 0.2});
+    let call = BlackScholesModel.option_price(&params(), OptionType::Call);
+    let put = BlackScholesModel.option_price(&params(), OptionType::Put);
+
+    let value = functions
+        .eval("bs_call(spot, strike, 0.05, vol, 0.5)", &context)
+        .unwrap();
+    assert!((number(value) - call).abs() < 1e-12);
+
+    let value = functions
+        .eval(
+            "bs_call(spot, strike, 0.05, vol, 0.5) - bs_put(spot, strike, 0.05, vol, 0.5)",
+            &context,
+        )
+        .unwrap();
+    assert!((number(value) - (call - put)).abs() < 1e-12);
+
+    let value = functions
+        .eval("delta(spot, strike, 0.05, vol, 0.5, 'put') < 0", &context)
+        .unwrap();
+    assert_eq!(value, json!(true));
+
+    // Nested calls: imply the volatility back out of a Black-Scholes price.
+    let value = functions
+        .eval(
+            "implied_vol(bs_call(spot, strike, 0.05, vol, 0.5), spot, strike, 0.05, 0.5, 'call')",
+            &context,
+        )
+        .unwrap();
+    assert!((number(value) - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn test_strategy_price_function() {
+    let functions = Functions::pricing();
+    let value = functions
+        .eval(
+            "strategy_price('straddle', {s: spot, k: 100, r: 0.05, sigma: 0.2, t: 0.5})",
+            &json!({"spot": 100}),
+        )
+        .unwrap();
+    let atm = OptionParameters {
+        k: 100.0,
+        ..params()
+    };
+    let expected = BlackScholesModel.option_price(&atm, OptionType::Call)
+        + BlackScholesModel.option_price(&atm, OptionType::Put);
+    assert!((number(value) - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_function_calls_inside_strings_are_left_alone() {
+    let functions = Functions::pricing();
+    let value = functions
+        .eval("'bs_call(1, 2)' + name", &json!({"name": "!"}))
+        .unwrap();
+    assert_eq!(value, json!("bs_call(1, 2)!"));
+}
+
+#[test]
+fn test_function_errors() {
+    let functions = Functions::pricing();
+    let result = functions.eval("delta(100, 105, 0.05, 0.2, 0.5, 'straddle')", &json!({}));
+    assert!(matches!(result, Err(EvalError::Function { name, .. }) if name == "delta"));
+
+    let result = functions.eval("strategy_price('condor', {s: 100})", &json!({}));
+    assert!(matches!(result, Err(EvalError::Function { .. })));
+
+    let mut functions = Functions::new();
+    functions.register("double", |args| {
+        Ok(json!(args[0].as_f64().unwrap_or_default() * 2.0))
+    });
+    assert_eq!(
+        functions.eval("double(x) + 1", &json!({"x": 2})).unwrap(),
+        json!(5)
+    );
+    assert!(!functions.contains("bs_call"));
+}
+
+#[test]
+fn test_math_functions() {
+    let mut functions = Functions::math();
+    functions.extend(Functions::pricing());
+    let context = json!({"x": 16, "xs": [3, -1, 2]});
+
+    assert_eq!(functions.eval("sqrt(x) + 1", &context).unwrap(), json!(5));
+    assert!((number(functions.eval("ln(exp(2))", &context).unwrap()) - 2.0).abs() < 1e-12);
+    assert_eq!(functions.eval("min(4, x, 2)", &context).unwrap(), json!(2));
+    assert_eq!(functions.eval("max(xs)", &context).unwrap(), json!(3));
+    assert!(functions.contains("bs_call"));
+
+    for expr in ["sqrt(1, 2)", "sqrt(-1)", "ln(0)", "max()", "min('a')"] {
+        assert!(
+            matches!(
+                functions.eval(expr, &context),
+                Err(EvalError::Function { .. })
+            ),
+            "{}",
+            expr
+        );
+    }
+}
+
+#[test]
+fn test_default_function_and_expansion() {
+    let functions = Functions::coalescing();
+    let context = json!({"order": {"qty": 5}, "price": null});
+
+    assert_eq!(
+        functions.eval("default(order.qty, 1)", &context).unwrap(),
+        json!(5)
+    );
+    assert_eq!(
+        functions
+            .eval("default(order.side, 'buy')", &context)
+            .unwrap(),
+        json!("buy")
+    );
+    assert_eq!(
+        functions
+            .eval("default(price, missing, 2) * 3", &context)
+            .unwrap(),
+        json!(6)
+    );
+    assert!(matches!(
+        functions.eval("default(price)", &context),
+        Err(EvalError::Function { name, .. }) if name == "default"
+    ));
+
+    assert_eq!(
+        expand_defaults("default(qty, 0) * price"),
+        "(qty ?? 0) * price"
+    );
+    assert_eq!(
+        expand_defaults("default(a.b, default(c, 1) + 1)"),
+        "(a.b ?? ((c ?? 1) + 1))"
+    );
+    assert_eq!(expand_defaults("'default(x, 1)'"), "'default(x, 1)'");
+    assert_eq!(expand_defaults("default(x)"), "default(x)");
+    for expr in [
+        "default(price, 2) * order.qty",
+        "default(a.b, default(c, 1) + 1)",
+    ] {
+        assert_eq!(
+            flow::try_eval(&expand_defaults(expr), &context).unwrap(),
+            functions.eval(expr, &context).unwrap(),
+            "{}",
+            expr
+        );
+    }
+}
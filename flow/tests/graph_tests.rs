@@ -0,0 +1,580 @@
+extern crate flow;
+use cqf_core::models::{BlackScholesModel, OptionParameters, OptionPricingModel, OptionType};
+use flow::functions::Functions;
+use flow::graph::{validate, DecisionGraphBuilder, GraphError};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use zen_engine::model::DecisionNodeKind;
+use zen_engine::DecisionEngine;
+
+fn switch_flow(hit_policy: &str) -> Vec<Decision> {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["hedge", "hold"],
+            "conditions": ["abs(delta) > 0.5", ""],
+            "hit_policy": hit_policy
+        },
+        {
+            "id": "hedge",
+            "kind": "expression",
+            "rules": "'hedge'",
+            "inputs": ["action"],
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "hold",
+            "kind": "expression",
+            "rules": "'hold'",
+            "inputs": ["fallback"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    refs.into_iter().map(Decision::from).collect()
+}
+
+async fn evaluate(flow: Vec<Decision>, input: Value) -> Value {
+    let content = DecisionGraphBuilder::new().build(flow);
+    DecisionEngine::default()
+        .create_decision(Arc::new(content))
+        .evaluate(&input)
+        .await
+        .unwrap()
+        .result
+}
+
+#[tokio::test]
+async fn test_switch_node_statements_and_edges() {
+    let content = DecisionGraphBuilder::new().build(switch_flow("first"));
+    let route = content.nodes.iter().find(|n| n.id == "route").unwrap();
+    let DecisionNodeKind::SwitchNode { content: switch } = &route.kind else {
+        panic!("expected a switch node");
+    };
+    assert_eq!(switch.statements.len(), 2);
+    assert_eq!(switch.statements[0].condition, "abs(delta) > 0.5");
+    assert_eq!(switch.statements[1].condition, "");
+
+    let hedge = content
+        .edges
+        .iter()
+        .find(|e| e.target_id == "hedge")
+        .unwrap();
+    assert_eq!(
+        hedge.source_handle.as_deref(),
+        Some(switch.statements[0].id.as_str())
+    );
+    let request = content
+        .edges
+        .iter()
+        .find(|e| e.target_id == "route")
+        .unwrap();
+    assert_eq!(request.source_handle.as_deref(), Some(""));
+}
+
+#[tokio::test]
+async fn test_switch_node_routes_first_match() {
+    let result = evaluate(switch_flow("first"), json!({"delta": -0.7})).await;
+    assert_eq!(result["action"], "hedge");
+    assert!(result.get("fallback").is_none());
+
+    let result = evaluate(switch_flow("first"), json!({"delta": 0.2})).await;
+    assert_eq!(result["fallback"], "hold");
+    assert!(result.get("action").is_none());
+}
+
+#[tokio::test]
+async fn test_switch_node_collects_every_match() {
+    let result = evaluate(switch_flow("collect"), json!({"delta": 0.9})).await;
+    assert_eq!(result["action"], "hedge");
+    assert_eq!(result["fallback"], "hold");
+}
+
+fn expression(id: &str, sources: &[&str], targets: &[&str]) -> Decision {
+    let value = json!({
+        "id": id,
+        "kind": "expression",
+        "rules": "1",
+        "inputs": [id],
+        "sources": sources,
+        "targets": targets
+    });
+    Decision::from(serde_json::from_value::<DecisionRef>(value).unwrap())
+}
+
+#[test]
+fn test_try_build_accepts_valid_flows() {
+    let builder = DecisionGraphBuilder::new();
+    assert!(builder.try_build(switch_flow("first")).is_ok());
+    let flow = vec![
+        expression("a", &["request"], &[]),
+        expression("b", &["a"], &["response"]),
+    ];
+    assert!(builder.try_build(flow).is_ok());
+}
+
+#[test]
+fn test_try_build_reports_unknown_kinds() {
+    let mut flow = vec![expression("a", &["request"], &["response"])];
+    flow[0].kind = "lookup".to_string();
+    let errors = DecisionGraphBuilder::new().try_build(flow).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![GraphError::UnknownKind {
+            node: "a".to_string(),
+            kind: "lookup".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_try_build_reports_expressions_without_a_key() {
+    let mut flow = vec![
+        expression("a", &["request"], &["b"]),
+        expression("b", &["a"], &["response"]),
+    ];
+    flow[1].inputs.clear();
+    let errors = DecisionGraphBuilder::new().try_build(flow).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![GraphError::MissingExpressionKey("b".to_string())]
+    );
+}
+
+#[test]
+fn test_validate_reports_every_problem() {
+    let flow = vec![
+        expression("a", &["request"], &["b"]),
+        expression("b", &[], &["a", "response"]),
+        expression("orphan", &[], &["response"]),
+        expression("sink", &["request"], &[]),
+        expression("lost", &["request", "ghost"], &["response"]),
+    ];
+    let content = DecisionGraphBuilder::new().build(flow);
+    let errors = validate(&content).unwrap_err();
+
+    assert!(errors.contains(&GraphError::DanglingEdge {
+        source_id: "ghost".to_string(),
+        target_id: "lost".to_string(),
+        missing: "ghost".to_string(),
+    }));
+    assert!(errors.iter().any(|e| matches!(e, GraphError::Cycle(path)
+        if path.first() == path.last() && path.contains(&"a".to_string()))));
+    assert!(errors.contains(&GraphError::Unreachable("orphan".to_string())));
+    assert!(errors.contains(&GraphError::NoPathToResponse("sink".to_string())));
+    assert!(!errors.contains(&GraphError::Unreachable("lost".to_string())));
+    assert_eq!(
+        GraphError::Cycle(vec!["a".into(), "b".into(), "a".into()]).to_string(),
+        "Cycle through nodes: a -> b -> a"
+    );
+}
+
+#[test]
+fn test_validate_reports_duplicate_nodes() {
+    let flow = vec![
+        expression("a", &["request"], &["response"]),
+        expression("a", &["request"], &["response"]),
+    ];
+    let content = DecisionGraphBuilder::new().build(flow);
+    assert_eq!(
+        validate(&content).unwrap_err(),
+        vec![GraphError::DuplicateNode("a".to_string())]
+    );
+}
+
+fn write_flow(dir: &std::path::Path, name: &str, flow: Value) -> String {
+    let path = dir.join(name);
+    std::fs::write(&path, flow.to_string()).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn refs(value: Value) -> Vec<Decision> {
+    let refs: Vec<DecisionRef> = serde_json::from_value(value).unwrap();
+    refs.into_iter().map(Decision::from).collect()
+}
+
+#[tokio::test]
+async fn test_sub_flows_are_inlined() {
+    let dir = tempfile::tempdir().unwrap();
+    let eligibility = write_flow(
+        dir.path(),
+        "eligibility.json",
+        json!([{
+            "id": "check",
+            "kind": "expression",
+            "rules": "qty > 0",
+            "inputs": ["eligible"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }]),
+    );
+    let wrapper = write_flow(
+        dir.path(),
+        "wrapper.json",
+        json!([{
+            "id": "inner",
+            "kind": "flow",
+            "rules": eligibility,
+            "sources": ["request"],
+            "targets": ["response"]
+        }]),
+    );
+    let flow = refs(json!([
+        {
+            "id": "eligibility",
+            "kind": "flow",
+            "rules": wrapper,
+            "sources": ["request"],
+            "targets": ["decide"]
+        },
+        {
+            "id": "decide",
+            "kind": "expression",
+            "rules": "eligible ? 'trade' : 'skip'",
+            "inputs": ["action"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]));
+
+    let content = DecisionGraphBuilder::new().try_build(flow.clone()).unwrap();
+    let ids: Vec<&str> = content.nodes.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec!["request", "eligibility/inner/check", "decide", "response"]
+    );
+    assert!(content
+        .edges
+        .iter()
+        .any(|e| e.source_id == "eligibility/inner/check" && e.target_id == "decide"));
+
+    assert_eq!(
+        evaluate(flow.clone(), json!({"qty": 5})).await,
+        json!({"action": "trade"})
+    );
+    assert_eq!(
+        evaluate(flow, json!({"qty": 0})).await,
+        json!({"action": "skip"})
+    );
+}
+
+#[tokio::test]
+async fn test_sub_flow_paths_are_relative_to_their_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let nested = dir.path().join("flows");
+    std::fs::create_dir(&nested).unwrap();
+    std::fs::write(
+        nested.join("size.csv"),
+        "qty,size\n> 100,'large'\n,'small'\n",
+    )
+    .unwrap();
+    write_flow(
+        &nested,
+        "eligibility.json",
+        json!([{
+            "id": "size",
+            "kind": "table",
+            "rules": "size.csv",
+            "inputs": ["qty"],
+            "outputs": ["size"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }]),
+    );
+    let wrapper = write_flow(
+        &nested,
+        "wrapper.json",
+        json!([{
+            "id": "inner",
+            "kind": "flow",
+            "rules": "eligibility.json",
+            "sources": ["request"],
+            "targets": ["response"]
+        }]),
+    );
+    let flow = refs(json!([{
+        "id": "eligibility",
+        "kind": "flow",
+        "rules": wrapper,
+        "sources": ["request"],
+        "targets": ["response"]
+    }]));
+
+    assert_ne!(std::env::current_dir().unwrap(), nested);
+    assert_eq!(
+        evaluate(flow.clone(), json!({"qty": 500})).await,
+        json!({"size": "large"})
+    );
+    assert_eq!(
+        evaluate(flow, json!({"qty": 5})).await,
+        json!({"size": "small"})
+    );
+}
+
+#[test]
+fn test_sub_flow_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir
+        .path()
+        .join("missing.json")
+        .to_string_lossy()
+        .to_string();
+    let flow = refs(json!([{
+        "id": "shared",
+        "kind": "flow",
+        "rules": missing,
+        "sources": ["request"],
+        "targets": ["response"]
+    }]));
+    let errors = DecisionGraphBuilder::new().try_build(flow).unwrap_err();
+    assert!(matches!(&errors[..], [GraphError::SubFlow { node, .. }] if node == "shared"));
+
+    let looped = dir.path().join("loop.json").to_string_lossy().to_string();
+    write_flow(
+        dir.path(),
+        "loop.json",
+        json!([{
+            "id": "again",
+            "kind": "flow",
+            "rules": looped,
+            "sources": ["request"],
+            "targets": ["response"]
+        }]),
+    );
+    let flow = refs(json!([{
+        "id": "outer",
+        "kind": "flow",
+        "rules": looped,
+        "sources": ["request"],
+        "targets": ["response"]
+    }]));
+    let errors = DecisionGraphBuilder::new().try_build(flow).unwrap_err();
+    assert!(
+        errors[0].to_string().contains("includes itself"),
+        "{:?}",
+        errors
+    );
+}
+
+#[tokio::test]
+async fn test_defaults_in_tables_expressions_and_switches() {
+    let dir = tempfile::tempdir().unwrap();
+    let rules = dir.path().join("size.csv");
+    std::fs::write(
+        &rules,
+        "qty,size,lots\n> 10,'large',\"default(qty, 0) / 10\"\n,'small',\"default(lots, 1)\"\n",
+    )
+    .unwrap();
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["size", "notional"],
+            "conditions": ["default(enabled, true)", ""],
+            "hit_policy": "collect"
+        },
+        {
+            "id": "size",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["qty"],
+            "outputs": ["size", "lots"],
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": "default(qty, 0) * default(price, 1)",
+            "inputs": ["notional"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    let flow: Vec<Decision> = refs.into_iter().map(Decision::from).collect();
+
+    let result = evaluate(flow.clone(), json!({"qty": 20, "price": 2})).await;
+    assert_eq!(result["size"], "large");
+    assert_eq!(result["lots"], json!(2));
+    assert_eq!(result["notional"], json!(40));
+
+    let result = evaluate(flow.clone(), json!({})).await;
+    assert_eq!(result["size"], "small");
+    assert_eq!(result["lots"], json!(1));
+    assert_eq!(result["notional"], json!(0));
+
+    let result = evaluate(flow, json!({"enabled": false})).await;
+    assert!(result.get("size").is_none());
+    assert_eq!(result["notional"], json!(0));
+}
+
+#[tokio::test]
+async fn test_pricing_functions_in_tables_expressions_and_switches() {
+    let dir = tempfile::tempdir().unwrap();
+    let rules = dir.path().join("quote.csv");
+    std::fs::write(
+        &rules,
+        "s,k,price\n> k,,\"bs_call(s, k, r, sigma, t)\"\n,\"> bs_call(s, k, r, sigma, t)\",'rich'\n",
+    )
+    .unwrap();
+    let flow = refs(json!([
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["quote", "delta"],
+            "conditions": ["bs_call(s, k, r, sigma, t) > 1", ""],
+            "hit_policy": "collect"
+        },
+        {
+            "id": "quote",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["s", "k"],
+            "outputs": ["price"],
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "delta",
+            "kind": "expression",
+            "rules": "round(delta(s, k, r, sigma, t, 'call') * 100) / 100",
+            "inputs": ["delta"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]));
+    let content = DecisionGraphBuilder::new().try_build(flow).unwrap();
+    assert!(content.nodes.iter().any(|n| n.id == "quote:calls"));
+    assert!(content
+        .edges
+        .iter()
+        .any(|e| e.source_id == "route" && e.target_id == "quote:calls"));
+    let decision = flow::iteration::engine().create_decision(Arc::new(content));
+
+    let params = OptionParameters {
+        s: 105.0,
+        k: 100.0,
+        r: 0.05,
+        sigma: 0.2,
+        t: 0.5,
+    };
+    let input = json!({"s": 105, "k": 100, "r": 0.05, "sigma": 0.2, "t": 0.5});
+    let result = decision.evaluate(&input).await.unwrap().result;
+    let call = BlackScholesModel.option_price(&params, OptionType::Call);
+    assert!((result["price"].as_f64().unwrap() - call).abs() < 1e-9);
+    let delta = BlackScholesModel.option_delta(&params, OptionType::Call);
+    assert_eq!(result["delta"], json!((delta * 100.0).round() / 100.0));
+    assert!(result.get("__calls").is_none());
+
+    // Out of the money, the second rule compares the strike with the computed price.
+    let input = json!({"s": 95, "k": 100, "r": 0.05, "sigma": 0.2, "t": 0.5});
+    let result = decision.evaluate(&input).await.unwrap().result;
+    assert_eq!(result["price"], "rich");
+
+    // A cheap call skips the table; the expression still runs as the default branch.
+    let input = json!({"s": 50, "k": 100, "r": 0.05, "sigma": 0.2, "t": 0.1});
+    let result = decision.evaluate(&input).await.unwrap().result;
+    assert!(result.get("price").is_none());
+    assert_eq!(result["delta"], json!(0));
+}
+
+#[tokio::test]
+async fn test_user_functions_in_table_cells() {
+    let dir = tempfile::tempdir().unwrap();
+    let rules = dir.path().join("margin.csv");
+    std::fs::write(
+        &rules,
+        "notional,margin\n\"> haircut(limit)\",haircut(notional) * 2\n,haircut(notional)\n",
+    )
+    .unwrap();
+    let flow = refs(json!([
+        {
+            "id": "margin",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["notional"],
+            "outputs": ["margin"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }
+    ]));
+    let mut functions = Functions::math();
+    functions.register("haircut", |args| {
+        let value = args
+            .first()
+            .and_then(Value::as_f64)
+            .ok_or("haircut: argument 1 must be a number")?;
+        Ok(json!(value * 0.1))
+    });
+    let builder = DecisionGraphBuilder::with_functions(functions);
+    let content = builder.try_build(flow.clone()).unwrap();
+    assert!(content.nodes.iter().any(|n| n.id == "margin:calls"));
+    drop(builder);
+    let decision = flow::iteration::engine().create_decision(Arc::new(content));
+
+    let input = json!({"notional": 2000, "limit": 1000});
+    let result = decision.evaluate(&input).await.unwrap().result;
+    assert_eq!(result["margin"], json!(400));
+    let input = json!({"notional": 50, "limit": 1000});
+    let result = decision.evaluate(&input).await.unwrap().result;
+    assert_eq!(result["margin"], json!(5));
+
+    // The default builder only knows the pricing functions.
+    let errors = DecisionGraphBuilder::new().try_build(flow).unwrap_err();
+    assert!(matches!(errors[..], [GraphError::Cell { .. }]));
+}
+
+#[tokio::test]
+async fn test_table_cells_are_expanded_and_checked() {
+    let dir = tempfile::tempdir().unwrap();
+    let rules = dir.path().join("band.yaml");
+    std::fs::write(
+        &rules,
+        "- {delta: '[0..0.5)', symbol: \"in ['SPX']\", band: \"'low'\"}\n\
+         - {delta: '[0.5..1]', symbol: \"'SPX', 'NDX'\", band: \"'high'\"}\n",
+    )
+    .unwrap();
+    let table = |rules: &std::path::Path| {
+        refs(json!([{
+            "id": "band",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["delta", "symbol"],
+            "outputs": ["band"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }]))
+    };
+    let flow = table(&rules);
+    let result = evaluate(flow.clone(), json!({"delta": 0.5, "symbol": "NDX"})).await;
+    assert_eq!(result["band"], "high");
+    let result = evaluate(flow, json!({"delta": 0.2, "symbol": "SPX"})).await;
+    assert_eq!(result["band"], "low");
+
+    std::fs::write(
+        &rules,
+        "- {delta: '[0..0.5', symbol: '', band: \"'low'\"}\n\
+         - {delta: '[1..0]', symbol: '>=', band: \"'high'\"}\n",
+    )
+    .unwrap();
+    let errors = DecisionGraphBuilder::new()
+        .try_build(table(&rules))
+        .unwrap_err();
+    assert_eq!(errors.len(), 3);
+    let GraphError::Cell { node, error } = &errors[1] else {
+        panic!("expected a cell error, got {:?}", errors[1]);
+    };
+    assert_eq!(node, "band");
+    assert_eq!((error.row, error.column.as_str()), (2, "delta"));
+}
@@ -0,0 +1,122 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::guard::{GuardError, GuardedFlow, LimitViolation, NodeLimits};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use zen_engine::model::DecisionContent;
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "label",
+            "kind": "expression",
+            "rules": "name + ' x' + string(qty)",
+            "inputs": ["label"],
+            "sources": ["request"],
+            "targets": ["response"]
+        },
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": "qty * price",
+            "inputs": ["notional"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+fn input() -> serde_json::Value {
+    json!({"name": "AAPL", "qty": 3, "price": 2})
+}
+
+#[tokio::test]
+async fn test_guarded_flow_traces_nodes_within_limits() {
+    let flow = GuardedFlow::new(content()).with_limits(
+        NodeLimits::default()
+            .timeout(Duration::from_secs(5))
+            .max_output_bytes(1024),
+    );
+    let response = flow.evaluate(&input()).await.unwrap();
+    assert_eq!(response.result["notional"], json!(6));
+
+    let label = response.trace.iter().find(|n| n.id == "label").unwrap();
+    assert!(label.duration.is_some());
+    assert_eq!(label.output_bytes, r#"{"label":"AAPL x3"}"#.len());
+    assert!(response.trace.iter().all(|n| n.violations.is_empty()));
+
+    assert!(flow.limits("request").is_none());
+    assert_eq!(
+        flow.limits("notional").unwrap().max_output_bytes,
+        Some(1024)
+    );
+}
+
+#[tokio::test]
+async fn test_guarded_flow_reports_violations() {
+    // The deadline is the five seconds given to `label`, so `notional` runs to completion
+    // and is caught by the trace.
+    let flow = GuardedFlow::new(content())
+        .with_limits(
+            NodeLimits::default()
+                .timeout(Duration::from_secs(5))
+                .max_output_bytes(18),
+        )
+        .with_node_limits("notional", NodeLimits::default().timeout(Duration::ZERO));
+    assert_eq!(flow.deadline(), Some(Duration::from_secs(5)));
+
+    match flow.evaluate(&input()).await {
+        Err(GuardError::Limits { violations, trace }) => {
+            assert_eq!(violations.len(), 2);
+            assert!(matches!(
+                &violations[0],
+                LimitViolation::OutputSize { node, limit: 18, size: 19 } if node == "label"
+            ));
+            assert!(matches!(
+                &violations[1],
+                LimitViolation::Timeout { node, .. } if node == "notional"
+            ));
+            let notional = trace.iter().find(|n| n.id == "notional").unwrap();
+            assert_eq!(notional.violations.len(), 1);
+        }
+        other => panic!("expected limit violations, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_guarded_flow_cuts_slow_nodes_short() {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([{
+        "id": "spin",
+        "kind": "function",
+        "rules": "",
+        "sources": ["request"],
+        "targets": ["response"]
+    }]))
+    .unwrap();
+    let mut flow: Vec<Decision> = refs.into_iter().map(Decision::from).collect();
+    flow[0].function = "export const handler = async () => {
+        const start = Date.now();
+        while (Date.now() - start < 1000) {}
+        return { done: true };
+    };"
+    .to_string();
+    let content = DecisionGraphBuilder::new().build(flow);
+
+    let limit = Duration::from_millis(100);
+    let flow = GuardedFlow::new(content.clone()).with_limits(NodeLimits::default().timeout(limit));
+    let started = Instant::now();
+    match flow.evaluate(&json!({})).await {
+        Err(GuardError::Deadline(deadline)) => assert_eq!(deadline, limit),
+        other => panic!("expected the deadline to pass, got {:?}", other),
+    }
+    assert!(started.elapsed() < Duration::from_millis(800));
+
+    let response = GuardedFlow::new(content)
+        .evaluate(&json!({}))
+        .await
+        .unwrap();
+    assert_eq!(response.result["done"], json!(true));
+}
@@ -0,0 +1,118 @@
+extern crate flow;
+use flow::graph::{DecisionGraphBuilder, GraphError};
+use flow::iteration::engine;
+use flow::memo::MemoizedFlow;
+use flow::rule::{Decision, DecisionRef};
+use serde_json::{json, Value};
+use std::path::Path;
+use std::sync::Arc;
+use zen_engine::model::DecisionContent;
+
+fn write_body(dir: &Path, name: &str, expression: &str, key: &str) -> String {
+    let path = dir.join(name);
+    let body = json!([{
+        "id": "score",
+        "kind": "expression",
+        "rules": expression,
+        "inputs": [key],
+        "sources": ["request"],
+        "targets": ["response"]
+    }]);
+    std::fs::write(&path, body.to_string()).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+fn looped(body: &str, items: &str, outputs: Value) -> Vec<Decision> {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([{
+        "id": "each",
+        "kind": "loop",
+        "rules": body,
+        "inputs": [items],
+        "outputs": outputs,
+        "sources": ["request"],
+        "targets": ["response"]
+    }]))
+    .unwrap();
+    refs.into_iter().map(Decision::from).collect()
+}
+
+async fn evaluate(content: DecisionContent, input: Value) -> Result<Value, String> {
+    engine()
+        .create_decision(Arc::new(content))
+        .evaluate(&input)
+        .await
+        .map(|response| response.result)
+        .map_err(|error| format!("{:?}", error))
+}
+
+#[tokio::test]
+async fn test_loop_maps_array_through_body() {
+    let dir = tempfile::tempdir().unwrap();
+    let body = write_body(dir.path(), "notional.json", "qty * price", "notional");
+    let content = DecisionGraphBuilder::new()
+        .try_build(looped(&body, "book.positions", json!(["notionals"])))
+        .unwrap();
+
+    let input = json!({"book": {"positions": [
+        {"qty": 2, "price": 10},
+        {"qty": 3, "price": 1.5}
+    ]}});
+    assert_eq!(
+        evaluate(content.clone(), input).await.unwrap(),
+        json!({"notionals": [{"notional": 20}, {"notional": 4.5}]})
+    );
+    assert_eq!(
+        evaluate(content.clone(), json!({})).await.unwrap(),
+        json!({"notionals": []})
+    );
+    assert!(evaluate(content, json!({"book": {"positions": 3}}))
+        .await
+        .unwrap_err()
+        .contains("not an array"));
+}
+
+#[tokio::test]
+async fn test_loop_wraps_scalars_and_defaults_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let body = write_body(dir.path(), "double.json", "item * 2", "double");
+    let content = DecisionGraphBuilder::new().build(looped(&body, "quotes", json!([])));
+    assert_eq!(
+        evaluate(content.clone(), json!({"quotes": [1, 2.5]}))
+            .await
+            .unwrap(),
+        json!({"results": [{"double": 2}, {"double": 5}]})
+    );
+
+    // Loop nodes also run inside memoized flows.
+    let flow = MemoizedFlow::new(content);
+    let result = flow.evaluate(&json!({"quotes": [4]})).await.unwrap();
+    assert_eq!(result, json!({"results": [{"double": 8}]}));
+}
+
+#[test]
+fn test_loop_body_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir
+        .path()
+        .join("missing.json")
+        .to_string_lossy()
+        .to_string();
+    let errors = DecisionGraphBuilder::new()
+        .try_build(looped(&missing, "items", json!([])))
+        .unwrap_err();
+    assert!(matches!(&errors[..], [GraphError::SubFlow { node, .. }] if node == "each"));
+
+    let path = dir.path().join("bad.json");
+    std::fs::write(
+        &path,
+        json!([{"id": "x", "kind": "magic", "rules": "", "sources": ["request"], "targets": ["response"]}])
+            .to_string(),
+    )
+    .unwrap();
+    let errors = DecisionGraphBuilder::new()
+        .try_build(looped(&path.to_string_lossy(), "items", json!([])))
+        .unwrap_err();
+    assert!(errors[0]
+        .to_string()
+        .contains("Unsupported decision kind magic"));
+}
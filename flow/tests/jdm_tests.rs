@@ -0,0 +1,170 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::jdm::{from_jdm, read_jdm, to_jdm, write_jdm, JdmError};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use zen_engine::model::DecisionContent;
+use zen_engine::DecisionEngine;
+
+fn content(dir: &std::path::Path) -> DecisionContent {
+    let rules = dir.join("tags.csv");
+    std::fs::write(&rules, "qty,tag\n> 10,'big'\n> 0,'positive'\n").unwrap();
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["tags", "empty"],
+            "conditions": ["qty > 0", ""]
+        },
+        {
+            "id": "tags",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["qty"],
+            "outputs": ["tag"],
+            "hit_policy": "collect",
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "empty",
+            "kind": "expression",
+            "rules": "'none'",
+            "inputs": ["tag"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+async fn evaluate(content: DecisionContent, input: Value) -> Value {
+    DecisionEngine::default()
+        .create_decision(Arc::new(content))
+        .evaluate(&input)
+        .await
+        .unwrap()
+        .result
+}
+
+#[test]
+fn test_to_jdm_lays_out_nodes_and_names_edges() {
+    let dir = tempfile::tempdir().unwrap();
+    let jdm = to_jdm(&content(dir.path()));
+    assert_eq!(jdm["contentType"], "application/vnd.gorules.decision");
+
+    let nodes = jdm["nodes"].as_array().unwrap();
+    let node = |id: &str| nodes.iter().find(|n| n["id"] == id).unwrap();
+    assert_eq!(node("request")["type"], "inputNode");
+    assert_eq!(node("request")["position"], json!({"x": 0, "y": 0}));
+    assert_eq!(node("route")["position"], json!({"x": 300, "y": 0}));
+    assert_eq!(node("empty")["position"], json!({"x": 600, "y": 120}));
+    assert_eq!(node("response")["position"]["x"], 900);
+    assert_eq!(node("tags")["content"]["hitPolicy"], "collect");
+
+    let edges = jdm["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 5);
+    assert_eq!(edges[0]["id"], "edge-1");
+    let to_tags = edges.iter().find(|e| e["targetId"] == "tags").unwrap();
+    assert_eq!(to_tags["sourceHandle"], "route:tags");
+    let to_route = edges.iter().find(|e| e["targetId"] == "route").unwrap();
+    assert!(to_route.get("sourceHandle").is_none());
+}
+
+#[tokio::test]
+async fn test_jdm_round_trip_preserves_behaviour() {
+    let dir = tempfile::tempdir().unwrap();
+    let original = content(dir.path());
+    let path = dir.path().join("flow.jdm.json");
+    write_jdm(&original, &path).unwrap();
+
+    let imported = read_jdm(&path).unwrap();
+    let decisions = from_jdm(&imported).unwrap();
+    let route = decisions.iter().find(|d| d.id == "route").unwrap();
+    assert_eq!(route.targets, vec!["tags", "empty"]);
+    assert_eq!(route.conditions, vec!["qty > 0", ""]);
+    let rebuilt = DecisionGraphBuilder::new().try_build(decisions).unwrap();
+
+    for input in [json!({"qty": 20}), json!({"qty": 5}), json!({"qty": 0})] {
+        assert_eq!(
+            evaluate(rebuilt.clone(), input.clone()).await,
+            evaluate(original.clone(), input).await
+        );
+    }
+    assert_eq!(
+        evaluate(rebuilt, json!({"qty": 20})).await,
+        json!([{"tag": "big"}, {"tag": "positive"}])
+    );
+}
+
+fn editor_graph(expressions: Value, table_field: Value) -> DecisionContent {
+    serde_json::from_value(json!({
+        "nodes": [
+            {"id": "a1", "name": "Request", "type": "inputNode",
+             "position": {"x": 0, "y": 0}},
+            {"id": "b2", "name": "Tier", "type": "decisionTableNode", "content": {
+                "hitPolicy": "first",
+                "inputs": [{"id": "c-in", "name": "Score", "field": table_field}],
+                "outputs": [{"id": "c-out", "name": "Tier", "field": "tier"}],
+                "rules": [
+                    {"_id": "r1", "c-in": ">= 700", "c-out": "'gold'"},
+                    {"_id": "r2", "c-in": "", "c-out": "'standard'"}
+                ]
+            }},
+            {"id": "d4", "name": "Label", "type": "expressionNode",
+             "content": {"expressions": expressions}},
+            {"id": "e5", "name": "Response", "type": "outputNode"}
+        ],
+        "edges": [
+            {"id": "x1", "sourceId": "a1", "targetId": "b2", "type": "edge"},
+            {"id": "x2", "sourceId": "b2", "targetId": "d4", "type": "edge"},
+            {"id": "x3", "sourceId": "d4", "targetId": "e5", "type": "edge"}
+        ]
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_from_jdm_imports_editor_graphs() {
+    let graph = editor_graph(
+        json!([{"id": "k", "key": "label", "value": "upper(tier)"}]),
+        json!("score"),
+    );
+    let decisions = from_jdm(&graph).unwrap();
+    let table = &decisions[0];
+    assert_eq!(
+        (table.inputs.clone(), table.outputs.clone()),
+        (vec!["score".to_string()], vec!["tier".to_string()])
+    );
+    assert_eq!(table.rules[0]["score"], ">= 700");
+    assert_eq!(table.sources, vec!["request"]);
+    assert_eq!(decisions[1].targets, vec!["response"]);
+
+    let content = DecisionGraphBuilder::new().try_build(decisions).unwrap();
+    assert_eq!(
+        evaluate(content, json!({"score": 720})).await,
+        json!({"label": "GOLD"})
+    );
+}
+
+#[test]
+fn test_from_jdm_rejects_unsupported_nodes() {
+    let two = json!([
+        {"id": "k1", "key": "a", "value": "1"},
+        {"id": "k2", "key": "b", "value": "2"}
+    ]);
+    let one = json!([{"id": "k", "key": "a", "value": "1"}]);
+    for (graph, node) in [
+        (editor_graph(two, json!("score")), "d4"),
+        (editor_graph(one, Value::Null), "b2"),
+    ] {
+        match from_jdm(&graph) {
+            Err(JdmError::Unsupported { node: found, .. }) => assert_eq!(found, node),
+            other => panic!("expected an unsupported node, got {:?}", other),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 extern crate flow;
 use flow::eval;
 use flow::ExpressionEvaluator;
+use flow::{try_eval, EvalError};
 use serde_json::json;
 use std::f64::consts;
 
@@ -351,3 +352,35 @@ fn test_array_operations() {
 
     assert_eq!(eval(expr, &context), expected);
 }
+
+#[test]
+fn test_try_eval_distinguishes_errors_from_strings() {
+    let context = json!({"name": "x + y", "x": 1});
+
+    assert_eq!(try_eval("name", &context).unwrap(), json!("x + y"));
+    assert_eq!(try_eval("x + 1", &context).unwrap(), json!(2));
+    assert!(matches!(
+        try_eval("1 +", &context),
+        Err(EvalError::Syntax(_))
+    ));
+    assert!(matches!(
+        try_eval("x + 'a'", &context),
+        Err(EvalError::Runtime(_))
+    ));
+
+    let error = try_eval("1 +", &context).unwrap_err();
+    assert_eq!(eval("1 +", &context), json!(error.to_string()));
+}
+
+#[test]
+fn test_expression_evaluator_try_eval() {
+    let context = json!({"x": 10});
+    let mut evaluator = ExpressionEvaluator::new(&context);
+
+    assert_eq!(evaluator.try_eval("x * 2").unwrap(), json!(20));
+    assert!(matches!(
+        evaluator.try_eval("x +"),
+        Err(EvalError::Syntax(_))
+    ));
+    assert!(evaluator.eval("x +").is_string());
+}
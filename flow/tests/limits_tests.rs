@@ -0,0 +1,115 @@
+extern crate flow;
+use cqf_core::models::{BlackScholesModel, OptionParameters, OptionType};
+use cqf_core::portfolio::{Portfolio, Position};
+use cqf_core::strategies::Side;
+use flow::limits::{
+    LimitEngine, LimitError, PortfolioSnapshot, Severity, VAR_CONFIDENCE, VAR_HORIZON,
+};
+use flow::rule::Rule;
+use std::io::Write;
+
+fn rule(cells: &[(&str, &str)]) -> Rule {
+    cells
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn portfolio() -> Portfolio {
+    let mut portfolio = Portfolio::new();
+    portfolio.add(Position::option(
+        OptionType::Call,
+        Side::Long,
+        100.0,
+        OptionParameters {
+            s: 100.0,
+            k: 100.0,
+            r: 0.05,
+            sigma: 0.2,
+            t: 0.5,
+        },
+        6.0,
+    ));
+    portfolio
+}
+
+fn snapshot() -> PortfolioSnapshot {
+    PortfolioSnapshot::new(&portfolio(), &BlackScholesModel)
+}
+
+#[test]
+fn test_snapshot_from_portfolio() {
+    let snapshot = snapshot();
+    assert_eq!(snapshot.positions, 1);
+    assert!(snapshot.delta > 50.0 && snapshot.delta < 70.0);
+    assert!(snapshot.stress_loss > 0.0);
+
+    let var = portfolio().value_at_risk(&BlackScholesModel, 0.2, VAR_CONFIDENCE, VAR_HORIZON);
+    assert_eq!(snapshot.var, Some(var.var));
+    assert!(var.var > 0.0 && var.var < snapshot.stress_loss);
+    assert_eq!(snapshot.to_value()["var"], serde_json::json!(var.var));
+
+    let mut stock = Portfolio::new();
+    stock.add(Position::stock(Side::Long, 100.0, 100.0, 100.0));
+    let snapshot = PortfolioSnapshot::new(&stock, &BlackScholesModel);
+    assert_eq!(snapshot.var, None);
+    assert_eq!(snapshot.to_value()["var"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_limit_engine_raises_matching_alerts() {
+    let engine = LimitEngine::new(vec![
+        rule(&[
+            ("limit", "'max_delta'"),
+            ("delta", "abs($) > 50"),
+            ("severity", "'breach'"),
+            ("message", "'Delta ' + string(round(delta))"),
+        ]),
+        rule(&[
+            ("limit", "'delta_warning'"),
+            ("delta", "> 40"),
+            ("vega", ""),
+            ("severity", "'warning'"),
+        ]),
+        rule(&[("limit", "'max_vega'"), ("vega", "> 5000")]),
+        rule(&[("limit", "'max_var'"), ("var", "> 1000")]),
+    ]);
+
+    let alerts = engine.check(&snapshot()).unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].limit, "max_delta");
+    assert_eq!(alerts[0].severity, Severity::Breach);
+    assert!(alerts[0].message.starts_with("Delta "));
+    assert!(alerts[0].observed.contains_key("delta"));
+    assert_eq!(alerts[1].limit, "delta_warning");
+    assert_eq!(alerts[1].severity, Severity::Warning);
+
+    let alerts = engine.check(&snapshot().with_var(2000.0)).unwrap();
+    assert_eq!(alerts.len(), 3);
+    assert_eq!(alerts[1].limit, "max_var");
+}
+
+#[test]
+fn test_limit_engine_reads_rule_files() {
+    let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+    writeln!(file, "limit,delta,severity\n'max_delta',> 50,'info'").unwrap();
+    let engine = LimitEngine::from_file(file.path().to_str().unwrap()).unwrap();
+    let alerts = engine.check(&snapshot()).unwrap();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].severity, Severity::Info);
+}
+
+#[test]
+fn test_limit_engine_errors() {
+    let unknown = LimitEngine::new(vec![rule(&[("beta", "> 1")])]);
+    assert!(matches!(
+        unknown.check(&snapshot()),
+        Err(LimitError::UnknownField { rule: 0, .. })
+    ));
+
+    let severity = LimitEngine::new(vec![rule(&[("delta", "> 0"), ("severity", "'fatal'")])]);
+    assert!(matches!(
+        severity.check(&snapshot()),
+        Err(LimitError::InvalidSeverity(_))
+    ));
+}
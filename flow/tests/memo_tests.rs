@@ -0,0 +1,105 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::memo::{MemoizedFlow, NodeCache};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use zen_engine::model::DecisionContent;
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": "qty * price",
+            "inputs": ["notional"],
+            "sources": ["request"],
+            "targets": ["size"]
+        },
+        {
+            "id": "size",
+            "kind": "expression",
+            "rules": "notional > 100 ? 'large' : 'small'",
+            "inputs": ["size"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+#[tokio::test]
+async fn test_memoized_flow_matches_engine_and_counts_hits() {
+    let flow = MemoizedFlow::new(content());
+    let inputs = vec![
+        json!({"qty": 10, "price": 20}),
+        json!({"qty": 10, "price": 20}),
+        json!({"qty": 20, "price": 10}),
+        json!({"qty": 1, "price": 5}),
+    ];
+    let results: Vec<_> = flow
+        .evaluate_batch(&inputs)
+        .await
+        .into_iter()
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        results,
+        vec![
+            json!({"size": "large"}),
+            json!({"size": "large"}),
+            json!({"size": "large"}),
+            json!({"size": "small"}),
+        ]
+    );
+
+    // The repeated input hits both nodes; the swapped one hits `size` only, since its
+    // notional is unchanged.
+    let cache = flow.cache();
+    assert_eq!(cache.hits(), 3);
+    assert_eq!(cache.misses(), 5);
+    assert_eq!(cache.len(), 5);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!((cache.hits(), cache.misses()), (0, 0));
+}
+
+#[tokio::test]
+async fn test_memoized_flows_can_share_a_cache() {
+    let cache = Arc::new(NodeCache::new());
+    let first = MemoizedFlow::with_cache(content(), Arc::clone(&cache));
+    let second = MemoizedFlow::with_cache(content(), Arc::clone(&cache));
+    let input = json!({"qty": 2, "price": 3});
+    first.evaluate(&input).await.unwrap();
+    assert_eq!(
+        second.evaluate(&input).await.unwrap(),
+        json!({"size": "small"})
+    );
+    assert_eq!(cache.hits(), 2);
+}
+
+#[tokio::test]
+async fn test_cache_misses_reuse_one_worker() {
+    let inputs: Vec<_> = (0..400).map(|i| json!({"qty": i, "price": 2})).collect();
+    let decision = flow::iteration::engine().create_decision(Arc::new(content()));
+    let started = Instant::now();
+    for input in &inputs {
+        decision.evaluate(input).await.unwrap();
+    }
+    let plain = started.elapsed();
+
+    // Every input is new, so each evaluation misses on both nodes.
+    let flow = MemoizedFlow::new(content());
+    let started = Instant::now();
+    for result in flow.evaluate_batch(&inputs).await {
+        result.unwrap();
+    }
+    let memoized = started.elapsed();
+    assert_eq!(flow.cache().misses(), 800);
+    // A miss costs an evaluation of the node on the worker, a few times a plain node;
+    // starting a thread and runtime per miss made it more than ten times.
+    assert!(memoized < plain * 6 + Duration::from_millis(20));
+}
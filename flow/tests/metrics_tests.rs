@@ -0,0 +1,96 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::metrics::{InstrumentedFlow, Metrics, MetricsCollector};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use zen_engine::model::DecisionContent;
+
+fn content(dir: &std::path::Path, expression: &str) -> DecisionContent {
+    let rules = dir.join("rules.csv");
+    std::fs::write(&rules, "qty,size\n> 10,'large'\n,'small'\n").unwrap();
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "size",
+            "kind": "table",
+            "rules": rules.to_string_lossy(),
+            "inputs": ["qty"],
+            "outputs": ["size"],
+            "sources": ["request"],
+            "targets": ["response"]
+        },
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": expression,
+            "inputs": ["notional"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+#[tokio::test]
+async fn test_collector_aggregates_nodes_and_rule_hits() {
+    let dir = tempfile::tempdir().unwrap();
+    let collector = Arc::new(MetricsCollector::new());
+    let flow =
+        InstrumentedFlow::with_metrics(content(dir.path(), "qty * price"), Arc::clone(&collector));
+    for qty in [20, 5, 30] {
+        let result = flow.evaluate(&json!({"qty": qty, "price": 2})).await;
+        assert_eq!(result.unwrap()["notional"], json!(qty * 2));
+    }
+
+    let snapshot = collector.snapshot();
+    assert_eq!((snapshot.evaluations, snapshot.failures), (3, 0));
+    let size = &snapshot.nodes["size"];
+    assert_eq!(size.count, 3);
+    assert_eq!(size.rule_hits.get(&0), Some(&2));
+    assert_eq!(size.rule_hits.get(&1), Some(&1));
+    assert!(size.min <= size.mean() && size.mean() <= size.max);
+    assert!(snapshot.nodes["notional"].rule_hits.is_empty());
+
+    collector.reset();
+    assert_eq!(collector.snapshot().evaluations, 0);
+}
+
+#[derive(Default)]
+struct Errors(Mutex<Vec<(Option<String>, bool)>>);
+
+impl Metrics for Errors {
+    fn error(&self, node: Option<&str>, _message: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((node.map(str::to_string), false));
+    }
+
+    fn evaluation_finished(&self, _duration: std::time::Duration, success: bool) {
+        self.0.lock().unwrap().push((None, success));
+    }
+}
+
+#[tokio::test]
+async fn test_errors_are_reported_with_their_node() {
+    let dir = tempfile::tempdir().unwrap();
+    let flow = InstrumentedFlow::with_metrics(content(dir.path(), "qty +"), Errors::default());
+    assert!(flow.evaluate(&json!({"qty": 1})).await.is_err());
+    assert_eq!(
+        *flow.metrics().0.lock().unwrap(),
+        vec![(Some("notional".to_string()), false), (None, false)]
+    );
+
+    let collector = MetricsCollector::new();
+    collector.error(Some("notional"), "boom");
+    assert_eq!(collector.snapshot().nodes["notional"].errors, 1);
+}
+
+#[tokio::test]
+async fn test_noop_metrics_by_default() {
+    let dir = tempfile::tempdir().unwrap();
+    let flow = InstrumentedFlow::new(content(dir.path(), "qty * price"));
+    let result = flow.evaluate(&json!({"qty": 1, "price": 3})).await.unwrap();
+    assert_eq!(result, json!({"size": "small", "notional": 3}));
+}
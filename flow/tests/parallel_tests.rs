@@ -0,0 +1,192 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::iteration::engine;
+use flow::parallel::{levels, ParallelError, ParallelFlow};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use zen_engine::model::{DecisionContent, DecisionEdge};
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": "qty * price",
+            "inputs": ["notional"],
+            "sources": ["request"],
+            "targets": ["response"]
+        },
+        {
+            "id": "route",
+            "kind": "switch",
+            "rules": "",
+            "sources": ["request"],
+            "targets": ["hedge", "hold"],
+            "conditions": ["abs(delta) > 0.5", ""]
+        },
+        {
+            "id": "hedge",
+            "kind": "expression",
+            "rules": "-delta",
+            "inputs": ["score.hedge"],
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "hold",
+            "kind": "expression",
+            "rules": "0",
+            "inputs": ["score.hedge"],
+            "sources": [],
+            "targets": ["response"]
+        },
+        {
+            "id": "risk",
+            "kind": "expression",
+            "rules": "abs(delta) * price",
+            "inputs": ["score.risk"],
+            "sources": ["request"],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+fn diamond() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([
+        {
+            "id": "notional",
+            "kind": "expression",
+            "rules": "qty * price",
+            "inputs": ["notional"],
+            "sources": ["request"],
+            "targets": ["double", "plus"]
+        },
+        {
+            "id": "double",
+            "kind": "expression",
+            "rules": "notional * 2",
+            "inputs": ["double"],
+            "sources": [],
+            "targets": ["total"]
+        },
+        {
+            "id": "plus",
+            "kind": "expression",
+            "rules": "notional + 1",
+            "inputs": ["plus"],
+            "sources": [],
+            "targets": ["total"]
+        },
+        {
+            "id": "total",
+            "kind": "expression",
+            "rules": "double + plus",
+            "inputs": ["total"],
+            "sources": [],
+            "targets": ["response"]
+        }
+    ]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+#[test]
+fn test_levels_join_at_merge_nodes() {
+    assert_eq!(
+        levels(&content()),
+        vec![
+            vec!["request"],
+            vec!["notional", "route", "risk"],
+            vec!["hedge", "hold"],
+            vec!["response"],
+        ]
+    );
+    assert_eq!(
+        levels(&diamond()),
+        vec![
+            vec!["request"],
+            vec!["notional"],
+            vec!["double", "plus"],
+            vec!["total"],
+            vec!["response"],
+        ]
+    );
+
+    let empty = DecisionGraphBuilder::new().build(Vec::new());
+    assert_eq!(levels(&empty), vec![vec!["request", "response"]]);
+}
+
+#[tokio::test]
+async fn test_diamond_joins_both_arms() {
+    let sequential = engine().create_decision(Arc::new(diamond()));
+    let input = json!({"qty": 2, "price": 10});
+    let expected = sequential.evaluate(&input).await.unwrap().result;
+    assert_eq!(expected, json!({"total": 61}));
+    for parallelism in [1, 2] {
+        let flow = ParallelFlow::new(diamond()).with_max_parallelism(parallelism);
+        assert_eq!(flow.evaluate(&input).await.unwrap(), expected);
+    }
+}
+
+#[tokio::test]
+async fn test_parallel_matches_sequential() {
+    let sequential = engine().create_decision(Arc::new(content()));
+    for parallelism in [1, 2, 8] {
+        let flow = ParallelFlow::new(content()).with_max_parallelism(parallelism);
+        assert_eq!(flow.levels().len(), 4);
+        for input in [
+            json!({"qty": 2, "price": 10, "delta": -0.8}),
+            json!({"qty": 1, "price": 4, "delta": 0.1}),
+        ] {
+            let expected = sequential.evaluate(&input).await.unwrap().result;
+            assert_eq!(flow.evaluate(&input).await.unwrap(), expected);
+        }
+    }
+    let result = ParallelFlow::new(content())
+        .evaluate(&json!({"qty": 2, "price": 10, "delta": -0.8}))
+        .await
+        .unwrap();
+    assert_eq!(
+        result,
+        json!({"notional": 20, "score": {"hedge": 0.8, "risk": 8}})
+    );
+}
+
+#[tokio::test]
+async fn test_parallel_reports_failing_branch() {
+    let flow = ParallelFlow::new(content()).with_max_parallelism(0);
+    assert_eq!(flow.max_parallelism(), 1);
+    let error = flow
+        .evaluate(&json!({"price": 10, "delta": 0.1}))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&error, ParallelError::Evaluation { node, .. } if node == "notional"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_direct_request_edge_passes_the_input() {
+    let mut content = diamond();
+    content.edges.push(DecisionEdge {
+        id: "".into(),
+        source_id: "request".to_string(),
+        target_id: "response".to_string(),
+        source_handle: Some("".into()),
+    });
+    let input = json!({"qty": 3, "price": 2});
+    let expected = engine()
+        .create_decision(Arc::new(content.clone()))
+        .evaluate(&input)
+        .await
+        .unwrap()
+        .result;
+    assert_eq!(expected, json!({"qty": 3, "price": 2, "total": 19}));
+    let result: Value = ParallelFlow::new(content).evaluate(&input).await.unwrap();
+    assert_eq!(result, expected);
+}
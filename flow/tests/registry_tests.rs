@@ -0,0 +1,97 @@
+extern crate flow;
+use flow::registry::{flow_version, FlowRegistry, RegistryError, Version};
+use flow::rule::{Decision, DecisionRef};
+use serde_json::json;
+
+fn flow(version: Option<&str>, expression: &str) -> Vec<Decision> {
+    let value = json!([{
+        "id": "score",
+        "kind": "expression",
+        "rules": expression,
+        "inputs": ["total"],
+        "sources": ["request"],
+        "targets": ["response"],
+        "version": version
+    }]);
+    let refs: Vec<DecisionRef> = serde_json::from_value(value).unwrap();
+    refs.into_iter().map(Decision::from).collect()
+}
+
+#[test]
+fn test_version_parse_and_compatibility() {
+    assert_eq!("1.2.3".parse::<Version>().unwrap(), Version::new(1, 2, 3));
+    assert_eq!("v2".parse::<Version>().unwrap(), Version::new(2, 0, 0));
+    assert_eq!(Version::new(1, 4, 0).to_string(), "1.4.0");
+    assert!(matches!(
+        "1.x".parse::<Version>(),
+        Err(RegistryError::InvalidVersion(_))
+    ));
+    assert!("1.2.3.4".parse::<Version>().is_err());
+
+    assert!(Version::new(1, 4, 0).is_compatible_with(&Version::new(1, 2, 0)));
+    assert!(!Version::new(1, 1, 0).is_compatible_with(&Version::new(1, 2, 0)));
+    assert!(!Version::new(2, 0, 0).is_compatible_with(&Version::new(1, 2, 0)));
+}
+
+#[test]
+fn test_flow_version_requires_a_single_version() {
+    assert!(matches!(
+        flow_version(&flow(None, "1")),
+        Err(RegistryError::MissingVersion)
+    ));
+
+    let mut mixed = flow(Some("1.0"), "1");
+    mixed.extend(flow(Some("1.1"), "2"));
+    assert!(matches!(flow_version(&mixed), Err(RegistryError::MixedVersions(v)) if v.len() == 2));
+
+    let mut same = flow(Some("1.0"), "1");
+    same.extend(flow(Some("1.0.0"), "2"));
+    assert_eq!(flow_version(&same).unwrap(), Version::new(1, 0, 0));
+
+    let mut inherited = flow(Some("1.1"), "1");
+    inherited.extend(flow(None, "2"));
+    assert_eq!(flow_version(&inherited).unwrap(), Version::new(1, 1, 0));
+}
+
+#[tokio::test]
+async fn test_registry_evaluates_pinned_versions() {
+    let mut registry = FlowRegistry::new();
+    let v1 = registry
+        .register("score", flow(Some("1.0.0"), "x + y"))
+        .unwrap();
+    let v2 = registry
+        .register("score", flow(Some("1.1.0"), "x * y"))
+        .unwrap();
+    let v3 = registry
+        .register("score", flow(Some("2.0.0"), "x - y"))
+        .unwrap();
+    assert_eq!(registry.versions("score"), vec![v1, v2, v3]);
+    assert!(matches!(
+        registry.register("score", flow(Some("1.1"), "0")),
+        Err(RegistryError::DuplicateVersion { .. })
+    ));
+
+    let input = json!({"x": 6, "y": 3});
+    assert_eq!(
+        registry.evaluate("score", &v1, &input).await.unwrap()["total"],
+        json!(9)
+    );
+    assert_eq!(
+        registry.evaluate("score", &v2, &input).await.unwrap()["total"],
+        json!(18)
+    );
+    assert_eq!(registry.latest("score").unwrap().0, v3);
+    assert_eq!(registry.latest_compatible("score", &v1).unwrap().0, v2);
+
+    assert!(matches!(
+        registry
+            .evaluate("score", &Version::new(1, 2, 0), &input)
+            .await,
+        Err(RegistryError::UnknownVersion { .. })
+    ));
+    assert!(matches!(
+        registry.get("other", &v1),
+        Err(RegistryError::UnknownFlow(_))
+    ));
+    assert!(registry.versions("other").is_empty());
+}
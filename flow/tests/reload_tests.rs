@@ -0,0 +1,120 @@
+extern crate flow;
+use flow::reload::{FileWatcher, HotFlow, ReloadError};
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use zen_engine::model::DecisionNodeKind;
+
+fn write_flow(path: &Path, rules: &Path, expression: &str) {
+    let content = format!(
+        r#"[
+            {{"id": "score", "kind": "expression", "rules": "{}", "inputs": ["total"],
+              "sources": ["request"], "targets": ["table"]}},
+            {{"id": "table", "kind": "table", "rules": "{}", "inputs": ["total"],
+              "outputs": ["action"], "sources": [], "targets": ["response"]}}
+        ]"#,
+        expression,
+        rules.display()
+    );
+    std::fs::write(path, content).unwrap();
+}
+
+fn expression(flow: &HotFlow) -> String {
+    let graph = flow.current();
+    let node = graph.nodes.iter().find(|n| n.id == "score").unwrap();
+    match &node.kind {
+        DecisionNodeKind::ExpressionNode { content } => content.expressions[0].value.clone(),
+        _ => panic!("expected an expression node"),
+    }
+}
+
+fn wait_for(condition: impl Fn() -> bool) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(10) {
+        if condition() {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    false
+}
+
+#[test]
+fn test_hot_flow_reloads_on_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let flow_path = dir.path().join("flow.json");
+    let rules_path = dir.path().join("rules.csv");
+    std::fs::write(&rules_path, "total,action\n> 10,'hedge'\n").unwrap();
+    write_flow(&flow_path, &rules_path, "x + y");
+
+    let (errors, received) = mpsc::channel();
+    let flow = HotFlow::watch(&flow_path, move |error| {
+        let _ = errors.send(error.to_string());
+    })
+    .unwrap();
+    assert_eq!(expression(&flow), "x + y");
+    assert_eq!(flow.generation(), 0);
+
+    write_flow(&flow_path, &rules_path, "x * y");
+    assert!(wait_for(|| expression(&flow) == "x * y"));
+
+    // Editing a rule table also reloads.
+    let generation = flow.generation();
+    std::fs::write(&rules_path, "total,action\n> 20,'hedge'\n").unwrap();
+    assert!(wait_for(|| flow.generation() > generation));
+
+    // A broken flow is reported and the last good graph kept.
+    std::fs::write(&flow_path, "[{").unwrap();
+    let error = received.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert!(error.starts_with("Reader error"), "{}", error);
+    assert_eq!(expression(&flow), "x * y");
+}
+
+#[test]
+fn test_hot_flow_rejects_invalid_graphs() {
+    let dir = tempfile::tempdir().unwrap();
+    let flow_path = dir.path().join("flow.json");
+    let rules_path = dir.path().join("missing.csv");
+    write_flow(&flow_path, &rules_path, "x");
+    assert!(matches!(
+        HotFlow::watch(&flow_path, |_| {}),
+        Err(ReloadError::Reader(_))
+    ));
+
+    let rules_path = dir.path().join("rules.csv");
+    std::fs::write(&rules_path, "total,action\n> 10,'hedge'\n").unwrap();
+    write_flow(&flow_path, &rules_path, "x");
+    let flow = HotFlow::watch(&flow_path, |_| {}).unwrap();
+
+    let dangling = r#"[{"id": "a", "kind": "expression", "rules": "1", "inputs": ["a"],
+        "sources": ["request"], "targets": ["nowhere"]}]"#;
+    std::fs::write(&flow_path, dangling).unwrap();
+    assert!(matches!(flow.reload(), Err(ReloadError::Graph(_))));
+    assert_eq!(expression(&flow), "x");
+}
+
+#[test]
+fn test_file_watcher_reports_changed_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let watched = dir.path().join("params.json");
+    let other = dir.path().join("other.json");
+    std::fs::write(&watched, "{}").unwrap();
+
+    let (changes, received) = mpsc::channel();
+    let _watcher = FileWatcher::watch(std::slice::from_ref(&watched), move |change| {
+        let _ = changes.send(change.map_err(|error| error.to_string()));
+    })
+    .unwrap();
+
+    // Files next to the watched one are ignored.
+    std::fs::write(&other, "{}").unwrap();
+    std::fs::write(&watched, r#"{"s": 101}"#).unwrap();
+    let changed = received
+        .recv_timeout(Duration::from_secs(10))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        std::fs::canonicalize(changed).unwrap(),
+        std::fs::canonicalize(&watched).unwrap()
+    );
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "remote")]
+extern crate flow;
+use flow::remote::RemoteLoader;
+use flow::rule::{DecisionReader, ReaderError};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serves fixed documents, answering `If-None-Match` with 304, and counts full bodies
+/// sent.
+async fn serve(documents: HashMap<&'static str, String>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+    let downloads = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&downloads);
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 4096];
+            let n = socket.read(&mut buffer).await.unwrap();
+            let request = String::from_utf8_lossy(&buffer[..n]).to_string();
+            let path = request.split_whitespace().nth(1).unwrap_or("/");
+            let etag = format!("\"{}\"", path.len());
+            let response = match documents.get(path) {
+                None => "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n".to_string(),
+                Some(_) if request.contains(&format!("if-none-match: {}", etag)) => format!(
+                    "HTTP/1.1 304 Not Modified\r\netag: {}\r\ncontent-length: 0\r\n\r\n",
+                    etag
+                ),
+                Some(body) => {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    format!(
+                        "HTTP/1.1 200 OK\r\netag: {}\r\ncontent-length: {}\r\n\r\n{}",
+                        etag,
+                        body.len(),
+                        body
+                    )
+                }
+            };
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    (address, downloads)
+}
+
+#[tokio::test]
+async fn test_remote_rules_are_cached_by_etag() {
+    let (address, downloads) = serve(HashMap::from([(
+        "/rules.csv?v=1",
+        "delta,action\n> 0.5,'hedge'\n<= 0.5,'hold'\n".to_string(),
+    )]))
+    .await;
+    let loader = RemoteLoader::new(Duration::from_secs(5)).unwrap();
+    let url = format!("{}/rules.csv?v=1", address);
+
+    let rules = loader.read_rules(&url).await.unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[1]["action"], "'hold'");
+    let rules = loader.read_rules(&url).await.unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(downloads.load(Ordering::SeqCst), 1);
+
+    let missing = loader.fetch(&format!("{}/missing.json", address)).await;
+    assert!(matches!(missing, Err(ReaderError::Http(_))));
+}
+
+#[tokio::test]
+async fn test_remote_flow_fetches_remote_rule_tables() {
+    let flow = r#"
+- id: route
+  kind: table
+  rules: "{address}/rules.json"
+  inputs: [delta]
+  outputs: [action]
+  sources: [request]
+  targets: [response]
+"#;
+    let (address, _) = serve(HashMap::from([(
+        "/rules.json",
+        r#"[{"delta": "> 0.5", "action": "'hedge'"}]"#.to_string(),
+    )]))
+    .await;
+    // The flow refers to the rule server's address, so it is served separately.
+    let document = flow.replace("{address}", &address);
+    let (flow_address, _) = serve(HashMap::from([("/flow.yaml", document)])).await;
+
+    let loader = RemoteLoader::new(Duration::from_secs(5)).unwrap();
+    let flow = DecisionReader::read_flow_remote(&loader, &format!("{}/flow.yaml", flow_address))
+        .await
+        .unwrap();
+    assert_eq!(flow.len(), 1);
+    assert_eq!(flow[0].rules.len(), 1);
+    assert_eq!(flow[0].rules[0]["action"], "'hedge'");
+    assert_eq!(flow[0].outputs, vec!["action".to_string()]);
+}
@@ -1,33 +1,66 @@
 extern crate flow;
-use tempfile::NamedTempFile;
+use flow::rule::{
+    read_flow, read_input, read_str, DecisionReader, ReaderError, RulesReader, XlsxOptions,
+};
 use std::io::Write;
-use flow::rule::{read_flow, read_input, read_str, RulesReader, DecisionReader};
+use tempfile::NamedTempFile;
 
 fn create_temp_file(content: &str, extension: &str) -> NamedTempFile {
-    let mut file = NamedTempFile::new().unwrap();
+    let mut file = tempfile::Builder::new()
+        .suffix(&format!(".{}", extension))
+        .tempfile()
+        .unwrap();
     writeln!(file, "{}", content).unwrap();
-    let path = file.path().to_owned();
-    let new_path = path.with_extension(extension);
-    std::fs::rename(&path, &new_path).unwrap();
     file
 }
 
 #[tokio::test]
 async fn test_read_rules_json() {
+    let file = create_temp_file(r#"[{"delta": "> 0.5", "action": "'hedge'"}]"#, "json");
+    let rules = RulesReader::read_rules(file.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(rules.len(), 1);
+    assert_eq!(rules[0]["delta"], "> 0.5");
+    assert_eq!(rules[0]["action"], "'hedge'");
 }
 
 #[tokio::test]
 async fn test_read_rules_csv() {
-   
+    let file = create_temp_file("delta,action\n> 0.5,'hedge'\n<= 0.5,'hold'", "csv");
+    let rules = RulesReader::read_rules(file.path().to_str().unwrap()).unwrap();
+
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[1]["delta"], "<= 0.5");
+    assert_eq!(rules[1]["action"], "'hold'");
 }
 
 #[tokio::test]
 async fn test_read_flow() {
+    let content = r#"[{
+        "id": "score",
+        "kind": "expression",
+        "rules": "x + y",
+        "inputs": ["total"],
+        "sources": ["request"],
+        "targets": ["response"]
+    }]"#;
+    let file = create_temp_file(content, "json");
+    let flow = read_flow(file.path()).await;
 
+    assert_eq!(flow.len(), 1);
+    assert_eq!(flow[0].id, "score");
+    assert_eq!(flow[0].expression, "x + y");
+    assert_eq!(flow[0].inputs, vec!["total".to_string()]);
+    assert!(flow[0].outputs.is_empty());
 }
 
 #[tokio::test]
 async fn test_read_input() {
+    let file = create_temp_file(r#"{"s": 100.0, "k": 105.0}"#, "json");
+    let value = read_input(file.path()).await;
+
+    assert_eq!(value["s"], 100.0);
+    assert_eq!(value["k"], 105.0);
 }
 
 #[tokio::test]
@@ -49,3 +82,93 @@ async fn test_error_handling() {
     let result = DecisionReader::read_str("invalid json").await;
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn test_read_rules_yaml() {
+    let content = "- delta: \"> 0.5\"\n  action: |\n    delta > 0.8\n      ? 'close'\n      : 'hedge'\n- delta: \"<= 0.5\"\n  action: \"'hold'\"";
+    for extension in ["yaml", "yml"] {
+        let file = create_temp_file(content, extension);
+        let rules = RulesReader::read_rules(file.path().to_str().unwrap()).unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["delta"], "> 0.5");
+        assert_eq!(
+            rules[0]["action"],
+            "delta > 0.8\n  ? 'close'\n  : 'hedge'\n"
+        );
+        assert_eq!(rules[1]["action"], "'hold'");
+    }
+}
+
+#[tokio::test]
+async fn test_read_flow_yaml() {
+    let content = "- id: score\n  kind: expression\n  rules: |-\n    x\n    + y\n  inputs: [total]\n  sources: [request]\n  targets: [response]";
+    let file = create_temp_file(content, "yaml");
+    let flow = read_flow(file.path()).await;
+
+    assert_eq!(flow.len(), 1);
+    assert_eq!(flow[0].id, "score");
+    assert_eq!(flow[0].expression, "x\n+ y");
+    assert_eq!(flow[0].inputs, vec!["total".to_string()]);
+
+    let file = create_temp_file("- id: [unclosed", "yml");
+    assert!(DecisionReader::read_flow(file.path()).await.is_err());
+}
+
+fn create_workbook() -> NamedTempFile {
+    let file = tempfile::Builder::new().suffix(".xlsx").tempfile().unwrap();
+    let mut workbook = rust_xlsxwriter::Workbook::new();
+    let notes = workbook.add_worksheet().set_name("notes").unwrap();
+    notes.write(0, 0, "maintained by the desk").unwrap();
+    let rules = workbook.add_worksheet().set_name("rules").unwrap();
+    rules.write(1, 1, "input:delta").unwrap();
+    rules.write(1, 2, "vega").unwrap();
+    rules.write(1, 3, "output:action").unwrap();
+    rules.write(2, 1, "> 0.5").unwrap();
+    rules.write(2, 2, 100).unwrap();
+    rules.write(2, 3, "'hedge'").unwrap();
+    rules.write(4, 1, "<= 0.5").unwrap();
+    rules.write(4, 3, "'hold'").unwrap();
+    workbook.save(file.path()).unwrap();
+    file
+}
+
+#[tokio::test]
+async fn test_read_table_xlsx() {
+    let file = create_workbook();
+    let options = XlsxOptions::default().sheet("rules");
+    let table = RulesReader::read_table_xlsx(file.path(), &options).unwrap();
+
+    assert_eq!(table.inputs, vec!["delta", "vega"]);
+    assert_eq!(table.outputs, vec!["action"]);
+    assert_eq!(table.rules.len(), 2);
+    assert_eq!(table.rules[0]["delta"], "> 0.5");
+    assert_eq!(table.rules[0]["vega"], "100");
+    assert_eq!(table.rules[1]["vega"], "");
+    assert_eq!(table.rules[1]["action"], "'hold'");
+
+    let options = XlsxOptions::default().sheet("rules").range("B2:C3");
+    let table = RulesReader::read_table_xlsx(file.path(), &options).unwrap();
+    assert_eq!(table.inputs, vec!["delta", "vega"]);
+    assert!(table.outputs.is_empty());
+    assert_eq!(table.rules.len(), 1);
+    assert!(!table.rules[0].contains_key("action"));
+}
+
+#[tokio::test]
+async fn test_read_rules_xlsx_errors() {
+    let file = create_workbook();
+    let rules = RulesReader::read_rules(file.path().to_str().unwrap()).unwrap();
+    assert!(rules.is_empty());
+
+    let options = XlsxOptions::default().sheet("missing");
+    assert!(matches!(
+        RulesReader::read_table_xlsx(file.path(), &options),
+        Err(ReaderError::MissingSheet(_))
+    ));
+    let options = XlsxOptions::default().range("C3:A1");
+    assert!(matches!(
+        RulesReader::read_table_xlsx(file.path(), &options),
+        Err(ReaderError::InvalidRange(_))
+    ));
+}
@@ -0,0 +1,143 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::rule::{Decision, DecisionRef};
+use flow::schema::{read_contract, Contract, ContractError, ContractFlow, Schema, SchemaError};
+use serde_json::json;
+use std::io::Write;
+use zen_engine::model::DecisionContent;
+
+fn schema(value: serde_json::Value) -> Schema {
+    serde_json::from_value(value).unwrap()
+}
+
+fn order_schema() -> Schema {
+    schema(json!({
+        "type": "object",
+        "required": ["side", "legs"],
+        "additionalProperties": false,
+        "properties": {
+            "side": {"type": "string", "enum": ["buy", "sell"]},
+            "legs": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["strike"],
+                    "properties": {"strike": {"type": "number", "minimum": 0}}
+                }
+            }
+        }
+    }))
+}
+
+fn paths(errors: &[SchemaError]) -> Vec<&str> {
+    errors.iter().map(|error| error.path.as_str()).collect()
+}
+
+#[test]
+fn test_schema_accepts_valid_value() {
+    let order = json!({"side": "buy", "legs": [{"strike": 100}, {"strike": 105.5}]});
+    assert_eq!(order_schema().validate(&order), Ok(()));
+    assert_eq!(Schema::default().validate(&json!([1, "a", null])), Ok(()));
+}
+
+#[test]
+fn test_schema_reports_every_error_with_its_path() {
+    let order = json!({
+        "side": "hold",
+        "legs": [{"strike": 100}, {"strike": -5}, {}, {"strike": "ATM"}],
+        "note": "x"
+    });
+    let errors = order_schema().validate(&order).unwrap_err();
+    assert_eq!(
+        paths(&errors),
+        vec![
+            "legs.1.strike",
+            "legs.2.strike",
+            "legs.3.strike",
+            "note",
+            "side"
+        ]
+    );
+    assert_eq!(
+        errors[2].to_string(),
+        "legs.3.strike: expected number, found string"
+    );
+    assert_eq!(errors[1].message, "required field is missing");
+    assert!(errors[4].message.contains("\"buy\""));
+
+    let errors = order_schema().validate(&json!(42)).unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "<root>: expected object, found number"
+    );
+}
+
+#[test]
+fn test_schema_integer_and_bounds() {
+    let qty = schema(json!({"type": "integer", "minimum": 1, "maximum": 10}));
+    assert_eq!(qty.validate(&json!(3)), Ok(()));
+    assert!(qty.validate(&json!(2.5)).is_err());
+    assert!(qty.validate(&json!(0)).unwrap_err()[0]
+        .message
+        .contains("minimum"));
+    assert!(qty.validate(&json!(11)).unwrap_err()[0]
+        .message
+        .contains("maximum"));
+}
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([{
+        "id": "notional",
+        "kind": "expression",
+        "rules": "qty * price",
+        "inputs": ["notional"],
+        "sources": ["request"],
+        "targets": ["response"]
+    }]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+#[tokio::test]
+async fn test_contract_flow_validates_input_and_output() {
+    let contract = Contract {
+        input: Some(schema(json!({
+            "type": "object",
+            "required": ["qty", "price"],
+            "properties": {"qty": {"type": "integer"}, "price": {"type": "number"}}
+        }))),
+        output: Some(schema(json!({
+            "type": "object",
+            "properties": {"notional": {"type": "number", "maximum": 1000}}
+        }))),
+    };
+    let flow = ContractFlow::new(content(), contract);
+
+    let result = flow.evaluate(&json!({"qty": 2, "price": 5})).await.unwrap();
+    assert_eq!(result["notional"], json!(10));
+
+    match flow.evaluate(&json!({"qty": "2"})).await {
+        Err(ContractError::Input(errors)) => assert_eq!(paths(&errors), vec!["price", "qty"]),
+        other => panic!("expected input error, got {:?}", other),
+    }
+
+    match flow.evaluate(&json!({"qty": 200, "price": 10})).await {
+        Err(ContractError::Output(errors)) => assert_eq!(paths(&errors), vec!["notional"]),
+        other => panic!("expected output error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_read_contract_yaml() {
+    let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+    write!(
+        file,
+        "input:\n  type: object\n  required: [qty]\n  properties:\n    qty:\n      type: integer\n"
+    )
+    .unwrap();
+    let contract = read_contract(file.path()).unwrap();
+    assert_eq!(contract.output, None);
+    let input = contract.input.unwrap();
+    assert_eq!(input.required, vec!["qty".to_string()]);
+    assert!(input.validate(&json!({})).is_err());
+}
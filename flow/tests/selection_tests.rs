@@ -0,0 +1,108 @@
+extern crate flow;
+use cqf_core::models::BlackScholesModel;
+use cqf_core::strategies::factory::{create_strategy, FactoryError, StrategyKind};
+use flow::graph::DecisionGraphBuilder;
+use flow::rule::{Decision, DecisionRef};
+use flow::selection::{SelectionError, StrategySelector};
+use serde_json::{json, Map, Value};
+use zen_engine::model::DecisionContent;
+
+fn content(dir: &std::path::Path, rules: &str, outputs: Value) -> DecisionContent {
+    let path = dir.join("select.csv");
+    std::fs::write(&path, rules).unwrap();
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([{
+        "id": "select",
+        "kind": "table",
+        "rules": path.to_string_lossy(),
+        "inputs": ["sigma"],
+        "outputs": outputs,
+        "sources": ["request"],
+        "targets": ["response"]
+    }]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+const RULES: &str =
+    "sigma,strategy,params.k\n< 0.15,'straddle',100\n> 0.5,'unknown',0\n> 0.3,null,0\n";
+
+fn market(sigma: f64) -> Value {
+    json!({"s": 100.0, "r": 0.05, "sigma": sigma, "t": 1.0})
+}
+
+#[tokio::test]
+async fn test_selected_strategy_is_priced() {
+    let dir = tempfile::tempdir().unwrap();
+    let selector = StrategySelector::new(
+        content(dir.path(), RULES, json!(["strategy", "params.k"])),
+        BlackScholesModel,
+    );
+
+    let selection = selector.select(&market(0.1)).await.unwrap();
+    assert_eq!(selection.decision["strategy"], json!("straddle"));
+    let priced = selection.strategy.unwrap();
+    assert_eq!(priced.kind, StrategyKind::Straddle);
+    assert_eq!(priced.params["k"], json!(100));
+    assert_eq!(priced.params["s"], json!(100.0));
+
+    let params: Map<String, Value> = serde_json::from_value(json!({
+        "s": 100.0, "r": 0.05, "sigma": 0.1, "t": 1.0, "k": 100.0
+    }))
+    .unwrap();
+    let direct = create_strategy(&BlackScholesModel, StrategyKind::Straddle, &params).unwrap();
+    assert!((priced.price - direct.price()).abs() < 1e-12);
+    assert_eq!(priced.greeks, direct.greeks());
+}
+
+#[tokio::test]
+async fn test_null_strategy_means_no_trade() {
+    let dir = tempfile::tempdir().unwrap();
+    let selector = StrategySelector::new(
+        content(dir.path(), RULES, json!(["strategy", "params.k"])),
+        BlackScholesModel,
+    );
+
+    let selection = selector.select(&market(0.4)).await.unwrap();
+    assert!(selection.strategy.is_none());
+    assert_eq!(selection.decision["strategy"], Value::Null);
+}
+
+#[tokio::test]
+async fn test_unknown_strategy_and_missing_parameter_fail() {
+    let dir = tempfile::tempdir().unwrap();
+    let selector = StrategySelector::new(
+        content(dir.path(), RULES, json!(["strategy", "params.k"])),
+        BlackScholesModel,
+    );
+    let error = selector.select(&market(0.6)).await.unwrap_err();
+    assert!(matches!(
+        error,
+        SelectionError::Factory(FactoryError::UnknownKind(_))
+    ));
+
+    let mut input = market(0.1);
+    input.as_object_mut().unwrap().remove("t");
+    let error = selector.select(&input).await.unwrap_err();
+    assert!(matches!(
+        error,
+        SelectionError::Factory(FactoryError::MissingParameter("t"))
+    ));
+}
+
+#[tokio::test]
+async fn test_custom_keys() {
+    let dir = tempfile::tempdir().unwrap();
+    let rules = "sigma,trade,leg.k\n,'single_leg',95\n";
+    let content = content(dir.path(), rules, json!(["trade", "leg.k"]));
+    let selector = StrategySelector::new(content, BlackScholesModel).with_keys("trade", "leg");
+
+    let priced = selector
+        .select(&market(0.2))
+        .await
+        .unwrap()
+        .strategy
+        .unwrap();
+    assert_eq!(priced.kind, StrategyKind::SingleLeg);
+    assert_eq!(priced.params["k"], json!(95));
+    assert!(priced.price > 0.0);
+}
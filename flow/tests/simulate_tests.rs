@@ -0,0 +1,82 @@
+extern crate flow;
+use flow::graph::DecisionGraphBuilder;
+use flow::rule::{Decision, DecisionRef};
+use flow::simulate::{read_cases, simulate, Case};
+use serde_json::json;
+use std::io::Write;
+use zen_engine::model::DecisionContent;
+
+fn content() -> DecisionContent {
+    let refs: Vec<DecisionRef> = serde_json::from_value(json!([{
+        "id": "notional",
+        "kind": "expression",
+        "rules": "qty * price",
+        "inputs": ["notional"],
+        "sources": ["request"],
+        "targets": ["response"]
+    }]))
+    .unwrap();
+    DecisionGraphBuilder::new().build(refs.into_iter().map(Decision::from).collect())
+}
+
+fn case(name: &str, input: serde_json::Value, expected: serde_json::Value) -> Case {
+    Case {
+        name: name.to_string(),
+        input,
+        expected,
+    }
+}
+
+#[tokio::test]
+async fn test_simulate_reports_per_case_diffs() {
+    let cases = vec![
+        case(
+            "ok",
+            json!({"qty": 3, "price": 0.1}),
+            json!({"notional": 0.3}),
+        ),
+        case(
+            "wrong",
+            json!({"qty": 2, "price": 5}),
+            json!({"notional": 11}),
+        ),
+        case(
+            "missing",
+            json!({"qty": 1, "price": 1}),
+            json!({"notional": 1, "risk": {"limit": 5}}),
+        ),
+    ];
+    let report = simulate(&content(), &cases).await;
+
+    assert_eq!(report.passed(), 1);
+    assert_eq!(report.failed(), 2);
+    assert!(!report.all_passed());
+    assert!(report.cases[0].passed);
+
+    let wrong = &report.cases[1];
+    assert_eq!(wrong.actual["notional"], json!(10));
+    assert_eq!(wrong.mismatches.len(), 1);
+    assert_eq!(wrong.mismatches[0].path, "notional");
+    assert_eq!(wrong.mismatches[0].expected, json!(11));
+    assert_eq!(wrong.mismatches[0].actual, Some(json!(10)));
+
+    let missing = &report.cases[2];
+    assert_eq!(missing.mismatches[0].path, "risk");
+    assert_eq!(missing.mismatches[0].actual, None);
+}
+
+#[tokio::test]
+async fn test_simulate_cases_from_yaml() {
+    let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+    writeln!(
+        file,
+        "- name: small\n  input: {{qty: 1, price: 2}}\n  expected: {{notional: 2}}"
+    )
+    .unwrap();
+    let cases = read_cases(file.path()).unwrap();
+    assert_eq!(cases[0].name, "small");
+
+    let report = simulate(&content(), &cases).await;
+    assert!(report.all_passed());
+    assert!(read_cases("missing.json").is_err());
+}
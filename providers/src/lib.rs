@@ -1,3 +1,11 @@
+pub mod lines;
+pub mod quote;
+pub mod websocket;
+
+pub use lines::JsonLinesSource;
+pub use quote::{Quote, QuoteSource, SourceError};
+pub use websocket::WebSocketSource;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
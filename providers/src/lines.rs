@@ -0,0 +1,34 @@
+use crate::quote::{Quote, QuoteSource, SourceError};
+use async_trait::async_trait;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+/// Reads one JSON quote per line, e.g. from stdin or a pipe. Blank lines are skipped.
+pub struct JsonLinesSource<R> {
+    reader: R,
+    line: String,
+}
+
+impl<R: AsyncBufRead + Unpin + Send> JsonLinesSource<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl<R: AsyncBufRead + Unpin + Send> QuoteSource for JsonLinesSource<R> {
+    async fn next_quote(&mut self) -> Result<Option<Quote>, SourceError> {
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line).await? == 0 {
+                return Ok(None);
+            }
+            let line = self.line.trim();
+            if !line.is_empty() {
+                return Ok(Some(serde_json::from_str(line)?));
+            }
+        }
+    }
+}
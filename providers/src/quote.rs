@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::io;
+use thiserror::Error;
+
+/// A market update for one underlying. Fields left out are unchanged.
+///
+/// Sources send it as JSON, e.g. `{"spot": 101.5, "sigma": 0.22}`; `vol` is accepted
+/// for `sigma`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Quote {
+    /// The underlying price.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spot: Option<f64>,
+    /// The volatility.
+    #[serde(default, alias = "vol", skip_serializing_if = "Option::is_none")]
+    pub sigma: Option<f64>,
+    /// The risk-free rate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<f64>,
+}
+
+impl Quote {
+    /// Folds a later update into this one; fields `later` sets win.
+    pub fn merge(&mut self, later: Quote) {
+        self.spot = later.spot.or(self.spot);
+        self.sigma = later.sigma.or(self.sigma);
+        self.rate = later.rate.or(self.rate);
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum SourceError {
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid quote: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("Handshake failed: {0}")]
+    Handshake(String),
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+}
+
+/// A feed of quotes, such as a websocket subscription.
+#[async_trait]
+pub trait QuoteSource: Send {
+    /// Waits for the next quote; `None` once the feed has ended.
+    async fn next_quote(&mut self) -> Result<Option<Quote>, SourceError>;
+}
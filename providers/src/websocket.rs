@@ -0,0 +1,72 @@
+use crate::quote::{Quote, QuoteSource, SourceError};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::error::ProtocolError;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::tungstenite::{Error as WsError, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// Messages larger than this are rejected rather than buffered.
+const MAX_MESSAGE: usize = 1 << 24;
+
+/// Subscribes to a websocket feed that sends one JSON quote per message.
+///
+/// Both `ws://` and `wss://` URLs are supported, the latter verified against the
+/// Mozilla root certificates. Pings are answered, and a close frame or the server
+/// hanging up between messages ends the feed.
+pub struct WebSocketSource {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl WebSocketSource {
+    /// Connects to `url`, e.g. `wss://feed.example.com/quotes`, and completes the
+    /// opening handshake.
+    pub async fn connect(url: &str) -> Result<Self, SourceError> {
+        let config = WebSocketConfig {
+            max_message_size: Some(MAX_MESSAGE),
+            max_frame_size: Some(MAX_MESSAGE),
+            ..WebSocketConfig::default()
+        };
+        let (stream, _) = tokio_tungstenite::connect_async_with_config(url, Some(config), false)
+            .await
+            .map_err(|err| match err {
+                WsError::Io(err) => SourceError::Io(err),
+                err => SourceError::Handshake(err.to_string()),
+            })?;
+        Ok(Self { stream })
+    }
+}
+
+#[async_trait]
+impl QuoteSource for WebSocketSource {
+    async fn next_quote(&mut self) -> Result<Option<Quote>, SourceError> {
+        loop {
+            let message = match self.stream.next().await {
+                None
+                | Some(Err(WsError::ConnectionClosed))
+                | Some(Err(WsError::Protocol(ProtocolError::ResetWithoutClosingHandshake))) => {
+                    return Ok(None)
+                }
+                Some(Err(WsError::Io(err))) => return Err(err.into()),
+                Some(Err(err)) => return Err(SourceError::Protocol(err.to_string())),
+                Some(Ok(message)) => message,
+            };
+            let text = match message {
+                Message::Text(text) => text.into_bytes(),
+                Message::Binary(data) => data,
+                Message::Close(_) => {
+                    // Sends the queued close reply; the server may already be gone, and
+                    // the feed is over either way.
+                    let _ = self.stream.close(None).await;
+                    return Ok(None);
+                }
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+            };
+            if text.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            return Ok(Some(serde_json::from_slice(&text)?));
+        }
+    }
+}
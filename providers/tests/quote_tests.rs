@@ -0,0 +1,68 @@
+extern crate providers;
+
+use providers::{JsonLinesSource, Quote, QuoteSource, SourceError};
+
+#[test]
+fn test_quote_parses_partial_updates_and_vol_alias() {
+    let quote: Quote = serde_json::from_str(r#"{"spot": 101.5, "vol": 0.22}"#).unwrap();
+    assert_eq!(
+        quote,
+        Quote {
+            spot: Some(101.5),
+            sigma: Some(0.22),
+            rate: None,
+        }
+    );
+}
+
+#[test]
+fn test_merge_keeps_earlier_fields_later_does_not_set() {
+    let mut quote = Quote {
+        spot: Some(100.0),
+        sigma: Some(0.2),
+        rate: None,
+    };
+    quote.merge(Quote {
+        spot: Some(101.0),
+        sigma: None,
+        rate: Some(0.04),
+    });
+    assert_eq!(
+        quote,
+        Quote {
+            spot: Some(101.0),
+            sigma: Some(0.2),
+            rate: Some(0.04),
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_json_lines_source_reads_until_eof() {
+    let input = "{\"spot\": 100}\n\n{\"sigma\": 0.3}\n";
+    let mut source = JsonLinesSource::new(input.as_bytes());
+    assert_eq!(
+        source.next_quote().await.unwrap(),
+        Some(Quote {
+            spot: Some(100.0),
+            ..Quote::default()
+        })
+    );
+    assert_eq!(
+        source.next_quote().await.unwrap(),
+        Some(Quote {
+            sigma: Some(0.3),
+            ..Quote::default()
+        })
+    );
+    assert_eq!(source.next_quote().await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_json_lines_source_reports_invalid_quotes() {
+    let mut source = JsonLinesSource::new("not json\n".as_bytes());
+    assert!(matches!(
+        source.next_quote().await,
+        Err(SourceError::Parse(_))
+    ));
+}
@@ -0,0 +1,139 @@
+extern crate providers;
+
+use providers::{Quote, QuoteSource, SourceError, WebSocketSource};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+
+/// An unmasked server frame.
+fn frame(fin: bool, opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![if fin { 0x80 } else { 0 } | opcode];
+    if payload.len() < 126 {
+        frame.push(payload.len() as u8);
+    } else {
+        frame.push(126);
+        frame.extend((payload.len() as u16).to_be_bytes());
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Accepts one client, answers its handshake with `accept` (the correct key when
+/// `None`) and returns the connection.
+async fn serve(listener: TcpListener, accept: Option<&str>) -> BufReader<TcpStream> {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut stream = BufReader::new(stream);
+    let mut key = String::new();
+    loop {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        if line.trim().is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Sec-WebSocket-Key:") {
+            key = value.trim().to_string();
+        }
+    }
+    let accept = accept.map_or_else(|| derive_accept_key(key.as_bytes()), str::to_string);
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept
+    );
+    stream
+        .get_mut()
+        .write_all(response.as_bytes())
+        .await
+        .unwrap();
+    stream
+}
+
+async fn listen() -> (TcpListener, String) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}/quotes", listener.local_addr().unwrap());
+    (listener, url)
+}
+
+#[tokio::test]
+async fn test_streams_quotes_answers_pings_and_ends_on_close() {
+    let (listener, url) = listen().await;
+    let server = tokio::spawn(async move {
+        let mut stream = serve(listener, None).await;
+        let mut out = Vec::new();
+        out.extend(frame(true, 0x1, br#"{"spot": 101.0}"#));
+        out.extend(frame(true, 0x9, b"hi"));
+        // A quote split across a text frame and a continuation frame.
+        out.extend(frame(false, 0x1, br#"{"sigma""#));
+        out.extend(frame(true, 0x0, br#": 0.25}"#));
+        out.extend(frame(true, 0x8, &[]));
+        stream.get_mut().write_all(&out).await.unwrap();
+
+        // The pong comes back masked with the ping's payload.
+        let mut head = [0u8; 2];
+        stream.read_exact(&mut head).await.unwrap();
+        assert_eq!(head, [0x8A, 0x82]);
+        let mut masked = [0u8; 6];
+        stream.read_exact(&mut masked).await.unwrap();
+        let payload: Vec<u8> = masked[4..]
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| byte ^ masked[i])
+            .collect();
+        assert_eq!(payload, b"hi");
+    });
+
+    let mut source = WebSocketSource::connect(&url).await.unwrap();
+    assert_eq!(
+        source.next_quote().await.unwrap(),
+        Some(Quote {
+            spot: Some(101.0),
+            ..Quote::default()
+        })
+    );
+    assert_eq!(
+        source.next_quote().await.unwrap(),
+        Some(Quote {
+            sigma: Some(0.25),
+            ..Quote::default()
+        })
+    );
+    assert_eq!(source.next_quote().await.unwrap(), None);
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejects_wrong_accept_key() {
+    let (listener, url) = listen().await;
+    let server = tokio::spawn(async move {
+        serve(listener, Some("bogus")).await;
+    });
+    assert!(matches!(
+        WebSocketSource::connect(&url).await,
+        Err(SourceError::Handshake(_))
+    ));
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejects_non_websocket_urls() {
+    assert!(matches!(
+        WebSocketSource::connect("http://example.com/quotes").await,
+        Err(SourceError::Handshake(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_wss_urls_negotiate_tls() {
+    // A plain TCP server cannot complete the TLS handshake a `wss://` URL starts with.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("wss://{}/quotes", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut hello = [0u8; 1];
+        stream.read_exact(&mut hello).await.unwrap();
+        // 0x16 opens a TLS handshake record.
+        assert_eq!(hello, [0x16]);
+    });
+    assert!(WebSocketSource::connect(&url).await.is_err());
+    server.await.unwrap();
+}